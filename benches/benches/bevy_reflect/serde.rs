@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use bevy_reflect::{
+    serde::{
+        BinaryReflectDeserializer, BinaryReflectSerializer, ReflectSerializer,
+        UntypedReflectDeserializer,
+    },
+    Reflect, TypeRegistry,
+};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use serde::de::{DeserializeSeed, SeqAccess, Visitor};
+use std::fmt::Formatter;
+
+criterion_group!(
+    benches,
+    ron_serialize,
+    ron_deserialize,
+    binary_serialize,
+    binary_deserialize,
+);
+criterion_main!(benches);
+
+const WARM_UP_TIME: Duration = Duration::from_millis(500);
+const MEASUREMENT_TIME: Duration = Duration::from_secs(4);
+const SIZES: [usize; 4] = [1, 16, 128, 1024];
+
+#[derive(Reflect, Default)]
+struct Transform {
+    translation: (f32, f32, f32),
+    rotation: (f32, f32, f32, f32),
+    scale: (f32, f32, f32),
+}
+
+#[derive(Reflect, Default)]
+struct Character {
+    name: String,
+    health: f32,
+    inventory: Vec<u32>,
+    transform: Transform,
+}
+
+fn get_registry() -> TypeRegistry {
+    let mut registry = TypeRegistry::default();
+    registry.register::<Character>();
+    registry.register::<Transform>();
+    registry.register::<String>();
+    registry.register::<f32>();
+    registry.register::<u32>();
+    registry.register::<Vec<u32>>();
+    registry.register::<(f32, f32, f32)>();
+    registry.register::<(f32, f32, f32, f32)>();
+    registry
+}
+
+fn get_values(count: usize) -> Vec<Character> {
+    (0..count)
+        .map(|i| Character {
+            name: format!("character_{i}"),
+            health: i as f32,
+            inventory: (0..8).collect(),
+            transform: Transform {
+                translation: (i as f32, 0.0, -(i as f32)),
+                rotation: (0.0, 0.0, 0.0, 1.0),
+                scale: (1.0, 1.0, 1.0),
+            },
+        })
+        .collect()
+}
+
+fn to_ron(values: &[Character], registry: &TypeRegistry) -> String {
+    let serializers = values
+        .iter()
+        .map(|value| ReflectSerializer::new(value, registry))
+        .collect::<Vec<_>>();
+    ron::to_string(&serializers).unwrap()
+}
+
+fn to_binary(values: &[Character], registry: &TypeRegistry) -> Vec<u8> {
+    let mut serializer = BinaryReflectSerializer::new(registry);
+    for value in values {
+        serializer.push(value);
+    }
+    serializer.to_bytes().unwrap()
+}
+
+/// Deserializes a list of values written by [`to_ron`].
+struct RonValuesDeserializer<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for RonValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for RonValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a list of reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(UntypedReflectDeserializer::new(self.0))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+fn ron_serialize(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("ron_serialize");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+
+    let registry = get_registry();
+    for size in SIZES {
+        let values = get_values(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &values,
+            |bencher, values| {
+                bencher.iter(|| to_ron(black_box(values), &registry));
+            },
+        );
+    }
+}
+
+fn ron_deserialize(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("ron_deserialize");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+
+    let registry = get_registry();
+    for size in SIZES {
+        let input = to_ron(&get_values(size), &registry);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &input,
+            |bencher, input| {
+                bencher.iter(|| {
+                    let mut deserializer = ron::Deserializer::from_str(black_box(input)).unwrap();
+                    RonValuesDeserializer(&registry)
+                        .deserialize(&mut deserializer)
+                        .unwrap()
+                });
+            },
+        );
+    }
+}
+
+fn binary_serialize(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("binary_serialize");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+
+    let registry = get_registry();
+    for size in SIZES {
+        let values = get_values(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &values,
+            |bencher, values| {
+                bencher.iter(|| to_binary(black_box(values), &registry));
+            },
+        );
+    }
+}
+
+fn binary_deserialize(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("binary_deserialize");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+
+    let registry = get_registry();
+    for size in SIZES {
+        let input = to_binary(&get_values(size), &registry);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &input,
+            |bencher, input| {
+                bencher.iter(|| {
+                    BinaryReflectDeserializer::new(&registry)
+                        .from_bytes(black_box(input))
+                        .unwrap()
+                });
+            },
+        );
+    }
+}
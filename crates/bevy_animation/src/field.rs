@@ -0,0 +1,713 @@
+//! Curves animating any reflected field of a component.
+
+use std::any::{type_name, TypeId};
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use bevy_math::{Quat, Vec2, Vec3, Vec4};
+use bevy_reflect::{
+    serde::{
+        DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry,
+        SerializeWithRegistry, TypedReflectDeserializer, TypedReflectSerializer,
+    },
+    FromReflect, ParsedPath, Reflect, TypeRegistry,
+};
+use bevy_render::color::Color;
+use bevy_utils::{tracing::warn, HashMap};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serializer,
+};
+
+use crate::{find_keyframe, AnimationLayerMode};
+
+/// List of keyframes of a [`FieldCurve`], for each of the types of field which can be animated.
+#[derive(Reflect, Clone, Debug)]
+pub enum FieldKeyframes {
+    /// Keyframes for a `f32` field.
+    F32(Vec<f32>),
+    /// Keyframes for a [`Vec2`] field.
+    Vec2(Vec<Vec2>),
+    /// Keyframes for a [`Vec3`] field.
+    Vec3(Vec<Vec3>),
+    /// Keyframes for a [`Vec4`] field.
+    Vec4(Vec<Vec4>),
+    /// Keyframes for a [`Quat`] field, using a spherical linear interpolation.
+    Quat(Vec<Quat>),
+    /// Keyframes for a [`Color`] field, interpolated in linear RGBA.
+    Color(Vec<Color>),
+}
+
+impl FieldKeyframes {
+    fn kind(&self) -> FieldKind {
+        match self {
+            FieldKeyframes::F32(_) => FieldKind::F32,
+            FieldKeyframes::Vec2(_) => FieldKind::Vec2,
+            FieldKeyframes::Vec3(_) => FieldKind::Vec3,
+            FieldKeyframes::Vec4(_) => FieldKind::Vec4,
+            FieldKeyframes::Quat(_) => FieldKind::Quat,
+            FieldKeyframes::Color(_) => FieldKind::Color,
+        }
+    }
+
+    /// The keyframe at `index`, packed in a [`Vec4`].
+    fn get(&self, index: usize) -> Vec4 {
+        match self {
+            FieldKeyframes::F32(keyframes) => Vec4::new(keyframes[index], 0.0, 0.0, 0.0),
+            FieldKeyframes::Vec2(keyframes) => keyframes[index].extend(0.0).extend(0.0),
+            FieldKeyframes::Vec3(keyframes) => keyframes[index].extend(0.0),
+            FieldKeyframes::Vec4(keyframes) => keyframes[index],
+            FieldKeyframes::Quat(keyframes) => Vec4::from(keyframes[index]),
+            FieldKeyframes::Color(keyframes) => {
+                Vec4::from_array(keyframes[index].as_linear_rgba_f32())
+            }
+        }
+    }
+}
+
+/// The type of a field animated by a [`FieldCurve`], whose values are packed in a [`Vec4`]
+/// while they are blended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FieldKind {
+    F32,
+    Vec2,
+    Vec3,
+    Vec4,
+    Quat,
+    Color,
+}
+
+impl FieldKind {
+    /// Packs a field of this kind in a [`Vec4`], returning `None` if the field is of another type.
+    fn read(self, field: &dyn Reflect) -> Option<Vec4> {
+        Some(match self {
+            FieldKind::F32 => Vec4::new(*field.downcast_ref::<f32>()?, 0.0, 0.0, 0.0),
+            FieldKind::Vec2 => field.downcast_ref::<Vec2>()?.extend(0.0).extend(0.0),
+            FieldKind::Vec3 => field.downcast_ref::<Vec3>()?.extend(0.0),
+            FieldKind::Vec4 => *field.downcast_ref::<Vec4>()?,
+            FieldKind::Quat => Vec4::from(*field.downcast_ref::<Quat>()?),
+            FieldKind::Color => {
+                Vec4::from_array(field.downcast_ref::<Color>()?.as_linear_rgba_f32())
+            }
+        })
+    }
+
+    /// Writes a packed value to a field of this kind, returning `None` if the field is of another type.
+    fn write(self, value: Vec4, field: &mut dyn Reflect) -> Option<()> {
+        match self {
+            FieldKind::F32 => *field.downcast_mut::<f32>()? = value.x,
+            FieldKind::Vec2 => *field.downcast_mut::<Vec2>()? = value.truncate().truncate(),
+            FieldKind::Vec3 => *field.downcast_mut::<Vec3>()? = value.truncate(),
+            FieldKind::Vec4 => *field.downcast_mut::<Vec4>()? = value,
+            FieldKind::Quat => *field.downcast_mut::<Quat>()? = Quat::from_vec4(value),
+            FieldKind::Color => {
+                let field = field.downcast_mut::<Color>()?;
+                let color = Color::rgba_linear(value.x, value.y, value.z, value.w);
+                // Keep the representation of the color
+                *field = match field {
+                    Color::Rgba { .. } => color.as_rgba(),
+                    Color::RgbaLinear { .. } => color,
+                    Color::Hsla { .. } => color.as_hsla(),
+                    Color::Lcha { .. } => color.as_lcha(),
+                };
+            }
+        }
+        Some(())
+    }
+
+    fn lerp(self, start: Vec4, end: Vec4, t: f32) -> Vec4 {
+        match self {
+            FieldKind::Quat => {
+                let start = Quat::from_vec4(start);
+                let mut end = Quat::from_vec4(end);
+                // Choose the smallest angle for the rotation
+                if end.dot(start) < 0.0 {
+                    end = -end;
+                }
+                Vec4::from(start.normalize().slerp(end.normalize(), t))
+            }
+            _ => start.lerp(end, t),
+        }
+    }
+}
+
+/// Describes how a field of a component should be animated, for fields which are not part of
+/// the [`Transform`](bevy_transform::prelude::Transform) or the
+/// [`MorphWeights`](bevy_render::mesh::morph::MorphWeights) of the entity.
+///
+/// The field is reached from the component with a [`ParsedPath`], and must be of the type of
+/// the keyframes. The component must be registered, and reflect [`Component`] with
+/// `#[reflect(Component)]`.
+///
+/// `keyframe_timestamps` and `keyframes` should have the same length.
+///
+/// The component is serialized by its type name, so it must be registered for the curve to be
+/// serialized or deserialized.
+///
+/// ```
+/// # use bevy_animation::{AnimationClip, EntityPath, FieldCurve, FieldKeyframes};
+/// # use bevy_core::Name;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::{ParsedPath, Reflect};
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Lamp {
+///     intensity: f32,
+/// }
+///
+/// let mut clip = AnimationClip::default();
+/// clip.add_field_curve_to_path(
+///     EntityPath {
+///         parts: vec![Name::new("lamp")],
+///     },
+///     FieldCurve::new::<Lamp>(
+///         ParsedPath::parse("intensity").unwrap(),
+///         vec![0.0, 1.0],
+///         FieldKeyframes::F32(vec![0.0, 800.0]),
+///     ),
+/// );
+/// ```
+#[derive(Reflect, Clone, Debug)]
+#[reflect_value(Debug, SerializeWithRegistry, DeserializeWithRegistry)]
+pub struct FieldCurve {
+    component: TypeId,
+    component_name: &'static str,
+    path: Arc<ParsedPath>,
+    /// Timestamp for each of the keyframes.
+    pub keyframe_timestamps: Vec<f32>,
+    /// List of the keyframes.
+    pub keyframes: FieldKeyframes,
+}
+
+impl FieldCurve {
+    /// Creates a curve animating the field at `path` in the component `C`.
+    pub fn new<C: Component>(
+        path: ParsedPath,
+        keyframe_timestamps: Vec<f32>,
+        keyframes: FieldKeyframes,
+    ) -> Self {
+        Self {
+            component: TypeId::of::<C>(),
+            component_name: type_name::<C>(),
+            path: Arc::new(path),
+            keyframe_timestamps,
+            keyframes,
+        }
+    }
+
+    /// The [`TypeId`] of the animated component.
+    pub fn component(&self) -> TypeId {
+        self.component
+    }
+
+    /// The path of the animated field in the component.
+    pub fn path(&self) -> &ParsedPath {
+        &self.path
+    }
+
+    pub(crate) fn key(&self) -> FieldKey {
+        FieldKey {
+            component: self.component,
+            component_name: self.component_name,
+            path: self.path.clone(),
+        }
+    }
+
+    /// Samples the curve at `elapsed`, returning `None` if the curve isn't started yet or is finished.
+    pub(crate) fn sample(&self, elapsed: f32) -> Option<Vec4> {
+        // Some curves have only one keyframe used to set a value
+        if self.keyframe_timestamps.len() == 1 {
+            return Some(self.keyframe(0));
+        }
+        let (step_start, lerp) = find_keyframe(&self.keyframe_timestamps, elapsed)?;
+        Some(self.keyframes.kind().lerp(
+            self.keyframes.get(step_start),
+            self.keyframes.get(step_start + 1),
+            lerp,
+        ))
+    }
+
+    /// The value of the keyframe at `index`, packed in a [`Vec4`].
+    pub(crate) fn keyframe(&self, index: usize) -> Vec4 {
+        self.keyframes.get(index)
+    }
+
+    pub(crate) fn blend(&self) -> FieldBlend {
+        FieldBlend::new(self.keyframes.kind())
+    }
+}
+
+const FIELD_CURVE_FIELDS: &[&str] = &["component", "path", "keyframe_timestamps", "keyframes"];
+
+impl SerializeWithRegistry for FieldCurve {
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldCurve", 4)?;
+        state.serialize_field("component", self.component_name)?;
+        state.serialize_field("path", &self.path.to_string())?;
+        state.serialize_field("keyframe_timestamps", &self.keyframe_timestamps)?;
+        state.serialize_field(
+            "keyframes",
+            &TypedReflectSerializer::new(&self.keyframes, registry),
+        )?;
+        state.end()
+    }
+}
+
+impl DeserializeWithRegistry for FieldCurve {
+    fn deserialize<'de, D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            "FieldCurve",
+            FIELD_CURVE_FIELDS,
+            FieldCurveVisitor { registry },
+        )
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum FieldCurveField {
+    Component,
+    Path,
+    KeyframeTimestamps,
+    Keyframes,
+}
+
+struct FieldCurveVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> FieldCurveVisitor<'a> {
+    fn keyframes_deserializer<E: de::Error>(&self) -> Result<TypedReflectDeserializer<'a>, E> {
+        let registration = self
+            .registry
+            .get(TypeId::of::<FieldKeyframes>())
+            .ok_or_else(|| E::custom("`FieldKeyframes` is not registered"))?;
+        Ok(TypedReflectDeserializer::new(registration, self.registry))
+    }
+
+    fn curve<E: de::Error>(
+        &self,
+        component: String,
+        path: String,
+        keyframe_timestamps: Vec<f32>,
+        keyframes: Box<dyn Reflect>,
+    ) -> Result<FieldCurve, E> {
+        let registration = self.registry.get_with_name(&component).ok_or_else(|| {
+            E::custom(format_args!(
+                "no registration found for component `{component}`"
+            ))
+        })?;
+        let path = ParsedPath::parse(&path).map_err(E::custom)?;
+        let keyframes = FieldKeyframes::from_reflect(&*keyframes)
+            .ok_or_else(|| E::custom("invalid field curve keyframes"))?;
+        Ok(FieldCurve {
+            component: registration.type_id(),
+            component_name: registration.type_name(),
+            path: Arc::new(path),
+            keyframe_timestamps,
+            keyframes,
+        })
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for FieldCurveVisitor<'a> {
+    type Value = FieldCurve;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a field curve")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let component = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let path = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let keyframe_timestamps = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        let keyframes = seq
+            .next_element_seed(self.keyframes_deserializer()?)?
+            .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+        self.curve(component, path, keyframe_timestamps, keyframes)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut component = None;
+        let mut path = None;
+        let mut keyframe_timestamps = None;
+        let mut keyframes = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                FieldCurveField::Component => {
+                    if component.is_some() {
+                        return Err(de::Error::duplicate_field("component"));
+                    }
+                    component = Some(map.next_value()?);
+                }
+                FieldCurveField::Path => {
+                    if path.is_some() {
+                        return Err(de::Error::duplicate_field("path"));
+                    }
+                    path = Some(map.next_value()?);
+                }
+                FieldCurveField::KeyframeTimestamps => {
+                    if keyframe_timestamps.is_some() {
+                        return Err(de::Error::duplicate_field("keyframe_timestamps"));
+                    }
+                    keyframe_timestamps = Some(map.next_value()?);
+                }
+                FieldCurveField::Keyframes => {
+                    if keyframes.is_some() {
+                        return Err(de::Error::duplicate_field("keyframes"));
+                    }
+                    keyframes = Some(map.next_value_seed(self.keyframes_deserializer()?)?);
+                }
+            }
+        }
+        self.curve(
+            component.ok_or_else(|| de::Error::missing_field("component"))?,
+            path.ok_or_else(|| de::Error::missing_field("path"))?,
+            keyframe_timestamps.ok_or_else(|| de::Error::missing_field("keyframe_timestamps"))?,
+            keyframes.ok_or_else(|| de::Error::missing_field("keyframes"))?,
+        )
+    }
+}
+
+/// The field animated by a [`FieldCurve`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct FieldKey {
+    component: TypeId,
+    component_name: &'static str,
+    path: Arc<ParsedPath>,
+}
+
+/// The weighted values sampled for a field, see `TargetBlend` for transforms.
+#[derive(Clone, Debug)]
+pub(crate) struct FieldBlend {
+    kind: FieldKind,
+    sum: Vec4,
+    weight: f32,
+    additive: Vec4,
+}
+
+impl FieldBlend {
+    fn new(kind: FieldKind) -> Self {
+        Self {
+            kind,
+            sum: Vec4::ZERO,
+            weight: 0.0,
+            additive: match kind {
+                FieldKind::Quat => Vec4::from(Quat::IDENTITY),
+                _ => Vec4::ZERO,
+            },
+        }
+    }
+
+    pub fn blend(&mut self, curve: &FieldCurve, value: Vec4, weight: f32) {
+        if curve.keyframes.kind() != self.kind {
+            return;
+        }
+        // Choose the smallest angle for the rotation
+        let value = if self.kind == FieldKind::Quat && self.sum.dot(value) < 0.0 {
+            -value
+        } else {
+            value
+        };
+        self.sum += value * weight;
+        self.weight += weight;
+    }
+
+    /// Adds the difference between `value` and `reference`, scaled by `weight`.
+    pub fn add(&mut self, curve: &FieldCurve, value: Vec4, reference: Vec4, weight: f32) {
+        if curve.keyframes.kind() != self.kind {
+            return;
+        }
+        if self.kind == FieldKind::Quat {
+            let difference = Quat::from_vec4(value) * Quat::from_vec4(reference).inverse();
+            let additive =
+                Quat::IDENTITY.slerp(difference, weight) * Quat::from_vec4(self.additive);
+            self.additive = Vec4::from(additive);
+        } else {
+            self.additive += (value - reference) * weight;
+        }
+    }
+
+    fn apply(&self, value: Vec4) -> Vec4 {
+        let value = if self.weight > 0.0 {
+            let average = match self.kind {
+                FieldKind::Quat => self.sum.normalize(),
+                _ => self.sum / self.weight,
+            };
+            if self.weight >= 1.0 {
+                average
+            } else {
+                // Keep the rest of the current value, see `TargetBlend`
+                self.kind.lerp(value, average, self.weight)
+            }
+        } else {
+            value
+        };
+        match self.kind {
+            FieldKind::Quat => Vec4::from(Quat::from_vec4(self.additive) * Quat::from_vec4(value)),
+            _ => value + self.additive,
+        }
+    }
+}
+
+/// Applies the base layer of an animated field, then each of the layers of the graph in order.
+fn apply_layers_to_field(
+    blends: &[FieldBlend],
+    layers: &[(AnimationLayerMode, f32)],
+    value: Vec4,
+) -> Vec4 {
+    let mut value = blends[0].apply(value);
+    for (blend, (mode, weight)) in blends[1..].iter().zip(layers) {
+        value = match mode {
+            AnimationLayerMode::Override => blend.kind.lerp(value, blend.apply(value), *weight),
+            AnimationLayerMode::Additive => blend.apply(value),
+        };
+    }
+    value
+}
+
+/// The fields of an entity sampled by an [`AnimationPlayer`](crate::AnimationPlayer).
+pub(crate) struct AnimatedFieldTarget {
+    pub entity: Entity,
+    /// The mode and weight of each layer of the player, above the base layer.
+    pub layers: Vec<(AnimationLayerMode, f32)>,
+    /// The weighted values sampled for each field, for the base layer then each of the layers.
+    pub fields: HashMap<FieldKey, Vec<FieldBlend>>,
+}
+
+/// The values sampled for the [`FieldCurve`]s by the [`animation_player`](crate::animation_player)
+/// system, written to the animated components by the [`animate_fields`] system.
+#[derive(Resource, Default)]
+pub struct AnimatedFields {
+    pub(crate) targets: Vec<AnimatedFieldTarget>,
+}
+
+/// System writing the values sampled for the [`FieldCurve`]s to the animated components,
+/// through their [`ReflectComponent`].
+pub fn animate_fields(world: &mut World) {
+    let targets = std::mem::take(&mut world.resource_mut::<AnimatedFields>().targets);
+    if targets.is_empty() {
+        return;
+    }
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    for target in targets {
+        let Some(mut entity) = world.get_entity_mut(target.entity) else {
+            continue;
+        };
+        for (key, blends) in &target.fields {
+            let Some(reflect_component) =
+                type_registry.get_type_data::<ReflectComponent>(key.component)
+            else {
+                warn!(
+                    "Cannot animate `{}`: it is not registered with `#[reflect(Component)]`",
+                    key.component_name
+                );
+                continue;
+            };
+            let Some(mut component) = reflect_component.reflect_mut(&mut entity) else {
+                continue;
+            };
+            let field = match key.path.reflect_element_mut(&mut *component) {
+                Ok(field) => field,
+                Err(error) => {
+                    warn!(
+                        "Cannot animate field `{}` of `{}`: {}",
+                        key.path, key.component_name, error
+                    );
+                    continue;
+                }
+            };
+            let kind = blends[0].kind;
+            let Some(value) = kind.read(field) else {
+                warn!(
+                    "Cannot animate field `{}` of `{}`: it is not of type {:?}",
+                    key.path, key.component_name, kind
+                );
+                continue;
+            };
+            let value = apply_layers_to_field(blends, &target.layers, value);
+            kind.write(value, field);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use bevy_ecs::prelude::*;
+    use bevy_math::{Quat, Vec2, Vec4};
+    use bevy_reflect::{ParsedPath, Reflect};
+    use bevy_render::color::Color;
+    use bevy_utils::HashMap;
+
+    use super::{animate_fields, apply_layers_to_field, AnimatedFieldTarget, AnimatedFields};
+    use crate::{AnimationLayerMode, FieldCurve, FieldKeyframes};
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Lamp {
+        intensity: f32,
+        radius: f32,
+        color: Color,
+    }
+
+    fn lamp_curve(field: &str, keyframes: FieldKeyframes) -> FieldCurve {
+        FieldCurve::new::<Lamp>(ParsedPath::parse(field).unwrap(), vec![0.0, 1.0], keyframes)
+    }
+
+    #[test]
+    fn should_interpolate_f32() {
+        let curve = lamp_curve("intensity", FieldKeyframes::F32(vec![0.0, 10.0]));
+        assert_eq!(curve.sample(0.25), Some(Vec4::new(2.5, 0.0, 0.0, 0.0)));
+        assert_eq!(curve.sample(-0.5), None);
+        assert_eq!(curve.sample(1.5), None);
+    }
+
+    #[test]
+    fn should_interpolate_quat_by_smallest_angle() {
+        // The same rotation as a quarter turn, with the opposite sign
+        let end = -Quat::from_rotation_y(FRAC_PI_2);
+        let curve = lamp_curve("rotation", FieldKeyframes::Quat(vec![Quat::IDENTITY, end]));
+        let sample = Quat::from_vec4(curve.sample(0.5).unwrap());
+        assert!(sample.abs_diff_eq(Quat::from_rotation_y(FRAC_PI_2 / 2.0), 1e-5));
+    }
+
+    #[test]
+    fn should_interpolate_color_in_linear_rgba() {
+        let curve = lamp_curve(
+            "color",
+            FieldKeyframes::Color(vec![
+                Color::rgba_linear(0.0, 0.0, 0.0, 1.0),
+                Color::rgba(1.0, 1.0, 1.0, 0.0),
+            ]),
+        );
+        assert_eq!(curve.sample(0.5), Some(Vec4::new(0.5, 0.5, 0.5, 0.5)));
+    }
+
+    #[test]
+    fn should_blend_field_values() {
+        let curve = lamp_curve("intensity", FieldKeyframes::F32(vec![1.0, 10.0]));
+        let mut blend = curve.blend();
+        blend.blend(&curve, Vec4::X * 10.0, 0.5);
+        blend.blend(&curve, Vec4::X * 20.0, 1.5);
+        assert_eq!(blend.apply(Vec4::ZERO), Vec4::X * 17.5);
+
+        // Values of another type are ignored
+        let other = lamp_curve("size", FieldKeyframes::Vec2(vec![Vec2::ZERO, Vec2::ONE]));
+        blend.blend(&other, Vec4::ONE, 1.0);
+        assert_eq!(blend.apply(Vec4::ZERO), Vec4::X * 17.5);
+
+        // The difference with the first keyframe is added on top of the blended value
+        blend.add(&curve, Vec4::X * 5.0, curve.keyframe(0), 0.5);
+        assert_eq!(blend.apply(Vec4::ZERO), Vec4::X * 19.5);
+    }
+
+    #[test]
+    fn should_keep_current_value_for_partial_weights() {
+        let curve = lamp_curve("intensity", FieldKeyframes::F32(vec![0.0, 10.0]));
+        let mut blend = curve.blend();
+        blend.blend(&curve, Vec4::X * 10.0, 0.25);
+        assert_eq!(blend.apply(Vec4::X * 2.0), Vec4::X * 4.0);
+        // Without any value, the current value is kept
+        assert_eq!(curve.blend().apply(Vec4::X * 2.0), Vec4::X * 2.0);
+    }
+
+    #[test]
+    fn should_apply_field_layers_in_order() {
+        let curve = lamp_curve("intensity", FieldKeyframes::F32(vec![0.0, 10.0]));
+        let mut base = curve.blend();
+        base.blend(&curve, Vec4::X * 10.0, 1.0);
+        let mut jump = curve.blend();
+        jump.blend(&curve, Vec4::X * 20.0, 1.0);
+        let mut offset = curve.blend();
+        offset.add(&curve, Vec4::X, Vec4::ZERO, 1.0);
+
+        let value = apply_layers_to_field(
+            &[base.clone(), jump.clone(), offset.clone()],
+            &[
+                (AnimationLayerMode::Override, 0.5),
+                (AnimationLayerMode::Additive, 1.0),
+            ],
+            Vec4::ZERO,
+        );
+        assert_eq!(value, Vec4::X * 16.0);
+        let value = apply_layers_to_field(
+            &[base, offset, jump],
+            &[
+                (AnimationLayerMode::Additive, 1.0),
+                (AnimationLayerMode::Override, 0.5),
+            ],
+            Vec4::ZERO,
+        );
+        assert_eq!(value, Vec4::X * 15.5);
+    }
+
+    #[test]
+    fn should_animate_fields() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Lamp>();
+        let lamp = world.spawn(Lamp::default()).id();
+
+        let intensity = lamp_curve("intensity", FieldKeyframes::F32(vec![0.0, 10.0]));
+        let color = lamp_curve(
+            "color",
+            FieldKeyframes::Color(vec![Color::BLACK, Color::WHITE]),
+        );
+        // A curve whose keyframes are not of the type of the field is ignored
+        let radius = lamp_curve("radius", FieldKeyframes::Vec2(vec![Vec2::ZERO, Vec2::ONE]));
+        let mut fields = HashMap::default();
+        for (curve, value) in [
+            (&intensity, Vec4::X * 5.0),
+            (&color, Vec4::new(0.5, 0.5, 0.5, 1.0)),
+            (&radius, Vec4::ONE),
+        ] {
+            let mut blend = curve.blend();
+            blend.blend(curve, value, 1.0);
+            fields.insert(curve.key(), vec![blend]);
+        }
+        world.insert_resource(AnimatedFields {
+            targets: vec![AnimatedFieldTarget {
+                entity: lamp,
+                layers: Vec::new(),
+                fields,
+            }],
+        });
+        animate_fields(&mut world);
+
+        let lamp = world.get::<Lamp>(lamp).unwrap();
+        assert_eq!(lamp.intensity, 5.0);
+        assert_eq!(lamp.radius, 0.0);
+        // The color keeps its representation, and is interpolated in linear RGBA
+        let Color::Rgba { red, .. } = lamp.color else {
+            panic!("expected a sRGB color")
+        };
+        assert_eq!(red, Color::rgba_linear(0.5, 0.5, 0.5, 1.0).as_rgba().r());
+    }
+}
@@ -0,0 +1,552 @@
+//! The [`AnimationGraph`] asset, which describes how to blend animation clips together.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use bevy_asset::Handle;
+use bevy_core::Name;
+use bevy_reflect::{Reflect, TypeUuid};
+use bevy_utils::HashMap;
+
+use crate::{AnimationClip, EntityPath};
+
+/// The index of a node in an [`AnimationGraph`].
+#[derive(Reflect, Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AnimationNodeIndex(pub(crate) usize);
+
+impl AnimationNodeIndex {
+    /// Returns the index of the node in [`AnimationGraph::nodes`].
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// What a node of an [`AnimationGraph`] does with the animations below it.
+#[derive(Reflect, Clone, Debug)]
+pub enum AnimationNodeKind {
+    /// Plays an [`AnimationClip`], when it is started on the [`AnimationPlayer`](crate::AnimationPlayer).
+    Clip(Handle<AnimationClip>),
+    /// Blends its children together, proportionally to their weights.
+    Blend,
+    /// Adds the motion of its children on top of the blend of the rest of the graph.
+    ///
+    /// The motion of a clip is measured from its first keyframe, so that an additive clip whose
+    /// first keyframe is the rest pose only adds the difference with that pose.
+    Add,
+    /// Applies its children on top of the layers below it, restricted to the entities of its mask.
+    ///
+    /// Layers are applied in the order they were added to the graph, after the clips which are not
+    /// in any layer.
+    Layer(AnimationLayer),
+}
+
+/// How an [`AnimationNodeKind::Layer`] is combined with the layers below it.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnimationLayerMode {
+    /// The children of the layer are blended together, then replace the result of the layers
+    /// below, proportionally to the weight of the layer.
+    #[default]
+    Override,
+    /// The children of the layer are added on top of the result of the layers below, like the
+    /// children of an [`AnimationNodeKind::Add`] node.
+    Additive,
+}
+
+/// A layer of an [`AnimationGraph`], see [`AnimationNodeKind::Layer`].
+#[derive(Reflect, Clone, Debug, Default)]
+pub struct AnimationLayer {
+    /// The name of the mask group of the graph restricting the entities animated by the layer.
+    ///
+    /// A layer without a mask animates every entity, unless it is inside another layer, in which
+    /// case it uses the mask of that layer. A layer whose mask group doesn't exist in the graph
+    /// doesn't animate anything.
+    pub mask: Option<String>,
+    /// How the layer is combined with the layers below it.
+    pub mode: AnimationLayerMode,
+}
+
+impl AnimationLayer {
+    /// Creates a layer with the given mode, animating every entity.
+    pub fn new(mode: AnimationLayerMode) -> Self {
+        Self { mask: None, mode }
+    }
+
+    /// Restricts the layer to the entities of the mask group with the given name.
+    pub fn with_mask(mut self, mask: impl Into<String>) -> Self {
+        self.mask = Some(mask.into());
+        self
+    }
+}
+
+/// A set of animated entities, identified by their [`EntityPath`] in the [`AnimationClip`]s.
+///
+/// Masks are added to an [`AnimationGraph`] as named mask groups, used by its layers.
+#[derive(Reflect, Clone, Debug, Default)]
+pub struct AnimationMask {
+    paths: Vec<EntityPath>,
+    subtrees: Vec<Name>,
+    #[reflect(ignore)]
+    version: MaskVersion,
+}
+
+/// Identifies the content of an [`AnimationMask`], so that the players only check which paths of
+/// a clip are in the mask again when it changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct MaskVersion(u64);
+
+impl Default for MaskVersion {
+    fn default() -> Self {
+        static NEXT_VERSION: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_VERSION.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl AnimationMask {
+    /// Creates an empty mask.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a single entity to the mask.
+    pub fn with_path(mut self, path: EntityPath) -> Self {
+        self.add_path(path);
+        self
+    }
+
+    /// Adds an entity and all its descendants to the mask, by the [`Name`] of the entity.
+    pub fn with_subtree(mut self, root: impl Into<Name>) -> Self {
+        self.add_subtree(root);
+        self
+    }
+
+    /// Adds a single entity to the mask.
+    pub fn add_path(&mut self, path: EntityPath) -> &mut Self {
+        self.paths.push(path);
+        self.version = MaskVersion::default();
+        self
+    }
+
+    /// Adds an entity and all its descendants to the mask, by the [`Name`] of the entity.
+    pub fn add_subtree(&mut self, root: impl Into<Name>) -> &mut Self {
+        self.subtrees.push(root.into());
+        self.version = MaskVersion::default();
+        self
+    }
+
+    /// Whether the entity at the given path is part of the mask.
+    pub fn contains(&self, path: &EntityPath) -> bool {
+        self.paths.contains(path) || path.parts.iter().any(|part| self.subtrees.contains(part))
+    }
+
+    pub(crate) fn version(&self) -> MaskVersion {
+        self.version
+    }
+}
+
+/// A node of an [`AnimationGraph`].
+#[derive(Reflect, Clone, Debug)]
+pub struct AnimationGraphNode {
+    /// What the node does with the animations below it.
+    pub kind: AnimationNodeKind,
+    /// The weight of the node, which multiplies the weights of the nodes below it.
+    ///
+    /// It can be overridden for a single player with [`AnimationPlayer::set_node_weight`](crate::AnimationPlayer::set_node_weight).
+    pub weight: f32,
+    children: Vec<AnimationNodeIndex>,
+}
+
+impl AnimationGraphNode {
+    /// The nodes below this one.
+    pub fn children(&self) -> &[AnimationNodeIndex] {
+        &self.children
+    }
+
+    /// The clip played by this node, if it is a [`AnimationNodeKind::Clip`] node.
+    pub fn clip(&self) -> Option<&Handle<AnimationClip>> {
+        match &self.kind {
+            AnimationNodeKind::Clip(clip) => Some(clip),
+            _ => None,
+        }
+    }
+
+    /// The layer of this node, if it is a [`AnimationNodeKind::Layer`] node.
+    pub fn layer(&self) -> Option<&AnimationLayer> {
+        match &self.kind {
+            AnimationNodeKind::Layer(layer) => Some(layer),
+            _ => None,
+        }
+    }
+}
+
+/// A tree of animation nodes, describing how the [`AnimationClip`]s played by an
+/// [`AnimationPlayer`](crate::AnimationPlayer) are blended together.
+///
+/// Add a [`Handle<AnimationGraph>`] to the entity of the player, and start the clip nodes with
+/// [`AnimationPlayer::play_node`](crate::AnimationPlayer::play_node).
+///
+/// The root of the graph is a [`AnimationNodeKind::Blend`] node.
+///
+/// ```
+/// # use bevy_animation::{AnimationClip, AnimationGraph};
+/// # use bevy_asset::Handle;
+/// # let (walk, run, aim): (Handle<AnimationClip>, Handle<AnimationClip>, Handle<AnimationClip>) = Default::default();
+/// let mut graph = AnimationGraph::new();
+/// // Blend walking and running depending on the speed of the character
+/// let locomotion = graph.add_blend(1.0, graph.root());
+/// let walk = graph.add_clip(walk, 0.7, locomotion);
+/// let run = graph.add_clip(run, 0.3, locomotion);
+/// // And aim on top of it
+/// let additive = graph.add_additive(1.0, graph.root());
+/// let aim = graph.add_clip(aim, 1.0, additive);
+/// ```
+///
+/// Parts of the body can be animated separately with layers, restricted to named mask groups:
+///
+/// ```
+/// # use bevy_animation::{AnimationClip, AnimationGraph, AnimationLayer, AnimationLayerMode, AnimationMask};
+/// # use bevy_asset::Handle;
+/// # let (run, shoot): (Handle<AnimationClip>, Handle<AnimationClip>) = Default::default();
+/// let mut graph = AnimationGraph::new();
+/// graph.add_mask_group("upper_body", AnimationMask::new().with_subtree("Spine"));
+/// let run = graph.add_clip(run, 1.0, graph.root());
+/// // Shoot while running
+/// let upper_body = graph.add_layer(
+///     AnimationLayer::new(AnimationLayerMode::Override).with_mask("upper_body"),
+///     1.0,
+///     graph.root(),
+/// );
+/// let shoot = graph.add_clip(shoot, 1.0, upper_body);
+/// ```
+#[derive(Reflect, Clone, TypeUuid, Debug)]
+#[uuid = "5a3b6e8f-2f5c-4d0a-9a3e-8d1f7c2b4e61"]
+pub struct AnimationGraph {
+    nodes: Vec<AnimationGraphNode>,
+    mask_groups: HashMap<String, AnimationMask>,
+}
+
+impl Default for AnimationGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimationGraph {
+    /// Creates a graph with only a root [`AnimationNodeKind::Blend`] node.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![AnimationGraphNode {
+                kind: AnimationNodeKind::Blend,
+                weight: 1.0,
+                children: Vec::new(),
+            }],
+            mask_groups: HashMap::default(),
+        }
+    }
+
+    /// Creates a graph playing a single clip, returning the graph and the node of the clip.
+    pub fn from_clip(clip: Handle<AnimationClip>) -> (Self, AnimationNodeIndex) {
+        let mut graph = Self::new();
+        let node = graph.add_clip(clip, 1.0, graph.root());
+        (graph, node)
+    }
+
+    /// The root node of the graph.
+    pub fn root(&self) -> AnimationNodeIndex {
+        AnimationNodeIndex(0)
+    }
+
+    /// Adds a node below `parent`, returning its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is not a node of this graph.
+    pub fn add_node(
+        &mut self,
+        kind: AnimationNodeKind,
+        weight: f32,
+        parent: AnimationNodeIndex,
+    ) -> AnimationNodeIndex {
+        let index = AnimationNodeIndex(self.nodes.len());
+        self.nodes[parent.0].children.push(index);
+        self.nodes.push(AnimationGraphNode {
+            kind,
+            weight,
+            children: Vec::new(),
+        });
+        index
+    }
+
+    /// Adds a [`AnimationNodeKind::Clip`] node below `parent`, returning its index.
+    pub fn add_clip(
+        &mut self,
+        clip: Handle<AnimationClip>,
+        weight: f32,
+        parent: AnimationNodeIndex,
+    ) -> AnimationNodeIndex {
+        self.add_node(AnimationNodeKind::Clip(clip), weight, parent)
+    }
+
+    /// Adds a [`AnimationNodeKind::Blend`] node below `parent`, returning its index.
+    pub fn add_blend(&mut self, weight: f32, parent: AnimationNodeIndex) -> AnimationNodeIndex {
+        self.add_node(AnimationNodeKind::Blend, weight, parent)
+    }
+
+    /// Adds a [`AnimationNodeKind::Add`] node below `parent`, returning its index.
+    pub fn add_additive(&mut self, weight: f32, parent: AnimationNodeIndex) -> AnimationNodeIndex {
+        self.add_node(AnimationNodeKind::Add, weight, parent)
+    }
+
+    /// Adds a [`AnimationNodeKind::Layer`] node below `parent`, returning its index.
+    pub fn add_layer(
+        &mut self,
+        layer: AnimationLayer,
+        weight: f32,
+        parent: AnimationNodeIndex,
+    ) -> AnimationNodeIndex {
+        self.add_node(AnimationNodeKind::Layer(layer), weight, parent)
+    }
+
+    /// Adds a named mask group, which can be used by the layers of the graph.
+    ///
+    /// Replaces any mask group with the same name.
+    pub fn add_mask_group(&mut self, name: impl Into<String>, mask: AnimationMask) -> &mut Self {
+        self.mask_groups.insert(name.into(), mask);
+        self
+    }
+
+    /// Gets a mask group by name.
+    pub fn mask_group(&self, name: &str) -> Option<&AnimationMask> {
+        self.mask_groups.get(name)
+    }
+
+    /// Gets a mask group by name mutably.
+    pub fn mask_group_mut(&mut self, name: &str) -> Option<&mut AnimationMask> {
+        self.mask_groups.get_mut(name)
+    }
+
+    /// Removes a mask group, returning it.
+    pub fn remove_mask_group(&mut self, name: &str) -> Option<AnimationMask> {
+        self.mask_groups.remove(name)
+    }
+
+    /// Gets a node of the graph.
+    pub fn get(&self, node: AnimationNodeIndex) -> Option<&AnimationGraphNode> {
+        self.nodes.get(node.0)
+    }
+
+    /// Gets a node of the graph mutably, to change its weight for every player using the graph.
+    pub fn get_mut(&mut self, node: AnimationNodeIndex) -> Option<&mut AnimationGraphNode> {
+        self.nodes.get_mut(node.0)
+    }
+
+    /// The nodes of the graph, along with their index.
+    pub fn nodes(&self) -> impl Iterator<Item = (AnimationNodeIndex, &AnimationGraphNode)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (AnimationNodeIndex(index), node))
+    }
+
+    /// Computes the weight of every clip node and layer of the graph.
+    ///
+    /// The weight of a node is the product of its own weight with the weights of its ancestors,
+    /// up to the closest layer, where `weight_of` gives the weight of each node.
+    pub(crate) fn weights(
+        &self,
+        weight_of: impl Fn(AnimationNodeIndex, &AnimationGraphNode) -> f32,
+    ) -> GraphWeights<'_> {
+        let mut clips = Vec::new();
+        let mut layers = Vec::new();
+        let mut stack = vec![(self.root(), 1.0, false, None, None)];
+        while let Some((index, parent_weight, mut additive, mut layer, mut mask)) = stack.pop() {
+            let Some(node) = self.get(index) else {
+                continue;
+            };
+            let mut weight = parent_weight * weight_of(index, node);
+            match &node.kind {
+                AnimationNodeKind::Clip(clip) => clips.push(ClipWeight {
+                    node: index,
+                    clip,
+                    weight,
+                    additive,
+                    layer,
+                    mask,
+                }),
+                AnimationNodeKind::Blend => {}
+                AnimationNodeKind::Add => additive = true,
+                AnimationNodeKind::Layer(animation_layer) => {
+                    if let Some(name) = &animation_layer.mask {
+                        // A layer with an unknown mask group doesn't animate anything
+                        let Some(mask_group) = self.mask_group(name) else {
+                            continue;
+                        };
+                        mask = Some(mask_group);
+                    }
+                    layers.push(LayerWeight {
+                        node: index,
+                        weight,
+                        mode: animation_layer.mode,
+                    });
+                    layer = Some(index);
+                    additive = animation_layer.mode == AnimationLayerMode::Additive;
+                    if !additive {
+                        weight = 1.0;
+                    }
+                }
+            }
+            stack.extend(
+                node.children
+                    .iter()
+                    .map(|child| (*child, weight, additive, layer, mask)),
+            );
+        }
+        layers.sort_by_key(|layer| layer.node);
+        GraphWeights { clips, layers }
+    }
+}
+
+/// The weights of the nodes of an [`AnimationGraph`], see [`AnimationGraph::weights`].
+pub(crate) struct GraphWeights<'a> {
+    pub clips: Vec<ClipWeight<'a>>,
+    /// The layers of the graph, in the order they are applied.
+    pub layers: Vec<LayerWeight>,
+}
+
+impl GraphWeights<'_> {
+    /// The position of the layer of a clip among the layers of the player, where 0 is the base
+    /// layer for clips which are not in any layer.
+    pub fn layer_slot(&self, clip: &ClipWeight) -> usize {
+        clip.layer.map_or(0, |node| {
+            1 + self
+                .layers
+                .binary_search_by_key(&node, |layer| layer.node)
+                .unwrap()
+        })
+    }
+}
+
+/// The weight of a clip node of an [`AnimationGraph`].
+pub(crate) struct ClipWeight<'a> {
+    pub node: AnimationNodeIndex,
+    pub clip: &'a Handle<AnimationClip>,
+    pub weight: f32,
+    pub additive: bool,
+    /// The closest layer above the node.
+    pub layer: Option<AnimationNodeIndex>,
+    /// The mask of the closest layer with a mask above the node.
+    pub mask: Option<&'a AnimationMask>,
+}
+
+/// The weight of a layer of an [`AnimationGraph`].
+pub(crate) struct LayerWeight {
+    pub node: AnimationNodeIndex,
+    pub weight: f32,
+    pub mode: AnimationLayerMode,
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Handle;
+
+    use crate::{AnimationGraph, AnimationLayer, AnimationLayerMode, AnimationMask, EntityPath};
+
+    fn path(parts: &[&str]) -> EntityPath {
+        EntityPath {
+            parts: parts.iter().map(|part| (*part).into()).collect(),
+        }
+    }
+
+    #[test]
+    fn should_inherit_mask_in_nested_layers() {
+        let mut graph = AnimationGraph::new();
+        graph.add_mask_group("upper_body", AnimationMask::new().with_subtree("Spine"));
+        let upper_body = graph.add_layer(
+            AnimationLayer::new(AnimationLayerMode::Override).with_mask("upper_body"),
+            0.5,
+            graph.root(),
+        );
+        let arms = graph.add_layer(
+            AnimationLayer::new(AnimationLayerMode::Additive),
+            1.0,
+            upper_body,
+        );
+        let clip = graph.add_clip(Handle::default(), 0.5, arms);
+
+        let weights = graph.weights(|_, node| node.weight);
+        let [clip_weight] = &weights.clips[..] else {
+            panic!("expected a single clip")
+        };
+        assert_eq!(clip_weight.node, clip);
+        assert_eq!(clip_weight.layer, Some(arms));
+        assert!(clip_weight.additive);
+        // The weight of the override layer is applied when blending the layer, not to its children
+        assert_eq!(clip_weight.weight, 0.5);
+        let mask = clip_weight.mask.unwrap();
+        assert!(mask.contains(&path(&["root", "Spine", "Arm"])));
+        assert!(!mask.contains(&path(&["root", "Leg"])));
+        assert_eq!(weights.layer_slot(clip_weight), 2);
+    }
+
+    #[test]
+    fn should_skip_layers_with_unknown_mask_group() {
+        let mut graph = AnimationGraph::new();
+        let base = graph.add_clip(Handle::default(), 1.0, graph.root());
+        let layer = graph.add_layer(
+            AnimationLayer::new(AnimationLayerMode::Override).with_mask("missing"),
+            1.0,
+            graph.root(),
+        );
+        graph.add_clip(Handle::default(), 1.0, layer);
+
+        let weights = graph.weights(|_, node| node.weight);
+        assert!(weights.layers.is_empty());
+        let [clip_weight] = &weights.clips[..] else {
+            panic!("expected a single clip")
+        };
+        assert_eq!(clip_weight.node, base);
+        assert_eq!(weights.layer_slot(clip_weight), 0);
+    }
+
+    #[test]
+    fn should_order_layers_as_added() {
+        let mut graph = AnimationGraph::new();
+        let additive = graph.add_layer(
+            AnimationLayer::new(AnimationLayerMode::Additive),
+            1.0,
+            graph.root(),
+        );
+        let nested = graph.add_layer(
+            AnimationLayer::new(AnimationLayerMode::Override),
+            0.25,
+            additive,
+        );
+        let last = graph.add_layer(
+            AnimationLayer::new(AnimationLayerMode::Override),
+            0.5,
+            graph.root(),
+        );
+
+        let weights = graph.weights(|_, node| node.weight);
+        let layers: Vec<_> = weights
+            .layers
+            .iter()
+            .map(|layer| (layer.node, layer.mode, layer.weight))
+            .collect();
+        assert_eq!(
+            layers,
+            vec![
+                (additive, AnimationLayerMode::Additive, 1.0),
+                (nested, AnimationLayerMode::Override, 0.25),
+                (last, AnimationLayerMode::Override, 0.5),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_change_mask_version_when_modified() {
+        let mut mask = AnimationMask::new().with_subtree("Spine");
+        let version = mask.version();
+        assert_eq!(mask.clone().version(), version);
+        mask.add_path(path(&["root", "Head"]));
+        assert_ne!(mask.version(), version);
+    }
+}
@@ -3,26 +3,46 @@
 #![warn(missing_docs)]
 #![allow(clippy::type_complexity)]
 
+use std::fmt;
 use std::ops::Deref;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use bevy_app::{App, Plugin, PostUpdate};
-use bevy_asset::{AddAsset, Assets, Handle};
+use bevy_asset::{AddAsset, Assets, Handle, HandleId};
 use bevy_core::Name;
 use bevy_ecs::prelude::*;
 use bevy_hierarchy::{Children, Parent};
-use bevy_math::{Quat, Vec3};
-use bevy_reflect::{Reflect, TypeUuid};
-use bevy_render::mesh::morph::MorphWeights;
+use bevy_math::{Quat, Vec2, Vec3, Vec4};
+use bevy_reflect::{
+    serde::{
+        DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry,
+        ReflectSerializer, SerializeWithRegistry, UntypedReflectDeserializer,
+    },
+    Reflect, ReflectDeserialize, ReflectFromReflect, ReflectSerialize, TypeRegistry, TypeUuid,
+};
+use bevy_render::{color::Color, mesh::morph::MorphWeights};
 use bevy_time::Time;
 use bevy_transform::{prelude::Transform, TransformSystem};
 use bevy_utils::{tracing::warn, HashMap};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+mod field;
+mod graph;
+
+pub use field::*;
+pub use graph::*;
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        AnimationClip, AnimationPlayer, AnimationPlugin, EntityPath, Keyframes, VariableCurve,
+        AnimationClip, AnimationEvent, AnimationGraph, AnimationPlayer, AnimationPlugin,
+        EntityPath, Keyframes, VariableCurve,
     };
 }
 
@@ -57,20 +77,269 @@ pub struct VariableCurve {
     pub keyframes: Keyframes,
 }
 
+/// Finds the keyframe starting the step of a curve at `elapsed`, and the interpolation factor
+/// with the next keyframe, returning `None` if the curve isn't started yet or is finished.
+fn find_keyframe(keyframe_timestamps: &[f32], elapsed: f32) -> Option<(usize, f32)> {
+    // Find the current keyframe
+    // PERF: finding the current keyframe can be optimised
+    let step_start =
+        match keyframe_timestamps.binary_search_by(|probe| probe.partial_cmp(&elapsed).unwrap()) {
+            Ok(n) if n >= keyframe_timestamps.len() - 1 => return None, // this curve is finished
+            Ok(i) => i,
+            Err(0) => return None, // this curve isn't started yet
+            Err(n) if n > keyframe_timestamps.len() - 1 => return None, // this curve is finished
+            Err(i) => i - 1,
+        };
+    let ts_start = keyframe_timestamps[step_start];
+    let ts_end = keyframe_timestamps[step_start + 1];
+    Some((step_start, (elapsed - ts_start) / (ts_end - ts_start)))
+}
+
+/// A value of a [`VariableCurve`] at a given time.
+enum CurveSample {
+    Rotation(Quat),
+    Translation(Vec3),
+    Scale(Vec3),
+    Weights(Vec<f32>),
+}
+
+impl VariableCurve {
+    /// Samples the curve at `elapsed`, returning `None` if the curve isn't started yet or is finished.
+    fn sample(&self, elapsed: f32) -> Option<CurveSample> {
+        // Some curves have only one keyframe used to set a transform
+        if self.keyframe_timestamps.len() == 1 {
+            return Some(self.keyframe(0));
+        }
+
+        let (step_start, lerp) = find_keyframe(&self.keyframe_timestamps, elapsed)?;
+        let sample = match (self.keyframe(step_start), self.keyframe(step_start + 1)) {
+            (CurveSample::Rotation(rot_start), CurveSample::Rotation(mut rot_end)) => {
+                // Choose the smallest angle for the rotation
+                if rot_end.dot(rot_start) < 0.0 {
+                    rot_end = -rot_end;
+                }
+                // Rotations are using a spherical linear interpolation
+                CurveSample::Rotation(rot_start.normalize().slerp(rot_end.normalize(), lerp))
+            }
+            (CurveSample::Translation(start), CurveSample::Translation(end)) => {
+                CurveSample::Translation(start.lerp(end, lerp))
+            }
+            (CurveSample::Scale(start), CurveSample::Scale(end)) => {
+                CurveSample::Scale(start.lerp(end, lerp))
+            }
+            (CurveSample::Weights(start), CurveSample::Weights(end)) => CurveSample::Weights(
+                start
+                    .iter()
+                    .zip(end)
+                    .map(|(start, end)| start + (end - start) * lerp)
+                    .collect(),
+            ),
+            _ => unreachable!(),
+        };
+        Some(sample)
+    }
+
+    /// The value of the keyframe at `index`.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is not a keyframe of the curve.
+    fn keyframe(&self, index: usize) -> CurveSample {
+        match &self.keyframes {
+            Keyframes::Rotation(keyframes) => CurveSample::Rotation(keyframes[index]),
+            Keyframes::Translation(keyframes) => CurveSample::Translation(keyframes[index]),
+            Keyframes::Scale(keyframes) => CurveSample::Scale(keyframes[index]),
+            Keyframes::Weights(keyframes) => {
+                let target_count = keyframes.len() / self.keyframe_timestamps.len();
+                let start = target_count * index;
+                CurveSample::Weights(keyframes[start..start + target_count].to_vec())
+            }
+        }
+    }
+}
+
 /// Path to an entity, with [`Name`]s. Each entity in a path must have a name.
+///
+/// It is serialized as the list of its names, so that it can be deserialized as a key of
+/// [`AnimationClip::paths`].
 #[derive(Reflect, Clone, Debug, Hash, PartialEq, Eq, Default)]
+#[reflect(Hash, PartialEq, Serialize, Deserialize)]
 pub struct EntityPath {
     /// Parts of the path
     pub parts: Vec<Name>,
 }
 
-/// A list of [`VariableCurve`], and the [`EntityPath`] to which they apply.
+impl Serialize for EntityPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.parts.iter().map(Name::as_str))
+    }
+}
+
+impl<'de> Deserialize<'de> for EntityPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let parts = Vec::<String>::deserialize(deserializer)?;
+        Ok(Self {
+            parts: parts.into_iter().map(Name::new).collect(),
+        })
+    }
+}
+
+/// An event of an [`AnimationClip`], fired when the playback reaches its time.
+///
+/// The value of the event is serialized along with its type name, so its type must be registered
+/// for the clip to be serialized or deserialized.
+#[derive(Reflect, Clone, Debug)]
+#[reflect_value(Debug, SerializeWithRegistry, DeserializeWithRegistry)]
+pub struct AnimationClipEvent {
+    /// Time of the event in the clip, in seconds.
+    pub time: f32,
+    event: Arc<dyn Reflect>,
+}
+
+impl AnimationClipEvent {
+    /// The value of the event.
+    pub fn event(&self) -> &dyn Reflect {
+        &*self.event
+    }
+
+    fn fire(&self, player: Entity, clip: &Handle<AnimationClip>, weight: f32) -> AnimationEvent {
+        AnimationEvent {
+            player,
+            clip: clip.clone_weak(),
+            time: self.time,
+            weight,
+            event: self.event.clone(),
+        }
+    }
+}
+
+const CLIP_EVENT_FIELDS: &[&str] = &["time", "event"];
+
+impl SerializeWithRegistry for AnimationClipEvent {
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AnimationClipEvent", 2)?;
+        state.serialize_field("time", &self.time)?;
+        state.serialize_field("event", &ReflectSerializer::new(&*self.event, registry))?;
+        state.end()
+    }
+}
+
+impl DeserializeWithRegistry for AnimationClipEvent {
+    fn deserialize<'de, D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            "AnimationClipEvent",
+            CLIP_EVENT_FIELDS,
+            ClipEventVisitor { registry },
+        )
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum ClipEventField {
+    Time,
+    Event,
+}
+
+struct ClipEventVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ClipEventVisitor<'a> {
+    /// Deserializes the value of an event, converting it to its concrete type when it reflects
+    /// [`FromReflect`](bevy_reflect::FromReflect), so that [`AnimationEvent::get`] can return it.
+    fn event<'de, A: MapAccess<'de>>(&self, map: &mut A) -> Result<Arc<dyn Reflect>, A::Error> {
+        let event = map.next_value_seed(UntypedReflectDeserializer::new(self.registry))?;
+        Ok(self.concrete(event).into())
+    }
+
+    fn concrete(&self, event: Box<dyn Reflect>) -> Box<dyn Reflect> {
+        event
+            .get_represented_type_info()
+            .and_then(|info| {
+                self.registry
+                    .get_type_data::<ReflectFromReflect>(info.type_id())
+            })
+            .and_then(|from_reflect| from_reflect.from_reflect(&*event))
+            .unwrap_or(event)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ClipEventVisitor<'a> {
+    type Value = AnimationClipEvent;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an animation clip event")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let time = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let event = seq
+            .next_element_seed(UntypedReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(AnimationClipEvent {
+            time,
+            event: self.concrete(event).into(),
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut time = None;
+        let mut event = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ClipEventField::Time => {
+                    if time.is_some() {
+                        return Err(de::Error::duplicate_field("time"));
+                    }
+                    time = Some(map.next_value()?);
+                }
+                ClipEventField::Event => {
+                    if event.is_some() {
+                        return Err(de::Error::duplicate_field("event"));
+                    }
+                    event = Some(self.event(&mut map)?);
+                }
+            }
+        }
+        Ok(AnimationClipEvent {
+            time: time.ok_or_else(|| de::Error::missing_field("time"))?,
+            event: event.ok_or_else(|| de::Error::missing_field("event"))?,
+        })
+    }
+}
+
+/// A list of [`VariableCurve`] and [`FieldCurve`], and the [`EntityPath`] to which they apply.
 #[derive(Reflect, Clone, TypeUuid, Debug, Default)]
 #[uuid = "d81b7179-0448-4eb0-89fe-c067222725bf"]
 pub struct AnimationClip {
     curves: Vec<Vec<VariableCurve>>,
+    // Indexed by the bone ID, may be shorter than `curves`
+    field_curves: Vec<Vec<FieldCurve>>,
     paths: HashMap<EntityPath, usize>,
     duration: f32,
+    // Sorted by time
+    events: Vec<AnimationClipEvent>,
 }
 
 impl AnimationClip {
@@ -123,10 +392,171 @@ impl AnimationClip {
         }
     }
 
+    /// Gets the [`FieldCurve`]s for a bone.
+    ///
+    /// Returns `None` if the bone is invalid or has no field curves.
+    #[inline]
+    pub fn get_field_curves(&self, bone_id: usize) -> Option<&'_ Vec<FieldCurve>> {
+        self.field_curves.get(bone_id)
+    }
+
+    /// Add a [`FieldCurve`] to an [`EntityPath`].
+    pub fn add_field_curve_to_path(&mut self, path: EntityPath, curve: FieldCurve) {
+        // Update the duration of the animation by this curve duration if it's longer
+        self.duration = self
+            .duration
+            .max(*curve.keyframe_timestamps.last().unwrap_or(&0.0));
+        let bone_id = *self.paths.entry(path).or_insert_with(|| {
+            self.curves.push(Vec::new());
+            self.curves.len() - 1
+        });
+        if self.field_curves.len() <= bone_id {
+            self.field_curves.resize(bone_id + 1, Vec::new());
+        }
+        self.field_curves[bone_id].push(curve);
+    }
+
     /// Whether this animation clip can run on entity with given [`Name`].
     pub fn compatible_with(&self, name: &Name) -> bool {
         self.paths.keys().all(|path| &path.parts[0] == name)
     }
+
+    /// Add an event, sent as an [`AnimationEvent`] when the playback of the clip reaches `time`,
+    /// in seconds.
+    ///
+    /// ```
+    /// # use bevy_animation::{AnimationClip, AnimationEvent};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::Reflect;
+    /// #[derive(Reflect)]
+    /// struct Footstep {
+    ///     left: bool,
+    /// }
+    ///
+    /// let mut clip = AnimationClip::default();
+    /// clip.add_event(0.2, Footstep { left: true });
+    /// clip.add_event(0.7, Footstep { left: false });
+    ///
+    /// fn play_footsteps(mut events: EventReader<AnimationEvent>) {
+    ///     for event in events.iter() {
+    ///         if let Some(footstep) = event.get::<Footstep>() {
+    ///             // Play the sound of the footstep
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn add_event(&mut self, time: f32, event: impl Reflect) {
+        self.add_event_reflect(time, Box::new(event));
+    }
+
+    /// Add an event from a reflected value, for example one deserialized with a
+    /// [`UntypedReflectDeserializer`](bevy_reflect::serde::UntypedReflectDeserializer).
+    ///
+    /// Note that [`AnimationEvent::get`] only returns the event if it is of the concrete type,
+    /// use [`FromReflect`](bevy_reflect::FromReflect) to convert dynamic values.
+    pub fn add_event_reflect(&mut self, time: f32, event: Box<dyn Reflect>) {
+        // Update the duration of the animation by this event if it's later
+        self.duration = self.duration.max(time);
+        let index = self.events.partition_point(|event| event.time <= time);
+        self.events.insert(
+            index,
+            AnimationClipEvent {
+                time,
+                event: event.into(),
+            },
+        );
+    }
+
+    /// The events of the clip, sorted by time.
+    pub fn events(&self) -> &[AnimationClipEvent] {
+        &self.events
+    }
+
+    /// The events crossed by the playback going from `from` to `to` seconds, in the order
+    /// they are crossed.
+    ///
+    /// Events exactly at `from` are crossed, and events exactly at `to` are not, so that each
+    /// event is crossed once when the playback continues from `to`. The events at the end of the
+    /// clip when playing forward, or at its start when playing backward, are instead crossed
+    /// when the playback reaches them, so that they are not crossed when starting at the other
+    /// end of the clip.
+    fn crossed_events(&self, from: f32, to: f32, repeat: bool) -> Vec<&AnimationClipEvent> {
+        let mut crossed = Vec::new();
+        if from == to || self.events.is_empty() {
+            return crossed;
+        }
+        let duration = self.duration;
+        // The loops of the clip overlapping the playback. A non-repeating clip is sampled from
+        // its end when the elapsed time is negative.
+        let loops = if duration <= 0.0 {
+            0..=0
+        } else if repeat {
+            let start = (from.min(to) / duration).floor() as i64 - 1;
+            let end = (from.max(to) / duration).floor() as i64;
+            start..=end
+        } else {
+            -1..=0
+        };
+
+        if from < to {
+            for k in loops {
+                for event in &self.events {
+                    let time = event.time + k as f32 * duration;
+                    let is_crossed = if event.time == duration {
+                        from < time && time <= to
+                    } else {
+                        from <= time && time < to
+                    };
+                    if is_crossed {
+                        crossed.push(event);
+                    }
+                }
+            }
+        } else {
+            for k in loops.rev() {
+                for event in self.events.iter().rev() {
+                    let time = event.time + k as f32 * duration;
+                    let is_crossed = if event.time == 0.0 {
+                        to <= time && time < from
+                    } else {
+                        to < time && time <= from
+                    };
+                    if is_crossed {
+                        crossed.push(event);
+                    }
+                }
+            }
+        }
+        crossed
+    }
+}
+
+/// An event of an [`AnimationClip`], sent when an [`AnimationPlayer`] reaches its time.
+///
+/// See [`AnimationClip::add_event`].
+#[derive(Event, Clone, Debug)]
+pub struct AnimationEvent {
+    /// The entity of the [`AnimationPlayer`].
+    pub player: Entity,
+    /// A weak handle to the clip of the event.
+    pub clip: Handle<AnimationClip>,
+    /// Time of the event in the clip, in seconds.
+    pub time: f32,
+    /// Weight of the clip when the event was fired, which is 0 for clips which are faded out.
+    pub weight: f32,
+    event: Arc<dyn Reflect>,
+}
+
+impl AnimationEvent {
+    /// The value of the event.
+    pub fn event(&self) -> &dyn Reflect {
+        &*self.event
+    }
+
+    /// The value of the event, if it is of type `T`.
+    pub fn get<T: Reflect>(&self) -> Option<&T> {
+        self.event.downcast_ref()
+    }
 }
 
 #[derive(Reflect)]
@@ -150,6 +580,126 @@ impl Default for PlayingAnimation {
     }
 }
 
+impl PlayingAnimation {
+    /// Advances the animation by `delta` seconds, if its clip is loaded.
+    fn contribution<'a>(
+        &'a mut self,
+        weight: f32,
+        delta: f32,
+        animations: &'a Assets<AnimationClip>,
+        root: Entity,
+        events: &mut Vec<AnimationEvent>,
+    ) -> Option<ClipContribution<'a>> {
+        let clip = animations.get(&self.animation_clip)?;
+        let from = self.elapsed;
+        self.elapsed += delta * self.speed;
+        events.extend(
+            clip.crossed_events(from, self.elapsed, self.repeat)
+                .into_iter()
+                .map(|event| event.fire(root, &self.animation_clip, weight)),
+        );
+        Some(ClipContribution::new(
+            clip,
+            self.elapsed,
+            self.repeat,
+            weight,
+            &mut self.path_cache,
+        ))
+    }
+}
+
+/// The playback state of a clip node of an [`AnimationGraph`], started with
+/// [`AnimationPlayer::play_node`].
+#[derive(Reflect, Clone, Debug)]
+pub struct ActiveAnimation {
+    repeat: bool,
+    speed: f32,
+    elapsed: f32,
+    path_cache: Vec<Vec<Option<Entity>>>,
+    #[reflect(ignore)]
+    mask_cache: MaskCache,
+}
+
+impl Default for ActiveAnimation {
+    fn default() -> Self {
+        Self {
+            repeat: false,
+            speed: 1.0,
+            elapsed: 0.0,
+            path_cache: Vec::new(),
+            mask_cache: MaskCache::default(),
+        }
+    }
+}
+
+/// Which paths of the clip of an [`ActiveAnimation`] are in the mask of its layer, indexed by
+/// the bone ID, computed again when the clip or the mask change.
+#[derive(Clone, Debug, Default)]
+struct MaskCache {
+    key: Option<(HandleId, MaskVersion)>,
+    bones: Vec<bool>,
+}
+
+impl MaskCache {
+    fn bones(
+        &mut self,
+        mask: &AnimationMask,
+        handle: &Handle<AnimationClip>,
+        clip: &AnimationClip,
+    ) -> &[bool] {
+        let key = (handle.id(), mask.version());
+        if self.key != Some(key) || self.bones.len() != clip.paths.len() {
+            self.bones = vec![false; clip.paths.len()];
+            for (path, bone_id) in &clip.paths {
+                self.bones[*bone_id] = mask.contains(path);
+            }
+            self.key = Some(key);
+        }
+        &self.bones
+    }
+}
+
+impl ActiveAnimation {
+    /// Set the animation to repeat
+    pub fn repeat(&mut self) -> &mut Self {
+        self.repeat = true;
+        self
+    }
+
+    /// Stop the animation from repeating
+    pub fn stop_repeating(&mut self) -> &mut Self {
+        self.repeat = false;
+        self
+    }
+
+    /// Is the animation repeating
+    pub fn is_repeating(&self) -> bool {
+        self.repeat
+    }
+
+    /// Speed of the animation playback
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Set the speed of the animation playback
+    pub fn set_speed(&mut self, speed: f32) -> &mut Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Time elapsed playing the animation
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Seek to a specific time in the animation
+    pub fn set_elapsed(&mut self, elapsed: f32) -> &mut Self {
+        self.elapsed = elapsed;
+        self
+    }
+}
+
 /// An animation that is being faded out as part of a transition
 struct AnimationTransition {
     /// The current weight. Starts at 1.0 and goes to 0.0 during the fade-out.
@@ -161,6 +711,10 @@ struct AnimationTransition {
 }
 
 /// Animation controls
+///
+/// The player either plays a single [`AnimationClip`] with [`AnimationPlayer::play`], or the clip
+/// nodes of the [`Handle<AnimationGraph>`] of its entity with [`AnimationPlayer::play_node`].
+/// Both can be used at the same time, in which case the single clip is blended with the graph.
 #[derive(Component, Default, Reflect)]
 #[reflect(Component)]
 pub struct AnimationPlayer {
@@ -175,6 +729,11 @@ pub struct AnimationPlayer {
     // Once a transition is finished, it will be automatically removed from the list
     #[reflect(ignore)]
     transitions: Vec<AnimationTransition>,
+
+    // Playback state of the clip nodes of the animation graph which are playing.
+    active_animations: HashMap<AnimationNodeIndex, ActiveAnimation>,
+    // Weights of the nodes of the animation graph, overriding the weights of the graph for this player.
+    node_weights: HashMap<AnimationNodeIndex, f32>,
 }
 
 impl AnimationPlayer {
@@ -289,6 +848,77 @@ impl AnimationPlayer {
         self.animation.elapsed = elapsed;
         self
     }
+
+    /// Start playing a clip node of the [`AnimationGraph`] of the player from the beginning.
+    ///
+    /// The other nodes keep playing, and are blended with this one as described by the graph.
+    ///
+    /// Nodes which are not clip nodes of the graph are stopped with a warning once the graph is loaded.
+    pub fn start_node(&mut self, node: AnimationNodeIndex) -> &mut ActiveAnimation {
+        let animation = self.active_animations.entry(node).or_default();
+        *animation = ActiveAnimation::default();
+        animation
+    }
+
+    /// Start playing a clip node of the [`AnimationGraph`] of the player, unless it is already playing.
+    ///
+    /// Nodes which are not clip nodes of the graph are stopped with a warning once the graph is loaded.
+    pub fn play_node(&mut self, node: AnimationNodeIndex) -> &mut ActiveAnimation {
+        self.active_animations.entry(node).or_default()
+    }
+
+    /// Stop playing a clip node of the [`AnimationGraph`] of the player.
+    pub fn stop_node(&mut self, node: AnimationNodeIndex) -> &mut Self {
+        self.active_animations.remove(&node);
+        self
+    }
+
+    /// Stop playing every clip node of the [`AnimationGraph`] of the player.
+    pub fn stop_all_nodes(&mut self) -> &mut Self {
+        self.active_animations.clear();
+        self
+    }
+
+    /// Is the clip node playing
+    pub fn is_node_playing(&self, node: AnimationNodeIndex) -> bool {
+        self.active_animations.contains_key(&node)
+    }
+
+    /// The playback state of a clip node, if it is playing.
+    pub fn node_animation(&self, node: AnimationNodeIndex) -> Option<&ActiveAnimation> {
+        self.active_animations.get(&node)
+    }
+
+    /// The playback state of a clip node, if it is playing.
+    pub fn node_animation_mut(&mut self, node: AnimationNodeIndex) -> Option<&mut ActiveAnimation> {
+        self.active_animations.get_mut(&node)
+    }
+
+    /// The clip nodes which are playing, with their playback state.
+    pub fn active_animations(
+        &self,
+    ) -> impl Iterator<Item = (AnimationNodeIndex, &ActiveAnimation)> {
+        self.active_animations
+            .iter()
+            .map(|(node, animation)| (*node, animation))
+    }
+
+    /// Set the weight of a node of the [`AnimationGraph`] for this player only.
+    pub fn set_node_weight(&mut self, node: AnimationNodeIndex, weight: f32) -> &mut Self {
+        self.node_weights.insert(node, weight);
+        self
+    }
+
+    /// Use the weight of the [`AnimationGraph`] for a node again, after [`AnimationPlayer::set_node_weight`].
+    pub fn reset_node_weight(&mut self, node: AnimationNodeIndex) -> &mut Self {
+        self.node_weights.remove(&node);
+        self
+    }
+
+    /// The weight of a node set with [`AnimationPlayer::set_node_weight`].
+    pub fn node_weight(&self, node: AnimationNodeIndex) -> Option<f32> {
+        self.node_weights.get(&node).copied()
+    }
 }
 
 fn entity_from_path(
@@ -341,9 +971,13 @@ fn verify_no_ancestor_player(
     player_parent: Option<&Parent>,
     parents: &Query<(Option<With<AnimationPlayer>>, Option<&Parent>)>,
 ) -> bool {
-    let Some(mut current) = player_parent.map(Parent::get) else { return true };
+    let Some(mut current) = player_parent.map(Parent::get) else {
+        return true;
+    };
     loop {
-        let Ok((maybe_player, parent)) = parents.get(current) else { return true };
+        let Ok((maybe_player, parent)) = parents.get(current) else {
+            return true;
+        };
         if maybe_player.is_some() {
             return false;
         }
@@ -357,24 +991,40 @@ fn verify_no_ancestor_player(
 
 /// System that will play all animations, using any entity with a [`AnimationPlayer`]
 /// and a [`Handle<AnimationClip>`] as an animation root
+///
+/// The animations of a player are sampled and blended together, before writing the result
+/// to the animated [`Transform`]s and [`MorphWeights`].
+///
+/// The [`AnimationEvent`]s crossed by each player are sent in the order they were crossed,
+/// but the events of different players are not sent in any particular order.
 #[allow(clippy::too_many_arguments)]
 pub fn animation_player(
     time: Res<Time>,
     animations: Res<Assets<AnimationClip>>,
+    graphs: Res<Assets<AnimationGraph>>,
     children: Query<&Children>,
     names: Query<&Name>,
     transforms: Query<&mut Transform>,
     morphs: Query<&mut MorphWeights>,
     parents: Query<(Option<With<AnimationPlayer>>, Option<&Parent>)>,
-    mut animation_players: Query<(Entity, Option<&Parent>, &mut AnimationPlayer)>,
+    mut animation_players: Query<(
+        Entity,
+        Option<&Parent>,
+        &mut AnimationPlayer,
+        Option<&Handle<AnimationGraph>>,
+    )>,
+    mut animation_events: EventWriter<AnimationEvent>,
+    mut animated_fields: ResMut<AnimatedFields>,
 ) {
+    let outputs = PlayerOutputs::default();
     animation_players
         .par_iter_mut()
-        .for_each(|(root, maybe_parent, mut player)| {
+        .for_each(|(root, maybe_parent, mut player, graph)| {
             update_transitions(&mut player, &time);
             run_animation_player(
                 root,
                 player,
+                graph.and_then(|graph| graphs.get(graph)),
                 &time,
                 &animations,
                 &names,
@@ -383,14 +1033,28 @@ pub fn animation_player(
                 maybe_parent,
                 &parents,
                 &children,
+                &outputs,
             );
         });
+    animation_events.send_batch(outputs.events.into_inner().unwrap());
+    animated_fields
+        .targets
+        .append(&mut outputs.fields.into_inner().unwrap());
+}
+
+/// What the players output besides the [`Transform`]s and [`MorphWeights`] they write, collected
+/// from every player before being sent at the end of the [`animation_player`] system.
+#[derive(Default)]
+struct PlayerOutputs {
+    events: Mutex<Vec<AnimationEvent>>,
+    fields: Mutex<Vec<AnimatedFieldTarget>>,
 }
 
 #[allow(clippy::too_many_arguments)]
 fn run_animation_player(
     root: Entity,
     mut player: Mut<AnimationPlayer>,
+    graph: Option<&AnimationGraph>,
     time: &Time,
     animations: &Assets<AnimationClip>,
     names: &Query<&Name>,
@@ -399,6 +1063,7 @@ fn run_animation_player(
     maybe_parent: Option<&Parent>,
     parents: &Query<(Option<With<AnimationPlayer>>, Option<&Parent>)>,
     children: &Query<&Children>,
+    outputs: &PlayerOutputs,
 ) {
     let paused = player.paused;
     // Continue if paused unless the `AnimationPlayer` was changed
@@ -406,193 +1071,454 @@ fn run_animation_player(
     if paused && !player.is_changed() {
         return;
     }
+    if !verify_no_ancestor_player(maybe_parent, parents) {
+        warn!("Animation player on {:?} has a conflicting animation player on an ancestor. Cannot safely animate.", root);
+        return;
+    }
+    let delta = if paused { 0.0 } else { time.delta_seconds() };
 
-    // Apply the main animation
-    apply_animation(
-        1.0,
-        &mut player.animation,
-        paused,
-        root,
-        time,
-        animations,
-        names,
-        transforms,
-        morphs,
-        maybe_parent,
-        parents,
-        children,
-    );
-
-    // Apply any potential fade-out transitions from previous animations
-    for AnimationTransition {
-        current_weight,
+    let AnimationPlayer {
         animation,
+        transitions,
+        active_animations,
+        node_weights,
         ..
-    } in &mut player.transitions
-    {
-        apply_animation(
-            *current_weight,
-            animation,
-            paused,
-            root,
-            time,
+    } = &mut *player;
+    let mut contributions = Vec::new();
+    let mut events = Vec::new();
+
+    // The main animation and the fade-out transitions from previous animations, weighted so that
+    // each transition is linearly blended with the result of the animations started after it
+    let mut remaining_weight = 1.0;
+    for transition in transitions.iter_mut().rev() {
+        let weight = transition.current_weight * remaining_weight;
+        remaining_weight -= weight;
+        contributions.extend(transition.animation.contribution(
+            weight,
+            delta,
             animations,
-            names,
-            transforms,
-            morphs,
-            maybe_parent,
-            parents,
-            children,
-        );
+            root,
+            &mut events,
+        ));
+    }
+    contributions.extend(animation.contribution(
+        remaining_weight,
+        delta,
+        animations,
+        root,
+        &mut events,
+    ));
+
+    // The clip nodes of the animation graph
+    let weights = graph.map(|graph| {
+        graph.weights(|index, node| node_weights.get(&index).copied().unwrap_or(node.weight))
+    });
+    if let Some(graph) = graph {
+        active_animations.retain(|node, _| {
+            let is_clip = graph.get(*node).is_some_and(|node| node.clip().is_some());
+            if !is_clip {
+                warn!(
+                    "Animation player on {:?} cannot play {:?}: it is not a clip node of its graph",
+                    root, node
+                );
+            }
+            is_clip
+        });
+    }
+    if let Some(weights) = &weights {
+        for (node, active) in active_animations.iter_mut() {
+            let Some(weight) = weights.clips.iter().find(|weight| weight.node == *node) else {
+                continue;
+            };
+            let Some(clip) = animations.get(weight.clip) else {
+                continue;
+            };
+            let from = active.elapsed;
+            active.elapsed += delta * active.speed;
+            events.extend(
+                clip.crossed_events(from, active.elapsed, active.repeat)
+                    .into_iter()
+                    .map(|event| event.fire(root, weight.clip, weight.weight)),
+            );
+            let mask = weight
+                .mask
+                .map(|mask| active.mask_cache.bones(mask, weight.clip, clip));
+            contributions.push(ClipContribution {
+                additive: weight.additive,
+                layer: weights.layer_slot(weight),
+                mask,
+                ..ClipContribution::new(
+                    clip,
+                    active.elapsed,
+                    active.repeat,
+                    weight.weight,
+                    &mut active.path_cache,
+                )
+            });
+        }
+    }
+    if !events.is_empty() {
+        outputs.events.lock().unwrap().append(&mut events);
+    }
+    let layers = weights.as_ref().map_or(&[][..], |weights| &weights.layers);
+
+    let mut targets = HashMap::<Entity, TargetLayers>::new();
+    for contribution in contributions {
+        contribution.accumulate(root, names, children, layers.len() + 1, &mut targets);
+    }
+
+    let mut fields = Vec::new();
+    for (
+        target,
+        TargetLayers {
+            blends,
+            fields: target_fields,
+        },
+    ) in targets
+    {
+        if !target_fields.is_empty() {
+            fields.push(AnimatedFieldTarget {
+                entity: target,
+                layers: layers
+                    .iter()
+                    .map(|layer| (layer.mode, layer.weight))
+                    .collect(),
+                fields: target_fields,
+            });
+        }
+
+        // SAFETY: The verify_no_ancestor_player check above ensures that two animation players cannot alias
+        // any of their descendant Transforms.
+        //
+        // The system scheduler prevents any other system from mutating Transforms at the same time,
+        // so the only way this fetch can alias is if two AnimationPlayers are targeting the same bone.
+        // This can only happen if there are two or more AnimationPlayers are ancestors to the same
+        // entities. By verifying that there is no other AnimationPlayer in the ancestors of a
+        // running AnimationPlayer before animating any entity, this fetch cannot alias.
+        //
+        // This means only the AnimationPlayers closest to the root of the hierarchy will be able
+        // to run their animation. Any players in the children or descendants will log a warning
+        // and do nothing.
+        if blends.iter().any(|blend| blend.animates_transform) {
+            if let Ok(mut transform) = unsafe { transforms.get_unchecked(target) } {
+                *transform = apply_layers_to_transform(&blends, layers, *transform);
+            }
+        }
+        if blends.iter().any(|blend| blend.animates_morph_weights) {
+            if let Ok(mut morphs) = unsafe { morphs.get_unchecked(target) } {
+                let morph_weights = morphs.weights_mut();
+                let result = apply_layers_to_morph_weights(&blends, layers, morph_weights.to_vec());
+                morph_weights.copy_from_slice(&result);
+            }
+        }
+    }
+    if !fields.is_empty() {
+        outputs.fields.lock().unwrap().append(&mut fields);
     }
 }
 
-/// Update `weights` based on weights in `keyframes` at index `key_index`
-/// with a linear interpolation on `key_lerp`.
-///
-/// # Panics
-///
-/// When `key_index * target_count` is larger than `keyframes`
-///
-/// This happens when `keyframes` is not formatted as described in
-/// [`Keyframes::Weights`]. A possible cause is [`AnimationClip`] not being
-/// meant to be used for the [`MorphWeights`] of the entity it's being applied to.
-fn lerp_morph_weights(weights: &mut [f32], key_lerp: f32, keyframes: &[f32], key_index: usize) {
-    let target_count = weights.len();
-    let start = target_count * key_index;
-    let end = target_count * (key_index + 1);
+/// Applies the base layer of an animated entity, then each of the layers of the graph in order.
+fn apply_layers_to_transform(
+    blends: &[TargetBlend],
+    layers: &[LayerWeight],
+    mut transform: Transform,
+) -> Transform {
+    blends[0].apply_to_transform(&mut transform);
+    for (blend, layer) in blends[1..].iter().zip(layers) {
+        match layer.mode {
+            AnimationLayerMode::Override => {
+                let mut layer_transform = transform;
+                blend.apply_to_transform(&mut layer_transform);
+                transform.translation = transform
+                    .translation
+                    .lerp(layer_transform.translation, layer.weight);
+                transform.rotation = transform
+                    .rotation
+                    .slerp(layer_transform.rotation, layer.weight);
+                transform.scale = transform.scale.lerp(layer_transform.scale, layer.weight);
+            }
+            AnimationLayerMode::Additive => blend.apply_to_transform(&mut transform),
+        }
+    }
+    transform
+}
 
-    let zipped = weights.iter_mut().zip(&keyframes[start..end]);
-    for (morph_weight, keyframe) in zipped {
-        let minus_lerp = 1.0 - key_lerp;
-        *morph_weight = (*morph_weight * minus_lerp) + (keyframe * key_lerp);
+/// Applies the base layer of an animated entity, then each of the layers of the graph in order.
+fn apply_layers_to_morph_weights(
+    blends: &[TargetBlend],
+    layers: &[LayerWeight],
+    mut morph_weights: Vec<f32>,
+) -> Vec<f32> {
+    blends[0].apply_to_morph_weights(&mut morph_weights);
+    for (blend, layer) in blends[1..].iter().zip(layers) {
+        match layer.mode {
+            AnimationLayerMode::Override => {
+                let mut layer_weights = morph_weights.clone();
+                blend.apply_to_morph_weights(&mut layer_weights);
+                for (morph_weight, layer_weight) in morph_weights.iter_mut().zip(layer_weights) {
+                    *morph_weight += (layer_weight - *morph_weight) * layer.weight;
+                }
+            }
+            AnimationLayerMode::Additive => blend.apply_to_morph_weights(&mut morph_weights),
+        }
     }
+    morph_weights
 }
 
-#[allow(clippy::too_many_arguments)]
-fn apply_animation(
+/// An [`AnimationClip`] sampled at a given time, contributing to the pose of the animated entities.
+struct ClipContribution<'a> {
+    clip: &'a AnimationClip,
+    elapsed: f32,
     weight: f32,
-    animation: &mut PlayingAnimation,
-    paused: bool,
-    root: Entity,
-    time: &Time,
-    animations: &Assets<AnimationClip>,
-    names: &Query<&Name>,
-    transforms: &Query<&mut Transform>,
-    morphs: &Query<&mut MorphWeights>,
-    maybe_parent: Option<&Parent>,
-    parents: &Query<(Option<With<AnimationPlayer>>, Option<&Parent>)>,
-    children: &Query<&Children>,
-) {
-    if let Some(animation_clip) = animations.get(&animation.animation_clip) {
-        if !paused {
-            animation.elapsed += time.delta_seconds() * animation.speed;
-        }
-        let mut elapsed = animation.elapsed;
-        if animation.repeat {
-            elapsed %= animation_clip.duration;
+    additive: bool,
+    /// The position of the layer of the clip, where 0 is the base layer.
+    layer: usize,
+    /// Whether each bone of the clip is in the mask of its layer.
+    mask: Option<&'a [bool]>,
+    path_cache: &'a mut Vec<Vec<Option<Entity>>>,
+}
+
+impl<'a> ClipContribution<'a> {
+    fn new(
+        clip: &'a AnimationClip,
+        mut elapsed: f32,
+        repeat: bool,
+        weight: f32,
+        path_cache: &'a mut Vec<Vec<Option<Entity>>>,
+    ) -> Self {
+        if repeat {
+            elapsed %= clip.duration;
         }
         if elapsed < 0.0 {
-            elapsed += animation_clip.duration;
+            elapsed += clip.duration;
         }
-        if animation.path_cache.len() != animation_clip.paths.len() {
-            animation.path_cache = vec![Vec::new(); animation_clip.paths.len()];
+        Self {
+            clip,
+            elapsed,
+            weight,
+            additive: false,
+            layer: 0,
+            mask: None,
+            path_cache,
         }
-        if !verify_no_ancestor_player(maybe_parent, parents) {
-            warn!("Animation player on {:?} has a conflicting animation player on an ancestor. Cannot safely animate.", root);
+    }
+
+    /// Adds the weighted values of the curves of the clip to the entity they animate.
+    fn accumulate(
+        self,
+        root: Entity,
+        names: &Query<&Name>,
+        children: &Query<&Children>,
+        layer_count: usize,
+        targets: &mut HashMap<Entity, TargetLayers>,
+    ) {
+        if self.weight == 0.0 {
             return;
         }
+        if self.path_cache.len() != self.clip.paths.len() {
+            *self.path_cache = vec![Vec::new(); self.clip.paths.len()];
+        }
 
-        for (path, bone_id) in &animation_clip.paths {
-            let cached_path = &mut animation.path_cache[*bone_id];
-            let curves = animation_clip.get_curves(*bone_id).unwrap();
-            let Some(target) = entity_from_path(root, path, children, names, cached_path) else { continue };
-            // SAFETY: The verify_no_ancestor_player check above ensures that two animation players cannot alias
-            // any of their descendant Transforms.
-            //
-            // The system scheduler prevents any other system from mutating Transforms at the same time,
-            // so the only way this fetch can alias is if two AnimationPlayers are targeting the same bone.
-            // This can only happen if there are two or more AnimationPlayers are ancestors to the same
-            // entities. By verifying that there is no other AnimationPlayer in the ancestors of a
-            // running AnimationPlayer before animating any entity, this fetch cannot alias.
-            //
-            // This means only the AnimationPlayers closest to the root of the hierarchy will be able
-            // to run their animation. Any players in the children or descendants will log a warning
-            // and do nothing.
-            let Ok(mut transform) = (unsafe { transforms.get_unchecked(target) }) else { continue };
-            let mut morphs = unsafe { morphs.get_unchecked(target) };
+        for (path, bone_id) in &self.clip.paths {
+            if self.mask.is_some_and(|mask| !mask[*bone_id]) {
+                continue;
+            }
+            let cached_path = &mut self.path_cache[*bone_id];
+            let curves = self.clip.get_curves(*bone_id).unwrap();
+            let Some(target) = entity_from_path(root, path, children, names, cached_path) else {
+                continue;
+            };
+            let target_layers = targets.entry(target).or_insert_with(|| TargetLayers {
+                blends: (0..layer_count).map(|_| TargetBlend::default()).collect(),
+                fields: HashMap::default(),
+            });
+            let blend = &mut target_layers.blends[self.layer];
             for curve in curves {
-                // Some curves have only one keyframe used to set a transform
-                if curve.keyframe_timestamps.len() == 1 {
-                    match &curve.keyframes {
-                        Keyframes::Rotation(keyframes) => {
-                            transform.rotation = transform.rotation.slerp(keyframes[0], weight);
-                        }
-                        Keyframes::Translation(keyframes) => {
-                            transform.translation =
-                                transform.translation.lerp(keyframes[0], weight);
-                        }
-                        Keyframes::Scale(keyframes) => {
-                            transform.scale = transform.scale.lerp(keyframes[0], weight);
-                        }
-                        Keyframes::Weights(keyframes) => {
-                            if let Ok(morphs) = &mut morphs {
-                                lerp_morph_weights(morphs.weights_mut(), weight, keyframes, 0);
-                            }
-                        }
-                    }
+                let Some(sample) = curve.sample(self.elapsed) else {
                     continue;
+                };
+                if self.additive {
+                    blend.add(sample, curve.keyframe(0), self.weight);
+                } else {
+                    blend.blend(sample, self.weight);
                 }
+            }
+            for curve in self.clip.get_field_curves(*bone_id).into_iter().flatten() {
+                let Some(sample) = curve.sample(self.elapsed) else {
+                    continue;
+                };
+                let blend = &mut target_layers
+                    .fields
+                    .entry(curve.key())
+                    .or_insert_with(|| vec![curve.blend(); layer_count])[self.layer];
+                if self.additive {
+                    blend.add(curve, sample, curve.keyframe(0), self.weight);
+                } else {
+                    blend.blend(curve, sample, self.weight);
+                }
+            }
+        }
+    }
+}
+
+/// The weighted values sampled for an animated entity, for each layer of the player.
+struct TargetLayers {
+    blends: Vec<TargetBlend>,
+    fields: HashMap<FieldKey, Vec<FieldBlend>>,
+}
+
+/// The weighted values sampled for an animated entity, written to its [`Transform`] and
+/// [`MorphWeights`] once every clip has been sampled.
+///
+/// Blended values are averaged proportionally to their weights, then additive values are
+/// added on top of them. When the weights of a property sum to less than 1, the average is
+/// only blended with the current value of the property by that sum, like a clip fading out
+/// over a finished clip.
+struct TargetBlend {
+    translation: Vec3,
+    translation_weight: f32,
+    rotation: Quat,
+    rotation_weight: f32,
+    scale: Vec3,
+    scale_weight: f32,
+    morph_weights: Vec<f32>,
+    morph_weights_weight: f32,
+    additive_translation: Vec3,
+    additive_rotation: Quat,
+    additive_scale: Vec3,
+    additive_morph_weights: Vec<f32>,
+    animates_transform: bool,
+    animates_morph_weights: bool,
+}
 
-                // Find the current keyframe
-                // PERF: finding the current keyframe can be optimised
-                let step_start = match curve
-                    .keyframe_timestamps
-                    .binary_search_by(|probe| probe.partial_cmp(&elapsed).unwrap())
-                {
-                    Ok(n) if n >= curve.keyframe_timestamps.len() - 1 => continue, // this curve is finished
-                    Ok(i) => i,
-                    Err(0) => continue, // this curve isn't started yet
-                    Err(n) if n > curve.keyframe_timestamps.len() - 1 => continue, // this curve is finished
-                    Err(i) => i - 1,
+impl Default for TargetBlend {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            translation_weight: 0.0,
+            rotation: Quat::from_xyzw(0.0, 0.0, 0.0, 0.0),
+            rotation_weight: 0.0,
+            scale: Vec3::ZERO,
+            scale_weight: 0.0,
+            morph_weights: Vec::new(),
+            morph_weights_weight: 0.0,
+            additive_translation: Vec3::ZERO,
+            additive_rotation: Quat::IDENTITY,
+            additive_scale: Vec3::ONE,
+            additive_morph_weights: Vec::new(),
+            animates_transform: false,
+            animates_morph_weights: false,
+        }
+    }
+}
+
+impl TargetBlend {
+    fn blend(&mut self, sample: CurveSample, weight: f32) {
+        match sample {
+            CurveSample::Rotation(rotation) => {
+                // Choose the smallest angle for the rotation
+                let rotation = if self.rotation.dot(rotation) < 0.0 {
+                    -rotation
+                } else {
+                    rotation
                 };
-                let ts_start = curve.keyframe_timestamps[step_start];
-                let ts_end = curve.keyframe_timestamps[step_start + 1];
-                let lerp = (elapsed - ts_start) / (ts_end - ts_start);
-
-                // Apply the keyframe
-                match &curve.keyframes {
-                    Keyframes::Rotation(keyframes) => {
-                        let rot_start = keyframes[step_start];
-                        let mut rot_end = keyframes[step_start + 1];
-                        // Choose the smallest angle for the rotation
-                        if rot_end.dot(rot_start) < 0.0 {
-                            rot_end = -rot_end;
-                        }
-                        // Rotations are using a spherical linear interpolation
-                        let rot = rot_start.normalize().slerp(rot_end.normalize(), lerp);
-                        transform.rotation = transform.rotation.slerp(rot, weight);
-                    }
-                    Keyframes::Translation(keyframes) => {
-                        let translation_start = keyframes[step_start];
-                        let translation_end = keyframes[step_start + 1];
-                        let result = translation_start.lerp(translation_end, lerp);
-                        transform.translation = transform.translation.lerp(result, weight);
-                    }
-                    Keyframes::Scale(keyframes) => {
-                        let scale_start = keyframes[step_start];
-                        let scale_end = keyframes[step_start + 1];
-                        let result = scale_start.lerp(scale_end, lerp);
-                        transform.scale = transform.scale.lerp(result, weight);
-                    }
-                    Keyframes::Weights(keyframes) => {
-                        if let Ok(morphs) = &mut morphs {
-                            lerp_morph_weights(morphs.weights_mut(), weight, keyframes, step_start);
-                        }
-                    }
+                self.rotation = self.rotation + rotation * weight;
+                self.rotation_weight += weight;
+                self.animates_transform = true;
+            }
+            CurveSample::Translation(translation) => {
+                self.translation += translation * weight;
+                self.translation_weight += weight;
+                self.animates_transform = true;
+            }
+            CurveSample::Scale(scale) => {
+                self.scale += scale * weight;
+                self.scale_weight += weight;
+                self.animates_transform = true;
+            }
+            CurveSample::Weights(morph_weights) => {
+                add_weighted(&mut self.morph_weights, &morph_weights, weight);
+                self.morph_weights_weight += weight;
+                self.animates_morph_weights = true;
+            }
+        }
+    }
+
+    /// Adds the difference between `sample` and `reference`, scaled by `weight`.
+    fn add(&mut self, sample: CurveSample, reference: CurveSample, weight: f32) {
+        match (sample, reference) {
+            (CurveSample::Rotation(rotation), CurveSample::Rotation(reference)) => {
+                let difference = rotation * reference.inverse();
+                self.additive_rotation =
+                    Quat::IDENTITY.slerp(difference, weight) * self.additive_rotation;
+                self.animates_transform = true;
+            }
+            (CurveSample::Translation(translation), CurveSample::Translation(reference)) => {
+                self.additive_translation += (translation - reference) * weight;
+                self.animates_transform = true;
+            }
+            (CurveSample::Scale(scale), CurveSample::Scale(reference)) => {
+                self.additive_scale *= Vec3::ONE.lerp(scale / reference, weight);
+                self.animates_transform = true;
+            }
+            (CurveSample::Weights(mut morph_weights), CurveSample::Weights(reference)) => {
+                for (morph_weight, reference) in morph_weights.iter_mut().zip(reference) {
+                    *morph_weight -= reference;
                 }
+                add_weighted(&mut self.additive_morph_weights, &morph_weights, weight);
+                self.animates_morph_weights = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn apply_to_transform(&self, transform: &mut Transform) {
+        if self.translation_weight >= 1.0 {
+            transform.translation = self.translation / self.translation_weight;
+        } else if self.translation_weight > 0.0 {
+            transform.translation = transform.translation.lerp(
+                self.translation / self.translation_weight,
+                self.translation_weight,
+            );
+        }
+        if self.rotation_weight >= 1.0 {
+            transform.rotation = self.rotation.normalize();
+        } else if self.rotation_weight > 0.0 {
+            transform.rotation = transform
+                .rotation
+                .slerp(self.rotation.normalize(), self.rotation_weight);
+        }
+        if self.scale_weight >= 1.0 {
+            transform.scale = self.scale / self.scale_weight;
+        } else if self.scale_weight > 0.0 {
+            transform.scale = transform
+                .scale
+                .lerp(self.scale / self.scale_weight, self.scale_weight);
+        }
+        transform.translation += self.additive_translation;
+        transform.rotation = self.additive_rotation * transform.rotation;
+        transform.scale *= self.additive_scale;
+    }
+
+    fn apply_to_morph_weights(&self, morph_weights: &mut [f32]) {
+        if self.morph_weights_weight > 0.0 {
+            let factor = self.morph_weights_weight.min(1.0);
+            for (morph_weight, sum) in morph_weights.iter_mut().zip(&self.morph_weights) {
+                *morph_weight += (sum / self.morph_weights_weight - *morph_weight) * factor;
             }
         }
+        for (morph_weight, additive) in morph_weights.iter_mut().zip(&self.additive_morph_weights) {
+            *morph_weight += additive;
+        }
+    }
+}
+
+fn add_weighted(sum: &mut Vec<f32>, values: &[f32], weight: f32) {
+    if sum.len() < values.len() {
+        sum.resize(values.len(), 0.0);
+    }
+    for (sum, value) in sum.iter_mut().zip(values) {
+        *sum += value * weight;
     }
 }
 
@@ -611,11 +1537,445 @@ impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app.add_asset::<AnimationClip>()
             .register_asset_reflect::<AnimationClip>()
+            .add_asset::<AnimationGraph>()
+            .register_asset_reflect::<AnimationGraph>()
             .register_type::<AnimationPlayer>()
             .register_type::<PlayingAnimation>()
+            .register_type::<ActiveAnimation>()
+            .register_type::<AnimationNodeIndex>()
+            .register_type::<AnimationClipEvent>()
+            .register_type::<Vec<AnimationClipEvent>>()
+            .register_type::<VariableCurve>()
+            .register_type::<Vec<Vec<VariableCurve>>>()
+            .register_type::<Vec<VariableCurve>>()
+            .register_type::<Keyframes>()
+            .register_type::<Vec<Quat>>()
+            .register_type::<Vec<Vec3>>()
+            .register_type::<Vec<f32>>()
+            .register_type::<EntityPath>()
+            .register_type::<HashMap<EntityPath, usize>>()
+            .register_type::<FieldCurve>()
+            .register_type::<Vec<Vec<FieldCurve>>>()
+            .register_type::<Vec<FieldCurve>>()
+            .register_type::<FieldKeyframes>()
+            .register_type::<Vec<Vec2>>()
+            .register_type::<Vec<Vec4>>()
+            .register_type::<Vec<Color>>()
+            .add_event::<AnimationEvent>()
+            .init_resource::<AnimatedFields>()
             .add_systems(
                 PostUpdate,
-                animation_player.before(TransformSystem::TransformPropagate),
+                (animation_player, animate_fields)
+                    .chain()
+                    .before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy_app::App;
+    use bevy_asset::{AssetPlugin, Assets, Handle};
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::Vec3;
+    use bevy_time::Time;
+    use bevy_transform::prelude::Transform;
+
+    use bevy_reflect::{
+        serde::{ReflectSerializer, UntypedReflectDeserializer},
+        FromReflect, ParsedPath, Reflect,
+    };
+    use serde::de::DeserializeSeed;
+
+    use crate::{
+        AnimationClip, AnimationEvent, AnimationGraph, AnimationLayer, AnimationLayerMode,
+        AnimationMask, AnimationPlayer, AnimationPlugin, EntityPath, FieldCurve, FieldKeyframes,
+        Keyframes, VariableCurve,
+    };
+
+    fn setup() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_core::TaskPoolPlugin::default(),
+            bevy_core::TypeRegistrationPlugin,
+            AssetPlugin::default(),
+            AnimationPlugin,
+        ));
+        let mut time = Time::default();
+        time.update_with_instant(time.startup());
+        app.insert_resource(time);
+        let root = app
+            .world
+            .spawn((
+                Name::new("root"),
+                Transform::default(),
+                AnimationPlayer::default(),
+            ))
+            .id();
+        (app, root)
+    }
+
+    /// Adds a clip moving the root along the x axis from `start` to `end` during `duration`.
+    fn add_clip(app: &mut App, start: f32, end: f32, duration: f32) -> Handle<AnimationClip> {
+        add_clip_to_paths(app, &[&["root"]], start, end, duration)
+    }
+
+    /// Adds a clip moving the entities at `paths` along the x axis from `start` to `end` during
+    /// `duration`.
+    fn add_clip_to_paths(
+        app: &mut App,
+        paths: &[&[&str]],
+        start: f32,
+        end: f32,
+        duration: f32,
+    ) -> Handle<AnimationClip> {
+        let mut clip = AnimationClip::default();
+        for parts in paths {
+            clip.add_curve_to_path(
+                EntityPath {
+                    parts: parts
+                        .iter()
+                        .map(|part| Name::new(part.to_string()))
+                        .collect(),
+                },
+                VariableCurve {
+                    keyframe_timestamps: vec![0.0, duration],
+                    keyframes: Keyframes::Translation(vec![Vec3::X * start, Vec3::X * end]),
+                },
+            );
+        }
+        app.world.resource_mut::<Assets<AnimationClip>>().add(clip)
+    }
+
+    /// Plays every clip node of `graph` on the root, repeating them.
+    fn play_graph(app: &mut App, root: Entity, graph: AnimationGraph) -> Handle<AnimationGraph> {
+        let clips: Vec<_> = graph
+            .nodes()
+            .filter(|(_, node)| node.clip().is_some())
+            .map(|(index, _)| index)
+            .collect();
+        let graph = app
+            .world
+            .resource_mut::<Assets<AnimationGraph>>()
+            .add(graph);
+        app.world.entity_mut(root).insert(graph.clone());
+        let mut player = player(app, root);
+        for clip in clips {
+            player.play_node(clip).repeat();
+        }
+        graph
+    }
+
+    /// Runs the app for one frame lasting `seconds`, returning the translation of the root.
+    fn advance(app: &mut App, root: Entity, seconds: f32) -> f32 {
+        let mut time = app.world.resource_mut::<Time>();
+        let last_update = time.last_update().unwrap();
+        time.update_with_instant(last_update + Duration::from_secs_f32(seconds));
+        app.update();
+        app.world.get::<Transform>(root).unwrap().translation.x
+    }
+
+    fn player(app: &mut App, root: Entity) -> Mut<'_, AnimationPlayer> {
+        app.world.get_mut::<AnimationPlayer>(root).unwrap()
+    }
+
+    #[test]
+    fn should_play_single_clip() {
+        let (mut app, root) = setup();
+        let clip = add_clip(&mut app, 0.0, 10.0, 1.0);
+        player(&mut app, root).play(clip);
+
+        assert_eq!(advance(&mut app, root, 0.5), 5.0);
+        // A finished clip leaves the transform as it is
+        assert_eq!(advance(&mut app, root, 0.75), 5.0);
+
+        player(&mut app, root).set_elapsed(0.0).repeat();
+        assert_eq!(advance(&mut app, root, 1.25), 2.5);
+    }
+
+    #[test]
+    fn should_crossfade_clips() {
+        let (mut app, root) = setup();
+        let walk = add_clip(&mut app, 10.0, 10.0, 4.0);
+        let run = add_clip(&mut app, 20.0, 20.0, 4.0);
+        player(&mut app, root).play(walk);
+        assert_eq!(advance(&mut app, root, 0.5), 10.0);
+
+        player(&mut app, root).play_with_transition(run, Duration::from_secs(1));
+        assert_eq!(advance(&mut app, root, 0.25), 12.5);
+        assert_eq!(advance(&mut app, root, 0.5), 17.5);
+        assert_eq!(advance(&mut app, root, 0.5), 20.0);
+    }
+
+    #[test]
+    fn should_fade_out_over_finished_clip() {
+        let (mut app, root) = setup();
+        let walk = add_clip(&mut app, 0.0, 40.0, 4.0);
+        let wave = add_clip(&mut app, 20.0, 20.0, 0.1);
+        player(&mut app, root).play(walk);
+        assert_eq!(advance(&mut app, root, 0.5), 5.0);
+
+        // The new clip finishes during the transition, so the previous clip is blended with the
+        // current transform by its remaining weight
+        player(&mut app, root).play_with_transition(wave, Duration::from_secs(1));
+        assert_eq!(advance(&mut app, root, 0.25), 5.0 + (7.5 - 5.0) * 0.75);
+    }
+
+    #[test]
+    fn should_blend_graph_clips_by_weight() {
+        let (mut app, root) = setup();
+        let walk = add_clip(&mut app, 10.0, 10.0, 4.0);
+        let run = add_clip(&mut app, 20.0, 20.0, 4.0);
+        let mut graph = AnimationGraph::new();
+        let walk = graph.add_clip(walk, 0.75, graph.root());
+        let run = graph.add_clip(run, 0.25, graph.root());
+        let graph = app
+            .world
+            .resource_mut::<Assets<AnimationGraph>>()
+            .add(graph);
+        app.world.entity_mut(root).insert(graph);
+        let mut player = player(&mut app, root);
+        player.play_node(walk).repeat();
+        player.play_node(run).repeat();
+        assert_eq!(advance(&mut app, root, 0.5), 12.5);
+
+        let mut player = app.world.get_mut::<AnimationPlayer>(root).unwrap();
+        player.set_node_weight(walk, 0.25);
+        player.set_node_weight(run, 0.75);
+        assert_eq!(advance(&mut app, root, 0.5), 17.5);
+
+        app.world
+            .get_mut::<AnimationPlayer>(root)
+            .unwrap()
+            .stop_node(run);
+        // The weight of the only playing clip is below 1, so it is blended with the current value
+        assert_eq!(advance(&mut app, root, 0.5), 17.5 + (10.0 - 17.5) * 0.25);
+    }
+
+    #[test]
+    fn should_stop_nodes_which_are_not_clips() {
+        let (mut app, root) = setup();
+        let graph = AnimationGraph::new();
+        let blend = graph.root();
+        let graph = app
+            .world
+            .resource_mut::<Assets<AnimationGraph>>()
+            .add(graph);
+        app.world.entity_mut(root).insert(graph);
+        player(&mut app, root).play_node(blend);
+        advance(&mut app, root, 0.5);
+        assert!(!player(&mut app, root).is_node_playing(blend));
+    }
+
+    #[test]
+    fn should_apply_layers_in_order() {
+        for (additive_first, expected) in [(true, (10.0 + 1.0 + 20.0) / 2.0), (false, 15.0 + 1.0)] {
+            let (mut app, root) = setup();
+            let base = add_clip(&mut app, 10.0, 10.0, 4.0);
+            let offset = add_clip(&mut app, 0.0, 4.0, 4.0);
+            let jump = add_clip(&mut app, 20.0, 20.0, 4.0);
+            let mut graph = AnimationGraph::new();
+            graph.add_clip(base, 1.0, graph.root());
+            let add_additive_layer = |graph: &mut AnimationGraph| {
+                let layer = graph.add_layer(
+                    AnimationLayer::new(AnimationLayerMode::Additive),
+                    1.0,
+                    graph.root(),
+                );
+                graph.add_clip(offset.clone(), 1.0, layer);
+            };
+            if additive_first {
+                add_additive_layer(&mut graph);
+            }
+            let layer = graph.add_layer(
+                AnimationLayer::new(AnimationLayerMode::Override),
+                0.5,
+                graph.root(),
             );
+            graph.add_clip(jump, 1.0, layer);
+            if !additive_first {
+                add_additive_layer(&mut graph);
+            }
+            play_graph(&mut app, root, graph);
+            assert_eq!(advance(&mut app, root, 1.0), expected);
+        }
+    }
+
+    #[test]
+    fn should_update_masks_of_playing_layers() {
+        let (mut app, root) = setup();
+        let arm = app
+            .world
+            .spawn((Name::new("arm"), Transform::default()))
+            .id();
+        app.world.entity_mut(root).add_child(arm);
+        let clip = add_clip_to_paths(&mut app, &[&["root"], &["root", "arm"]], 10.0, 10.0, 4.0);
+        let mut graph = AnimationGraph::new();
+        graph.add_mask_group("arm", AnimationMask::new().with_subtree("hand"));
+        let layer = graph.add_layer(
+            AnimationLayer::new(AnimationLayerMode::Override).with_mask("arm"),
+            1.0,
+            graph.root(),
+        );
+        graph.add_clip(clip, 1.0, layer);
+        let graph = play_graph(&mut app, root, graph);
+        let arm_x = |app: &App| app.world.get::<Transform>(arm).unwrap().translation.x;
+
+        assert_eq!(advance(&mut app, root, 0.5), 0.0);
+        assert_eq!(arm_x(&app), 0.0);
+
+        app.world
+            .resource_mut::<Assets<AnimationGraph>>()
+            .get_mut(&graph)
+            .unwrap()
+            .mask_group_mut("arm")
+            .unwrap()
+            .add_subtree("arm");
+        assert_eq!(advance(&mut app, root, 0.5), 0.0);
+        assert_eq!(arm_x(&app), 10.0);
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Footstep {
+        left: bool,
+    }
+
+    /// A clip lasting one second, with an event at each of `times` whose value is its time.
+    fn clip_with_events(times: &[f32]) -> AnimationClip {
+        let mut clip = AnimationClip::default();
+        for time in times {
+            clip.add_event(*time, *time);
+        }
+        clip
+    }
+
+    fn crossed(clip: &AnimationClip, from: f32, to: f32, repeat: bool) -> Vec<f32> {
+        clip.crossed_events(from, to, repeat)
+            .into_iter()
+            .map(|event| *event.event().downcast_ref::<f32>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn should_cross_events_playing_forward() {
+        let clip = clip_with_events(&[0.0, 0.5, 1.0]);
+        assert_eq!(crossed(&clip, 0.0, 0.5, false), vec![0.0]);
+        assert_eq!(crossed(&clip, 0.5, 0.6, false), vec![0.5]);
+        // The event at the end of the clip is crossed when the playback reaches it, once
+        assert_eq!(crossed(&clip, 0.6, 1.0, false), vec![1.0]);
+        assert_eq!(crossed(&clip, 1.0, 1.2, false), Vec::<f32>::new());
+        assert_eq!(crossed(&clip, 0.0, 2.5, false), vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn should_cross_events_looping() {
+        let clip = clip_with_events(&[0.0, 0.5, 1.0]);
+        assert_eq!(crossed(&clip, 0.6, 1.6, true), vec![1.0, 0.0, 0.5]);
+        assert_eq!(
+            crossed(&clip, 0.0, 2.5, true),
+            vec![0.0, 0.5, 1.0, 0.0, 0.5, 1.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn should_cross_events_playing_backward() {
+        let clip = clip_with_events(&[0.0, 0.5, 1.0]);
+        assert_eq!(crossed(&clip, 0.6, 0.1, true), vec![0.5]);
+        // The event at the start of the clip is crossed when the playback reaches it, then the
+        // playback loops to the end of the clip
+        assert_eq!(crossed(&clip, 0.6, -0.2, true), vec![0.5, 0.0, 1.0]);
+        assert_eq!(crossed(&clip, 0.1, 0.0, false), vec![0.0]);
+    }
+
+    #[test]
+    fn should_send_events_after_seeking() {
+        let (mut app, root) = setup();
+        let clip = app
+            .world
+            .resource_mut::<Assets<AnimationClip>>()
+            .add(clip_with_events(&[0.2, 0.7]));
+        player(&mut app, root).play(clip);
+        let sent = |app: &mut App, seconds| {
+            advance(app, root, seconds);
+            app.world
+                .resource_mut::<Events<AnimationEvent>>()
+                .drain()
+                .map(|event| *event.get::<f32>().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(sent(&mut app, 0.1), Vec::<f32>::new());
+        // Seeking doesn't cross the events between the previous and the new time
+        player(&mut app, root).set_elapsed(0.65);
+        assert_eq!(sent(&mut app, 0.1), vec![0.7]);
+    }
+
+    #[test]
+    fn should_serialize_clip_events() {
+        let (mut app, _) = setup();
+        app.register_type::<Footstep>();
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let mut clip = AnimationClip::default();
+        clip.add_event(0.2, Footstep { left: true });
+        clip.add_event(0.7, Footstep { left: false });
+
+        let serialized = ron::to_string(&ReflectSerializer::new(&clip, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let deserialized = AnimationClip::from_reflect(&*deserialized).unwrap();
+
+        assert_eq!(deserialized.duration(), 0.7);
+        let events: Vec<_> = deserialized
+            .events()
+            .iter()
+            .map(|event| (event.time, event.event().downcast_ref::<Footstep>()))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (0.2, Some(&Footstep { left: true })),
+                (0.7, Some(&Footstep { left: false }))
+            ]
+        );
+    }
+
+    #[test]
+    fn should_serialize_field_curves() {
+        let (mut app, _) = setup();
+        app.register_type::<Transform>();
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let mut clip = AnimationClip::default();
+        clip.add_field_curve_to_path(
+            EntityPath {
+                parts: vec![Name::new("root")],
+            },
+            FieldCurve::new::<Transform>(
+                ParsedPath::parse("translation.x").unwrap(),
+                vec![0.0, 1.0],
+                FieldKeyframes::F32(vec![0.0, 10.0]),
+            ),
+        );
+
+        let serialized = ron::to_string(&ReflectSerializer::new(&clip, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let deserialized = AnimationClip::from_reflect(&*deserialized).unwrap();
+
+        assert_eq!(deserialized.duration(), 1.0);
+        let [curve] = &deserialized.get_field_curves(0).unwrap()[..] else {
+            panic!("expected a single field curve")
+        };
+        assert_eq!(curve.component(), std::any::TypeId::of::<Transform>());
+        assert_eq!(curve.path().to_string(), ".translation.x");
+        assert_eq!(curve.keyframe_timestamps, vec![0.0, 1.0]);
+        assert!(matches!(&curve.keyframes, FieldKeyframes::F32(values) if values == &[0.0, 10.0]));
     }
 }
@@ -182,6 +182,9 @@ impl Default for App {
         #[cfg(feature = "bevy_reflect")]
         app.init_resource::<AppTypeRegistry>();
 
+        #[cfg(feature = "reflect_auto_register")]
+        app.register_derived_types();
+
         app.add_plugins(MainSchedulePlugin);
         app.add_event::<AppExit>();
 
@@ -707,6 +710,26 @@ impl App {
         self
     }
 
+    /// Registers every non-generic type deriving [`Reflect`](bevy_reflect::Reflect) in the
+    /// [`TypeRegistry`](bevy_reflect::TypeRegistry) resource.
+    ///
+    /// This is called automatically when the [`App`] is created,
+    /// and only needs to be called again if the registry resource was replaced.
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_derived_types`].
+    #[cfg(feature = "reflect_auto_register")]
+    pub fn register_derived_types(&mut self) -> &mut Self {
+        let registry = self.world.resource_mut::<AppTypeRegistry>();
+        if !registry.write().register_derived_types() {
+            bevy_utils::tracing::warn!(
+                "No types were collected for automatic registration. \
+                Link-time collection may be unsupported on this platform, \
+                so types must be registered with `App::register_type` instead."
+            );
+        }
+        self
+    }
+
     /// Adds the type data `D` to type `T` in the [`TypeRegistry`](bevy_reflect::TypeRegistry) resource.
     ///
     /// Most of the time [`App::register_type`] can be used instead to register a type you derived [`Reflect`](bevy_reflect::Reflect) for.
@@ -2,16 +2,19 @@ use crate::{
     path::{AssetPath, AssetPathId, SourcePathId},
     Asset, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel, AssetLifecycleEvent,
     AssetLoader, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext, LoadState,
-    RefChange, RefChangeChannel, SourceInfo, SourceMeta,
+    RefChange, RefChangeChannel, SourceInfo, SourceMeta, UseClock,
 };
 use anyhow::Result;
-use bevy_ecs::system::{Res, ResMut, Resource};
+use bevy_ecs::{
+    event::{Event, EventWriter},
+    system::{Res, ResMut, Resource},
+};
 use bevy_log::warn;
 use bevy_tasks::IoTaskPool;
-use bevy_utils::{Entry, HashMap, Uuid};
-use crossbeam_channel::TryRecvError;
+use bevy_utils::{Entry, HashMap, HashSet, Uuid};
+use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use parking_lot::{Mutex, RwLock};
-use std::{path::Path, sync::Arc};
+use std::{any::TypeId, fmt::Debug, path::Path, sync::Arc};
 use thiserror::Error;
 
 /// Errors that occur while loading assets with an [`AssetServer`].
@@ -30,6 +33,24 @@ pub enum AssetServerError {
         extensions: Vec<String>,
     },
 
+    /// Several asset loaders support the extension, and none could be selected for the requested
+    /// asset type.
+    ///
+    /// Use [`AssetServer::load`] with an asset type declared by one of the loaders through
+    /// [`AssetLoader::asset_type_id`], or [`AssetServer::load_with_loader`] to pick a loader.
+    #[error("multiple `AssetLoader`s found for the extension `{extension}`: {}", .loaders.join(", "))]
+    AmbiguousAssetLoader {
+        /// The extension shared by the loaders.
+        extension: String,
+        /// The type names of the loaders supporting the extension.
+        loaders: Vec<&'static str>,
+    },
+
+    /// The asset loader explicitly requested with [`AssetServer::load_with_loader`] was not added
+    /// to the asset server.
+    #[error("the `AssetLoader` `{0}` was not added to the asset server")]
+    UnregisteredAssetLoader(&'static str),
+
     /// The handle type does not match the type of the loaded asset.
     #[error("the given type does not match the type of the loaded asset")]
     IncorrectHandleType,
@@ -43,6 +64,18 @@ pub enum AssetServerError {
     AssetIoError(#[from] AssetIoError),
 }
 
+/// An event emitted when an asset source failed to load.
+///
+/// The same error is retained in [`LoadState::Failed`] and can be queried with
+/// [`AssetServer::get_load_state`].
+#[derive(Event, Debug, Clone)]
+pub struct AssetLoadFailedEvent {
+    /// The path of the asset source which failed to load.
+    pub path: AssetPath<'static>,
+    /// The error that caused the failure.
+    pub error: Arc<AssetServerError>,
+}
+
 fn format_missing_asset_ext(exts: &[String]) -> String {
     if !exts.is_empty() {
         format!(
@@ -55,22 +88,76 @@ fn format_missing_asset_ext(exts: &[String]) -> String {
     }
 }
 
+/// An event emitted when an asset only held by cache handles was evicted from its [`Assets`]
+/// collection because the collection exceeded its memory budget.
+///
+/// Events are always sent with a _Weak_ handle, as the asset no longer exists.
+#[derive(Event)]
+pub struct AssetEvictedEvent<T: Asset> {
+    /// A weak handle to the evicted asset.
+    pub handle: Handle<T>,
+    /// The memory size of the evicted asset, in bytes.
+    pub size: usize,
+}
+
+impl<T: Asset> Debug for AssetEvictedEvent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!(
+            "AssetEvictedEvent<{}>",
+            std::any::type_name::<T>()
+        ))
+        .field("handle", &self.handle.id())
+        .field("size", &self.size)
+        .finish()
+    }
+}
+
 #[derive(Default)]
 pub(crate) struct AssetRefCounter {
     pub(crate) channel: Arc<RefChangeChannel>,
     pub(crate) ref_counts: Arc<RwLock<HashMap<HandleId, usize>>>,
+    pub(crate) cache_ref_counts: Arc<RwLock<HashMap<HandleId, usize>>>,
     pub(crate) mark_unused_assets: Arc<Mutex<Vec<HandleId>>>,
+    pub(crate) cached_assets: Arc<Mutex<CachedAssets>>,
+    pub(crate) use_clock: Arc<UseClock>,
+}
+
+/// Assets that are only held by cache handles, with when their handles were last used.
+#[derive(Default)]
+pub(crate) struct CachedAssets {
+    last_used: HashMap<HandleId, u64>,
+}
+
+impl CachedAssets {
+    fn touch(&mut self, handle_id: HandleId, use_clock: &UseClock) {
+        self.last_used.insert(handle_id, use_clock.tick());
+    }
 }
 
 #[derive(Clone)]
 enum MaybeAssetLoader {
-    Ready(Arc<dyn AssetLoader>),
+    Ready {
+        loader: Arc<dyn AssetLoader>,
+        type_id: TypeId,
+        type_name: &'static str,
+    },
     Pending {
         sender: async_channel::Sender<()>,
         receiver: async_channel::Receiver<()>,
     },
 }
 
+/// How the asset loader of an asset source is selected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LoaderSelection {
+    /// Use the loader supporting the extension of the source.
+    Extension,
+    /// Use the loader supporting the extension of the source and producing the asset type.
+    AssetType(TypeId),
+    /// Use the loader of the given type, regardless of the extension of the source.
+    Loader(TypeId, &'static str),
+}
+
 /// Internal data for the asset server.
 ///
 /// [`AssetServer`] is the public API for interacting with the asset server.
@@ -80,8 +167,11 @@ pub struct AssetServerInternal {
     pub(crate) asset_sources: Arc<RwLock<HashMap<SourcePathId, SourceInfo>>>,
     pub(crate) asset_lifecycles: Arc<RwLock<HashMap<Uuid, Box<dyn AssetLifecycle>>>>,
     loaders: RwLock<Vec<MaybeAssetLoader>>,
-    extension_to_loader_index: RwLock<HashMap<String, usize>>,
+    extension_to_loader_indices: RwLock<HashMap<String, Vec<usize>>>,
+    loader_selections: RwLock<HashMap<SourcePathId, LoaderSelection>>,
     handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
+    load_failed_sender: Sender<AssetLoadFailedEvent>,
+    load_failed_receiver: Receiver<AssetLoadFailedEvent>,
 }
 
 /// Loads assets from the filesystem in the background.
@@ -134,15 +224,19 @@ impl AssetServer {
 
     /// Creates a new asset server with a boxed asset I/O.
     pub fn with_boxed_io(asset_io: Box<dyn AssetIo>) -> Self {
+        let (load_failed_sender, load_failed_receiver) = crossbeam_channel::unbounded();
         AssetServer {
             server: Arc::new(AssetServerInternal {
                 loaders: Default::default(),
-                extension_to_loader_index: Default::default(),
+                extension_to_loader_indices: Default::default(),
+                loader_selections: Default::default(),
                 asset_sources: Default::default(),
                 asset_ref_counter: Default::default(),
                 handle_to_path: Default::default(),
                 asset_lifecycles: Default::default(),
                 asset_io,
+                load_failed_sender,
+                load_failed_receiver,
             }),
         }
     }
@@ -163,26 +257,29 @@ impl AssetServer {
             panic!("Error while registering new asset type: {:?} with UUID: {:?}. Another type with the same UUID is already registered. Can not register new asset type with the same UUID",
                 std::any::type_name::<T>(), T::TYPE_UUID);
         }
-        Assets::new(self.server.asset_ref_counter.channel.sender.clone())
+        Assets::new(
+            self.server.asset_ref_counter.channel.sender.clone(),
+            self.server.asset_ref_counter.use_clock.clone(),
+        )
     }
 
     /// Pre-register a loader that will later be added.
     ///
     /// Assets loaded with matching extensions will be blocked until the
-    /// real loader is added.
+    /// real loader is added. Loaders already added for these extensions are kept,
+    /// as the real loader may produce a different asset type.
     pub fn preregister_loader(&self, extensions: &[&str]) {
         let mut loaders = self.server.loaders.write();
         let loader_index = loaders.len();
+        let mut loader_map = self.server.extension_to_loader_indices.write();
         for extension in extensions {
-            if self
-                .server
-                .extension_to_loader_index
-                .write()
-                .insert(extension.to_string(), loader_index)
-                .is_some()
-            {
+            let indices = loader_map.entry(extension.to_string()).or_default();
+            let previous_len = indices.len();
+            indices.retain(|&index| !matches!(loaders[index], MaybeAssetLoader::Pending { .. }));
+            if indices.len() != previous_len {
                 warn!("duplicate preregistration for `{extension}`, any assets loaded with the previous loader will never complete.");
             }
+            indices.push(loader_index);
         }
         let (sender, receiver) = async_channel::bounded(1);
         loaders.push(MaybeAssetLoader::Pending { sender, receiver });
@@ -191,55 +288,49 @@ impl AssetServer {
     /// Adds the provided asset loader to the server.
     ///
     /// If `loader` has one or more supported extensions in conflict with loaders that came before
-    /// it, it will replace them.
+    /// it, it will replace them, unless both loaders declare different asset types with
+    /// [`AssetLoader::asset_type_id`]. Several loaders producing different asset types can then
+    /// support the same extension, and the loader is selected by the asset type requested with
+    /// [`load`](AssetServer::load).
     pub fn add_loader<T>(&self, loader: T)
     where
         T: AssetLoader,
     {
         let mut loaders = self.server.loaders.write();
-        let next_loader_index = loaders.len();
-        let mut maybe_existing_loader_index = None;
-        let mut loader_map = self.server.extension_to_loader_index.write();
-        let mut maybe_sender = None;
+        let loader_index = loaders.len();
+        let mut loader_map = self.server.extension_to_loader_indices.write();
+        let asset_type_id = loader.asset_type_id();
+        let mut pending_senders = Vec::new();
 
         for extension in loader.extensions() {
-            if let Some(&extension_index) = loader_map.get(*extension) {
-                // replacing an existing entry
-                match maybe_existing_loader_index {
-                    None => {
-                        match &loaders[extension_index] {
-                            MaybeAssetLoader::Ready(_) => {
-                                // replacing an existing loader, nothing special to do
-                            }
-                            MaybeAssetLoader::Pending { sender, .. } => {
-                                // the loader was pre-registered, store the channel to notify pending assets
-                                maybe_sender = Some(sender.clone());
-                            }
-                        }
-                    }
-                    Some(index) => {
-                        // ensure the loader extensions are consistent
-                        if index != extension_index {
-                            warn!("inconsistent extensions between loader preregister_loader and add_loader, \
-                                   loading `{extension}` assets will never complete.");
-                        }
-                    }
+            let indices = loader_map.entry(extension.to_string()).or_default();
+            indices.retain(|&index| match &loaders[index] {
+                MaybeAssetLoader::Ready {
+                    loader: existing, ..
+                } => {
+                    // keep loaders producing a different asset type
+                    matches!(
+                        (existing.asset_type_id(), asset_type_id),
+                        (Some(existing), Some(new)) if existing != new
+                    )
                 }
-
-                maybe_existing_loader_index = Some(extension_index);
-            } else {
-                loader_map.insert(extension.to_string(), next_loader_index);
-            }
+                MaybeAssetLoader::Pending { sender, .. } => {
+                    // the loader was pre-registered, store the channel to notify pending assets
+                    pending_senders.push(sender.clone());
+                    false
+                }
+            });
+            indices.push(loader_index);
         }
 
-        if let Some(existing_index) = maybe_existing_loader_index {
-            loaders[existing_index] = MaybeAssetLoader::Ready(Arc::new(loader));
-            if let Some(sender) = maybe_sender {
-                // notify after replacing the loader
-                let _ = sender.close();
-            }
-        } else {
-            loaders.push(MaybeAssetLoader::Ready(Arc::new(loader)));
+        loaders.push(MaybeAssetLoader::Ready {
+            loader: Arc::new(loader),
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+        });
+        // notify after adding the loader
+        for sender in pending_senders {
+            let _ = sender.close();
         }
     }
 
@@ -255,24 +346,79 @@ impl AssetServer {
         HandleUntyped::strong(id.into(), sender)
     }
 
-    fn get_asset_loader(&self, extension: &str) -> Result<MaybeAssetLoader, AssetServerError> {
-        let index = {
+    fn get_asset_loader(
+        &self,
+        extension: &str,
+        asset_type_id: Option<TypeId>,
+        include_pending: bool,
+    ) -> Result<Option<MaybeAssetLoader>, AssetServerError> {
+        let indices = {
             // scope map to drop lock as soon as possible
-            let map = self.server.extension_to_loader_index.read();
-            map.get(extension).copied()
+            let map = self.server.extension_to_loader_indices.read();
+            match map.get(extension) {
+                Some(indices) => indices.clone(),
+                None => return Ok(None),
+            }
         };
-        index
-            .map(|index| self.server.loaders.read()[index].clone())
-            .ok_or_else(|| AssetServerError::MissingAssetLoader {
-                extensions: vec![extension.to_string()],
-            })
+        let loaders = self.server.loaders.read();
+        let candidates = indices
+            .into_iter()
+            .map(|index| &loaders[index])
+            .filter(|loader| include_pending || matches!(loader, MaybeAssetLoader::Ready { .. }))
+            .collect::<Vec<_>>();
+        if candidates.len() <= 1 {
+            return Ok(candidates.first().map(|&loader| loader.clone()));
+        }
+
+        if let Some(asset_type_id) = asset_type_id {
+            let mut matching = candidates.iter().filter(|loader| {
+                matches!(loader, MaybeAssetLoader::Ready { loader, .. } if loader.asset_type_id() == Some(asset_type_id))
+            });
+            if let (Some(&loader), None) = (matching.next(), matching.next()) {
+                return Ok(Some(loader.clone()));
+            }
+        }
+        // wait for a pre-registered loader before resolving the ambiguity
+        if let Some(&pending) = candidates
+            .iter()
+            .find(|loader| matches!(loader, MaybeAssetLoader::Pending { .. }))
+        {
+            return Ok(Some(pending.clone()));
+        }
+        Err(AssetServerError::AmbiguousAssetLoader {
+            extension: extension.to_string(),
+            loaders: candidates
+                .iter()
+                .filter_map(|loader| match loader {
+                    MaybeAssetLoader::Ready { type_name, .. } => Some(*type_name),
+                    MaybeAssetLoader::Pending { .. } => None,
+                })
+                .collect(),
+        })
     }
 
     fn get_path_asset_loader<P: AsRef<Path>>(
         &self,
         path: P,
         include_pending: bool,
+        selection: LoaderSelection,
     ) -> Result<MaybeAssetLoader, AssetServerError> {
+        let asset_type_id = match selection {
+            LoaderSelection::Extension => None,
+            LoaderSelection::AssetType(asset_type_id) => Some(asset_type_id),
+            LoaderSelection::Loader(loader_type_id, loader_type_name) => {
+                return self
+                    .server
+                    .loaders
+                    .read()
+                    .iter()
+                    .rev()
+                    .find(|loader| matches!(loader, MaybeAssetLoader::Ready { type_id, .. } if *type_id == loader_type_id))
+                    .cloned()
+                    .ok_or(AssetServerError::UnregisteredAssetLoader(loader_type_name));
+            }
+        };
+
         let s = path
             .as_ref()
             .file_name()
@@ -290,10 +436,8 @@ impl AssetServer {
         while let Some(idx) = ext.find('.') {
             ext = &ext[idx + 1..];
             exts.push(ext);
-            if let Ok(loader) = self.get_asset_loader(ext) {
-                if include_pending || matches!(loader, MaybeAssetLoader::Ready(_)) {
-                    return Ok(loader);
-                }
+            if let Some(loader) = self.get_asset_loader(ext, asset_type_id, include_pending)? {
+                return Ok(loader);
             }
         }
         Err(AssetServerError::MissingAssetLoader {
@@ -301,6 +445,22 @@ impl AssetServer {
         })
     }
 
+    /// Records how the loader of the asset source at `asset_path` should be selected, so that
+    /// reloads use the same loader.
+    fn set_loader_selection(&self, asset_path: &AssetPath, selection: LoaderSelection) {
+        let mut loader_selections = self.server.loader_selections.write();
+        let entry = loader_selections
+            .entry(asset_path.get_id().source_path_id())
+            .or_insert(selection);
+        // an explicitly requested loader takes precedence over the requested asset type
+        if !matches!(
+            (*entry, selection),
+            (LoaderSelection::Loader(..), LoaderSelection::AssetType(_))
+        ) {
+            *entry = selection;
+        }
+    }
+
     /// Gets the source path of an asset from the provided handle.
     pub fn get_handle_path<H: Into<HandleId>>(&self, handle: H) -> Option<AssetPath<'_>> {
         self.server
@@ -311,18 +471,78 @@ impl AssetServer {
     }
 
     /// Gets the load state of an asset from the provided handle.
+    ///
+    /// This only reflects the asset source itself: an asset can be [`LoadState::Loaded`] while
+    /// the assets it depends on are still loading. Use
+    /// [`get_recursive_dependency_load_state`](AssetServer::get_recursive_dependency_load_state)
+    /// to also take dependencies into account.
     pub fn get_load_state<H: Into<HandleId>>(&self, handle: H) -> LoadState {
         match handle.into() {
             HandleId::AssetPathId(id) => {
                 let asset_sources = self.server.asset_sources.read();
                 asset_sources
                     .get(&id.source_path_id())
-                    .map_or(LoadState::NotLoaded, |info| info.load_state)
+                    .map_or(LoadState::NotLoaded, |info| info.load_state.clone())
             }
             HandleId::Id(_, _) => LoadState::NotLoaded,
         }
     }
 
+    /// Gets the load state of an asset and all of its dependencies, recursively.
+    ///
+    /// Dependencies are the asset paths added to a [`LoadedAsset`](crate::LoadedAsset) by its
+    /// loader, such as the textures referenced by a glTF file. This method only returns
+    /// [`LoadState::Loaded`] once the asset and every asset it transitively depends on are
+    /// loaded. If any of them failed, the [`LoadState::Failed`] state of the first failure found
+    /// is returned. Dependencies that have not started loading yet are reported as
+    /// [`LoadState::Loading`].
+    pub fn get_recursive_dependency_load_state<H: Into<HandleId>>(&self, handle: H) -> LoadState {
+        let HandleId::AssetPathId(id) = handle.into() else {
+            return LoadState::NotLoaded;
+        };
+        let root = id.source_path_id();
+
+        let asset_sources = self.server.asset_sources.read();
+        let mut load_state = LoadState::Loaded;
+        let mut visited = HashSet::default();
+        let mut to_visit = vec![root];
+        while let Some(source_path_id) = to_visit.pop() {
+            if !visited.insert(source_path_id) {
+                continue;
+            }
+            let Some(source_info) = asset_sources.get(&source_path_id) else {
+                if source_path_id == root {
+                    return LoadState::NotLoaded;
+                }
+                // dependencies are queued for loading once their dependant is loaded
+                load_state = LoadState::Loading;
+                continue;
+            };
+            match &source_info.load_state {
+                LoadState::Loaded => {}
+                LoadState::Failed(_) | LoadState::Unloaded => {
+                    return source_info.load_state.clone();
+                }
+                LoadState::NotLoaded if source_path_id == root => return LoadState::NotLoaded,
+                LoadState::NotLoaded | LoadState::Loading => {
+                    load_state = LoadState::Loading;
+                    // dependencies are only known once the source is loaded
+                    continue;
+                }
+            }
+            if let Some(meta) = &source_info.meta {
+                to_visit.extend(
+                    meta.assets
+                        .iter()
+                        .flat_map(|asset| &asset.dependencies)
+                        .map(|dependency| dependency.get_id().source_path_id()),
+                );
+            }
+        }
+
+        load_state
+    }
+
     /// Gets the overall load state of a group of assets from the provided handles.
     ///
     /// This method will only return [`LoadState::Loaded`] if all assets in the
@@ -336,7 +556,7 @@ impl AssetServer {
                     LoadState::Loading => {
                         load_state = LoadState::Loading;
                     }
-                    LoadState::Failed => return LoadState::Failed,
+                    state @ LoadState::Failed(_) => return state,
                     LoadState::NotLoaded => return LoadState::NotLoaded,
                     LoadState::Unloaded => return LoadState::Unloaded,
                 },
@@ -369,17 +589,79 @@ impl AssetServer {
     /// effectively loaded and available in the [`Assets`] collection. The asset will always fail to
     /// load if the provided path doesn't contain an extension.
     ///
+    /// If several loaders support the extension of the asset, the loader declaring `T` as its
+    /// [asset type](AssetLoader::asset_type_id) is used.
+    ///
     /// [asset loader]: AssetLoader
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<T> {
-        self.load_untyped(path).typed()
+        let asset_path = path.into();
+        self.set_loader_selection(&asset_path, LoaderSelection::AssetType(TypeId::of::<T>()));
+        self.load_untyped(asset_path).typed()
+    }
+
+    /// Queues the [`Asset`] at the provided path for loading with the asset loader `L`, and
+    /// returns an untyped handle.
+    ///
+    /// The loader is used regardless of the extension of the asset, and must have been added to
+    /// the asset server with [`add_loader`](AssetServer::add_loader). Reloading the asset will
+    /// keep using the same loader.
+    ///
+    /// ```no_run
+    /// # use bevy_asset::*;
+    /// # use bevy_reflect::{TypePath, TypeUuid};
+    /// # #[derive(TypeUuid, TypePath)]
+    /// # #[uuid = "00000000-0000-0000-0000-000000000000"]
+    /// # struct Level;
+    /// # struct LevelLoader;
+    /// # impl AssetLoader for LevelLoader {
+    /// #     fn load<'a>(&'a self, _: &'a mut Reader, _: &'a mut LoadContext) -> BoxedFuture<'a, Result<(), Error>> { unimplemented!() }
+    /// #     fn extensions(&self) -> &[&str] { &["json"] }
+    /// # }
+    /// # fn system(asset_server: &AssetServer) {
+    /// let level: Handle<Level> = asset_server
+    ///     .load_with_loader::<LevelLoader>("levels/forest.json")
+    ///     .typed();
+    /// # }
+    /// ```
+    ///
+    /// See [`load`](AssetServer::load).
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_loader<'a, L: AssetLoader>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+    ) -> HandleUntyped {
+        let asset_path = path.into();
+        self.set_loader_selection(
+            &asset_path,
+            LoaderSelection::Loader(TypeId::of::<L>(), std::any::type_name::<L>()),
+        );
+        self.load_untyped(asset_path)
+    }
+
+    /// Queues an [`Asset`] at the provided relative path for asynchronous loading, returning a
+    /// cache handle.
+    ///
+    /// Assets which are only held by cache handles stay loaded until their [`Assets`] collection
+    /// exceeds its memory budget, at which point they are evicted in least-recently-used order.
+    /// Calling this method again for an asset marks it as recently used, and reloads it if it was
+    /// evicted.
+    ///
+    /// See [`load`](AssetServer::load) and [`Assets::set_memory_budget`].
+    #[must_use = "not using the returned cache handle may result in the unexpected release of the asset"]
+    pub fn load_cached<'a, T: Asset, P: Into<AssetPath<'a>>>(&self, path: P) -> Handle<T> {
+        let asset_path = path.into();
+        self.set_loader_selection(&asset_path, LoaderSelection::AssetType(TypeId::of::<T>()));
+        let handle_id = self.load_untracked(asset_path, false);
+        let sender = self.server.asset_ref_counter.channel.sender.clone();
+        Handle::cache(handle_id, sender)
     }
 
     async fn load_async(
         &self,
         asset_path: AssetPath<'_>,
         force: bool,
-    ) -> Result<AssetPathId, AssetServerError> {
+    ) -> Result<AssetPathId, Arc<AssetServerError>> {
         let asset_path_id: AssetPathId = asset_path.get_id();
 
         // load metadata and update source info. this is done in a scope to ensure we release the
@@ -403,7 +685,7 @@ impl AssetServer {
                 && (source_info
                     .committed_assets
                     .contains(&asset_path_id.label_id())
-                    || source_info.load_state == LoadState::Loading)
+                    || matches!(source_info.load_state, LoadState::Loading))
             {
                 return Ok(asset_path_id);
             }
@@ -415,38 +697,49 @@ impl AssetServer {
             source_info.version
         };
 
-        let set_asset_failed = || {
+        let set_asset_failed = |error: AssetServerError| {
+            let error = Arc::new(error);
             let mut asset_sources = self.server.asset_sources.write();
             let source_info = asset_sources
                 .get_mut(&asset_path_id.source_path_id())
                 .expect("`AssetSource` should exist at this point.");
-            source_info.load_state = LoadState::Failed;
+            source_info.load_state = LoadState::Failed(error.clone());
+            let _ = self.server.load_failed_sender.send(AssetLoadFailedEvent {
+                path: asset_path.to_owned(),
+                error: error.clone(),
+            });
+            error
         };
 
         // get the according asset loader
-        let mut maybe_asset_loader = self.get_path_asset_loader(asset_path.path(), true);
+        let selection = self
+            .server
+            .loader_selections
+            .read()
+            .get(&asset_path_id.source_path_id())
+            .copied()
+            .unwrap_or(LoaderSelection::Extension);
+        let mut maybe_asset_loader = self.get_path_asset_loader(asset_path.path(), true, selection);
 
         // if it's still pending, block until notified and refetch the new asset loader
         if let Ok(MaybeAssetLoader::Pending { receiver, .. }) = maybe_asset_loader {
             let _ = receiver.recv().await;
-            maybe_asset_loader = self.get_path_asset_loader(asset_path.path(), false);
+            maybe_asset_loader = self.get_path_asset_loader(asset_path.path(), false, selection);
         }
 
         let asset_loader = match maybe_asset_loader {
-            Ok(MaybeAssetLoader::Ready(loader)) => loader,
+            Ok(MaybeAssetLoader::Ready { loader, .. }) => loader,
             Err(err) => {
-                set_asset_failed();
-                return Err(err);
+                return Err(set_asset_failed(err));
             }
             Ok(MaybeAssetLoader::Pending { .. }) => unreachable!(),
         };
 
-        // load the asset bytes
-        let bytes = match self.asset_io().load_path(asset_path.path()).await {
-            Ok(bytes) => bytes,
+        // open a reader over the asset source
+        let mut reader = match self.asset_io().read_path(asset_path.path()).await {
+            Ok(reader) => reader,
             Err(err) => {
-                set_asset_failed();
-                return Err(AssetServerError::AssetIoError(err));
+                return Err(set_asset_failed(AssetServerError::AssetIoError(err)));
             }
         };
 
@@ -458,13 +751,8 @@ impl AssetServer {
             version,
         );
 
-        if let Err(err) = asset_loader
-            .load(&bytes, &mut load_context)
-            .await
-            .map_err(AssetServerError::AssetLoaderError)
-        {
-            set_asset_failed();
-            return Err(err);
+        if let Err(err) = asset_loader.load(&mut *reader, &mut load_context).await {
+            return Err(set_asset_failed(AssetServerError::AssetLoaderError(err)));
         }
 
         // if version has changed since we loaded and grabbed a lock, return. there is a newer
@@ -491,14 +779,20 @@ impl AssetServer {
             assets: load_context.get_asset_metas(),
         });
 
-        // load asset dependencies and prepare asset type hashmap
+        // prepare asset type hashmap and collect asset dependencies
+        let mut dependencies = Vec::new();
         for (label, loaded_asset) in &mut load_context.labeled_assets {
             let label_id = LabelId::from(label.as_ref().map(|label| label.as_str()));
             let type_uuid = loaded_asset.value.as_ref().unwrap().type_uuid();
             source_info.asset_types.insert(label_id, type_uuid);
-            for dependency in &loaded_asset.dependencies {
-                self.load_untracked(dependency.clone(), false);
-            }
+            dependencies.extend(loaded_asset.dependencies.iter().cloned());
+        }
+
+        // release the lock before loading dependencies, as tasks may run inline on the current
+        // thread and need to access asset sources themselves
+        drop(asset_sources);
+        for dependency in dependencies {
+            self.load_untracked(dependency, false);
         }
 
         self.asset_io()
@@ -546,6 +840,10 @@ impl AssetServer {
         asset_path.into()
     }
 
+    pub(crate) fn send_load_failed_events(&self, events: &mut EventWriter<AssetLoadFailedEvent>) {
+        events.send_batch(self.server.load_failed_receiver.try_iter());
+    }
+
     /// Loads assets from the specified folder recursively.
     ///
     /// # Errors
@@ -571,7 +869,10 @@ impl AssetServer {
             if self.asset_io().is_dir(&child_path) {
                 handles.extend(self.load_folder(&child_path)?);
             } else {
-                if self.get_path_asset_loader(&child_path, true).is_err() {
+                // assets with ambiguous loaders are still loaded, so that the error is reported
+                if let Err(AssetServerError::MissingAssetLoader { .. }) =
+                    self.get_path_asset_loader(&child_path, true, LoaderSelection::Extension)
+                {
                     continue;
                 }
                 let handle =
@@ -589,10 +890,18 @@ impl AssetServer {
 
         if !potential_frees.is_empty() {
             let ref_counts = self.server.asset_ref_counter.ref_counts.read();
+            let cache_ref_counts = self.server.asset_ref_counter.cache_ref_counts.read();
             let asset_sources = self.server.asset_sources.read();
             let asset_lifecycles = self.server.asset_lifecycles.read();
+            let mut freed_sources = HashSet::default();
             for potential_free in potential_frees.drain(..) {
-                if let Some(&0) = ref_counts.get(&potential_free) {
+                let count = |ref_counts: &HashMap<HandleId, usize>| {
+                    ref_counts.get(&potential_free).copied().unwrap_or(0)
+                };
+                if count(&ref_counts) == 0 && count(&cache_ref_counts) == 0 {
+                    if let HandleId::AssetPathId(id) = potential_free {
+                        freed_sources.insert(id.source_path_id());
+                    }
                     let type_uuid = match potential_free {
                         HandleId::Id(type_uuid, _) => Some(type_uuid),
                         HandleId::AssetPathId(id) => asset_sources
@@ -607,13 +916,39 @@ impl AssetServer {
                     }
                 }
             }
+
+            // forget the loader selected for a source once none of its assets are referenced
+            if !freed_sources.is_empty() {
+                let is_referenced = |source_path_id| {
+                    ref_counts
+                        .iter()
+                        .chain(cache_ref_counts.iter())
+                        .any(|(handle_id, &count)| match handle_id {
+                            HandleId::AssetPathId(id) => {
+                                count > 0 && id.source_path_id() == source_path_id
+                            }
+                            HandleId::Id(..) => false,
+                        })
+                };
+                self.server
+                    .loader_selections
+                    .write()
+                    .retain(|source_path_id, _| {
+                        !freed_sources.contains(source_path_id) || is_referenced(*source_path_id)
+                    });
+            }
         }
     }
 
     /// Iterates through asset references and marks assets with no active handles as unused.
+    ///
+    /// Assets with no strong handles but at least one cache handle are marked as cached instead.
     pub fn mark_unused_assets(&self) {
-        let receiver = &self.server.asset_ref_counter.channel.receiver;
-        let mut ref_counts = self.server.asset_ref_counter.ref_counts.write();
+        let ref_counter = &self.server.asset_ref_counter;
+        let receiver = &ref_counter.channel.receiver;
+        let mut ref_counts = ref_counter.ref_counts.write();
+        let mut cache_ref_counts = ref_counter.cache_ref_counts.write();
+        let mut cached_assets = ref_counter.cached_assets.lock();
         let mut potential_frees = None;
         loop {
             let ref_change = match receiver.try_recv() {
@@ -622,22 +957,103 @@ impl AssetServer {
                 Err(TryRecvError::Disconnected) => panic!("RefChange channel disconnected."),
             };
             match ref_change {
-                RefChange::Increment(handle_id) => *ref_counts.entry(handle_id).or_insert(0) += 1,
+                RefChange::Increment(handle_id) => {
+                    let entry = ref_counts.entry(handle_id).or_insert(0);
+                    *entry += 1;
+                    if *entry == 1 {
+                        cached_assets.last_used.remove(&handle_id);
+                    }
+                }
                 RefChange::Decrement(handle_id) => {
                     let entry = ref_counts.entry(handle_id).or_insert(0);
                     *entry -= 1;
                     if *entry == 0 {
-                        potential_frees
-                            .get_or_insert_with(|| {
-                                self.server.asset_ref_counter.mark_unused_assets.lock()
-                            })
-                            .push(handle_id);
+                        if cache_ref_counts.get(&handle_id).copied().unwrap_or(0) > 0 {
+                            cached_assets.touch(handle_id, &ref_counter.use_clock);
+                        } else {
+                            potential_frees
+                                .get_or_insert_with(|| ref_counter.mark_unused_assets.lock())
+                                .push(handle_id);
+                        }
+                    }
+                }
+                RefChange::IncrementCache(handle_id) => {
+                    *cache_ref_counts.entry(handle_id).or_insert(0) += 1;
+                    if ref_counts.get(&handle_id).copied().unwrap_or(0) == 0 {
+                        cached_assets.touch(handle_id, &ref_counter.use_clock);
+                    }
+                }
+                RefChange::DecrementCache(handle_id) => {
+                    let entry = cache_ref_counts.entry(handle_id).or_insert(0);
+                    *entry -= 1;
+                    if *entry == 0 {
+                        cached_assets.last_used.remove(&handle_id);
+                        if ref_counts.get(&handle_id).copied().unwrap_or(0) == 0 {
+                            potential_frees
+                                .get_or_insert_with(|| ref_counter.mark_unused_assets.lock())
+                                .push(handle_id);
+                        }
                     }
                 }
             }
         }
     }
 
+    /// Evicts assets of type `T` only held by cache handles, in least-recently-used order, until
+    /// the memory usage of `assets` fits within its memory budget.
+    pub(crate) fn evict_cached_assets<T: Asset>(
+        &self,
+        mut assets: ResMut<Assets<T>>,
+        events: &mut EventWriter<AssetEvictedEvent<T>>,
+    ) {
+        assets.update_memory_usage();
+        let (Some(budget), Some(mut memory_usage)) =
+            (assets.memory_budget(), assets.memory_usage())
+        else {
+            return;
+        };
+        if memory_usage <= budget {
+            return;
+        }
+
+        // make sure recently created or dropped handles are accounted for
+        self.mark_unused_assets();
+
+        let mut cached_assets = self.server.asset_ref_counter.cached_assets.lock();
+        let mut candidates = cached_assets
+            .last_used
+            .iter()
+            .filter_map(|(&handle_id, &last_used)| {
+                let size = assets.asset_memory_size(handle_id)?;
+                // the asset may have been read through the collection after its handles were used
+                let last_used = last_used.max(assets.asset_last_used(handle_id).unwrap_or(0));
+                Some((handle_id, size, last_used))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_unstable_by_key(|(_, _, last_used)| *last_used);
+
+        let mut asset_sources = self.server.asset_sources.write();
+        for (handle_id, size, _) in candidates {
+            if memory_usage <= budget {
+                break;
+            }
+            assets.remove(handle_id);
+            memory_usage = memory_usage.saturating_sub(size);
+            cached_assets.last_used.remove(&handle_id);
+
+            if let HandleId::AssetPathId(id) = handle_id {
+                if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
+                    source_info.committed_assets.remove(&id.label_id());
+                    source_info.load_state = LoadState::Unloaded;
+                }
+            }
+            events.send(AssetEvictedEvent {
+                handle: Handle::weak(handle_id),
+                size,
+            });
+        }
+    }
+
     fn create_assets_in_load_context(&self, load_context: &mut LoadContext) {
         let asset_lifecycles = self.server.asset_lifecycles.read();
         for (label, asset) in &mut load_context.labeled_assets {
@@ -720,14 +1136,35 @@ pub fn free_unused_assets_system(asset_server: Res<AssetServer>) {
     free_unused_assets_system_impl(&asset_server);
 }
 
+/// A system evicting assets of type `T` only held by cache handles when their [`Assets`]
+/// collection exceeds its memory budget.
+pub fn evict_cached_assets_system<T: Asset>(
+    asset_server: Res<AssetServer>,
+    assets: ResMut<Assets<T>>,
+    mut events: EventWriter<AssetEvictedEvent<T>>,
+) {
+    asset_server.evict_cached_assets(assets, &mut events);
+}
+
+/// A system emitting an [`AssetLoadFailedEvent`] for each asset source that failed to load.
+pub fn asset_load_failed_event_system(
+    asset_server: Res<AssetServer>,
+    mut events: EventWriter<AssetLoadFailedEvent>,
+) {
+    asset_server.send_load_failed_events(&mut events);
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{loader::LoadedAsset, update_asset_storage_system};
+    use crate::{
+        loader::LoadedAsset, update_asset_storage_system, AsyncReadExt, AsyncSeekExt, Reader,
+    };
     use bevy_app::{App, Update};
     use bevy_ecs::prelude::*;
     use bevy_reflect::{TypePath, TypeUuid};
     use bevy_utils::BoxedFuture;
+    use std::io::SeekFrom;
 
     #[derive(Debug, TypeUuid, TypePath)]
     #[uuid = "a5189b72-0572-4290-a2e0-96f73a491c44"]
@@ -737,7 +1174,7 @@ mod test {
     impl AssetLoader for FakePngLoader {
         fn load<'a>(
             &'a self,
-            _: &'a [u8],
+            _: &'a mut Reader,
             ctx: &'a mut LoadContext,
         ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
             ctx.set_default_asset(LoadedAsset::new(PngAsset));
@@ -753,7 +1190,7 @@ mod test {
     impl AssetLoader for FailingLoader {
         fn load<'a>(
             &'a self,
-            _: &'a [u8],
+            _: &'a mut Reader,
             _: &'a mut LoadContext,
         ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
             Box::pin(async { anyhow::bail!("failed") })
@@ -768,7 +1205,7 @@ mod test {
     impl AssetLoader for FakeMultipleDotLoader {
         fn load<'a>(
             &'a self,
-            _: &'a [u8],
+            _: &'a mut Reader,
             _: &'a mut LoadContext,
         ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
             Box::pin(async move { Ok(()) })
@@ -779,6 +1216,54 @@ mod test {
         }
     }
 
+    struct DependantLoader {
+        extensions: &'static [&'static str],
+        dependencies: &'static [&'static str],
+    }
+    impl AssetLoader for DependantLoader {
+        fn load<'a>(
+            &'a self,
+            _: &'a mut Reader,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            let mut asset = LoadedAsset::new(PngAsset);
+            for dependency in self.dependencies {
+                asset.add_dependency((*dependency).into());
+            }
+            ctx.set_default_asset(asset);
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            self.extensions
+        }
+    }
+
+    struct SeekingLoader;
+    impl AssetLoader for SeekingLoader {
+        fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            Box::pin(async move {
+                // the first byte is the offset of the payload
+                let mut header = [0; 1];
+                reader.read_exact(&mut header).await?;
+                reader.seek(SeekFrom::Start(header[0] as u64)).await?;
+                let mut payload = Vec::new();
+                reader.read_to_end(&mut payload).await?;
+                anyhow::ensure!(payload == b"payload", "unexpected payload");
+                ctx.set_default_asset(LoadedAsset::new(PngAsset));
+                Ok(())
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["chunked"]
+        }
+    }
+
     fn setup(asset_path: impl AsRef<Path>) -> AssetServer {
         use crate::FileAssetIo;
         IoTaskPool::init(Default::default);
@@ -790,7 +1275,9 @@ mod test {
         let asset_server = setup(".");
         asset_server.add_loader(FakePngLoader);
 
-        let Ok(MaybeAssetLoader::Ready(t)) = asset_server.get_path_asset_loader("test.png", true) else {
+        let Ok(MaybeAssetLoader::Ready { loader: t, .. }) =
+            asset_server.get_path_asset_loader("test.png", true, LoaderSelection::Extension)
+        else {
             panic!();
         };
 
@@ -802,7 +1289,9 @@ mod test {
         let asset_server = setup(".");
         asset_server.add_loader(FakePngLoader);
 
-        let Ok(MaybeAssetLoader::Ready(t)) = asset_server.get_path_asset_loader("test.PNG", true) else {
+        let Ok(MaybeAssetLoader::Ready { loader: t, .. }) =
+            asset_server.get_path_asset_loader("test.PNG", true, LoaderSelection::Extension)
+        else {
             panic!();
         };
         assert_eq!(t.extensions()[0], "png");
@@ -811,7 +1300,7 @@ mod test {
     #[test]
     fn no_loader() {
         let asset_server = setup(".");
-        let t = asset_server.get_path_asset_loader("test.pong", true);
+        let t = asset_server.get_path_asset_loader("test.pong", true, LoaderSelection::Extension);
         assert!(t.is_err());
     }
 
@@ -819,13 +1308,15 @@ mod test {
     fn multiple_extensions_no_loader() {
         let asset_server = setup(".");
 
-        assert!(
-            match asset_server.get_path_asset_loader("test.v1.2.3.pong", true) {
-                Err(AssetServerError::MissingAssetLoader { extensions }) =>
-                    extensions == vec!["v1.2.3.pong", "2.3.pong", "3.pong", "pong"],
-                _ => false,
-            }
-        );
+        assert!(match asset_server.get_path_asset_loader(
+            "test.v1.2.3.pong",
+            true,
+            LoaderSelection::Extension
+        ) {
+            Err(AssetServerError::MissingAssetLoader { extensions }) =>
+                extensions == vec!["v1.2.3.pong", "2.3.pong", "3.pong", "pong"],
+            _ => false,
+        });
     }
 
     #[test]
@@ -855,7 +1346,9 @@ mod test {
         let asset_server = setup(".");
         asset_server.add_loader(FakePngLoader);
 
-        let Ok(MaybeAssetLoader::Ready(t)) = asset_server.get_path_asset_loader("test-v1.2.3.png", true) else {
+        let Ok(MaybeAssetLoader::Ready { loader: t, .. }) =
+            asset_server.get_path_asset_loader("test-v1.2.3.png", true, LoaderSelection::Extension)
+        else {
             panic!();
         };
         assert_eq!(t.extensions()[0], "png");
@@ -866,12 +1359,222 @@ mod test {
         let asset_server = setup(".");
         asset_server.add_loader(FakeMultipleDotLoader);
 
-        let Ok(MaybeAssetLoader::Ready(t)) = asset_server.get_path_asset_loader("test.test.png", true) else {
+        let Ok(MaybeAssetLoader::Ready { loader: t, .. }) =
+            asset_server.get_path_asset_loader("test.test.png", true, LoaderSelection::Extension)
+        else {
             panic!();
         };
         assert_eq!(t.extensions()[0], "test.png");
     }
 
+    #[derive(Debug, TypeUuid, TypePath)]
+    #[uuid = "0d5b9b3e-3d0a-4b8e-9c1f-5e7f3c2a6b41"]
+    struct OtherAsset;
+
+    struct DataLoader<A: Asset>(fn() -> A);
+    impl<A: Asset> AssetLoader for DataLoader<A> {
+        fn load<'a>(
+            &'a self,
+            _: &'a mut Reader,
+            ctx: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+            ctx.set_default_asset(LoadedAsset::new((self.0)()));
+            Box::pin(async move { Ok(()) })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["data"]
+        }
+
+        fn asset_type_id(&self) -> Option<TypeId> {
+            Some(TypeId::of::<A>())
+        }
+    }
+
+    #[test]
+    fn loaders_per_asset_type() {
+        let asset_server = setup(".");
+        asset_server.add_loader(DataLoader(|| PngAsset));
+        asset_server.add_loader(DataLoader(|| OtherAsset));
+
+        let loader_type_id =
+            |selection| match asset_server.get_path_asset_loader("test.data", true, selection) {
+                Ok(MaybeAssetLoader::Ready { type_id, .. }) => Ok(type_id),
+                Ok(MaybeAssetLoader::Pending { .. }) => panic!(),
+                Err(err) => Err(err),
+            };
+
+        assert_eq!(
+            loader_type_id(LoaderSelection::AssetType(TypeId::of::<OtherAsset>())).unwrap(),
+            TypeId::of::<DataLoader<OtherAsset>>()
+        );
+        assert_eq!(
+            loader_type_id(LoaderSelection::AssetType(TypeId::of::<PngAsset>())).unwrap(),
+            TypeId::of::<DataLoader<PngAsset>>()
+        );
+        assert_eq!(
+            loader_type_id(LoaderSelection::Loader(
+                TypeId::of::<DataLoader<PngAsset>>(),
+                ""
+            ))
+            .unwrap(),
+            TypeId::of::<DataLoader<PngAsset>>()
+        );
+        assert!(matches!(
+            loader_type_id(LoaderSelection::Loader(TypeId::of::<FakePngLoader>(), "")),
+            Err(AssetServerError::UnregisteredAssetLoader(_))
+        ));
+        match loader_type_id(LoaderSelection::Extension) {
+            Err(AssetServerError::AmbiguousAssetLoader { extension, loaders }) => {
+                assert_eq!(extension, "data");
+                assert_eq!(loaders.len(), 2);
+            }
+            _ => panic!("expected an ambiguous loader error"),
+        }
+
+        // a loader for the same asset type replaces the previous one
+        asset_server.add_loader(DataLoader(|| OtherAsset));
+        assert!(matches!(
+            loader_type_id(LoaderSelection::Extension),
+            Err(AssetServerError::AmbiguousAssetLoader { loaders, .. }) if loaders.len() == 2
+        ));
+
+        // a loader without a declared asset type replaces all previous ones
+        asset_server.add_loader(DependantLoader {
+            extensions: &["data"],
+            dependencies: &[],
+        });
+        assert_eq!(
+            loader_type_id(LoaderSelection::Extension).unwrap(),
+            TypeId::of::<DependantLoader>()
+        );
+    }
+
+    #[test]
+    fn preregistered_loader_keeps_ready_loaders() {
+        let asset_server = setup(".");
+        asset_server.add_loader(DataLoader(|| PngAsset));
+        asset_server.preregister_loader(&["data"]);
+        asset_server.preregister_loader(&["data"]);
+
+        let loader = |selection| {
+            asset_server
+                .get_path_asset_loader("test.data", true, selection)
+                .unwrap()
+        };
+        // only the latest pre-registration is kept, alongside the ready loader
+        assert_eq!(
+            asset_server.server.extension_to_loader_indices.read()["data"],
+            vec![0, 2]
+        );
+        assert!(matches!(
+            loader(LoaderSelection::AssetType(TypeId::of::<PngAsset>())),
+            MaybeAssetLoader::Ready { .. }
+        ));
+        assert!(matches!(
+            loader(LoaderSelection::AssetType(TypeId::of::<OtherAsset>())),
+            MaybeAssetLoader::Pending { .. }
+        ));
+
+        // the real loader replaces the pending one
+        asset_server.add_loader(DataLoader(|| OtherAsset));
+        assert_eq!(
+            asset_server.server.extension_to_loader_indices.read()["data"],
+            vec![0, 3]
+        );
+        assert!(matches!(
+            loader(LoaderSelection::AssetType(TypeId::of::<OtherAsset>())),
+            MaybeAssetLoader::Ready { type_id, .. } if type_id == TypeId::of::<DataLoader<OtherAsset>>()
+        ));
+    }
+
+    #[test]
+    fn load_with_selected_loader() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["a.data", "b.data"] {
+            std::fs::write(dir.path().join(file), []).unwrap();
+        }
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(DataLoader(|| PngAsset));
+        asset_server.add_loader(DataLoader(|| OtherAsset));
+        let mut app = App::new();
+        app.insert_resource(asset_server.register_asset_type::<PngAsset>());
+        app.insert_resource(asset_server.register_asset_type::<OtherAsset>());
+        app.insert_resource(asset_server.clone());
+        app.add_systems(
+            Update,
+            (
+                update_asset_storage_system::<PngAsset>,
+                update_asset_storage_system::<OtherAsset>,
+            ),
+        );
+
+        let path: AssetPath = "a.data".into();
+        asset_server.set_loader_selection(
+            &path,
+            LoaderSelection::AssetType(TypeId::of::<OtherAsset>()),
+        );
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        app.update();
+        let handle = Handle::<OtherAsset>::weak(path.get_id().into());
+        assert!(app.world.resource::<Assets<OtherAsset>>().contains(&handle));
+        assert!(app.world.resource::<Assets<PngAsset>>().is_empty());
+
+        // the explicitly selected loader is kept when loading with a type
+        asset_server.set_loader_selection(
+            &path,
+            LoaderSelection::Loader(TypeId::of::<DataLoader<PngAsset>>(), ""),
+        );
+        asset_server.set_loader_selection(
+            &path,
+            LoaderSelection::AssetType(TypeId::of::<OtherAsset>()),
+        );
+        futures_lite::future::block_on(asset_server.load_async(path.clone(), true)).unwrap();
+        app.update();
+        assert_eq!(app.world.resource::<Assets<PngAsset>>().len(), 1);
+
+        let err = futures_lite::future::block_on(asset_server.load_async("b.data".into(), true))
+            .unwrap_err();
+        assert!(matches!(
+            *err,
+            AssetServerError::AmbiguousAssetLoader { .. }
+        ));
+    }
+
+    #[test]
+    fn loader_selection_is_freed_with_its_assets() {
+        let dir = create_dir_and_file("a.data");
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(DataLoader(|| PngAsset));
+        asset_server.add_loader(DataLoader(|| OtherAsset));
+        let source_path_id = AssetPath::from("a.data").get_id().source_path_id();
+        let has_selection = || {
+            asset_server
+                .server
+                .loader_selections
+                .read()
+                .contains_key(&source_path_id)
+        };
+        let free = || {
+            asset_server.mark_unused_assets();
+            asset_server.free_unused_assets();
+        };
+
+        let handle: Handle<OtherAsset> = asset_server.load("a.data");
+        let labeled: Handle<OtherAsset> = asset_server.load("a.data#label");
+        free();
+        assert!(has_selection());
+
+        // another asset of the same source is still referenced
+        drop(handle);
+        free();
+        assert!(has_selection());
+
+        drop(labeled);
+        free();
+        assert!(!has_selection());
+    }
+
     fn create_dir_and_file(file: impl AsRef<Path>) -> tempfile::TempDir {
         let asset_dir = tempfile::tempdir().unwrap();
         std::fs::write(asset_dir.path().join(file), []).unwrap();
@@ -888,14 +1591,14 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(match err {
+        assert!(match &*err {
             AssetServerError::MissingAssetLoader { extensions } => {
-                extensions == ["not-a-real-extension"]
+                extensions == &["not-a-real-extension"]
             }
             _ => false,
         });
 
-        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
+        assert!(asset_server.get_load_state(handle).is_failed());
     }
 
     #[test]
@@ -908,9 +1611,9 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetIoError(_)));
+        assert!(matches!(*err, AssetServerError::AssetIoError(_)));
 
-        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
+        assert!(asset_server.get_load_state(handle).is_failed());
     }
 
     #[test]
@@ -924,9 +1627,116 @@ mod test {
 
         let err = futures_lite::future::block_on(asset_server.load_async(path.clone(), true))
             .unwrap_err();
-        assert!(matches!(err, AssetServerError::AssetLoaderError(_)));
+        assert!(matches!(*err, AssetServerError::AssetLoaderError(_)));
+
+        // the load state and the failure event retain the error
+        assert_eq!(
+            asset_server.get_load_state(handle),
+            LoadState::Failed(err.clone())
+        );
+        let events = asset_server
+            .server
+            .load_failed_receiver
+            .try_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].path, path);
+        assert!(Arc::ptr_eq(&events[0].error, &err));
+    }
 
-        assert_eq!(asset_server.get_load_state(handle), LoadState::Failed);
+    fn setup_dependency_app(asset_server: &AssetServer) -> App {
+        let mut app = App::new();
+        app.insert_resource(asset_server.register_asset_type::<PngAsset>());
+        app.insert_resource(asset_server.clone());
+        app.add_systems(Update, update_asset_storage_system::<PngAsset>);
+        app
+    }
+
+    #[test]
+    fn test_recursive_dependency_load_state() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["a.parent", "dep.png"] {
+            std::fs::write(dir.path().join(file), []).unwrap();
+        }
+        let asset_server = setup(dir.path());
+        // the parent also depends on itself, which must not loop forever
+        asset_server.add_loader(DependantLoader {
+            extensions: &["parent"],
+            dependencies: &["dep.png", "a.parent"],
+        });
+        // keep the dependency pending until its loader is added
+        asset_server.preregister_loader(&["png"]);
+        let mut app = setup_dependency_app(&asset_server);
+
+        let parent: AssetPath = "a.parent".into();
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(parent.get_id()),
+            LoadState::NotLoaded
+        );
+
+        futures_lite::future::block_on(asset_server.load_async(parent.clone(), true)).unwrap();
+        app.update();
+        assert_eq!(
+            asset_server.get_load_state(parent.get_id()),
+            LoadState::Loaded
+        );
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(parent.get_id()),
+            LoadState::Loading
+        );
+
+        asset_server.add_loader(FakePngLoader);
+        futures_lite::future::block_on(asset_server.load_async("dep.png".into(), true)).unwrap();
+        app.update();
+        assert_eq!(
+            asset_server.get_recursive_dependency_load_state(parent.get_id()),
+            LoadState::Loaded
+        );
+    }
+
+    #[test]
+    fn test_recursive_dependency_load_state_failed() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["a.parent", "dep.fail"] {
+            std::fs::write(dir.path().join(file), []).unwrap();
+        }
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(DependantLoader {
+            extensions: &["parent"],
+            dependencies: &["dep.fail"],
+        });
+        asset_server.add_loader(FailingLoader);
+        let mut app = setup_dependency_app(&asset_server);
+
+        let parent: AssetPath = "a.parent".into();
+        futures_lite::future::block_on(asset_server.load_async(parent.clone(), true)).unwrap();
+        app.update();
+        futures_lite::future::block_on(asset_server.load_async("dep.fail".into(), true))
+            .unwrap_err();
+
+        assert_eq!(
+            asset_server.get_load_state(parent.get_id()),
+            LoadState::Loaded
+        );
+        assert!(asset_server
+            .get_recursive_dependency_load_state(parent.get_id())
+            .is_failed());
+    }
+
+    #[test]
+    fn test_seeking_loader() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut data = vec![0; 16];
+        data[0] = 16;
+        data.extend_from_slice(b"payload");
+        std::fs::write(dir.path().join("data.chunked"), data).unwrap();
+
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(SeekingLoader);
+        let _assets = asset_server.register_asset_type::<PngAsset>();
+
+        let path: AssetPath = "data.chunked".into();
+        futures_lite::future::block_on(asset_server.load_async(path, true)).unwrap();
     }
 
     #[test]
@@ -1011,6 +1821,127 @@ mod test {
         assert!(get_asset(&handle, &app.world).is_some());
     }
 
+    #[test]
+    fn test_cached_asset_eviction() {
+        let asset_server = setup(".");
+        let mut assets = asset_server.register_asset_type::<PngAsset>();
+        assets.set_memory_budget(25, |_| 10);
+
+        let mut app = App::new();
+        app.insert_resource(asset_server);
+        app.add_event::<AssetEvictedEvent<PngAsset>>();
+        app.add_systems(
+            Update,
+            (
+                free_unused_assets_system,
+                update_asset_storage_system::<PngAsset>,
+                evict_cached_assets_system::<PngAsset>,
+            )
+                .chain(),
+        );
+
+        // three assets only held by cache handles, and one held by a strong handle
+        let cache_handles = (0..3)
+            .map(|_| {
+                let handle = assets.add(PngAsset);
+                let cache_handle = assets.get_cache_handle(&handle);
+                assert!(cache_handle.is_cache());
+                cache_handle
+            })
+            .collect::<Vec<_>>();
+        let strong_handle = assets.add(PngAsset);
+        app.insert_resource(assets);
+        app.world.resource::<AssetServer>().mark_unused_assets();
+
+        // use the first asset again, making the second one the least recently used
+        let first = app
+            .world
+            .resource::<Assets<PngAsset>>()
+            .get_cache_handle(&cache_handles[0]);
+
+        app.update();
+        let assets = app.world.resource::<Assets<PngAsset>>();
+        assert_eq!(assets.memory_usage(), Some(20));
+        assert!(assets.get(&first).is_some());
+        assert!(assets.get(&cache_handles[1]).is_none());
+        assert!(assets.get(&cache_handles[2]).is_none());
+        assert!(assets.get(&strong_handle).is_some());
+
+        let events = app.world.resource::<Events<AssetEvictedEvent<PngAsset>>>();
+        let evicted = events
+            .iter_current_update_events()
+            .map(|event| (event.handle.id(), event.size))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            evicted,
+            vec![(cache_handles[1].id(), 10), (cache_handles[2].id(), 10)]
+        );
+
+        // assets held by strong handles are never evicted
+        let mut assets = app.world.resource_mut::<Assets<PngAsset>>();
+        assets.set_memory_budget(0, |_| 10);
+        let _cache_handle = assets.get_cache_handle(&strong_handle);
+        drop(cache_handles);
+        drop(first);
+        app.update();
+        app.update();
+        let assets = app.world.resource::<Assets<PngAsset>>();
+        assert_eq!(assets.len(), 1);
+        assert!(assets.get(&strong_handle).is_some());
+    }
+
+    #[derive(Debug, TypeUuid, TypePath)]
+    #[uuid = "6f3a2c1e-8b4d-4e2a-9d7c-1b5e0f9a3c62"]
+    struct BytesAsset(Vec<u8>);
+
+    #[test]
+    fn test_cached_asset_eviction_tracks_reads() {
+        let asset_server = setup(".");
+        let mut assets = asset_server.register_asset_type::<BytesAsset>();
+        assets.set_memory_budget(25, |asset| asset.0.len());
+
+        let mut app = App::new();
+        app.insert_resource(asset_server);
+        app.add_event::<AssetEvictedEvent<BytesAsset>>();
+        app.add_systems(
+            Update,
+            (
+                free_unused_assets_system,
+                update_asset_storage_system::<BytesAsset>,
+                evict_cached_assets_system::<BytesAsset>,
+            )
+                .chain(),
+        );
+
+        let cache_handles = (0..2)
+            .map(|_| {
+                let handle = assets.add(BytesAsset(vec![0; 10]));
+                assets.get_cache_handle(&handle)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(assets.memory_usage(), Some(20));
+        app.insert_resource(assets);
+        app.world.resource::<AssetServer>().mark_unused_assets();
+
+        // reading the first asset makes the second one the least recently used
+        let mut assets = app.world.resource_mut::<Assets<BytesAsset>>();
+        assert!(assets.get(&cache_handles[0]).is_some());
+        // growing the first asset is accounted for without a new budget
+        assets.get_mut(&cache_handles[0]).unwrap().0.resize(20, 0);
+        assert_eq!(assets.memory_usage(), Some(30));
+
+        app.update();
+        let assets = app.world.resource::<Assets<BytesAsset>>();
+        assert_eq!(assets.memory_usage(), Some(20));
+        assert!(assets.get(&cache_handles[0]).is_some());
+        assert!(assets.get(&cache_handles[1]).is_none());
+
+        // removed assets no longer count
+        let mut assets = app.world.resource_mut::<Assets<BytesAsset>>();
+        assets.remove(&cache_handles[0]);
+        assert_eq!(assets.memory_usage(), Some(0));
+    }
+
     #[test]
     fn test_get_handle_path() {
         const PATH: &str = "path/file.png";
@@ -1,14 +1,18 @@
 use crate::{
-    update_asset_storage_system, Asset, AssetEvents, AssetLoader, AssetServer, Handle, HandleId,
-    LoadAssets, RefChange, ReflectAsset, ReflectHandle,
+    evict_cached_assets_system, update_asset_storage_system, Asset, AssetEvents, AssetEvictedEvent,
+    AssetLoader, AssetServer, Handle, HandleId, LoadAssets, RefChange, ReflectAsset, ReflectHandle,
 };
 use bevy_app::App;
 use bevy_ecs::prelude::*;
 use bevy_ecs::reflect::AppTypeRegistry;
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect};
-use bevy_utils::HashMap;
+use bevy_utils::{HashMap, HashSet};
 use crossbeam_channel::Sender;
 use std::fmt::Debug;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
 
 /// Events that involve assets of type `T`.
 ///
@@ -52,6 +56,55 @@ impl<T: Asset> Debug for AssetEvent<T> {
     }
 }
 
+/// Reports the memory used by an [`Asset`], used to enforce memory budgets.
+///
+/// See [`AddAsset::set_asset_memory_budget`].
+pub trait AssetMemorySize {
+    /// Returns the approximate memory size of the asset, in bytes.
+    fn memory_size(&self) -> usize;
+}
+
+/// A counter ordering the uses of assets, shared by the [`AssetServer`] and its [`Assets`]
+/// collections to find the least recently used assets.
+#[derive(Debug, Default)]
+pub(crate) struct UseClock(AtomicU64);
+
+impl UseClock {
+    /// Returns a time later than every time returned before.
+    pub(crate) fn tick(&self) -> u64 {
+        self.0.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+/// The memory accounting of an [`Assets`] collection with a memory budget.
+#[derive(Debug, Default)]
+struct AssetMemory {
+    /// The size of each asset when it was last measured.
+    sizes: HashMap<HandleId, usize>,
+    /// The sum of `sizes`.
+    total: usize,
+    /// Assets which were mutably accessed since they were last measured.
+    stale: HashSet<HandleId>,
+    /// When each asset was last read, on the [`UseClock`].
+    last_used: HashMap<HandleId, AtomicU64>,
+}
+
+impl AssetMemory {
+    fn record(&mut self, id: HandleId, size: usize) {
+        let previous = self.sizes.insert(id, size).unwrap_or(0);
+        self.total = self.total - previous + size;
+        self.stale.remove(&id);
+    }
+
+    fn forget(&mut self, id: HandleId) {
+        if let Some(size) = self.sizes.remove(&id) {
+            self.total -= size;
+        }
+        self.stale.remove(&id);
+        self.last_used.remove(&id);
+    }
+}
+
 /// Stores Assets of a given type and tracks changes to them.
 ///
 /// Each asset is mapped by a unique [`HandleId`], allowing any [`Handle`] with the same
@@ -65,22 +118,66 @@ impl<T: Asset> Debug for AssetEvent<T> {
 /// Remember, if there are no Strong handles for an asset (i.e. they have all been dropped), the
 /// asset will unload. Make sure you always have a Strong handle when you want to keep an asset
 /// loaded!
+///
+/// Assets only held by Cache handles stay loaded as long as the collection is within its memory
+/// budget, see [`set_memory_budget`](Assets::set_memory_budget).
 #[derive(Debug, Resource)]
 pub struct Assets<T: Asset> {
     assets: HashMap<HandleId, T>,
     events: Events<AssetEvent<T>>,
+    memory_budget: Option<usize>,
+    memory_size: Option<fn(&T) -> usize>,
+    memory: AssetMemory,
+    use_clock: Arc<UseClock>,
     pub(crate) ref_change_sender: Sender<RefChange>,
 }
 
 impl<T: Asset> Assets<T> {
-    pub(crate) fn new(ref_change_sender: Sender<RefChange>) -> Self {
+    pub(crate) fn new(ref_change_sender: Sender<RefChange>, use_clock: Arc<UseClock>) -> Self {
         Assets {
             assets: HashMap::default(),
             events: Events::default(),
+            memory_budget: None,
+            memory_size: None,
+            memory: AssetMemory::default(),
+            use_clock,
             ref_change_sender,
         }
     }
 
+    /// Measures an asset which was inserted or replaced, if the collection has a memory budget.
+    fn measure(&mut self, id: HandleId) {
+        let (Some(memory_size), Some(asset)) = (self.memory_size, self.assets.get(&id)) else {
+            return;
+        };
+        self.memory.record(id, memory_size(asset));
+        self.memory
+            .last_used
+            .insert(id, AtomicU64::new(self.use_clock.tick()));
+    }
+
+    /// Marks an asset as used, if the collection has a memory budget.
+    fn mark_used(&self, id: HandleId) {
+        if let Some(last_used) = self.memory.last_used.get(&id) {
+            last_used.store(self.use_clock.tick(), Ordering::Relaxed);
+        }
+    }
+
+    /// Marks an asset as used and to be measured again, as its size may change through a mutable
+    /// reference.
+    fn mark_modified(&mut self, id: HandleId) {
+        if self.memory_size.is_none() || !self.assets.contains_key(&id) {
+            return;
+        }
+        self.memory.stale.insert(id);
+        let tick = self.use_clock.tick();
+        self.memory
+            .last_used
+            .entry(id)
+            .or_default()
+            .store(tick, Ordering::Relaxed);
+    }
+
     /// Adds an asset to the collection, returning a Strong handle to that asset.
     ///
     /// # Events
@@ -89,6 +186,7 @@ impl<T: Asset> Assets<T> {
     pub fn add(&mut self, asset: T) -> Handle<T> {
         let id = HandleId::random::<T>();
         self.assets.insert(id, asset);
+        self.measure(id);
         self.events.send(AssetEvent::Created {
             handle: Handle::weak(id),
         });
@@ -128,6 +226,7 @@ impl<T: Asset> Assets<T> {
                 handle: Handle::weak(id),
             });
         }
+        self.measure(id);
     }
 
     /// Gets the asset for the given handle.
@@ -135,7 +234,9 @@ impl<T: Asset> Assets<T> {
     /// This is the main method for accessing asset data from an [Assets] collection. If you need
     /// mutable access to the asset, use [`get_mut`](Assets::get_mut).
     pub fn get(&self, handle: &Handle<T>) -> Option<&T> {
-        self.assets.get::<HandleId>(&handle.into())
+        let id: HandleId = handle.into();
+        self.mark_used(id);
+        self.assets.get(&id)
     }
 
     /// Checks if an asset exists for the given handle
@@ -152,6 +253,7 @@ impl<T: Asset> Assets<T> {
         self.events.send(AssetEvent::Modified {
             handle: Handle::weak(id),
         });
+        self.mark_modified(id);
         self.assets.get_mut(&id)
     }
 
@@ -160,6 +262,15 @@ impl<T: Asset> Assets<T> {
         Handle::strong(handle.into(), self.ref_change_sender.clone())
     }
 
+    /// Gets a _Cache_ handle pointing to the same asset as the given one.
+    ///
+    /// Creating a cache handle marks the asset as recently used.
+    pub fn get_cache_handle<H: Into<HandleId>>(&self, handle: H) -> Handle<T> {
+        let id: HandleId = handle.into();
+        self.mark_used(id);
+        Handle::cache(id, self.ref_change_sender.clone())
+    }
+
     /// Gets mutable access to an asset for the given handle, inserting a new value if none exists.
     ///
     /// # Events
@@ -172,7 +283,7 @@ impl<T: Asset> Assets<T> {
     ) -> &mut T {
         let mut event = None;
         let id: HandleId = handle.into();
-        let borrowed = self.assets.entry(id).or_insert_with(|| {
+        self.assets.entry(id).or_insert_with(|| {
             event = Some(AssetEvent::Created {
                 handle: Handle::weak(id),
             });
@@ -182,7 +293,8 @@ impl<T: Asset> Assets<T> {
         if let Some(event) = event {
             self.events.send(event);
         }
-        borrowed
+        self.mark_modified(id);
+        self.assets.get_mut(&id).unwrap()
     }
 
     /// Gets an iterator over all assets in the collection.
@@ -192,6 +304,12 @@ impl<T: Asset> Assets<T> {
 
     /// Gets a mutable iterator over all assets in the collection.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (HandleId, &mut T)> {
+        if self.memory_size.is_some() {
+            let ids: Vec<_> = self.assets.keys().copied().collect();
+            for id in ids {
+                self.mark_modified(id);
+            }
+        }
         self.assets.iter_mut().map(|(k, v)| {
             self.events.send(AssetEvent::Modified {
                 handle: Handle::weak(*k),
@@ -215,6 +333,7 @@ impl<T: Asset> Assets<T> {
     pub fn remove<H: Into<HandleId>>(&mut self, handle: H) -> Option<T> {
         let id: HandleId = handle.into();
         let asset = self.assets.remove(&id);
+        self.memory.forget(id);
         if asset.is_some() {
             self.events.send(AssetEvent::Removed {
                 handle: Handle::weak(id),
@@ -228,6 +347,7 @@ impl<T: Asset> Assets<T> {
     /// Keeps the allocated memory for reuse.
     pub fn clear(&mut self) {
         self.assets.clear();
+        self.memory = AssetMemory::default();
     }
 
     /// Reserves capacity for at least additional more elements to be inserted into the assets.
@@ -267,6 +387,66 @@ impl<T: Asset> Assets<T> {
     pub fn is_empty(&self) -> bool {
         self.assets.is_empty()
     }
+
+    /// Sets the memory budget of the collection, in bytes, using `memory_size` to measure the
+    /// size of each asset.
+    ///
+    /// When the collection exceeds its budget, assets only held by Cache handles are evicted in
+    /// least-recently-used order until it fits again, sending an
+    /// [`AssetEvictedEvent`](crate::AssetEvictedEvent) for each of them. An asset is used when it
+    /// is added or accessed through the collection, when it is held by a Strong handle, and when a
+    /// new Cache handle to it is created. Assets held by Strong handles are never evicted, so the
+    /// budget may still be exceeded.
+    ///
+    /// Each asset is measured when it is added, and again after it was mutably accessed.
+    pub fn set_memory_budget(&mut self, budget: usize, memory_size: fn(&T) -> usize) {
+        self.memory_budget = Some(budget);
+        self.memory_size = Some(memory_size);
+        self.memory = AssetMemory::default();
+        let ids: Vec<_> = self.assets.keys().copied().collect();
+        for id in ids {
+            self.measure(id);
+        }
+    }
+
+    /// Gets the memory budget of the collection, in bytes, if any.
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget
+    }
+
+    /// Gets the memory used by all assets in the collection, in bytes.
+    ///
+    /// Returns `None` if no memory budget was set for the collection.
+    pub fn memory_usage(&self) -> Option<usize> {
+        let memory_size = self.memory_size?;
+        let mut usage = self.memory.total;
+        // Only the assets which may have changed are measured again
+        for id in &self.memory.stale {
+            usage -= self.memory.sizes.get(id).copied().unwrap_or(0);
+            usage += self.assets.get(id).map_or(0, memory_size);
+        }
+        Some(usage)
+    }
+
+    /// Measures the assets which were mutably accessed since they were last measured.
+    pub(crate) fn update_memory_usage(&mut self) {
+        for id in std::mem::take(&mut self.memory.stale) {
+            self.measure(id);
+        }
+    }
+
+    /// The size of an asset when it was last measured.
+    pub(crate) fn asset_memory_size(&self, id: HandleId) -> Option<usize> {
+        self.memory.sizes.get(&id).copied()
+    }
+
+    /// When an asset was last used through the collection, on the [`UseClock`].
+    pub(crate) fn asset_last_used(&self, id: HandleId) -> Option<u64> {
+        self.memory
+            .last_used
+            .get(&id)
+            .map(|last_used| last_used.load(Ordering::Relaxed))
+    }
 }
 
 /// [`App`] extension methods for adding new asset types.
@@ -321,6 +501,25 @@ pub trait AddAsset {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader(&mut self, extensions: &[&str]) -> &mut Self;
+
+    /// Sets the memory budget, in bytes, of the [`Assets`] collection of `T`.
+    ///
+    /// See [`Assets::set_memory_budget`].
+    fn set_asset_memory_budget<T>(&mut self, budget: usize) -> &mut Self
+    where
+        T: Asset + AssetMemorySize;
+
+    /// Sets the memory budget, in bytes, of the [`Assets`] collection of `T`, using `memory_size`
+    /// to measure the size of each asset.
+    ///
+    /// See [`Assets::set_memory_budget`].
+    fn set_asset_memory_budget_with<T>(
+        &mut self,
+        budget: usize,
+        memory_size: fn(&T) -> usize,
+    ) -> &mut Self
+    where
+        T: Asset;
 }
 
 impl AddAsset for App {
@@ -337,10 +536,18 @@ impl AddAsset for App {
         };
 
         self.insert_resource(assets)
-            .add_systems(LoadAssets, update_asset_storage_system::<T>)
+            .add_systems(
+                LoadAssets,
+                (
+                    update_asset_storage_system::<T>,
+                    evict_cached_assets_system::<T>,
+                )
+                    .chain(),
+            )
             .add_systems(AssetEvents, Assets::<T>::asset_event_system)
             .register_type::<Handle<T>>()
             .add_event::<AssetEvent<T>>()
+            .add_event::<AssetEvictedEvent<T>>()
     }
 
     fn register_asset_reflect<T>(&mut self) -> &mut Self
@@ -415,6 +622,27 @@ impl AddAsset for App {
             .preregister_loader(extensions);
         self
     }
+
+    fn set_asset_memory_budget<T>(&mut self, budget: usize) -> &mut Self
+    where
+        T: Asset + AssetMemorySize,
+    {
+        self.set_asset_memory_budget_with::<T>(budget, T::memory_size)
+    }
+
+    fn set_asset_memory_budget_with<T>(
+        &mut self,
+        budget: usize,
+        memory_size: fn(&T) -> usize,
+    ) -> &mut Self
+    where
+        T: Asset,
+    {
+        self.world
+            .resource_mut::<Assets<T>>()
+            .set_memory_budget(budget, memory_size);
+        self
+    }
 }
 
 /// Loads an internal asset from a project source file.
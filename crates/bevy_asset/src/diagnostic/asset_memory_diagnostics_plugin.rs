@@ -0,0 +1,72 @@
+use crate::{Asset, Assets};
+use bevy_app::prelude::*;
+use bevy_diagnostic::{
+    Diagnostic, DiagnosticId, Diagnostics, DiagnosticsStore, MAX_DIAGNOSTIC_NAME_WIDTH,
+};
+use bevy_ecs::prelude::*;
+use bevy_utils::Uuid;
+
+/// Adds an asset memory usage diagnostic to an [`App`] for assets of type `T`.
+///
+/// The memory usage is only measured if the [`Assets`] collection of `T` has a memory budget,
+/// see [`AddAsset::set_asset_memory_budget`](crate::AddAsset::set_asset_memory_budget).
+pub struct AssetMemoryDiagnosticsPlugin<T: Asset> {
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<T: Asset> Default for AssetMemoryDiagnosticsPlugin<T> {
+    fn default() -> Self {
+        Self {
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: Asset> Plugin for AssetMemoryDiagnosticsPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, Self::setup_system)
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl<T: Asset> AssetMemoryDiagnosticsPlugin<T> {
+    /// Gets unique id of this diagnostic.
+    ///
+    /// The diagnostic id is derived from the type uuid of `T`, so that it doesn't collide with
+    /// the id of the [`AssetCountDiagnosticsPlugin`](super::AssetCountDiagnosticsPlugin).
+    pub fn diagnostic_id() -> DiagnosticId {
+        DiagnosticId(Uuid::from_u128(
+            T::TYPE_UUID.as_u128() ^ 0x6d2b_7f1c_94a0_4c5e_b3d8_0e51_a7c2_19f4,
+        ))
+    }
+
+    /// Registers the asset memory diagnostic for the current application.
+    pub fn setup_system(mut diagnostics: ResMut<DiagnosticsStore>) {
+        let asset_type_name = std::any::type_name::<T>();
+        let max_length = MAX_DIAGNOSTIC_NAME_WIDTH - "asset_memory ".len();
+        diagnostics.add(
+            Diagnostic::new(
+                Self::diagnostic_id(),
+                format!(
+                    "asset_memory {}",
+                    if asset_type_name.len() > max_length {
+                        asset_type_name
+                            .split_at(asset_type_name.len() - max_length + 1)
+                            .1
+                    } else {
+                        asset_type_name
+                    }
+                ),
+                20,
+            )
+            .with_suffix("B"),
+        );
+    }
+
+    /// Updates the memory usage of `T` assets, in bytes.
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, assets: Res<Assets<T>>) {
+        if let Some(memory_usage) = assets.memory_usage() {
+            diagnostics.add_measurement(Self::diagnostic_id(), || memory_usage as f64);
+        }
+    }
+}
@@ -95,6 +95,14 @@ impl HandleId {
 /// handle to the unloaded asset, but it will not be able to retrieve the image data, resulting in
 /// collisions no longer being detected for that entity.
 ///
+/// # Cache
+///
+/// A third kind of handle, the "Cache" handle, keeps an asset loaded only as long as its
+/// [`Assets`] collection stays within its memory budget (see [`Assets::set_memory_budget`]). Once
+/// the budget is exceeded, assets only held by Cache handles are evicted in least-recently-used
+/// order. Cache handles can be obtained with [`AssetServer::load_cached`](crate::AssetServer::load_cached)
+/// or [`Assets::get_cache_handle`].
+///
 #[derive(Component, Reflect)]
 #[reflect(Component, Default)]
 pub struct Handle<T>
@@ -114,6 +122,7 @@ enum HandleType {
     #[default]
     Weak,
     Strong(Sender<RefChange>),
+    Cache(Sender<RefChange>),
 }
 
 impl Debug for HandleType {
@@ -121,6 +130,7 @@ impl Debug for HandleType {
         match self {
             HandleType::Weak => f.write_str("Weak"),
             HandleType::Strong(_) => f.write_str("Strong"),
+            HandleType::Cache(_) => f.write_str("Cache"),
         }
     }
 }
@@ -135,6 +145,17 @@ impl<T: Asset> Handle<T> {
         }
     }
 
+    pub(crate) fn cache(id: HandleId, ref_change_sender: Sender<RefChange>) -> Self {
+        ref_change_sender
+            .send(RefChange::IncrementCache(id))
+            .unwrap();
+        Self {
+            id,
+            handle_type: HandleType::Cache(ref_change_sender),
+            marker: PhantomData,
+        }
+    }
+
     /// Creates a weak handle into an Asset identified by `id`.
     #[inline]
     pub fn weak(id: HandleId) -> Self {
@@ -176,6 +197,11 @@ impl<T: Asset> Handle<T> {
         matches!(self.handle_type, HandleType::Strong(_))
     }
 
+    /// Returns `true` if this is a cache handle.
+    pub fn is_cache(&self) -> bool {
+        matches!(self.handle_type, HandleType::Cache(_))
+    }
+
     /// Makes this handle Strong if it wasn't already.
     ///
     /// This method requires the corresponding [`Assets`](crate::Assets) collection.
@@ -185,7 +211,13 @@ impl<T: Asset> Handle<T> {
         }
         let sender = assets.ref_change_sender.clone();
         sender.send(RefChange::Increment(self.id)).unwrap();
-        self.handle_type = HandleType::Strong(sender);
+        // dropping a previous cache handle type only after incrementing the strong count ensures
+        // the asset is never considered unused in between
+        if let HandleType::Cache(sender) =
+            std::mem::replace(&mut self.handle_type, HandleType::Strong(sender))
+        {
+            let _ = sender.send(RefChange::DecrementCache(self.id));
+        }
     }
 
     /// Creates a weak copy of this handle.
@@ -199,6 +231,7 @@ impl<T: Asset> Handle<T> {
     pub fn clone_untyped(&self) -> HandleUntyped {
         match &self.handle_type {
             HandleType::Strong(sender) => HandleUntyped::strong(self.id, sender.clone()),
+            HandleType::Cache(sender) => HandleUntyped::cache(self.id, sender.clone()),
             HandleType::Weak => HandleUntyped::weak(self.id),
         }
     }
@@ -217,6 +250,9 @@ impl<T: Asset> Drop for Handle<T> {
                 // stopped
                 let _ = sender.send(RefChange::Decrement(self.id));
             }
+            HandleType::Cache(ref sender) => {
+                let _ = sender.send(RefChange::DecrementCache(self.id));
+            }
             HandleType::Weak => {}
         }
     }
@@ -301,6 +337,7 @@ impl<T: Asset> Clone for Handle<T> {
     fn clone(&self) -> Self {
         match self.handle_type {
             HandleType::Strong(ref sender) => Handle::strong(self.id, sender.clone()),
+            HandleType::Cache(ref sender) => Handle::cache(self.id, sender.clone()),
             HandleType::Weak => Handle::weak(self.id),
         }
     }
@@ -335,6 +372,16 @@ impl HandleUntyped {
         }
     }
 
+    pub(crate) fn cache(id: HandleId, ref_change_sender: Sender<RefChange>) -> Self {
+        ref_change_sender
+            .send(RefChange::IncrementCache(id))
+            .unwrap();
+        Self {
+            id,
+            handle_type: HandleType::Cache(ref_change_sender),
+        }
+    }
+
     /// Create a weak, untyped handle into an Asset identified by `id`.
     pub fn weak(id: HandleId) -> Self {
         Self {
@@ -365,6 +412,11 @@ impl HandleUntyped {
         matches!(self.handle_type, HandleType::Strong(_))
     }
 
+    /// Returns `true` if this is a cache handle.
+    pub fn is_cache(&self) -> bool {
+        matches!(self.handle_type, HandleType::Cache(_))
+    }
+
     /// Create a weak typed [`Handle`] from this handle.
     ///
     /// If this handle is strong and dropped, there is no guarantee that the asset
@@ -375,7 +427,7 @@ impl HandleUntyped {
 
     /// Converts this handle into a typed [`Handle`] of an [`Asset`] `T`.
     ///
-    /// The new handle will maintain the Strong or Weak status of the current handle.
+    /// The new handle will maintain the Strong, Cache or Weak status of the current handle.
     ///
     /// # Panics
     ///
@@ -387,12 +439,8 @@ impl HandleUntyped {
                 "Attempted to convert handle to invalid type."
             );
         }
-        let handle_type = match &self.handle_type {
-            HandleType::Strong(sender) => HandleType::Strong(sender.clone()),
-            HandleType::Weak => HandleType::Weak,
-        };
         // ensure we don't send the RefChange event when "self" is dropped
-        self.handle_type = HandleType::Weak;
+        let handle_type = std::mem::replace(&mut self.handle_type, HandleType::Weak);
         Handle {
             handle_type,
             id: self.id,
@@ -409,6 +457,9 @@ impl Drop for HandleUntyped {
                 // stopped
                 let _ = sender.send(RefChange::Decrement(self.id));
             }
+            HandleType::Cache(ref sender) => {
+                let _ = sender.send(RefChange::DecrementCache(self.id));
+            }
             HandleType::Weak => {}
         }
     }
@@ -448,6 +499,7 @@ impl Clone for HandleUntyped {
     fn clone(&self) -> Self {
         match self.handle_type {
             HandleType::Strong(ref sender) => HandleUntyped::strong(self.id, sender.clone()),
+            HandleType::Cache(ref sender) => HandleUntyped::cache(self.id, sender.clone()),
             HandleType::Weak => HandleUntyped::weak(self.id),
         }
     }
@@ -456,6 +508,8 @@ impl Clone for HandleUntyped {
 pub(crate) enum RefChange {
     Increment(HandleId),
     Decrement(HandleId),
+    IncrementCache(HandleId),
+    DecrementCache(HandleId),
 }
 
 #[derive(Clone)]
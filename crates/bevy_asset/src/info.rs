@@ -1,7 +1,7 @@
-use crate::{path::AssetPath, LabelId};
+use crate::{path::AssetPath, AssetServerError, LabelId};
 use bevy_utils::{HashMap, HashSet, Uuid};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 /// Metadata for an asset source.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -53,7 +53,7 @@ impl SourceInfo {
 }
 
 /// The load state of an asset.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Debug)]
 pub enum LoadState {
     /// The asset has not been loaded.
     NotLoaded,
@@ -61,9 +61,36 @@ pub enum LoadState {
     Loading,
     /// The asset has been loaded and is living inside an [`Assets`](crate::Assets) collection.
     Loaded,
-    /// The asset failed to load.
-    Failed,
+    /// The asset failed to load, with the error that caused the failure.
+    Failed(Arc<AssetServerError>),
     /// The asset was previously loaded, however all handles were dropped and the asset was removed
     /// from the [`Assets`](crate::Assets) collection.
     Unloaded,
 }
+
+impl LoadState {
+    /// Returns `true` if the asset failed to load.
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
+
+    /// Returns the error that caused the asset to fail to load, if any.
+    pub fn error(&self) -> Option<&AssetServerError> {
+        match self {
+            Self::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Two [`LoadState::Failed`] states are only equal if they hold the same error instance.
+impl PartialEq for LoadState {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Failed(a), Self::Failed(b)) => Arc::ptr_eq(a, b),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for LoadState {}
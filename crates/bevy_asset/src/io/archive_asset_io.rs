@@ -0,0 +1,538 @@
+use crate::{AssetIo, AssetIoError, ChangeWatcher, FileType, Metadata, Reader, VecReader};
+use anyhow::Result;
+use bevy_utils::{BoxedFuture, HashMap};
+use parking_lot::Mutex;
+use std::{
+    collections::BTreeSet,
+    io::{Cursor, Read, Seek},
+    path::{Component, Path, PathBuf},
+};
+use zip::ZipArchive;
+
+/// A readable and seekable source of archive data.
+trait ArchiveReader: Read + Seek + Send + Sync + 'static {}
+
+impl<T: Read + Seek + Send + Sync + 'static> ArchiveReader for T {}
+
+/// A single zip archive mounted into an [`ArchiveAssetIo`].
+///
+/// The central directory is indexed once when the archive is mounted; entry data is only read
+/// (and decompressed) when the corresponding asset is loaded.
+struct MountedArchive {
+    archive: Mutex<ZipArchive<Box<dyn ArchiveReader>>>,
+    files: HashMap<PathBuf, usize>,
+    directories: HashMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
+impl MountedArchive {
+    fn new(reader: Box<dyn ArchiveReader>) -> Result<Self, AssetIoError> {
+        let mut archive = ZipArchive::new(reader).map_err(std::io::Error::from)?;
+        let mut files = HashMap::default();
+        let mut directories: HashMap<PathBuf, BTreeSet<PathBuf>> = HashMap::default();
+        directories.insert(PathBuf::new(), BTreeSet::new());
+
+        for index in 0..archive.len() {
+            let entry = archive.by_index_raw(index).map_err(std::io::Error::from)?;
+            // entries escaping the archive root (absolute paths, `..`) are never exposed
+            let Some(path) = entry.enclosed_name().map(normalize_path) else {
+                continue;
+            };
+            if path.as_os_str().is_empty() {
+                continue;
+            }
+            let is_dir = entry.is_dir();
+            drop(entry);
+
+            // register every ancestor, as archives are not required to contain directory entries
+            let mut child = path.clone();
+            while let Some(parent) = child.parent() {
+                let parent = parent.to_path_buf();
+                let children = directories.entry(parent.clone()).or_default();
+                let is_new = children.insert(child);
+                if !is_new {
+                    break;
+                }
+                child = parent;
+            }
+
+            if is_dir {
+                directories.entry(path).or_default();
+            } else {
+                files.insert(path, index);
+            }
+        }
+
+        Ok(Self {
+            archive: Mutex::new(archive),
+            files,
+            directories,
+        })
+    }
+
+    fn load(&self, path: &Path) -> Option<Result<Vec<u8>, AssetIoError>> {
+        let index = *self.files.get(path)?;
+        let mut archive = self.archive.lock();
+        Some(
+            archive
+                .by_index(index)
+                .map_err(std::io::Error::from)
+                .and_then(|mut entry| {
+                    let mut bytes = Vec::with_capacity(entry.size() as usize);
+                    entry.read_to_end(&mut bytes)?;
+                    Ok(bytes)
+                })
+                .map_err(AssetIoError::from),
+        )
+    }
+
+    fn get_metadata(&self, path: &Path) -> Option<Metadata> {
+        if self.files.contains_key(path) {
+            Some(Metadata::new(FileType::File))
+        } else if self.directories.contains_key(path) {
+            Some(Metadata::new(FileType::Directory))
+        } else {
+            None
+        }
+    }
+}
+
+enum ArchiveLayerSource {
+    Archive(MountedArchive),
+    AssetIo(Box<dyn AssetIo>),
+}
+
+struct ArchiveLayer {
+    priority: i32,
+    source: ArchiveLayerSource,
+}
+
+/// I/O implementation reading assets from one or more zip archives.
+///
+/// Archives can use the `stored` or `deflate` compression methods. Each archive is mounted at
+/// the asset root with a priority: when several layers contain the same path, the one with the
+/// highest priority wins, and layers of equal priority are resolved in reverse mount order.
+/// This makes it possible to ship a base archive and override parts of it with a patch archive.
+///
+/// Any other [`AssetIo`] (most commonly the [`FileAssetIo`](crate::FileAssetIo)) can be
+/// mounted as a layer as well, so loose files can either be overridden by archives or take
+/// precedence over them. Change watching is forwarded to those layers, but note that the
+/// built-in filesystem watcher only reloads assets when the [`FileAssetIo`](crate::FileAssetIo)
+/// is used directly.
+///
+/// ```no_run
+/// # use bevy_asset::{ArchiveAssetIo, AssetPlugin, AssetServer};
+/// let mut asset_io = ArchiveAssetIo::new();
+/// asset_io.mount_io(AssetPlugin::default().create_platform_default_asset_io(), 0);
+/// asset_io.mount_archive("content.zip", 1).unwrap();
+/// asset_io.mount_archive("patch_1.zip", 2).unwrap();
+/// let asset_server = AssetServer::new(asset_io);
+/// ```
+#[derive(Default)]
+pub struct ArchiveAssetIo {
+    // sorted from the highest to the lowest priority
+    layers: Vec<ArchiveLayer>,
+}
+
+impl ArchiveAssetIo {
+    /// Creates a new `ArchiveAssetIo` without any mounted layer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mounts the zip archive at the provided filesystem path with the given priority.
+    ///
+    /// Unlike asset paths, `path` is not relative to the asset folder.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn mount_archive<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        priority: i32,
+    ) -> Result<(), AssetIoError> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                AssetIoError::NotFound(path.to_owned())
+            } else {
+                e.into()
+            }
+        })?;
+        let archive = MountedArchive::new(Box::new(std::io::BufReader::new(file)))?;
+        self.push_layer(priority, ArchiveLayerSource::Archive(archive));
+        Ok(())
+    }
+
+    /// Mounts a zip archive already loaded in memory with the given priority.
+    pub fn mount_archive_bytes(
+        &mut self,
+        bytes: impl Into<Vec<u8>>,
+        priority: i32,
+    ) -> Result<(), AssetIoError> {
+        let archive = MountedArchive::new(Box::new(Cursor::new(bytes.into())))?;
+        self.push_layer(priority, ArchiveLayerSource::Archive(archive));
+        Ok(())
+    }
+
+    /// Mounts another [`AssetIo`] as a layer with the given priority.
+    pub fn mount_io(&mut self, asset_io: Box<dyn AssetIo>, priority: i32) {
+        self.push_layer(priority, ArchiveLayerSource::AssetIo(asset_io));
+    }
+
+    fn push_layer(&mut self, priority: i32, source: ArchiveLayerSource) {
+        let index = self
+            .layers
+            .iter()
+            .position(|layer| layer.priority <= priority)
+            .unwrap_or(self.layers.len());
+        self.layers.insert(index, ArchiveLayer { priority, source });
+    }
+}
+
+impl AssetIo for ArchiveAssetIo {
+    fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>> {
+        Box::pin(async move {
+            let normalized = normalize_path(path);
+            for layer in &self.layers {
+                match &layer.source {
+                    ArchiveLayerSource::Archive(archive) => {
+                        if let Some(result) = archive.load(&normalized) {
+                            return result;
+                        }
+                    }
+                    ArchiveLayerSource::AssetIo(asset_io) => {
+                        if asset_io.is_file(path) {
+                            return asset_io.load_path(path).await;
+                        }
+                    }
+                }
+            }
+            Err(AssetIoError::NotFound(path.to_owned()))
+        })
+    }
+
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        Box::pin(async move {
+            let normalized = normalize_path(path);
+            for layer in &self.layers {
+                match &layer.source {
+                    // compressed entries can't be seeked into, so they are read in full
+                    ArchiveLayerSource::Archive(archive) => {
+                        if let Some(result) = archive.load(&normalized) {
+                            let reader: Box<Reader> = Box::new(VecReader::new(result?));
+                            return Ok(reader);
+                        }
+                    }
+                    ArchiveLayerSource::AssetIo(asset_io) => {
+                        if asset_io.is_file(path) {
+                            return asset_io.read_path(path).await;
+                        }
+                    }
+                }
+            }
+            Err(AssetIoError::NotFound(path.to_owned()))
+        })
+    }
+
+    fn read_directory(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = PathBuf>>, AssetIoError> {
+        let normalized = normalize_path(path);
+        let mut found = false;
+        let mut entries = BTreeSet::new();
+        for layer in &self.layers {
+            match &layer.source {
+                ArchiveLayerSource::Archive(archive) => {
+                    if let Some(children) = archive.directories.get(&normalized) {
+                        found = true;
+                        entries.extend(children.iter().cloned());
+                    }
+                }
+                ArchiveLayerSource::AssetIo(asset_io) => {
+                    if asset_io.is_dir(path) {
+                        found = true;
+                        entries.extend(asset_io.read_directory(path)?.map(|p| normalize_path(&p)));
+                    }
+                }
+            }
+        }
+        if !found {
+            return Err(AssetIoError::NotFound(path.to_owned()));
+        }
+        Ok(Box::new(entries.into_iter()))
+    }
+
+    fn get_metadata(&self, path: &Path) -> Result<Metadata, AssetIoError> {
+        let normalized = normalize_path(path);
+        for layer in &self.layers {
+            match &layer.source {
+                ArchiveLayerSource::Archive(archive) => {
+                    if let Some(metadata) = archive.get_metadata(&normalized) {
+                        return Ok(metadata);
+                    }
+                }
+                ArchiveLayerSource::AssetIo(asset_io) => match asset_io.get_metadata(path) {
+                    Err(AssetIoError::NotFound(_)) => {}
+                    result => return result,
+                },
+            }
+        }
+        Err(AssetIoError::NotFound(path.to_owned()))
+    }
+
+    fn watch_path_for_changes(
+        &self,
+        to_watch: &Path,
+        to_reload: Option<PathBuf>,
+    ) -> Result<(), AssetIoError> {
+        for layer in &self.layers {
+            if let ArchiveLayerSource::AssetIo(asset_io) = &layer.source {
+                if asset_io.get_metadata(to_watch).is_ok() {
+                    asset_io.watch_path_for_changes(to_watch, to_reload.clone())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn watch_for_changes(&self, configuration: &ChangeWatcher) -> Result<(), AssetIoError> {
+        for layer in &self.layers {
+            if let ArchiveLayerSource::AssetIo(asset_io) = &layer.source {
+                asset_io.watch_for_changes(configuration)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Strips `.` and root components so that `./a/b`, `/a/b` and `a/b` resolve to the same entry.
+fn normalize_path(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| matches!(component, Component::Normal(_)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+    fn create_archive(entries: &[(&str, &[u8])], compression: CompressionMethod) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = FileOptions::default().compression_method(compression);
+        for (name, data) in entries {
+            if name.ends_with('/') {
+                writer.add_directory(*name, options).unwrap();
+            } else {
+                writer.start_file(*name, options).unwrap();
+                writer.write_all(data).unwrap();
+            }
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn load(asset_io: &ArchiveAssetIo, path: &str) -> Result<Vec<u8>, AssetIoError> {
+        futures_lite::future::block_on(asset_io.load_path(Path::new(path)))
+    }
+
+    fn read_directory(asset_io: &ArchiveAssetIo, path: &str) -> Vec<PathBuf> {
+        asset_io.read_directory(Path::new(path)).unwrap().collect()
+    }
+
+    #[test]
+    fn load_stored_and_deflated() {
+        for compression in [CompressionMethod::Stored, CompressionMethod::Deflated] {
+            let bytes = create_archive(
+                &[("root.txt", b"root"), ("textures/grass.png", &[7; 4096])],
+                compression,
+            );
+            let mut asset_io = ArchiveAssetIo::new();
+            asset_io.mount_archive_bytes(bytes, 0).unwrap();
+
+            assert_eq!(load(&asset_io, "root.txt").unwrap(), b"root");
+            assert_eq!(
+                load(&asset_io, "textures/grass.png").unwrap(),
+                vec![7; 4096]
+            );
+            assert_eq!(
+                load(&asset_io, "./textures/grass.png").unwrap(),
+                vec![7; 4096]
+            );
+            assert!(matches!(
+                load(&asset_io, "textures/missing.png"),
+                Err(AssetIoError::NotFound(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn metadata_and_directories() {
+        let bytes = create_archive(
+            &[
+                ("empty/", b""),
+                ("models/hero/hero.gltf", b"{}"),
+                ("models/tree.gltf", b"{}"),
+            ],
+            CompressionMethod::Deflated,
+        );
+        let mut asset_io = ArchiveAssetIo::new();
+        asset_io.mount_archive_bytes(bytes, 0).unwrap();
+
+        assert!(asset_io.is_dir(Path::new("")));
+        assert!(asset_io.is_dir(Path::new("empty")));
+        assert!(asset_io.is_dir(Path::new("models/hero")));
+        assert!(asset_io.is_file(Path::new("models/tree.gltf")));
+        assert!(asset_io.get_metadata(Path::new("nothing")).is_err());
+
+        assert_eq!(
+            read_directory(&asset_io, ""),
+            vec![PathBuf::from("empty"), PathBuf::from("models")]
+        );
+        assert_eq!(
+            read_directory(&asset_io, "models"),
+            vec![
+                PathBuf::from("models/hero"),
+                PathBuf::from("models/tree.gltf")
+            ]
+        );
+        assert!(read_directory(&asset_io, "empty").is_empty());
+        assert!(asset_io.read_directory(Path::new("nothing")).is_err());
+    }
+
+    #[test]
+    fn priority_overrides() {
+        let base = create_archive(
+            &[("a.txt", b"base"), ("b.txt", b"base")],
+            CompressionMethod::Deflated,
+        );
+        let patch = create_archive(
+            &[("a.txt", b"patch"), ("c.txt", b"patch")],
+            CompressionMethod::Stored,
+        );
+
+        // the patch is mounted first, priority must win over mount order
+        let mut asset_io = ArchiveAssetIo::new();
+        asset_io.mount_archive_bytes(patch.clone(), 1).unwrap();
+        asset_io.mount_archive_bytes(base.clone(), 0).unwrap();
+        assert_eq!(load(&asset_io, "a.txt").unwrap(), b"patch");
+        assert_eq!(load(&asset_io, "b.txt").unwrap(), b"base");
+        assert_eq!(load(&asset_io, "c.txt").unwrap(), b"patch");
+        assert_eq!(
+            read_directory(&asset_io, ""),
+            vec![
+                PathBuf::from("a.txt"),
+                PathBuf::from("b.txt"),
+                PathBuf::from("c.txt")
+            ]
+        );
+
+        // with the same priority, the last mounted archive wins
+        let mut asset_io = ArchiveAssetIo::new();
+        asset_io.mount_archive_bytes(patch, 0).unwrap();
+        asset_io.mount_archive_bytes(base, 0).unwrap();
+        assert_eq!(load(&asset_io, "a.txt").unwrap(), b"base");
+    }
+
+    #[test]
+    fn layered_over_file_asset_io() {
+        use crate::FileAssetIo;
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("levels")).unwrap();
+        std::fs::write(dir.path().join("levels/one.level"), b"file").unwrap();
+        std::fs::write(dir.path().join("levels/two.level"), b"file").unwrap();
+
+        let archive_path = dir.path().join("patch.zip");
+        std::fs::write(
+            &archive_path,
+            create_archive(
+                &[
+                    ("levels/two.level", b"archive"),
+                    ("levels/three.level", b"archive"),
+                ],
+                CompressionMethod::Deflated,
+            ),
+        )
+        .unwrap();
+
+        let mut asset_io = ArchiveAssetIo::new();
+        asset_io.mount_io(Box::new(FileAssetIo::new(dir.path(), &None)), 0);
+        asset_io.mount_archive(&archive_path, 1).unwrap();
+
+        assert_eq!(load(&asset_io, "levels/one.level").unwrap(), b"file");
+        assert_eq!(load(&asset_io, "levels/two.level").unwrap(), b"archive");
+        assert_eq!(load(&asset_io, "levels/three.level").unwrap(), b"archive");
+        assert_eq!(
+            read_directory(&asset_io, "levels"),
+            vec![
+                PathBuf::from("levels/one.level"),
+                PathBuf::from("levels/three.level"),
+                PathBuf::from("levels/two.level")
+            ]
+        );
+
+        assert!(matches!(
+            asset_io.mount_archive(dir.path().join("missing.zip"), 2),
+            Err(AssetIoError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn load_folder() {
+        use crate::{AssetLoader, AssetServer, LoadContext, Reader};
+        use bevy_tasks::IoTaskPool;
+
+        struct TextLoader;
+        impl AssetLoader for TextLoader {
+            fn load<'a>(
+                &'a self,
+                _: &'a mut Reader,
+                _: &'a mut LoadContext,
+            ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+                Box::pin(async move { Ok(()) })
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["txt"]
+            }
+        }
+
+        let bytes = create_archive(
+            &[
+                ("text/a.txt", b"a"),
+                ("text/nested/b.txt", b"b"),
+                ("text/ignored.bin", b"c"),
+            ],
+            CompressionMethod::Deflated,
+        );
+        let mut asset_io = ArchiveAssetIo::new();
+        asset_io.mount_archive_bytes(bytes, 0).unwrap();
+
+        IoTaskPool::init(Default::default);
+        let asset_server = AssetServer::new(asset_io);
+        asset_server.add_loader(TextLoader);
+
+        let handles = asset_server.load_folder("text").unwrap();
+        let mut paths = handles
+            .iter()
+            .map(|handle| {
+                asset_server
+                    .get_handle_path(handle)
+                    .unwrap()
+                    .path()
+                    .to_owned()
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("text/a.txt"),
+                PathBuf::from("text/nested/b.txt")
+            ]
+        );
+    }
+}
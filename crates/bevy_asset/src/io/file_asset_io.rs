@@ -1,6 +1,6 @@
 #[cfg(feature = "filesystem_watcher")]
 use crate::{filesystem_watcher::FilesystemWatcher, AssetServer};
-use crate::{AssetIo, AssetIoError, ChangeWatcher, Metadata};
+use crate::{AssetIo, AssetIoError, ChangeWatcher, Metadata, Reader};
 use anyhow::Result;
 #[cfg(feature = "filesystem_watcher")]
 use bevy_ecs::system::{Local, Res};
@@ -109,6 +109,28 @@ impl AssetIo for FileAssetIo {
         })
     }
 
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        Box::pin(async move {
+            let full_path = self.root_path.join(path);
+            match async_fs::File::open(&full_path).await {
+                Ok(file) => {
+                    let reader: Box<Reader> = Box::new(file);
+                    Ok(reader)
+                }
+                Err(e) => {
+                    if e.kind() == std::io::ErrorKind::NotFound {
+                        Err(AssetIoError::NotFound(full_path))
+                    } else {
+                        Err(e.into())
+                    }
+                }
+            }
+        })
+    }
+
     fn read_directory(
         &self,
         path: &Path,
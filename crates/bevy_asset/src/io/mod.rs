@@ -1,5 +1,7 @@
 #[cfg(target_os = "android")]
 mod android_asset_io;
+#[cfg(feature = "asset_archive")]
+mod archive_asset_io;
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 mod file_asset_io;
 #[cfg(target_arch = "wasm32")]
@@ -9,6 +11,8 @@ mod metadata;
 
 #[cfg(target_os = "android")]
 pub use android_asset_io::*;
+#[cfg(feature = "asset_archive")]
+pub use archive_asset_io::*;
 #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
 pub use file_asset_io::*;
 #[cfg(target_arch = "wasm32")]
@@ -19,9 +23,12 @@ pub use metadata::*;
 use anyhow::Result;
 use bevy_utils::BoxedFuture;
 use downcast_rs::{impl_downcast, Downcast};
+use futures_io::{AsyncRead, AsyncSeek};
 use std::{
-    io,
+    io::{self, SeekFrom},
     path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
 };
 use thiserror::Error;
 
@@ -58,6 +65,26 @@ pub trait AssetIo: Downcast + Send + Sync + 'static {
     /// Returns a future to load the full file data at the provided path.
     fn load_path<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<Vec<u8>, AssetIoError>>;
 
+    /// Returns a future to open a [`Reader`] over the file data at the provided path.
+    ///
+    /// This is what the [`AssetServer`] hands to [`AssetLoader`]s, so that they can read and seek
+    /// to only the parts of a file they need. The default implementation loads the full file
+    /// with [`load_path`](AssetIo::load_path) and wraps it in a [`VecReader`]; implementations
+    /// backed by seekable storage should override it.
+    ///
+    /// [`AssetServer`]: crate::AssetServer
+    /// [`AssetLoader`]: crate::AssetLoader
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        Box::pin(async move {
+            let bytes = self.load_path(path).await?;
+            let reader: Box<Reader> = Box::new(VecReader::new(bytes));
+            Ok(reader)
+        })
+    }
+
     /// Returns an iterator of directory entry names at the provided path.
     fn read_directory(
         &self,
@@ -103,3 +130,110 @@ pub trait AssetIo: Downcast + Send + Sync + 'static {
 }
 
 impl_downcast!(AssetIo);
+
+/// A type that can be read from and seeked asynchronously.
+///
+/// This trait is automatically implemented for every type implementing both [`AsyncRead`] and
+/// [`AsyncSeek`].
+pub trait AsyncReadAndSeek: AsyncRead + AsyncSeek {}
+
+impl<T: AsyncRead + AsyncSeek> AsyncReadAndSeek for T {}
+
+/// A data stream over the contents of an asset file, as returned by [`AssetIo::read_path`].
+///
+/// Use the [`AsyncReadExt`](crate::AsyncReadExt) and [`AsyncSeekExt`](crate::AsyncSeekExt)
+/// extension traits to read from it.
+pub type Reader<'a> = dyn AsyncReadAndSeek + Unpin + Send + Sync + 'a;
+
+/// A [`Reader`] over bytes that have already been loaded in memory.
+///
+/// This is used by the default implementation of [`AssetIo::read_path`] to adapt asset I/Os
+/// which only provide the full contents of a file.
+#[derive(Debug, Clone, Default)]
+pub struct VecReader {
+    bytes: Vec<u8>,
+    position: u64,
+}
+
+impl VecReader {
+    /// Creates a new reader starting at the beginning of `bytes`.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Returns the underlying bytes.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl AsyncRead for VecReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let start = this.position.min(this.bytes.len() as u64) as usize;
+        let remaining = &this.bytes[start..];
+        let len = remaining.len().min(buf.len());
+        buf[..len].copy_from_slice(&remaining[..len]);
+        this.position += len as u64;
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl AsyncSeek for VecReader {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+        let (base, offset) = match pos {
+            SeekFrom::Start(offset) => (0, offset as i128),
+            SeekFrom::End(offset) => (this.bytes.len() as i128, offset as i128),
+            SeekFrom::Current(offset) => (this.position as i128, offset as i128),
+        };
+        let position = base + offset;
+        if position < 0 {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )));
+        }
+        this.position = position as u64;
+        Poll::Ready(Ok(this.position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::{future::block_on, AsyncReadExt, AsyncSeekExt};
+
+    #[test]
+    fn vec_reader() {
+        let mut reader = VecReader::new((0..10).collect());
+
+        let mut header = [0; 3];
+        block_on(reader.read_exact(&mut header)).unwrap();
+        assert_eq!(header, [0, 1, 2]);
+
+        assert_eq!(block_on(reader.seek(SeekFrom::Current(2))).unwrap(), 5);
+        let mut rest = Vec::new();
+        block_on(reader.read_to_end(&mut rest)).unwrap();
+        assert_eq!(rest, [5, 6, 7, 8, 9]);
+
+        assert_eq!(block_on(reader.seek(SeekFrom::End(-2))).unwrap(), 8);
+        let mut chunk = [0; 4];
+        assert_eq!(block_on(reader.read(&mut chunk)).unwrap(), 2);
+        assert_eq!(chunk[..2], [8, 9]);
+
+        // reading past the end yields no data
+        assert_eq!(block_on(reader.seek(SeekFrom::Start(42))).unwrap(), 42);
+        assert_eq!(block_on(reader.read(&mut chunk)).unwrap(), 0);
+
+        assert!(block_on(reader.seek(SeekFrom::Current(-43))).is_err());
+    }
+}
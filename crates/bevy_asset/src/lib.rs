@@ -32,7 +32,8 @@ mod reflect;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        AddAsset, AssetEvent, AssetPlugin, AssetServer, Assets, Handle, HandleUntyped,
+        AddAsset, AssetEvent, AssetLoadFailedEvent, AssetPlugin, AssetServer, Assets, Handle,
+        HandleUntyped,
     };
 }
 
@@ -40,6 +41,7 @@ pub use anyhow::Error;
 pub use asset_server::*;
 pub use assets::*;
 pub use bevy_utils::BoxedFuture;
+pub use futures_lite::{AsyncReadExt, AsyncSeekExt};
 pub use handle::*;
 pub use info::*;
 pub use io::*;
@@ -132,8 +134,10 @@ impl Plugin for AssetPlugin {
         app.register_type::<HandleId>();
         app.register_type::<AssetPath>();
 
+        app.add_event::<AssetLoadFailedEvent>();
         app.add_systems(PreUpdate, asset_server::free_unused_assets_system);
         app.init_schedule(LoadAssets);
+        app.add_systems(LoadAssets, asset_server::asset_load_failed_event_system);
         app.init_schedule(AssetEvents);
 
         #[cfg(all(
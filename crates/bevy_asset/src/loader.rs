@@ -1,6 +1,6 @@
 use crate::{
     path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, Assets, Handle, HandleId,
-    HandleUntyped, RefChangeChannel,
+    HandleUntyped, Reader, RefChangeChannel,
 };
 use anyhow::Error;
 use anyhow::Result;
@@ -10,22 +10,36 @@ use bevy_reflect::{TypeUuid, TypeUuidDynamic};
 use bevy_utils::{BoxedFuture, HashMap};
 use crossbeam_channel::{Receiver, Sender};
 use downcast_rs::{impl_downcast, Downcast};
-use std::path::Path;
+use std::{any::TypeId, path::Path};
 
 /// A loader for an asset source.
 ///
 /// Types implementing this trait are used by the [`AssetServer`] to load assets
 /// into their respective asset storages.
+///
+/// Loaders receive a [`Reader`] over the asset source rather than its full contents, so that
+/// they can parse headers and seek to only the data they need. Loaders needing the whole file
+/// can read it with [`AsyncReadExt::read_to_end`](crate::AsyncReadExt::read_to_end).
 pub trait AssetLoader: Send + Sync + 'static {
     /// Processes the asset in an asynchronous closure.
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<(), Error>>;
 
     /// Returns a list of extensions supported by this asset loader, without the preceding dot.
     fn extensions(&self) -> &[&str];
+
+    /// Returns the [`TypeId`] of the asset type produced by this loader as its default asset, if
+    /// known.
+    ///
+    /// Declaring the asset type allows several loaders producing different asset types to
+    /// support the same extension. The [`AssetServer`] then selects the loader matching the
+    /// asset type requested with [`AssetServer::load`].
+    fn asset_type_id(&self) -> Option<TypeId> {
+        None
+    }
 }
 
 /// An essential piece of data of an application.
@@ -115,9 +129,9 @@ impl<T: Asset> From<LoadedAsset<T>> for BoxedLoadedAsset {
 
 /// An asynchronous context where an [`Asset`] is processed.
 ///
-/// The load context is created by the [`AssetServer`] to process an asset source after loading its
-/// contents into memory. It is then passed to the appropriate [`AssetLoader`] based on the file
-/// extension of the asset's path.
+/// The load context is created by the [`AssetServer`] to process an asset source after opening a
+/// [`Reader`] over its contents. It is then passed to the appropriate [`AssetLoader`] based on the
+/// file extension of the asset's path.
 ///
 /// An asset source can define one or more assets from a single source path. The main asset is set
 /// using [`LoadContext::set_default_asset`] and sub-assets are defined with
@@ -187,6 +201,14 @@ impl<'a> LoadContext<'a> {
         self.asset_io.load_path(path.as_ref()).await
     }
 
+    /// Opens a [`Reader`] over the file at the specified path through the [`AssetIo`]
+    /// associated with this context.
+    pub async fn read_asset<'b>(&'b self, path: &'b Path) -> Result<Box<Reader<'b>>, AssetIoError> {
+        self.asset_io
+            .watch_path_for_changes(path, Some(self.path.to_owned()))?;
+        self.asset_io.read_path(path).await
+    }
+
     /// Generates metadata for the assets managed by this load context.
     pub fn get_asset_metas(&self) -> Vec<AssetMeta> {
         let mut asset_metas = Vec::new();
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use bevy_asset::{Asset, AssetLoader, LoadContext, LoadedAsset};
+use bevy_asset::{Asset, AssetLoader, AsyncReadExt, LoadContext, LoadedAsset, Reader};
 use bevy_reflect::{TypePath, TypeUuid};
 use bevy_utils::BoxedFuture;
-use std::{io::Cursor, sync::Arc};
+use std::{any::TypeId, io::Cursor, sync::Arc};
 
 /// A source of audio data
 #[derive(Debug, Clone, TypeUuid, TypePath)]
@@ -38,11 +38,19 @@ impl AsRef<[u8]> for AudioSource {
 pub struct AudioLoader;
 
 impl AssetLoader for AudioLoader {
-    fn load(&self, bytes: &[u8], load_context: &mut LoadContext) -> BoxedFuture<Result<()>> {
-        load_context.set_default_asset(LoadedAsset::new(AudioSource {
-            bytes: bytes.into(),
-        }));
-        Box::pin(async move { Ok(()) })
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            load_context.set_default_asset(LoadedAsset::new(AudioSource {
+                bytes: bytes.into(),
+            }));
+            Ok(())
+        })
     }
 
     fn extensions(&self) -> &[&str] {
@@ -61,6 +69,10 @@ impl AssetLoader for AudioLoader {
             "spx",
         ]
     }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<AudioSource>())
+    }
 }
 
 /// A type implementing this trait can be converted to a [`rodio::Source`] type.
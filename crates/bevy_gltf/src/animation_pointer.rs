@@ -0,0 +1,314 @@
+//! Support for the `KHR_animation_pointer` glTF extension.
+//!
+//! The `gltf` crate only understands channels targeting a node's transform or morph weights, so
+//! channels with a `pointer` target are taken out of the JSON before the document is parsed and
+//! converted into curves separately.
+
+use crate::GltfError;
+use bevy_log::warn;
+use serde_json::Value;
+use std::borrow::Cow;
+
+const EXTENSION: &str = "KHR_animation_pointer";
+
+/// An animation channel targeting a JSON pointer into the glTF document.
+#[cfg_attr(not(feature = "bevy_animation"), allow(dead_code))]
+pub(crate) struct AnimationPointer {
+    /// Index of the animation the channel belongs to.
+    pub animation: usize,
+    /// Index of the channel's sampler in its animation.
+    pub sampler: usize,
+    /// The JSON pointer, for example `/nodes/0/translation`.
+    pub pointer: String,
+}
+
+/// Parses a `.gltf` or `.glb` file, removing its `KHR_animation_pointer` channels.
+pub(crate) fn parse_gltf(bytes: &[u8]) -> Result<(gltf::Gltf, Vec<AnimationPointer>), GltfError> {
+    let (json, blob) = if bytes.starts_with(b"glTF") {
+        let glb = gltf::Glb::from_slice(bytes)?;
+        (glb.json, glb.bin)
+    } else {
+        (Cow::Borrowed(bytes), None)
+    };
+    // Avoid the extra JSON round trip for the files that don't use the extension
+    if !json
+        .windows(EXTENSION.len())
+        .any(|window| window == EXTENSION.as_bytes())
+    {
+        return Ok((gltf::Gltf::from_slice(bytes)?, Vec::new()));
+    }
+
+    let mut json: Value = serde_json::from_slice(&json).map_err(gltf::Error::Deserialize)?;
+    let pointers = take_animation_pointers(&mut json);
+    let root = serde_json::from_value(json).map_err(gltf::Error::Deserialize)?;
+    let document = gltf::Document::from_json(root)?;
+    Ok((
+        gltf::Gltf {
+            document,
+            blob: blob.map(Cow::into_owned),
+        },
+        pointers,
+    ))
+}
+
+pub(crate) fn take_animation_pointers(json: &mut Value) -> Vec<AnimationPointer> {
+    let mut pointers = Vec::new();
+    if let Some(animations) = json.get_mut("animations").and_then(Value::as_array_mut) {
+        for (index, animation) in animations.iter_mut().enumerate() {
+            let Some(channels) = animation.get_mut("channels").and_then(Value::as_array_mut) else { continue };
+            channels.retain(|channel| {
+                let target = &channel["target"];
+                if target["path"] != "pointer" {
+                    return true;
+                }
+                match (
+                    channel["sampler"].as_u64(),
+                    target["extensions"][EXTENSION]["pointer"].as_str(),
+                ) {
+                    (Some(sampler), Some(pointer)) => pointers.push(AnimationPointer {
+                        animation: index,
+                        sampler: sampler as usize,
+                        pointer: pointer.to_string(),
+                    }),
+                    _ => warn!(
+                        "Animation {} has a channel with an invalid {} target, it will be ignored",
+                        index, EXTENSION
+                    ),
+                }
+                false
+            });
+        }
+    }
+    // The channels using the extension are handled by the loader, so the `gltf` crate
+    // shouldn't refuse the document for requiring an extension it doesn't know about.
+    if let Some(required) = json
+        .get_mut("extensionsRequired")
+        .and_then(Value::as_array_mut)
+    {
+        required.retain(|extension| extension != EXTENSION);
+    }
+    pointers
+}
+
+#[cfg(feature = "bevy_animation")]
+pub(crate) use curves::*;
+
+#[cfg(feature = "bevy_animation")]
+mod curves {
+    use super::AnimationPointer;
+    use crate::GltfError;
+    use bevy_animation::{
+        AnimationClip, EntityPath, FieldCurve, FieldKeyframes, Keyframes, VariableCurve,
+    };
+    use bevy_core::Name;
+    use bevy_ecs::component::Component;
+    use bevy_log::warn;
+    use bevy_math::{Quat, Vec3};
+    use bevy_pbr::{DirectionalLight, PointLight, SpotLight};
+    use bevy_reflect::ParsedPath;
+    use bevy_render::color::Color;
+    use bevy_utils::{HashMap, HashSet};
+    use gltf::{
+        accessor::{Accessor, DataType, Dimensions, Item, Iter},
+        khr_lights_punctual::Kind,
+    };
+
+    /// Adds the curves animating the target of `pointer` to `animation_clip`.
+    ///
+    /// Only node transforms and morph weights, and `KHR_lights_punctual` lights are supported.
+    pub(crate) fn add_pointer_curves<'a>(
+        gltf: &gltf::Gltf,
+        pointer: &AnimationPointer,
+        buffer_data: &[Vec<u8>],
+        paths: &'a HashMap<usize, (usize, Vec<Name>)>,
+        animation_roots: &mut HashSet<&'a usize>,
+        animation_clip: &mut AnimationClip,
+    ) -> Result<(), GltfError> {
+        let Some(sampler) = gltf
+            .animations()
+            .nth(pointer.animation)
+            .and_then(|animation| animation.samplers().nth(pointer.sampler))
+        else {
+            return Err(GltfError::MissingAnimationSampler(pointer.animation));
+        };
+        match sampler.interpolation() {
+            gltf::animation::Interpolation::Linear => (),
+            other => warn!(
+                "Animation interpolation {:?} is not supported, will use linear",
+                other
+            ),
+        };
+        let Some(keyframe_timestamps) =
+            read_output::<f32>(sampler.input(), Dimensions::Scalar, buffer_data)
+        else {
+            return Ok(());
+        };
+        let output = sampler.output();
+
+        let segments: Vec<&str> = pointer.pointer.split('/').skip(1).collect();
+        match segments.as_slice() {
+            ["nodes", node, property] => {
+                let Ok(node) = node.parse::<usize>() else {
+                    warn!("Invalid animation pointer {}", pointer.pointer);
+                    return Ok(());
+                };
+                let keyframes = match *property {
+                    "translation" => read_output::<[f32; 3]>(output, Dimensions::Vec3, buffer_data)
+                        .map(|values| Keyframes::Translation(values.map(Vec3::from).collect())),
+                    "rotation" => read_output(output, Dimensions::Vec4, buffer_data)
+                        .map(|values| Keyframes::Rotation(values.map(Quat::from_array).collect())),
+                    "scale" => read_output::<[f32; 3]>(output, Dimensions::Vec3, buffer_data)
+                        .map(|values| Keyframes::Scale(values.map(Vec3::from).collect())),
+                    "weights" => read_output(output, Dimensions::Scalar, buffer_data)
+                        .map(|values| Keyframes::Weights(values.collect())),
+                    _ => {
+                        warn!("Unsupported animation pointer {}", pointer.pointer);
+                        None
+                    }
+                };
+                let Some(keyframes) = keyframes else { return Ok(()) };
+                let Some((root_index, path)) = paths.get(&node) else {
+                    warn!(
+                        "Animation ignored for node {}: part of its hierarchy is missing a name",
+                        node
+                    );
+                    return Ok(());
+                };
+                animation_roots.insert(root_index);
+                animation_clip.add_curve_to_path(
+                    EntityPath {
+                        parts: path.clone(),
+                    },
+                    VariableCurve {
+                        keyframe_timestamps: keyframe_timestamps.collect(),
+                        keyframes,
+                    },
+                );
+            }
+            ["extensions", "KHR_lights_punctual", "lights", light, property @ ..] => {
+                let Ok(light) = light.parse::<usize>() else {
+                    warn!("Invalid animation pointer {}", pointer.pointer);
+                    return Ok(());
+                };
+                let keyframe_timestamps: Vec<f32> = keyframe_timestamps.collect();
+                for node in gltf.nodes() {
+                    let Some(node_light) = node.light().filter(|l| l.index() == light) else { continue };
+                    let Some(curve) = light_curve(
+                        node_light.kind(),
+                        property,
+                        keyframe_timestamps.clone(),
+                        output.clone(),
+                        buffer_data,
+                    ) else {
+                        warn!("Unsupported animation pointer {}", pointer.pointer);
+                        return Ok(());
+                    };
+                    let (Some((root_index, path)), Some(name)) =
+                        (paths.get(&node.index()), node_light.name())
+                    else {
+                        warn!(
+                            "Animation ignored for light {} of node {}: it or part of its hierarchy is missing a name",
+                            light,
+                            node.index()
+                        );
+                        continue;
+                    };
+                    animation_roots.insert(root_index);
+                    let mut parts = path.clone();
+                    parts.push(Name::new(name.to_string()));
+                    animation_clip.add_field_curve_to_path(EntityPath { parts }, curve);
+                }
+            }
+            _ => warn!("Unsupported animation pointer {}", pointer.pointer),
+        }
+        Ok(())
+    }
+
+    /// Maps a `KHR_lights_punctual` light property to a field of the light component spawned for
+    /// it, converting the values the same way the loader does.
+    fn light_curve(
+        kind: Kind,
+        property: &[&str],
+        keyframe_timestamps: Vec<f32>,
+        output: Accessor,
+        buffer_data: &[Vec<u8>],
+    ) -> Option<FieldCurve> {
+        fn curve<C: Component>(
+            field: &'static str,
+            keyframe_timestamps: Vec<f32>,
+            keyframes: Option<FieldKeyframes>,
+        ) -> Option<FieldCurve> {
+            let path = ParsedPath::parse_static(field).ok()?;
+            Some(FieldCurve::new::<C>(path, keyframe_timestamps, keyframes?))
+        }
+
+        let colors = || {
+            read_output::<[f32; 3]>(output.clone(), Dimensions::Vec3, buffer_data)
+                .map(|values| FieldKeyframes::Color(values.map(Color::from).collect()))
+        };
+        let scalars = |scale: f32| {
+            read_output::<f32>(output.clone(), Dimensions::Scalar, buffer_data)
+                .map(|values| FieldKeyframes::F32(values.map(|value| value * scale).collect()))
+        };
+        // For point and spot lights, luminous power = 4 * pi * luminous intensity
+        let power = std::f32::consts::PI * 4.0;
+        match (kind, property) {
+            (Kind::Directional, ["color"]) => {
+                curve::<DirectionalLight>("color", keyframe_timestamps, colors())
+            }
+            (Kind::Directional, ["intensity"]) => {
+                curve::<DirectionalLight>("illuminance", keyframe_timestamps, scalars(1.0))
+            }
+            (Kind::Point, ["color"]) => curve::<PointLight>("color", keyframe_timestamps, colors()),
+            (Kind::Point, ["intensity"]) => {
+                curve::<PointLight>("intensity", keyframe_timestamps, scalars(power))
+            }
+            (Kind::Point, ["range"]) => {
+                curve::<PointLight>("range", keyframe_timestamps, scalars(1.0))
+            }
+            (Kind::Spot { .. }, ["color"]) => {
+                curve::<SpotLight>("color", keyframe_timestamps, colors())
+            }
+            (Kind::Spot { .. }, ["intensity"]) => {
+                curve::<SpotLight>("intensity", keyframe_timestamps, scalars(power))
+            }
+            (Kind::Spot { .. }, ["range"]) => {
+                curve::<SpotLight>("range", keyframe_timestamps, scalars(1.0))
+            }
+            (Kind::Spot { .. }, ["spot", "innerConeAngle"]) => {
+                curve::<SpotLight>("inner_angle", keyframe_timestamps, scalars(1.0))
+            }
+            (Kind::Spot { .. }, ["spot", "outerConeAngle"]) => {
+                curve::<SpotLight>("outer_angle", keyframe_timestamps, scalars(1.0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Reads the values of a float sampler accessor, warning if it isn't supported.
+    fn read_output<'a, T: Item>(
+        accessor: Accessor<'a>,
+        dimensions: Dimensions,
+        buffer_data: &'a [Vec<u8>],
+    ) -> Option<gltf::accessor::util::ItemIter<'a, T>> {
+        if accessor.data_type() != DataType::F32 || accessor.dimensions() != dimensions {
+            warn!(
+                "Animation sampler accessor {} should contain {:?} floats",
+                accessor.index(),
+                dimensions
+            );
+            return None;
+        }
+        match Iter::<T>::new(accessor, |buffer| Some(&buffer_data[buffer.index()])) {
+            Some(Iter::Standard(values)) => Some(values),
+            Some(Iter::Sparse(_)) => {
+                warn!("Sparse accessor not supported for animation sampler");
+                None
+            }
+            None => {
+                warn!("Animation sampler accessor is missing its data");
+                None
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@
 use bevy_animation::AnimationClip;
 use bevy_utils::HashMap;
 
+mod animation_pointer;
 mod loader;
 mod vertex_attributes;
 pub use loader::*;
@@ -1,7 +1,8 @@
-use crate::{vertex_attributes::*, Gltf, GltfExtras, GltfNode};
+use crate::{animation_pointer::*, vertex_attributes::*, Gltf, GltfExtras, GltfNode};
 use anyhow::Result;
 use bevy_asset::{
-    AssetIoError, AssetLoader, AssetPath, BoxedFuture, Handle, HandleId, LoadContext, LoadedAsset,
+    AssetIoError, AssetLoader, AssetPath, AsyncReadExt, BoxedFuture, Handle, HandleId, LoadContext,
+    LoadedAsset, Reader,
 };
 use bevy_core::Name;
 use bevy_core_pipeline::prelude::Camera3dBundle;
@@ -38,7 +39,7 @@ use gltf::{
     Material, Node, Primitive,
 };
 use serde::Deserialize;
-use std::{collections::VecDeque, path::Path};
+use std::{any::TypeId, collections::VecDeque, path::Path};
 use thiserror::Error;
 
 /// An error that occurs when loading a glTF file.
@@ -77,15 +78,23 @@ pub struct GltfLoader {
 impl AssetLoader for GltfLoader {
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
-        Box::pin(async move { Ok(load_gltf(bytes, load_context, self).await?) })
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(load_gltf(&bytes, load_context, self).await?)
+        })
     }
 
     fn extensions(&self) -> &[&str] {
         &["gltf", "glb"]
     }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<Gltf>())
+    }
 }
 
 /// Loads an entire glTF file.
@@ -94,7 +103,9 @@ async fn load_gltf<'a, 'b>(
     load_context: &'a mut LoadContext<'b>,
     loader: &GltfLoader,
 ) -> Result<(), GltfError> {
-    let gltf = gltf::Gltf::from_slice(bytes)?;
+    let (gltf, animation_pointers) = parse_gltf(bytes)?;
+    #[cfg(not(feature = "bevy_animation"))]
+    let _ = animation_pointers;
     let buffer_data = load_buffers(&gltf, load_context, load_context.path()).await?;
 
     let mut materials = vec![];
@@ -202,6 +213,19 @@ async fn load_gltf<'a, 'b>(
                     );
                 }
             }
+            for pointer in animation_pointers
+                .iter()
+                .filter(|pointer| pointer.animation == animation.index())
+            {
+                add_pointer_curves(
+                    &gltf,
+                    pointer,
+                    &buffer_data,
+                    &paths,
+                    &mut animation_roots,
+                    &mut animation_clip,
+                )?;
+            }
             let handle = load_context.set_labeled_asset(
                 &format!("Animation{}", animation.index()),
                 LoadedAsset::new(animation_clip),
@@ -1225,8 +1249,13 @@ struct MorphTargetNames {
 mod test {
     use std::path::PathBuf;
 
+    use serde_json::json;
+
     use super::resolve_node_hierarchy;
-    use crate::GltfNode;
+    use crate::{
+        animation_pointer::{parse_gltf, take_animation_pointers},
+        GltfNode,
+    };
 
     impl GltfNode {
         fn empty() -> Self {
@@ -1343,4 +1372,103 @@ mod test {
         assert_eq!(result[0].0, "l2");
         assert_eq!(result[0].1.children.len(), 0);
     }
+
+    #[test]
+    fn animation_pointers_taken_from_channels() {
+        let mut json = json!({
+            "animations": [
+                {
+                    "channels": [
+                        { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+                        {
+                            "sampler": 1,
+                            "target": {
+                                "path": "pointer",
+                                "extensions": {
+                                    "KHR_animation_pointer": { "pointer": "/nodes/0/scale" }
+                                }
+                            }
+                        },
+                    ]
+                },
+                {
+                    "channels": [
+                        // Invalid channels using the extension are removed without a pointer
+                        { "sampler": 0, "target": { "path": "pointer" } },
+                        {
+                            "sampler": 2,
+                            "target": {
+                                "path": "pointer",
+                                "extensions": {
+                                    "KHR_animation_pointer": {
+                                        "pointer": "/extensions/KHR_lights_punctual/lights/0/color"
+                                    }
+                                }
+                            }
+                        },
+                    ]
+                }
+            ],
+            "extensionsRequired": ["KHR_animation_pointer", "KHR_lights_punctual"]
+        });
+
+        let pointers = take_animation_pointers(&mut json);
+        let pointers: Vec<_> = pointers
+            .iter()
+            .map(|pointer| (pointer.animation, pointer.sampler, pointer.pointer.as_str()))
+            .collect();
+        assert_eq!(
+            pointers,
+            vec![
+                (0, 1, "/nodes/0/scale"),
+                (1, 2, "/extensions/KHR_lights_punctual/lights/0/color"),
+            ]
+        );
+        assert_eq!(
+            json["animations"][0]["channels"],
+            json!([{ "sampler": 0, "target": { "node": 0, "path": "translation" } }])
+        );
+        assert_eq!(json["animations"][1]["channels"], json!([]));
+        assert_eq!(json["extensionsRequired"], json!(["KHR_lights_punctual"]));
+    }
+
+    #[test]
+    fn animation_pointer_extension_not_required_after_parsing() {
+        let json = json!({
+            "asset": { "version": "2.0" },
+            "nodes": [{ "name": "root" }],
+            "buffers": [{ "byteLength": 8 }],
+            "bufferViews": [{ "buffer": 0, "byteLength": 8 }],
+            "accessors": [{
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 2,
+                "type": "SCALAR",
+                "min": [0.0],
+                "max": [1.0]
+            }],
+            "animations": [{
+                "samplers": [{ "input": 0, "output": 0 }],
+                "channels": [{
+                    "sampler": 0,
+                    "target": {
+                        "path": "pointer",
+                        "extensions": {
+                            "KHR_animation_pointer": { "pointer": "/nodes/0/weights" }
+                        }
+                    }
+                }]
+            }],
+            "extensionsUsed": ["KHR_animation_pointer"],
+            "extensionsRequired": ["KHR_animation_pointer"]
+        });
+
+        let (gltf, pointers) = parse_gltf(json.to_string().as_bytes()).unwrap();
+        assert_eq!(pointers.len(), 1);
+        assert_eq!(pointers[0].pointer, "/nodes/0/weights");
+        let animation = gltf.animations().next().unwrap();
+        assert_eq!(animation.channels().count(), 0);
+        assert_eq!(animation.samplers().count(), 1);
+        assert_eq!(gltf.extensions_required().count(), 0);
+    }
 }
@@ -0,0 +1,38 @@
+//! Contains code related to automatically registering reflected types.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Path, Type};
+
+/// Submits `ty` to be collected for automatic registration.
+///
+/// Returns an empty `TokenStream` if the `auto_register` feature is disabled.
+pub(crate) fn submit_registration(bevy_reflect_path: &Path, ty: &impl ToTokens) -> TokenStream {
+    if !cfg!(feature = "auto_register") {
+        return TokenStream::new();
+    }
+
+    quote! {
+        #bevy_reflect_path::__macro_exports::auto_register::inventory::submit! {
+            #bevy_reflect_path::__macro_exports::auto_register::AutomaticReflectRegistration(
+                #bevy_reflect_path::TypeRegistry::register::<#ty>
+            )
+        }
+    }
+}
+
+/// Submits each of the given types to be collected for automatic registration.
+pub(crate) fn auto_register_types(
+    bevy_reflect_path: &Path,
+    types: &Punctuated<Type, Comma>,
+) -> TokenStream {
+    let submissions = types
+        .iter()
+        .map(|ty| submit_registration(bevy_reflect_path, ty));
+
+    quote! {
+        #(#submissions)*
+    }
+}
@@ -5,6 +5,7 @@
 //! the derive helper attribute for `Reflect`, which looks like:
 //! `#[reflect(PartialEq, Default, ...)]` and `#[reflect_value(PartialEq, Default, ...)]`.
 
+use crate::custom_attributes::CustomAttributes;
 use crate::fq_std::{FQAny, FQOption};
 use crate::utility;
 use proc_macro2::{Ident, Span};
@@ -13,7 +14,7 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Expr, LitBool, Meta, Path};
+use syn::{Expr, LitBool, LitInt, Meta, Path, Token};
 
 // The "special" trait idents that are used internally for reflection.
 // Received via attributes like `#[reflect(PartialEq, Hash, ...)]`
@@ -31,6 +32,12 @@ const FROM_REFLECT_ATTR: &str = "from_reflect";
 // Attributes for `TypePath` implementation
 const TYPE_PATH_ATTR: &str = "type_path";
 
+// Attribute to opt out of the field bounds added to the `where` clause
+const NO_FIELD_BOUNDS_ATTR: &str = "no_field_bounds";
+
+// Attribute for the version of a type's serialized representation
+const VERSION_ATTR: &str = "version";
+
 // The error message to show when a trait/type is specified multiple times
 const CONFLICTING_TYPE_DATA_MESSAGE: &str = "conflicting type data registration";
 
@@ -211,10 +218,39 @@ pub(crate) struct ReflectTraits {
     partial_eq: TraitImpl,
     from_reflect_attrs: FromReflectAttrs,
     type_path_attrs: TypePathAttrs,
+    custom_attributes: CustomAttributes,
+    no_field_bounds: bool,
+    version: Option<LitInt>,
     idents: Vec<Ident>,
 }
 
 impl ReflectTraits {
+    /// Parses the contents of a `#[reflect(...)]` attribute.
+    ///
+    /// This handles both trait registrations (i.e. `Hash` or `Default`)
+    /// and custom attributes (i.e. `@Tooltip::new("...")`).
+    pub fn parse_with(input: ParseStream, is_from_reflect_derive: bool) -> syn::Result<Self> {
+        let mut metas = Vec::new();
+        let mut custom_attributes = CustomAttributes::default();
+
+        while !input.is_empty() {
+            if input.peek(Token![@]) {
+                custom_attributes.parse_custom_attribute(input)?;
+            } else {
+                metas.push(input.parse::<Meta>()?);
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Comma>()?;
+        }
+
+        let mut traits = Self::from_metas(metas.into_iter().collect(), is_from_reflect_derive)?;
+        traits.custom_attributes = custom_attributes;
+        Ok(traits)
+    }
+
     pub fn from_metas(
         metas: Punctuated<Meta, Comma>,
         is_from_reflect_derive: bool,
@@ -244,6 +280,9 @@ impl ReflectTraits {
                         HASH_ATTR => {
                             traits.hash.merge(TraitImpl::Implemented(span))?;
                         }
+                        NO_FIELD_BOUNDS_ATTR => {
+                            traits.no_field_bounds = true;
+                        }
                         // We only track reflected idents for traits not considered special
                         _ => {
                             // Create the reflect ident
@@ -301,6 +340,19 @@ impl ReflectTraits {
                     } else if pair.path.is_ident(TYPE_PATH_ATTR) {
                         traits.type_path_attrs.auto_derive =
                             Some(extract_bool(&pair.value, Clone::clone)?);
+                    } else if pair.path.is_ident(VERSION_ATTR) {
+                        let Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Int(lit),
+                            ..
+                        }) = &pair.value
+                        else {
+                            return Err(syn::Error::new(
+                                pair.value.span(),
+                                "expected an integer literal",
+                            ));
+                        };
+                        lit.base10_parse::<u32>()?;
+                        traits.version = Some(lit.clone());
                     } else {
                         return Err(syn::Error::new(pair.path.span(), "Unknown attribute"));
                     }
@@ -333,6 +385,21 @@ impl ReflectTraits {
         &self.type_path_attrs
     }
 
+    /// Whether `#[reflect(no_field_bounds)]` was used to opt out of field bounds on this type.
+    pub fn no_field_bounds(&self) -> bool {
+        self.no_field_bounds
+    }
+
+    /// The version given by `#[reflect(version = N)]`, if any.
+    pub fn version(&self) -> Option<&LitInt> {
+        self.version.as_ref()
+    }
+
+    /// The custom attributes found within `#[reflect(...)]` attributes on this type.
+    pub fn custom_attributes(&self) -> &CustomAttributes {
+        &self.custom_attributes
+    }
+
     /// Returns the implementation of `Reflect::reflect_hash` as a `TokenStream`.
     ///
     /// If `Hash` was not registered, returns `None`.
@@ -411,6 +478,17 @@ impl ReflectTraits {
         self.partial_eq.merge(other.partial_eq)?;
         self.from_reflect_attrs.merge(other.from_reflect_attrs)?;
         self.type_path_attrs.merge(other.type_path_attrs)?;
+        self.custom_attributes.merge(other.custom_attributes);
+        self.no_field_bounds |= other.no_field_bounds;
+        if let Some(version) = other.version {
+            if self.version.is_some() {
+                return Err(syn::Error::new(
+                    version.span(),
+                    format!("`{VERSION_ATTR}` is already set"),
+                ));
+            }
+            self.version = Some(version);
+        }
         for ident in other.idents {
             add_unique_ident(&mut self.idents, ident)?;
         }
@@ -420,7 +498,7 @@ impl ReflectTraits {
 
 impl Parse for ReflectTraits {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        ReflectTraits::from_metas(Punctuated::<Meta, Comma>::parse_terminated(input)?, false)
+        ReflectTraits::parse_with(input, false)
     }
 }
 
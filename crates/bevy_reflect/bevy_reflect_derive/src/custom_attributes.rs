@@ -0,0 +1,65 @@
+//! Contains code related to custom attributes for reflected types.
+//!
+//! A custom attribute is an arbitrary value attached to a type, field, or variant
+//! using the `@` prefix within the `#[reflect(...)]` attribute, such as:
+//! `#[reflect(@0.0..1.0_f32)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::{Expr, Path, Token};
+
+/// A collection of custom attribute expressions for a type, field, or variant.
+#[derive(Default, Clone)]
+pub(crate) struct CustomAttributes {
+    attributes: Vec<Expr>,
+}
+
+impl CustomAttributes {
+    /// Generates a `TokenStream` for `CustomAttributes` construction.
+    pub fn to_tokens(&self, bevy_reflect_path: &Path) -> TokenStream {
+        let attributes = self.attributes.iter().map(|value| {
+            quote! {
+                .with_attribute(#value)
+            }
+        });
+
+        quote! {
+            #bevy_reflect_path::attributes::CustomAttributes::default()
+                #(#attributes)*
+        }
+    }
+
+    /// Generates a `.with_custom_attributes(...)` builder call,
+    /// or `None` if there are no custom attributes.
+    pub fn to_builder_tokens(&self, bevy_reflect_path: &Path) -> Option<TokenStream> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let attributes = self.to_tokens(bevy_reflect_path);
+        Some(quote! {
+            .with_custom_attributes(#attributes)
+        })
+    }
+
+    /// Returns `true` if there are no custom attributes.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
+    /// Merges the custom attributes of this [`CustomAttributes`] with another.
+    pub fn merge(&mut self, other: CustomAttributes) {
+        self.attributes.extend(other.attributes);
+    }
+
+    /// Parse a single custom attribute, consuming the leading `@`.
+    ///
+    /// The attribute value may be any expression, such as `@Tooltip::new("Hello")`
+    /// or `@0.0..=1.0_f32`.
+    pub fn parse_custom_attribute(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<Token![@]>()?;
+        self.attributes.push(input.parse()?);
+        Ok(())
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
     utility, REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME, TYPE_NAME_ATTRIBUTE_NAME,
     TYPE_PATH_ATTRIBUTE_NAME,
 };
+use syn::parse::ParseStream;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
@@ -45,6 +46,8 @@ pub(crate) struct ReflectMeta<'a> {
     type_path: ReflectTypePath<'a>,
     /// A cached instance of the path to the `bevy_reflect` crate.
     bevy_reflect_path: Path,
+    /// The foreign type this type mirrors, if created with `#[reflect_remote]`.
+    remote_ty: Option<&'a syn::TypePath>,
     /// The documentation for this type, if any
     #[cfg(feature = "documentation")]
     docs: crate::documentation::Documentation,
@@ -160,10 +163,9 @@ impl<'a> ReflectDerive<'a> {
                     }
 
                     reflect_mode = Some(ReflectMode::Normal);
-                    let new_traits = ReflectTraits::from_metas(
-                        meta_list.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?,
-                        is_from_reflect_derive,
-                    )?;
+                    let new_traits = meta_list.parse_args_with(|input: ParseStream| {
+                        ReflectTraits::parse_with(input, is_from_reflect_derive)
+                    })?;
                     traits.merge(new_traits)?;
                 }
                 Meta::List(meta_list) if meta_list.path.is_ident(REFLECT_VALUE_ATTRIBUTE_NAME) => {
@@ -175,10 +177,9 @@ impl<'a> ReflectDerive<'a> {
                     }
 
                     reflect_mode = Some(ReflectMode::Value);
-                    let new_traits = ReflectTraits::from_metas(
-                        meta_list.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?,
-                        is_from_reflect_derive,
-                    )?;
+                    let new_traits = meta_list.parse_args_with(|input: ParseStream| {
+                        ReflectTraits::parse_with(input, is_from_reflect_derive)
+                    })?;
                     traits.merge(new_traits)?;
                 }
                 Meta::Path(path) if path.is_ident(REFLECT_VALUE_ATTRIBUTE_NAME) => {
@@ -195,7 +196,8 @@ impl<'a> ReflectDerive<'a> {
                     let syn::Expr::Lit(syn::ExprLit {
                         lit: syn::Lit::Str(lit),
                         ..
-                    }) = &pair.value else {
+                    }) = &pair.value
+                    else {
                         return Err(syn::Error::new(
                             pair.span(),
                             format_args!("`#[{TYPE_PATH_ATTRIBUTE_NAME} = \"...\"]` must be a string literal"),
@@ -211,7 +213,8 @@ impl<'a> ReflectDerive<'a> {
                     let syn::Expr::Lit(syn::ExprLit {
                         lit: syn::Lit::Str(lit),
                         ..
-                    }) = &pair.value else {
+                    }) = &pair.value
+                    else {
                         return Err(syn::Error::new(
                             pair.span(),
                             format_args!("`#[{TYPE_NAME_ATTRIBUTE_NAME} = \"...\"]` must be a string literal"),
@@ -261,6 +264,16 @@ impl<'a> ReflectDerive<'a> {
         // Use normal reflection if unspecified
         let reflect_mode = reflect_mode.unwrap_or(ReflectMode::Normal);
 
+        // Values are serialized by their own `Serialize` implementation, which can't be versioned
+        if let Some(version) = meta.traits().version() {
+            if reflect_mode == ReflectMode::Value || matches!(input.data, Data::Union(..)) {
+                return Err(syn::Error::new(
+                    version.span(),
+                    "`version` can only be used on structs and enums",
+                ));
+            }
+        }
+
         if reflect_mode == ReflectMode::Value {
             return Ok(Self::Value(meta));
         }
@@ -305,12 +318,32 @@ impl<'a> ReflectDerive<'a> {
         }
     }
 
+    /// Marks this type as a mirror of the given foreign type.
+    ///
+    /// This is used by `#[reflect_remote]`, where the reflected type is a wrapper
+    /// around the remote type and all fields are accessed through it.
+    pub fn set_remote(&mut self, remote_ty: Option<&'a syn::TypePath>) {
+        match self {
+            ReflectDerive::Struct(data)
+            | ReflectDerive::TupleStruct(data)
+            | ReflectDerive::UnitStruct(data) => data.meta.remote_ty = remote_ty,
+            ReflectDerive::Enum(data) => data.meta.remote_ty = remote_ty,
+            ReflectDerive::Value(meta) => meta.remote_ty = remote_ty,
+        }
+    }
+
     fn collect_struct_fields(fields: &'a Fields) -> Result<Vec<StructField<'a>>, syn::Error> {
         let sifter: utility::ResultSifter<StructField<'a>> = fields
             .iter()
             .enumerate()
             .map(|(index, field)| -> Result<StructField, syn::Error> {
                 let attrs = parse_field_attrs(&field.attrs)?;
+                if let (None, Some(alias)) = (&field.ident, attrs.aliases.first()) {
+                    return Err(syn::Error::new(
+                        alias.span(),
+                        "aliases can only be used on named fields",
+                    ));
+                }
                 Ok(StructField {
                     index,
                     attrs,
@@ -365,6 +398,7 @@ impl<'a> ReflectMeta<'a> {
             traits,
             type_path,
             bevy_reflect_path: utility::get_bevy_reflect_path(),
+            remote_ty: None,
             #[cfg(feature = "documentation")]
             docs: Default::default(),
         }
@@ -397,6 +431,36 @@ impl<'a> ReflectMeta<'a> {
         &self.bevy_reflect_path
     }
 
+    /// The foreign type this type mirrors, if any.
+    pub fn remote_ty(&self) -> Option<&'a syn::TypePath> {
+        self.remote_ty
+    }
+
+    /// The path used to access the data of this type from within its own impls.
+    ///
+    /// For remote types, this is the wrapped value (`self.0`), otherwise it is `self`.
+    pub fn self_access(&self) -> proc_macro2::TokenStream {
+        if self.remote_ty.is_some() {
+            quote!(self.0)
+        } else {
+            quote!(self)
+        }
+    }
+
+    /// The path to the remote type without any generic arguments.
+    ///
+    /// This can be used to construct or destructure the remote type, letting the compiler
+    /// infer its generics.
+    pub fn remote_path_without_generics(&self) -> Option<Path> {
+        self.remote_ty.map(|remote_ty| {
+            let mut path = remote_ty.path.clone();
+            for segment in &mut path.segments {
+                segment.arguments = syn::PathArguments::None;
+            }
+            path
+        })
+    }
+
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
     pub fn get_type_registration(
         &self,
@@ -443,7 +507,7 @@ impl<'a> ReflectStruct<'a> {
     /// Get a collection of types which are exposed to the reflection API
     pub fn active_types(&self) -> Vec<syn::Type> {
         self.active_fields()
-            .map(|field| field.data.ty.clone())
+            .map(|field| field.reflected_type().clone())
             .collect()
     }
 
@@ -479,7 +543,15 @@ impl<'a> ReflectEnum<'a> {
     }
 
     /// Returns the given ident as a qualified unit variant of this enum.
+    ///
+    /// For remote enums, this is the variant of the remote type.
     pub fn get_unit(&self, variant: &Ident) -> proc_macro2::TokenStream {
+        if let Some(remote_path) = self.meta.remote_path_without_generics() {
+            return quote! {
+                #remote_path::#variant
+            };
+        }
+
         let name = self.meta.type_path();
         quote! {
             #name::#variant
@@ -510,6 +582,62 @@ impl<'a> ReflectEnum<'a> {
     }
 }
 
+impl<'a> StructField<'a> {
+    /// The type exposed to the reflection API for this field.
+    ///
+    /// This is the wrapper given by `#[reflect(remote = ...)]`, if any, or the field's own type.
+    pub fn reflected_type(&self) -> &Type {
+        self.attrs.remote.as_ref().unwrap_or(&self.data.ty)
+    }
+
+    /// Converts a reference to this field into a reference to its reflected type.
+    pub fn reflect_ref(
+        &self,
+        bevy_reflect_path: &Path,
+        field_ref: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.attrs.remote {
+            Some(wrapper) => {
+                quote!(<#wrapper as #bevy_reflect_path::ReflectRemote>::as_wrapper(#field_ref))
+            }
+            None => field_ref,
+        }
+    }
+
+    /// Converts a mutable reference to this field into a mutable reference to its reflected type.
+    pub fn reflect_mut(
+        &self,
+        bevy_reflect_path: &Path,
+        field_mut: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.attrs.remote {
+            Some(wrapper) => {
+                quote!(<#wrapper as #bevy_reflect_path::ReflectRemote>::as_wrapper_mut(#field_mut))
+            }
+            None => field_mut,
+        }
+    }
+
+    /// Returns an expression that creates this field's value from the given `&dyn Reflect`,
+    /// resulting in an `Option` of the field's type.
+    pub fn field_from_reflect(
+        &self,
+        bevy_reflect_path: &Path,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.attrs.remote {
+            Some(wrapper) => quote! {
+                <#wrapper as #bevy_reflect_path::FromReflect>::from_reflect(#value)
+                    .map(<#wrapper as #bevy_reflect_path::ReflectRemote>::into_remote)
+            },
+            None => {
+                let ty = &self.data.ty;
+                quote!(<#ty as #bevy_reflect_path::FromReflect>::from_reflect(#value))
+            }
+        }
+    }
+}
+
 impl<'a> EnumVariant<'a> {
     /// Get an iterator of fields which are exposed to the reflection API
     #[allow(dead_code)]
@@ -42,7 +42,6 @@ pub(crate) fn get_variant_constructors(
         let mut reflect_index: usize = 0;
         let constructor_fields = fields.iter().enumerate().map(|(declare_index, field)| {
             let field_ident = ident_or_index(field.data.ident.as_ref(), declare_index);
-            let field_ty = &field.data.ty;
 
             let field_value = if field.attrs.ignore.is_ignored() {
                 match &field.attrs.default {
@@ -74,10 +73,11 @@ pub(crate) fn get_variant_constructors(
                 };
                 reflect_index += 1;
 
+                let from_reflect = field.field_from_reflect(bevy_reflect_path, quote!(field));
                 match &field.attrs.default {
                     DefaultBehavior::Func(path) => quote! {
                         if let #FQOption::Some(field) = #field_accessor {
-                            <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                            #from_reflect
                             #resolve_error
                         } else {
                             #path()
@@ -85,22 +85,33 @@ pub(crate) fn get_variant_constructors(
                     },
                     DefaultBehavior::Default => quote! {
                         if let #FQOption::Some(field) = #field_accessor {
-                            <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                            #from_reflect
                             #resolve_error
                         } else {
                             #FQDefault::default()
                         }
                     },
-                    DefaultBehavior::Required => quote! {
-                        <#field_ty as #bevy_reflect_path::FromReflect>::from_reflect(#field_accessor #resolve_missing)
-                        #resolve_error
-                    },
+                    DefaultBehavior::Required => {
+                        let from_reflect = field.field_from_reflect(
+                            bevy_reflect_path,
+                            quote!(#field_accessor #resolve_missing),
+                        );
+                        quote! {
+                            #from_reflect
+                            #resolve_error
+                        }
+                    }
                 }
             };
             quote! { #field_ident : #field_value }
         });
-        variant_constructors.push(quote! {
+        let constructor = quote! {
             #variant_constructor { #( #constructor_fields ),* }
+        };
+        variant_constructors.push(if reflect_enum.meta().remote_ty().is_some() {
+            quote!(Self(#constructor))
+        } else {
+            constructor
         });
         variant_names.push(name);
     }
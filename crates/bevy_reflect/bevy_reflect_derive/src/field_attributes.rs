@@ -4,15 +4,22 @@
 //! as opposed to an entire struct or enum. An example of such an attribute is
 //! the derive helper attribute for `Reflect`, which looks like: `#[reflect(ignore)]`.
 
+use crate::custom_attributes::CustomAttributes;
 use crate::REFLECT_ATTRIBUTE_NAME;
-use syn::meta::ParseNestedMeta;
-use syn::{Attribute, LitStr, Token};
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, ExprLit, Lit, LitStr, Meta, Token};
 
 pub(crate) static IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
 pub(crate) static IGNORE_ALL_ATTR: &str = "ignore";
 
 pub(crate) static DEFAULT_ATTR: &str = "default";
 
+pub(crate) static REMOTE_ATTR: &str = "remote";
+
+pub(crate) static ALIAS_ATTR: &str = "alias";
+
 /// Stores data about if the field should be visible via the Reflect and serialization interfaces
 ///
 /// Note the relationship between serialization and reflection is such that a member must be reflected in order to be serialized.
@@ -51,6 +58,20 @@ pub(crate) struct ReflectFieldAttr {
     pub ignore: ReflectIgnoreBehavior,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// Custom attributes created via `#[reflect(@...)]`.
+    pub custom_attributes: CustomAttributes,
+    /// The `#[reflect_remote]` wrapper used to reflect this field, if any.
+    pub remote: Option<syn::Type>,
+    /// Previous names of this field or variant, accepted when deserializing.
+    pub aliases: Vec<LitStr>,
+}
+
+impl ReflectFieldAttr {
+    /// Returns the `with_aliases` builder call for the aliases of this field or variant, if any.
+    pub fn aliases_to_builder_tokens(&self) -> Option<proc_macro2::TokenStream> {
+        let aliases = &self.aliases;
+        (!aliases.is_empty()).then(|| quote!(.with_aliases(&[#(#aliases),*])))
+    }
 }
 
 /// Controls how the default value is determined for a field.
@@ -68,7 +89,7 @@ pub(crate) enum DefaultBehavior {
     Func(syn::ExprPath),
 }
 
-/// Parse all field attributes marked "reflect" (such as `#[reflect(ignore)]`).
+/// Parse all field attributes marked "reflect" (such as `#[reflect(ignore)]` or `#[reflect(@Tooltip::new("..."))]`).
 pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr, syn::Error> {
     let mut args = ReflectFieldAttr::default();
     let mut errors: Option<syn::Error> = None;
@@ -77,7 +98,7 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
         .iter()
         .filter(|a| a.path().is_ident(REFLECT_ATTRIBUTE_NAME));
     for attr in attrs {
-        let result = attr.parse_nested_meta(|meta| parse_meta(&mut args, meta));
+        let result = attr.parse_args_with(|input: ParseStream| parse_attr_args(&mut args, input));
         if let Err(err) = result {
             if let Some(ref mut error) = errors {
                 error.combine(err);
@@ -94,53 +115,152 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
     }
 }
 
-fn parse_meta(args: &mut ReflectFieldAttr, meta: ParseNestedMeta) -> Result<(), syn::Error> {
-    if meta.path.is_ident(DEFAULT_ATTR) {
+/// Parse the comma-separated contents of a single `#[reflect(...)]` attribute.
+fn parse_attr_args(args: &mut ReflectFieldAttr, input: ParseStream) -> Result<(), syn::Error> {
+    while !input.is_empty() {
+        if input.peek(Token![@]) {
+            args.custom_attributes.parse_custom_attribute(input)?;
+        } else {
+            parse_meta(args, &input.parse::<Meta>()?)?;
+        }
+
+        if input.is_empty() {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+    }
+
+    Ok(())
+}
+
+fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error> {
+    let error = |message: String| syn::Error::new(meta.span(), message);
+
+    if meta.path().is_ident(DEFAULT_ATTR) {
         // Allow:
         // - `#[reflect(default)]`
         // - `#[reflect(default = "path::to::func")]`
         if !matches!(args.default, DefaultBehavior::Required) {
-            return Err(meta.error(format!("only one of [{:?}] is allowed", [DEFAULT_ATTR])));
+            return Err(error(format!(
+                "only one of [{:?}] is allowed",
+                [DEFAULT_ATTR]
+            )));
         }
 
-        if meta.input.peek(Token![=]) {
-            let lit = meta.value()?.parse::<LitStr>()?;
-            args.default = DefaultBehavior::Func(lit.parse()?);
-        } else {
-            args.default = DefaultBehavior::Default;
+        match meta {
+            Meta::Path(_) => {
+                args.default = DefaultBehavior::Default;
+            }
+            Meta::NameValue(pair) => {
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) = &pair.value
+                else {
+                    return Err(syn::Error::new(
+                        pair.value.span(),
+                        "expected a string literal",
+                    ));
+                };
+                args.default = DefaultBehavior::Func(lit.parse()?);
+            }
+            Meta::List(_) => {
+                return Err(error(format!(
+                    "expected `{DEFAULT_ATTR}` or `{DEFAULT_ATTR} = \"path::to::func\"`"
+                )));
+            }
         }
 
         Ok(())
-    } else if meta.path.is_ident(IGNORE_ALL_ATTR) {
+    } else if meta.path().is_ident(IGNORE_ALL_ATTR) {
         // Allow:
         // - `#[reflect(ignore)]`
         if args.ignore != ReflectIgnoreBehavior::None {
-            return Err(meta.error(format!(
+            return Err(error(format!(
                 "only one of [{:?}] is allowed",
                 [IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR]
             )));
         }
 
+        meta.require_path_only()?;
         args.ignore = ReflectIgnoreBehavior::IgnoreAlways;
 
         Ok(())
-    } else if meta.path.is_ident(IGNORE_SERIALIZATION_ATTR) {
+    } else if meta.path().is_ident(IGNORE_SERIALIZATION_ATTR) {
         // Allow:
         // - `#[reflect(skip_serializing)]`
         if args.ignore != ReflectIgnoreBehavior::None {
-            return Err(meta.error(format!(
+            return Err(error(format!(
                 "only one of [{:?}] is allowed",
                 [IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR]
             )));
         }
 
+        meta.require_path_only()?;
         args.ignore = ReflectIgnoreBehavior::IgnoreSerialization;
 
+        Ok(())
+    } else if meta.path().is_ident(REMOTE_ATTR) {
+        // Allow:
+        // - `#[reflect(remote = path::to::Wrapper)]`
+        if args.remote.is_some() {
+            return Err(error(format!(
+                "only one of [{:?}] is allowed",
+                [REMOTE_ATTR]
+            )));
+        }
+
+        let Meta::NameValue(pair) = meta else {
+            return Err(error(format!(
+                "expected `{REMOTE_ATTR} = path::to::Wrapper`"
+            )));
+        };
+        let Expr::Path(path) = &pair.value else {
+            return Err(syn::Error::new(
+                pair.value.span(),
+                "expected a path to a `#[reflect_remote]` type",
+            ));
+        };
+        args.remote = Some(syn::Type::Path(syn::TypePath {
+            qself: path.qself.clone(),
+            path: path.path.clone(),
+        }));
+
+        Ok(())
+    } else if meta.path().is_ident(ALIAS_ATTR) {
+        // Allow:
+        // - `#[reflect(alias = "old_name")]`
+        let Meta::NameValue(pair) = meta else {
+            return Err(error(format!("expected `{ALIAS_ATTR} = \"old_name\"`")));
+        };
+        let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value
+        else {
+            return Err(syn::Error::new(
+                pair.value.span(),
+                "expected a string literal",
+            ));
+        };
+        if args
+            .aliases
+            .iter()
+            .any(|alias| alias.value() == lit.value())
+        {
+            return Err(syn::Error::new(lit.span(), "duplicate alias"));
+        }
+        args.aliases.push(lit.clone());
+
         Ok(())
     } else {
-        Err(meta.error(format!(
+        Err(error(format!(
             "unknown attribute, expected {:?}",
-            [DEFAULT_ATTR, IGNORE_ALL_ATTR, IGNORE_SERIALIZATION_ATTR]
+            [
+                DEFAULT_ATTR,
+                IGNORE_ALL_ATTR,
+                IGNORE_SERIALIZATION_ATTR,
+                REMOTE_ATTR,
+                ALIAS_ATTR
+            ]
         )))
     }
 }
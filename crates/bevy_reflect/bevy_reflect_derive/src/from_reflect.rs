@@ -114,12 +114,18 @@ fn impl_struct_internal(
 
     let is_defaultable = reflect_struct.meta().traits().contains(REFLECT_DEFAULT);
     let constructor = if is_defaultable {
+        let this_access = if reflect_struct.meta().remote_ty().is_some() {
+            quote!(__this.0)
+        } else {
+            quote!(__this)
+        };
+
         quote!(
             let mut __this: Self = #FQDefault::default();
             #(
                 if let #fqoption::Some(__field) = #active_values() {
                     // Iff field exists -> use its value
-                    __this.#active_members = __field;
+                    #this_access.#active_members = __field;
                 }
             )*
             #FQOption::Some(__this)
@@ -127,14 +133,17 @@ fn impl_struct_internal(
     } else {
         let MemberValuePair(ignored_members, ignored_values) = get_ignored_fields(reflect_struct);
 
-        quote!(
-            #FQOption::Some(
-                Self {
-                    #(#active_members: #active_values()?,)*
-                    #(#ignored_members: #ignored_values,)*
-                }
-            )
-        )
+        let fields = quote! {
+            {
+                #(#active_members: #active_values()?,)*
+                #(#ignored_members: #ignored_values,)*
+            }
+        };
+
+        match reflect_struct.meta().remote_path_without_generics() {
+            Some(remote_path) => quote!(#FQOption::Some(Self(#remote_path #fields))),
+            None => quote!(#FQOption::Some(Self #fields)),
+        }
     };
 
     let (impl_generics, ty_generics, where_clause) = reflect_struct
@@ -220,17 +229,17 @@ fn get_active_fields(
             .map(|field| {
                 let member = ident_or_index(field.data.ident.as_ref(), field.index);
                 let accessor = get_field_accessor(field.data, field.index, is_tuple);
-                let ty = field.data.ty.clone();
 
                 let get_field = quote! {
                     #bevy_reflect_path::#struct_type::field(#dyn_struct_name, #accessor)
                 };
+                let from_reflect = field.field_from_reflect(bevy_reflect_path, quote!(field));
 
                 let value = match &field.attrs.default {
                     DefaultBehavior::Func(path) => quote! {
                         (||
                             if let #FQOption::Some(field) = #get_field {
-                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                                #from_reflect
                             } else {
                                 #FQOption::Some(#path())
                             }
@@ -239,15 +248,19 @@ fn get_active_fields(
                     DefaultBehavior::Default => quote! {
                         (||
                             if let #FQOption::Some(field) = #get_field {
-                                <#ty as #bevy_reflect_path::FromReflect>::from_reflect(field)
+                                #from_reflect
                             } else {
                                 #FQOption::Some(#FQDefault::default())
                             }
                         )
                     },
-                    DefaultBehavior::Required => quote! {
-                        (|| <#ty as #bevy_reflect_path::FromReflect>::from_reflect(#get_field?))
-                    },
+                    DefaultBehavior::Required => {
+                        let from_reflect =
+                            field.field_from_reflect(bevy_reflect_path, quote!(#get_field?));
+                        quote! {
+                            (|| #from_reflect)
+                        }
+                    }
                 };
 
                 (member, value)
@@ -0,0 +1,91 @@
+use bevy_macro_utils::BevyManifest;
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, FnArg, GenericParam, ImplItem, ItemImpl, Pat};
+
+/// An impl block attribute macro that registers the methods marked with `#[reflect]`.
+///
+/// This generates an implementation of `RegisterMethods` for the implementing type,
+/// which registers each marked method as a `DynamicFunction` named `<type path>::<method>`.
+pub(crate) fn reflect_methods(_args: &TokenStream, input: TokenStream) -> TokenStream {
+    let mut item_impl = parse_macro_input!(input as ItemImpl);
+    if let Some((_, path, _)) = &item_impl.trait_ {
+        return syn::Error::new(
+            path.span(),
+            "#[reflect_methods] can only be used on inherent impl blocks",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    let bevy_reflect_path = BevyManifest::default().get_path("bevy_reflect");
+    let mut registrations = Vec::new();
+    for item in &mut item_impl.items {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+
+        let attr_count = method.attrs.len();
+        method
+            .attrs
+            .retain(|attr| !attr.path().is_ident(crate::REFLECT_ATTRIBUTE_NAME));
+        if method.attrs.len() == attr_count {
+            continue;
+        }
+
+        let sig = &method.sig;
+        if let Some(param) = sig
+            .generics
+            .params
+            .iter()
+            .find(|param| !matches!(param, GenericParam::Lifetime(_)))
+        {
+            return syn::Error::new(param.span(), "generic methods cannot be reflected")
+                .into_compile_error()
+                .into();
+        }
+        if let Some(token) = sig.asyncness {
+            return syn::Error::new(token.span(), "async methods cannot be reflected")
+                .into_compile_error()
+                .into();
+        }
+        if let Some(token) = sig.unsafety {
+            return syn::Error::new(token.span(), "unsafe methods cannot be reflected")
+                .into_compile_error()
+                .into();
+        }
+
+        let ident = &sig.ident;
+        let name = ident.to_string();
+        let arg_names = sig.inputs.iter().map(|input| match input {
+            FnArg::Receiver(_) => String::from("self"),
+            FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+                Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+                pat => quote!(#pat).to_string(),
+            },
+        });
+
+        registrations.push(quote! {
+            // Registering the same method twice is a no-op, just like registering a type twice.
+            let _ = registry.register_function_with_name(
+                ::std::format!("{}::{}", <Self as #bevy_reflect_path::TypePath>::type_path(), #name),
+                #bevy_reflect_path::func::IntoFunction::into_function(<Self>::#ident)
+                    .with_arg_names([#(#arg_names),*]),
+            );
+        });
+    }
+
+    let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+    let self_ty = &item_impl.self_ty;
+
+    TokenStream::from(quote! {
+        #item_impl
+
+        impl #impl_generics #bevy_reflect_path::RegisterMethods for #self_ty #where_clause {
+            fn register_methods(registry: &mut #bevy_reflect_path::TypeRegistry) {
+                #(#registrations)*
+            }
+        }
+    })
+}
@@ -1,7 +1,7 @@
 use crate::derive_data::{EnumVariant, EnumVariantFields, ReflectEnum, StructField};
 use crate::enum_utility::{get_variant_constructors, EnumVariantConstructors};
 use crate::fq_std::{FQAny, FQBox, FQOption, FQResult};
-use crate::impls::{impl_type_path, impl_typed};
+use crate::impls::{generate_generics, impl_type_path, impl_typed};
 use crate::utility::extend_where_clause;
 use proc_macro2::{Ident, Span};
 use quote::quote;
@@ -20,7 +20,9 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
     let EnumImpls {
         variant_info,
         enum_field,
+        enum_field_mut,
         enum_field_at,
+        enum_field_at_mut,
         enum_index_of,
         enum_name_at,
         enum_field_len,
@@ -29,6 +31,13 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
         enum_variant_type,
     } = generate_impls(reflect_enum, &ref_index, &ref_name);
 
+    // Remote enums are matched through the wrapped value
+    let (match_ref, match_mut) = if reflect_enum.meta().remote_ty().is_some() {
+        (quote!(&self.0), quote!(&mut self.0))
+    } else {
+        (quote!(self), quote!(self))
+    };
+
     let EnumVariantConstructors {
         variant_names,
         variant_constructors,
@@ -59,19 +68,25 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
         });
 
     let string_name = enum_path.get_ident().unwrap().to_string();
+    let custom_attributes = reflect_enum
+        .meta()
+        .traits()
+        .custom_attributes()
+        .to_builder_tokens(bevy_reflect_path);
+    let generics = generate_generics(reflect_enum.meta());
 
     #[cfg(feature = "documentation")]
     let info_generator = {
         let doc = reflect_enum.meta().doc();
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants).with_docs(#doc)
+            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants) #custom_attributes #generics .with_docs(#doc)
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants)
+            #bevy_reflect_path::EnumInfo::new::<Self>(#string_name, &variants) #custom_attributes #generics
         }
     };
 
@@ -105,42 +120,42 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
         impl #impl_generics #bevy_reflect_path::Enum for #enum_path #ty_generics #where_reflect_clause {
             fn field(&self, #ref_name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
-                 match self {
+                 match #match_ref {
                     #(#enum_field,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at(&self, #ref_index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
-                match self {
+                match #match_ref {
                     #(#enum_field_at,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_mut(&mut self, #ref_name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
-                 match self {
-                    #(#enum_field,)*
+                 match #match_mut {
+                    #(#enum_field_mut,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at_mut(&mut self, #ref_index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
-                match self {
-                    #(#enum_field_at,)*
+                match #match_mut {
+                    #(#enum_field_at_mut,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn index_of(&self, #ref_name: &str) -> #FQOption<usize> {
-                 match self {
+                 match #match_ref {
                     #(#enum_index_of,)*
                     _ => #FQOption::None,
                 }
             }
 
             fn name_at(&self, #ref_index: usize) -> #FQOption<&str> {
-                 match self {
+                 match #match_ref {
                     #(#enum_name_at,)*
                     _ => #FQOption::None,
                 }
@@ -152,7 +167,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn field_len(&self) -> usize {
-                 match self {
+                 match #match_ref {
                     #(#enum_field_len,)*
                     _ => 0,
                 }
@@ -160,7 +175,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn variant_name(&self) -> &str {
-                 match self {
+                 match #match_ref {
                     #(#enum_variant_name,)*
                     _ => unreachable!(),
                 }
@@ -168,7 +183,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn variant_index(&self) -> usize {
-                 match self {
+                 match #match_ref {
                     #(#enum_variant_index,)*
                     _ => unreachable!(),
                 }
@@ -176,7 +191,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #[inline]
             fn variant_type(&self) -> #bevy_reflect_path::VariantType {
-                 match self {
+                 match #match_ref {
                     #(#enum_variant_type,)*
                     _ => unreachable!(),
                 }
@@ -296,7 +311,9 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 struct EnumImpls {
     variant_info: Vec<proc_macro2::TokenStream>,
     enum_field: Vec<proc_macro2::TokenStream>,
+    enum_field_mut: Vec<proc_macro2::TokenStream>,
     enum_field_at: Vec<proc_macro2::TokenStream>,
+    enum_field_at_mut: Vec<proc_macro2::TokenStream>,
     enum_index_of: Vec<proc_macro2::TokenStream>,
     enum_name_at: Vec<proc_macro2::TokenStream>,
     enum_field_len: Vec<proc_macro2::TokenStream>,
@@ -310,7 +327,9 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
 
     let mut variant_info = Vec::new();
     let mut enum_field = Vec::new();
+    let mut enum_field_mut = Vec::new();
     let mut enum_field_at = Vec::new();
+    let mut enum_field_at_mut = Vec::new();
     let mut enum_index_of = Vec::new();
     let mut enum_name_at = Vec::new();
     let mut enum_field_len = Vec::new();
@@ -360,18 +379,26 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
         }
 
         let mut push_variant =
-            |_variant: &EnumVariant, arguments: proc_macro2::TokenStream, field_len: usize| {
+            |variant: &EnumVariant, arguments: proc_macro2::TokenStream, field_len: usize| {
                 #[cfg(feature = "documentation")]
                 let with_docs = {
-                    let doc = quote::ToTokens::to_token_stream(&_variant.doc);
+                    let doc = quote::ToTokens::to_token_stream(&variant.doc);
                     Some(quote!(.with_docs(#doc)))
                 };
                 #[cfg(not(feature = "documentation"))]
                 let with_docs: Option<proc_macro2::TokenStream> = None;
 
+                let custom_attributes = variant
+                    .attrs
+                    .custom_attributes
+                    .to_builder_tokens(bevy_reflect_path);
+                let aliases = variant.attrs.aliases_to_builder_tokens();
+
                 variant_info.push(quote! {
                     #bevy_reflect_path::VariantInfo::#variant_type_ident(
                         #bevy_reflect_path::#variant_info_ident::new(#arguments)
+                        #custom_attributes
+                        #aliases
                         #with_docs
                     )
                 });
@@ -390,8 +417,13 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             EnumVariantFields::Unnamed(fields) => {
                 let args = get_field_args(fields, |reflect_idx, declaration_index, field| {
                     let declare_field = syn::Index::from(declaration_index);
+                    let value_ref = field.reflect_ref(bevy_reflect_path, quote!(value));
+                    let value_mut = field.reflect_mut(bevy_reflect_path, quote!(value));
                     enum_field_at.push(quote! {
-                        #unit { #declare_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(value)
+                        #unit { #declare_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(#value_ref)
+                    });
+                    enum_field_at_mut.push(quote! {
+                        #unit { #declare_field : value, .. } if #ref_index == #reflect_idx => #FQOption::Some(#value_mut)
                     });
 
                     #[cfg(feature = "documentation")]
@@ -402,9 +434,15 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     #[cfg(not(feature = "documentation"))]
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
-                    let field_ty = &field.data.ty;
+                    let custom_attributes = field
+                        .attrs
+                        .custom_attributes
+                        .to_builder_tokens(bevy_reflect_path);
+
+                    let field_ty = field.reflected_type();
                     quote! {
                         #bevy_reflect_path::UnnamedField::new::<#field_ty>(#reflect_idx)
+                        #custom_attributes
                         #with_docs
                     }
                 });
@@ -416,11 +454,19 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                 let args = get_field_args(fields, |reflect_idx, _, field| {
                     let field_ident = field.data.ident.as_ref().unwrap();
                     let field_name = field_ident.to_string();
+                    let value_ref = field.reflect_ref(bevy_reflect_path, quote!(#field_ident));
+                    let value_mut = field.reflect_mut(bevy_reflect_path, quote!(#field_ident));
                     enum_field.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#field_ident)
+                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#value_ref)
+                    });
+                    enum_field_mut.push(quote! {
+                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#value_mut)
                     });
                     enum_field_at.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#field_ident)
+                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#value_ref)
+                    });
+                    enum_field_at_mut.push(quote! {
+                        #unit{ #field_ident, .. } if #ref_index == #reflect_idx => #FQOption::Some(#value_mut)
                     });
                     enum_index_of.push(quote! {
                         #unit{ .. } if #ref_name == #field_name => #FQOption::Some(#reflect_idx)
@@ -437,9 +483,18 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     #[cfg(not(feature = "documentation"))]
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
-                    let field_ty = &field.data.ty;
+                    let custom_attributes = field
+                        .attrs
+                        .custom_attributes
+                        .to_builder_tokens(bevy_reflect_path);
+
+                    let aliases = field.attrs.aliases_to_builder_tokens();
+
+                    let field_ty = field.reflected_type();
                     quote! {
                         #bevy_reflect_path::NamedField::new::<#field_ty>(#field_name)
+                        #custom_attributes
+                        #aliases
                         #with_docs
                     }
                 });
@@ -453,7 +508,9 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
     EnumImpls {
         variant_info,
         enum_field,
+        enum_field_mut,
         enum_field_at,
+        enum_field_at_mut,
         enum_index_of,
         enum_name_at,
         enum_field_len,
@@ -7,6 +7,7 @@ mod values;
 pub(crate) use enums::impl_enum;
 pub(crate) use structs::impl_struct;
 pub(crate) use tuple_structs::impl_tuple_struct;
+pub(crate) use typed::generate_generics;
 pub(crate) use typed::impl_type_path;
 pub(crate) use typed::impl_typed;
 pub(crate) use values::impl_value;
@@ -1,5 +1,5 @@
 use crate::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
-use crate::impls::{impl_type_path, impl_typed};
+use crate::impls::{generate_generics, impl_type_path, impl_typed};
 use crate::utility::{extend_where_clause, ident_or_index};
 use crate::ReflectStruct;
 use quote::{quote, ToTokens};
@@ -26,6 +26,19 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
         .active_fields()
         .map(|field| ident_or_index(field.data.ident.as_ref(), field.index))
         .collect::<Vec<_>>();
+    let self_access = reflect_struct.meta().self_access();
+    let field_refs = reflect_struct
+        .active_fields()
+        .zip(&field_idents)
+        .map(|(field, ident)| field.reflect_ref(bevy_reflect_path, quote!(&#self_access.#ident)))
+        .collect::<Vec<_>>();
+    let field_muts = reflect_struct
+        .active_fields()
+        .zip(&field_idents)
+        .map(|(field, ident)| {
+            field.reflect_mut(bevy_reflect_path, quote!(&mut #self_access.#ident))
+        })
+        .collect::<Vec<_>>();
     let field_types = reflect_struct.active_types();
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
@@ -46,37 +59,52 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
             }
         });
 
+    let field_custom_attributes = reflect_struct.active_fields().map(|field| {
+        let custom_attributes = field
+            .attrs
+            .custom_attributes
+            .to_builder_tokens(bevy_reflect_path);
+        let aliases = field.attrs.aliases_to_builder_tokens();
+        quote!(#custom_attributes #aliases)
+    });
+
     #[cfg(feature = "documentation")]
     let field_generator = {
         let docs = reflect_struct
             .active_fields()
             .map(|field| quote::ToTokens::to_token_stream(&field.doc));
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names).with_docs(#docs) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) #field_custom_attributes.with_docs(#docs) ,)*
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let field_generator = {
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) #field_custom_attributes ,)*
         }
     };
 
     let string_name = struct_path.get_ident().unwrap().to_string();
+    let custom_attributes = reflect_struct
+        .meta()
+        .traits()
+        .custom_attributes()
+        .to_builder_tokens(bevy_reflect_path);
+    let generics = generate_generics(reflect_struct.meta());
 
     #[cfg(feature = "documentation")]
     let info_generator = {
         let doc = reflect_struct.meta().doc();
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields).with_docs(#doc)
+            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields) #custom_attributes #generics .with_docs(#doc)
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let info_generator = {
         quote! {
-            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields)
+            #bevy_reflect_path::StructInfo::new::<Self>(#string_name, &fields) #custom_attributes #generics
         }
     };
 
@@ -113,28 +141,28 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
         impl #impl_generics #bevy_reflect_path::Struct for #struct_path #ty_generics #where_reflect_clause {
             fn field(&self, name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match name {
-                    #(#field_names => #fqoption::Some(&self.#field_idents),)*
+                    #(#field_names => #fqoption::Some(#field_refs),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_mut(&mut self, name: &str) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match name {
-                    #(#field_names => #fqoption::Some(&mut self.#field_idents),)*
+                    #(#field_names => #fqoption::Some(#field_muts),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at(&self, index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&self.#field_idents),)*
+                    #(#field_indices => #fqoption::Some(#field_refs),)*
                     _ => #FQOption::None,
                 }
             }
 
             fn field_at_mut(&mut self, index: usize) -> #FQOption<&mut dyn #bevy_reflect_path::Reflect> {
                 match index {
-                    #(#field_indices => #fqoption::Some(&mut self.#field_idents),)*
+                    #(#field_indices => #fqoption::Some(#field_muts),)*
                     _ => #FQOption::None,
                 }
             }
@@ -157,7 +185,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
             fn clone_dynamic(&self) -> #bevy_reflect_path::DynamicStruct {
                 let mut dynamic: #bevy_reflect_path::DynamicStruct = #FQDefault::default();
                 dynamic.set_represented_type(#bevy_reflect_path::Reflect::get_represented_type_info(self));
-                #(dynamic.insert_boxed(#field_names, #bevy_reflect_path::Reflect::clone_value(&self.#field_idents));)*
+                #(dynamic.insert_boxed(#field_names, #bevy_reflect_path::Reflect::clone_value(#field_refs));)*
                 dynamic
             }
         }
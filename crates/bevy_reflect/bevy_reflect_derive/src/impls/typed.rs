@@ -1,5 +1,6 @@
 use crate::utility::{extend_where_clause, StringExpr, WhereClauseOptions};
 use quote::{quote, ToTokens};
+use syn::GenericParam;
 
 use crate::{
     derive_data::{ReflectMeta, ReflectTypePath},
@@ -152,3 +153,55 @@ pub(crate) fn impl_typed(
         }
     }
 }
+
+/// Returns the `.with_generics(...)` builder call describing the type's generic parameters.
+///
+/// Lifetimes are skipped, and nothing is generated for non-generic types.
+/// Type parameters rely on the `TypePath` bounds added when `TypePath` is derived,
+/// so this is also skipped for types opting out of it.
+pub(crate) fn generate_generics(meta: &ReflectMeta) -> Option<proc_macro2::TokenStream> {
+    if !meta.type_path().impl_is_generic() || !meta.traits().type_path_attrs().should_auto_derive()
+    {
+        return None;
+    }
+
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    let params = meta
+        .type_path()
+        .generics()
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                let name = ident.to_string();
+                let with_default = param
+                    .default
+                    .as_ref()
+                    .map(|default| quote!(.with_default::<#default>()));
+                Some(quote! {
+                    #bevy_reflect_path::TypeParamInfo::new::<#ident>(#name) #with_default
+                })
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                let ty = &param.ty;
+                let name = ident.to_string();
+                let with_default = param
+                    .default
+                    .as_ref()
+                    .map(|default| quote!(.with_default::<#ty>(#default)));
+                Some(quote! {
+                    #bevy_reflect_path::ConstParamInfo::new::<#ty>(#name, #ident) #with_default
+                })
+            }
+            GenericParam::Lifetime(_) => None,
+        });
+
+    Some(quote! {
+        .with_generics(
+            #bevy_reflect_path::Generics::new()
+                #(.with(#params))*
+        )
+    })
+}
@@ -1,5 +1,5 @@
 use crate::fq_std::{FQAny, FQBox, FQClone, FQOption, FQResult};
-use crate::impls::{impl_type_path, impl_typed};
+use crate::impls::{generate_generics, impl_type_path, impl_typed};
 use crate::utility::{extend_where_clause, WhereClauseOptions};
 use crate::ReflectMeta;
 use quote::quote;
@@ -21,12 +21,18 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     #[cfg(not(feature = "documentation"))]
     let with_docs: Option<proc_macro2::TokenStream> = None;
 
+    let custom_attributes = meta
+        .traits()
+        .custom_attributes()
+        .to_builder_tokens(bevy_reflect_path);
+    let generics = generate_generics(meta);
+
     let where_clause_options = WhereClauseOptions::new_value(meta);
     let typed_impl = impl_typed(
         meta,
         &where_clause_options,
         quote! {
-            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs;
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #custom_attributes #generics #with_docs;
             #bevy_reflect_path::TypeInfo::Value(info)
         },
     );
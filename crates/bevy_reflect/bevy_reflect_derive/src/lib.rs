@@ -5,16 +5,19 @@
 //! such as `Struct`, `GetTypeRegistration`, and more— all with a single derive!
 //!
 //! Some other noteworthy exports include the derive macros for [`FromReflect`] and
-//! [`TypeUuid`], as well as the [`reflect_trait`] attribute macro.
+//! [`TypeUuid`], as well as the [`reflect_trait`] and [`reflect_methods`] attribute macros.
 //!
 //! [`Reflect`]: crate::derive_reflect
 //! [`FromReflect`]: crate::derive_from_reflect
 //! [`TypeUuid`]: crate::derive_type_uuid
 //! [`reflect_trait`]: macro@reflect_trait
+//! [`reflect_methods`]: macro@reflect_methods
 
 extern crate proc_macro;
 
+mod auto_register;
 mod container_attributes;
+mod custom_attributes;
 mod derive_data;
 #[cfg(feature = "documentation")]
 mod documentation;
@@ -22,9 +25,11 @@ mod enum_utility;
 mod field_attributes;
 mod fq_std;
 mod from_reflect;
+mod function_reflection;
 mod impls;
 mod reflect_value;
 mod registration;
+mod remote;
 mod trait_reflection;
 mod type_path;
 mod type_uuid;
@@ -135,6 +140,52 @@ pub(crate) static TYPE_NAME_ATTRIBUTE_NAME: &str = "type_name";
 /// This is useful for when a type can't or shouldn't implement `TypePath`,
 /// or if a manual implementation is desired.
 ///
+/// ## `#[reflect(no_field_bounds)]`
+///
+/// By default, the generated implementations require every reflected field type
+/// to implement `Reflect` (or `FromReflect`).
+///
+/// This attribute will opt-out of these field bounds,
+/// which is needed for recursive types such as `struct Node { children: Vec<Node> }`,
+/// where the bounds would otherwise never be resolved.
+///
+/// Generic parameters are then only bounded by the bounds declared on the type,
+/// so they must declare the reflection traits themselves,
+/// as in `enum Tree<T: FromReflect + TypePath> { Leaf(T), Branch(Vec<Tree<T>>) }`.
+///
+/// ## `#[reflect(version = N)]`
+///
+/// This attribute sets the version of a type's serialized representation,
+/// registering `VersionData` within the `GetTypeRegistration` implementation.
+///
+/// The version is written alongside the fields when serializing,
+/// and data from older versions is upgraded using the migrations registered
+/// with `TypeRegistry::register_migration` when deserializing.
+///
+/// This attribute may be used on structs, tuple structs and enums, but not with `#[reflect_value]`.
+///
+/// ## `#[reflect(@...)]`
+///
+/// This attribute attaches a custom attribute to the type's `TypeInfo`.
+///
+/// The value following the `@` may be any expression whose type implements `Reflect`.
+/// It can later be retrieved by type, such as with `StructInfo::get_attribute`.
+///
+/// Custom attributes may also be applied to fields and enum variants using the same syntax.
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(@Tooltip::new("An RGB color"))]
+/// struct Color {
+///     #[reflect(@0.0..=1.0_f32)]
+///     r: f32,
+///     #[reflect(@0.0..=1.0_f32)]
+///     g: f32,
+///     #[reflect(@0.0..=1.0_f32)]
+///     b: f32,
+/// }
+/// ```
+///
 /// # Field Attributes
 ///
 /// Along with the container attributes, this macro comes with some attributes that may be applied
@@ -158,6 +209,25 @@ pub(crate) static TYPE_NAME_ATTRIBUTE_NAME: &str = "type_name";
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## `#[reflect(alias = "old_name")]`
+///
+/// This attribute allows a named field to be deserialized from a previous name.
+/// It may be given multiple times, and may also be applied to enum variants.
+///
+/// ## `#[reflect(@...)]`
+///
+/// This attribute attaches a custom attribute to the field's `NamedField` or `UnnamedField` info,
+/// which can be retrieved using `get_attribute`.
+/// See the container attribute of the same name for more details.
+///
+/// ## `#[reflect(remote = path::to::Wrapper)]`
+///
+/// This attribute allows a field whose type comes from another crate to be reflected
+/// using a wrapper generated by [`#[reflect_remote]`](macro@reflect_remote).
+///
+/// The field keeps its original type, but is exposed to the reflection API
+/// (including its field info and serialization) as the given wrapper.
+///
 /// [`reflect_trait`]: macro@reflect_trait
 #[proc_macro_derive(Reflect, attributes(reflect, reflect_value, type_path, type_name))]
 pub fn derive_reflect(input: TokenStream) -> TokenStream {
@@ -345,6 +415,73 @@ pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
     trait_reflection::reflect_trait(&args, input)
 }
 
+/// An attribute macro for impl blocks that allows their methods to be called dynamically.
+///
+/// Methods marked with `#[reflect]` are converted into a `DynamicFunction` and registered
+/// in the `TypeRegistry` when calling `TypeRegistry::register_methods` for the type.
+/// Each method is registered under the type's `TypePath` followed by the method name,
+/// e.g. `my_crate::Player::heal`.
+///
+/// Only non-generic, safe, synchronous methods whose arguments and return value are reflectable
+/// can be marked, and this attribute may only be used on a single inherent impl block per type.
+///
+/// See the `bevy_reflect::func` module for an example.
+#[proc_macro_attribute]
+pub fn reflect_methods(args: TokenStream, input: TokenStream) -> TokenStream {
+    function_reflection::reflect_methods(&args, input)
+}
+
+/// An attribute macro for reflecting a type defined in another crate.
+///
+/// Due to the orphan rule, `Reflect` cannot be implemented for a foreign type directly.
+/// Instead, this macro takes a definition that mirrors the foreign type and generates
+/// a `#[repr(transparent)]` wrapper of the same name around it.
+/// The reflection traits (`Reflect`, `FromReflect`, `TypePath`, `Typed`, and `GetTypeRegistration`)
+/// are implemented for this wrapper, as is `ReflectRemote`, which converts between the wrapper
+/// and the foreign type at no cost.
+///
+/// The mirrored definition must list exactly the same fields (or variants) with the same types
+/// as the foreign type, all of which must be public.
+/// This is checked at compile time by exhaustively destructuring the foreign type.
+///
+/// Since the reflection impls belong to the wrapper, the type registered in the `TypeRegistry`
+/// and produced by reflection is the wrapper rather than the foreign type.
+/// To store the foreign type in a reflected struct, mark the field with
+/// `#[reflect(remote = Wrapper)]`.
+///
+/// Container and field attributes work the same as with [deriving `Reflect`],
+/// and any other attributes (such as derives) are applied to the wrapper.
+/// `#[reflect_value]` is not supported.
+///
+/// # Example
+///
+/// ```ignore
+/// mod external_crate {
+///     pub struct Position {
+///         pub x: f32,
+///         pub y: f32,
+///     }
+/// }
+///
+/// #[reflect_remote(external_crate::Position)]
+/// struct PositionWrapper {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(remote = PositionWrapper)]
+///     position: external_crate::Position,
+/// }
+/// ```
+///
+/// [deriving `Reflect`]: Reflect
+#[proc_macro_attribute]
+pub fn reflect_remote(args: TokenStream, input: TokenStream) -> TokenStream {
+    remote::reflect_remote(args, input)
+}
+
 /// A macro used to generate reflection trait implementations for the given type.
 ///
 /// This is functionally the same as [deriving `Reflect`] using the `#[reflect_value]` container attribute.
@@ -586,6 +723,35 @@ pub fn impl_type_path(input: TokenStream) -> TokenStream {
     impls::impl_type_path(&meta, &WhereClauseOptions::new_value(&meta)).into()
 }
 
+/// Submits concrete instantiations of generic types for automatic registration.
+///
+/// When the `auto_register` feature of `bevy_reflect` is enabled, every non-generic type deriving `Reflect`
+/// is collected at link time and registered by `TypeRegistry::register_derived_types`.
+/// Generic types can't be collected this way, as only their concrete instantiations can be registered.
+/// This macro lists the instantiations which should be collected alongside them.
+///
+/// When the feature is disabled, this macro expands to nothing.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// struct Stat<T> {
+///     base: T,
+///     bonus: T,
+/// }
+///
+/// auto_register_types!(Stat<f32>, Stat<u32>);
+/// ```
+#[proc_macro]
+pub fn auto_register_types(input: TokenStream) -> TokenStream {
+    let types = parse_macro_input!(
+        input with syn::punctuated::Punctuated::<syn::Type, syn::token::Comma>::parse_terminated
+    );
+
+    auto_register::auto_register_types(&utility::get_bevy_reflect_path(), &types).into()
+}
+
 /// Derives `TypeUuid` for the given type. This is used internally to implement `TypeUuid` on foreign types, such as those in the std. This macro should be used in the format of `<[Generic Params]> [Type (Path)], [Uuid (String Literal)]`.
 #[proc_macro]
 pub fn impl_type_uuid(input: TokenStream) -> TokenStream {
@@ -1,5 +1,6 @@
 //! Contains code related specifically to Bevy's type registration.
 
+use crate::auto_register::submit_registration;
 use crate::derive_data::ReflectMeta;
 use crate::utility::{extend_where_clause, WhereClauseOptions};
 use bit_set::BitSet;
@@ -18,6 +19,18 @@ pub(crate) fn impl_get_type_registration(
     let (impl_generics, ty_generics, where_clause) = type_path.generics().split_for_impl();
     let where_reflect_clause = extend_where_clause(where_clause, where_clause_options);
 
+    // Field types are registered alongside this type if they are registrable,
+    // which is resolved where the type is derived without adding bounds (see `TypeDependency`).
+    let type_dependencies = where_clause_options.active_types();
+    let register_type_dependencies = (!type_dependencies.is_empty()).then(|| {
+        quote! {
+            fn register_type_dependencies(registry: &mut #bevy_reflect_path::TypeRegistry) {
+                use #bevy_reflect_path::__macro_exports::{RegisterTypeDependency as _, SkipTypeDependency as _};
+                #((&&#bevy_reflect_path::__macro_exports::TypeDependency::<#type_dependencies>::new()).register_type_dependency(registry);)*
+            }
+        }
+    });
+
     let from_reflect_data = if meta.from_reflect().should_auto_derive() {
         Some(quote! {
         registration.insert::<#bevy_reflect_path::ReflectFromReflect>(#bevy_reflect_path::FromType::<Self>::from_type());
@@ -26,6 +39,12 @@ pub(crate) fn impl_get_type_registration(
         None
     };
 
+    let version_data = meta.traits().version().map(|version| {
+        quote! {
+            registration.insert::<#bevy_reflect_path::serde::VersionData>(#bevy_reflect_path::serde::VersionData::new(#version));
+        }
+    });
+
     let serialization_data = serialization_denylist.map(|denylist| {
         let denylist = denylist.into_iter();
         quote! {
@@ -34,7 +53,16 @@ pub(crate) fn impl_get_type_registration(
         }
     });
 
+    // Only non-generic types can be collected, as generic types have no single registration
+    let auto_registration = type_path
+        .generics()
+        .params
+        .is_empty()
+        .then(|| submit_registration(bevy_reflect_path, type_path));
+
     quote! {
+        #auto_registration
+
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
             fn get_type_registration() -> #bevy_reflect_path::TypeRegistration {
@@ -42,9 +70,12 @@ pub(crate) fn impl_get_type_registration(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #version_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
+
+            #register_type_dependencies
         }
     }
 }
@@ -0,0 +1,203 @@
+use crate::derive_data::{EnumVariantFields, ReflectDerive, StructField};
+use crate::utility::ident_or_index;
+use crate::{
+    from_reflect, impls, REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME,
+    TYPE_NAME_ATTRIBUTE_NAME, TYPE_PATH_ATTRIBUTE_NAME,
+};
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, DeriveInput, TypePath};
+
+/// Generates a `#[repr(transparent)]` wrapper around the given remote type,
+/// along with the reflection impls for the wrapper.
+///
+/// The fields of the annotated definition must exactly mirror those of the remote type.
+/// This is enforced at compile time by destructuring the remote type with the mirrored fields.
+pub(crate) fn reflect_remote(args: TokenStream, input: TokenStream) -> TokenStream {
+    let remote_ty = parse_macro_input!(args as TypePath);
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match impl_reflect_remote(&ast, &remote_ty) {
+        Ok(output) => output.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn impl_reflect_remote(
+    ast: &DeriveInput,
+    remote_ty: &TypePath,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let mut derive_data = ReflectDerive::from_input(ast, false)?;
+    derive_data.set_remote(Some(remote_ty));
+
+    let (reflect_impls, from_reflect_impl) = match &derive_data {
+        ReflectDerive::Struct(struct_data) | ReflectDerive::UnitStruct(struct_data) => (
+            impls::impl_struct(struct_data),
+            struct_data
+                .meta()
+                .from_reflect()
+                .should_auto_derive()
+                .then(|| from_reflect::impl_struct(struct_data)),
+        ),
+        ReflectDerive::TupleStruct(struct_data) => (
+            impls::impl_tuple_struct(struct_data),
+            struct_data
+                .meta()
+                .from_reflect()
+                .should_auto_derive()
+                .then(|| from_reflect::impl_tuple_struct(struct_data)),
+        ),
+        ReflectDerive::Enum(enum_data) => (
+            impls::impl_enum(enum_data),
+            enum_data
+                .meta()
+                .from_reflect()
+                .should_auto_derive()
+                .then(|| from_reflect::impl_enum(enum_data)),
+        ),
+        ReflectDerive::Value(_) => {
+            return Err(syn::Error::new(
+                ast.span(),
+                format_args!(
+                    "`#[{REFLECT_VALUE_ATTRIBUTE_NAME}]` cannot be used with `#[reflect_remote]`"
+                ),
+            ));
+        }
+    };
+
+    let meta = derive_data.meta();
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    let remote_path = meta.remote_path_without_generics().unwrap();
+
+    let vis = &ast.vis;
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let generics = &ast.generics;
+
+    // Reflection attributes only apply to the mirrored definition
+    let attrs = ast.attrs.iter().filter(|attr| {
+        let path = attr.path();
+        !path.is_ident(REFLECT_ATTRIBUTE_NAME)
+            && !path.is_ident(REFLECT_VALUE_ATTRIBUTE_NAME)
+            && !path.is_ident(TYPE_PATH_ATTRIBUTE_NAME)
+            && !path.is_ident(TYPE_NAME_ATTRIBUTE_NAME)
+    });
+
+    let layout_assertion = match &derive_data {
+        ReflectDerive::Struct(struct_data)
+        | ReflectDerive::TupleStruct(struct_data)
+        | ReflectDerive::UnitStruct(struct_data) => {
+            let (pattern, assertions) = destructure_fields(&remote_path, struct_data.fields());
+            quote! {
+                let #pattern = remote;
+                #(#assertions)*
+            }
+        }
+        ReflectDerive::Enum(enum_data) => {
+            let arms = enum_data.variants().iter().map(|variant| {
+                let variant_ident = &variant.data.ident;
+                let variant_path = quote!(#remote_path::#variant_ident);
+                let fields = match &variant.fields {
+                    EnumVariantFields::Named(fields) | EnumVariantFields::Unnamed(fields) => {
+                        fields.as_slice()
+                    }
+                    EnumVariantFields::Unit => &[],
+                };
+                let (pattern, assertions) = destructure_fields(&variant_path, fields);
+                quote! {
+                    #pattern => {
+                        #(#assertions)*
+                    }
+                }
+            });
+            quote! {
+                match remote {
+                    #(#arms)*
+                }
+            }
+        }
+        ReflectDerive::Value(_) => unreachable!(),
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #[repr(transparent)]
+        #vis struct #ident #generics (pub #remote_ty) #where_clause;
+
+        #reflect_impls
+
+        #from_reflect_impl
+
+        impl #impl_generics #bevy_reflect_path::ReflectRemote for #ident #ty_generics #where_clause {
+            type Remote = #remote_ty;
+
+            #[inline]
+            fn as_remote(&self) -> &Self::Remote {
+                &self.0
+            }
+
+            #[inline]
+            fn as_remote_mut(&mut self) -> &mut Self::Remote {
+                &mut self.0
+            }
+
+            #[inline]
+            fn into_remote(self) -> Self::Remote {
+                self.0
+            }
+
+            #[inline]
+            fn as_wrapper(remote: &Self::Remote) -> &Self {
+                // SAFE: `Self` is a `repr(transparent)` wrapper around `Self::Remote`
+                unsafe { &*(remote as *const Self::Remote as *const Self) }
+            }
+
+            #[inline]
+            fn as_wrapper_mut(remote: &mut Self::Remote) -> &mut Self {
+                // SAFE: `Self` is a `repr(transparent)` wrapper around `Self::Remote`
+                unsafe { &mut *(remote as *mut Self::Remote as *mut Self) }
+            }
+
+            #[inline]
+            fn into_wrapper(remote: Self::Remote) -> Self {
+                Self(remote)
+            }
+        }
+
+        const _: () = {
+            // Ensures the mirrored definition matches the remote type
+            #[allow(unused_variables, unreachable_patterns, clippy::all)]
+            fn assert_remote_layout #impl_generics (remote: #remote_ty) #where_clause {
+                #layout_assertion
+            }
+        };
+    })
+}
+
+/// Creates an exhaustive pattern for the given fields, binding each to a local variable,
+/// along with assertions that each binding has the mirrored field's type.
+fn destructure_fields(
+    path: &impl quote::ToTokens,
+    fields: &[StructField],
+) -> (proc_macro2::TokenStream, Vec<proc_macro2::TokenStream>) {
+    let (members, bindings): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .map(|field| {
+            let member = ident_or_index(field.data.ident.as_ref(), field.index);
+            let binding = Ident::new(&format!("__field_{}", field.index), Span::call_site());
+            (member, binding)
+        })
+        .unzip();
+    let assertions = fields
+        .iter()
+        .zip(&bindings)
+        .map(|(field, binding)| {
+            let ty = &field.data.ty;
+            quote!(let _: #ty = #binding;)
+        })
+        .collect();
+
+    (quote!(#path { #(#members: #bindings),* }), assertions)
+}
@@ -125,9 +125,13 @@ impl WhereClauseOptions {
         let bevy_reflect_path = meta.bevy_reflect_path();
         let is_from_reflect = meta.from_reflect().should_auto_derive();
 
+        // Recursive types opt out of field bounds, as they would otherwise require themselves
+        let no_field_bounds = meta.traits().no_field_bounds();
+
         let (active_types, active_trait_bounds): (Vec<_>, Vec<_>) = active_fields
+            .filter(|_| !no_field_bounds)
             .map(|field| {
-                let ty = field.data.ty.clone();
+                let ty = field.reflected_type().clone();
 
                 let custom_bounds = active_bounds(field).map(|bounds| quote!(+ #bounds));
 
@@ -178,6 +182,13 @@ impl WhereClauseOptions {
             parameter_trait_bounds: parameter_trait_bounds.into_boxed_slice(),
         }
     }
+
+    /// The types of the active fields, which will be reflected.
+    ///
+    /// This is empty if the type opted out of field bounds.
+    pub fn active_types(&self) -> &[Type] {
+        &self.active_types
+    }
 }
 
 /// Extends the `where` clause in reflection with any additional bounds needed.
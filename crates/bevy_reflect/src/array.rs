@@ -1,6 +1,6 @@
 use crate::{
-    self as bevy_reflect, utility::reflect_hasher, Reflect, ReflectMut, ReflectOwned, ReflectRef,
-    TypeInfo,
+    self as bevy_reflect, utility::reflect_hasher, Generics, Reflect, ReflectMut, ReflectOwned,
+    ReflectRef, TypeInfo,
 };
 use bevy_reflect_derive::impl_type_path;
 use std::{
@@ -82,6 +82,7 @@ pub struct ArrayInfo {
     item_type_name: &'static str,
     item_type_id: TypeId,
     capacity: usize,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -100,11 +101,17 @@ impl ArrayInfo {
             item_type_name: std::any::type_name::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
             capacity,
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the generic parameters for this array.
+    pub fn with_generics(self, generics: Generics) -> Self {
+        Self { generics, ..self }
+    }
+
     /// Sets the docstring for this array.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -150,6 +157,11 @@ impl ArrayInfo {
         TypeId::of::<T>() == self.item_type_id
     }
 
+    /// The generic parameters of this array.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
     /// The docstring of this array, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
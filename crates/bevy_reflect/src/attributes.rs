@@ -0,0 +1,364 @@
+use crate::Reflect;
+use bevy_utils::HashMap;
+use std::any::TypeId;
+use std::fmt::{Debug, Formatter};
+
+/// A collection of custom attributes for a type, field, or variant.
+///
+/// These attributes can be created with the [`Reflect` derive macro]
+/// by prefixing a value with `@` inside a `#[reflect(...)]` attribute:
+///
+/// ```
+/// # use std::ops::RangeInclusive;
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// #[derive(Reflect)]
+/// struct Slider {
+///     #[reflect(@RangeInclusive::<f32>::new(0.0, 1.0))]
+///     value: f32,
+/// }
+///
+/// let TypeInfo::Struct(info) = Slider::type_info() else {
+///     panic!("expected struct info");
+/// };
+///
+/// let range = info.field("value").unwrap().get_attribute::<RangeInclusive<f32>>().unwrap();
+/// assert_eq!(&(0.0..=1.0), range);
+/// ```
+///
+/// Attributes are stored by their [`TypeId`],
+/// so only one attribute of a given type may exist per collection.
+/// Adding a second attribute of the same type replaces the first one.
+///
+/// [`Reflect` derive macro]: derive@crate::Reflect
+#[derive(Default)]
+pub struct CustomAttributes {
+    attributes: HashMap<TypeId, CustomAttribute>,
+}
+
+impl CustomAttributes {
+    /// Inserts a custom attribute into the collection.
+    ///
+    /// Note that this will overwrite any existing attribute of the same type.
+    pub fn with_attribute<T: Reflect>(mut self, value: T) -> Self {
+        self.attributes
+            .insert(TypeId::of::<T>(), CustomAttribute::new(value));
+        self
+    }
+
+    /// Returns `true` if this collection contains a custom attribute of the specified type.
+    pub fn contains<T: Reflect>(&self) -> bool {
+        self.attributes.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns `true` if this collection contains a custom attribute with the specified [`TypeId`].
+    pub fn contains_by_id(&self, id: TypeId) -> bool {
+        self.attributes.contains_key(&id)
+    }
+
+    /// Gets a custom attribute by type.
+    pub fn get<T: Reflect>(&self) -> Option<&T> {
+        self.attributes.get(&TypeId::of::<T>())?.value::<T>()
+    }
+
+    /// Gets a custom attribute by its [`TypeId`].
+    pub fn get_by_id(&self, id: TypeId) -> Option<&dyn Reflect> {
+        Some(self.attributes.get(&id)?.reflect_value())
+    }
+
+    /// Returns an iterator over all custom attributes and their [`TypeId`].
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&TypeId, &dyn Reflect)> {
+        self.attributes
+            .iter()
+            .map(|(key, value)| (key, value.reflect_value()))
+    }
+
+    /// Returns the number of custom attributes in this collection.
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns `true` if this collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+}
+
+impl Debug for CustomAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.attributes.values()).finish()
+    }
+}
+
+/// A single custom attribute value.
+struct CustomAttribute {
+    value: Box<dyn Reflect>,
+}
+
+impl CustomAttribute {
+    fn new<T: Reflect>(value: T) -> Self {
+        Self {
+            value: Box::new(value),
+        }
+    }
+
+    fn value<T: Reflect>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    fn reflect_value(&self) -> &dyn Reflect {
+        &*self.value
+    }
+}
+
+impl Debug for CustomAttribute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.value.debug(f)
+    }
+}
+
+/// Implements methods for accessing custom attributes.
+///
+/// Implements the following methods:
+///
+/// * `fn custom_attributes(&self) -> &CustomAttributes`
+/// * `fn get_attribute<T: Reflect>(&self) -> Option<&T>`
+/// * `fn get_attribute_by_id(&self, id: TypeId) -> Option<&dyn Reflect>`
+/// * `fn has_attribute<T: Reflect>(&self) -> bool`
+/// * `fn has_attribute_by_id(&self, id: TypeId) -> bool`
+///
+/// # Params
+///
+/// * `$self` - The name of the variable containing the custom attributes (usually `self`).
+/// * `$attributes` - The name of the field containing the [`CustomAttributes`].
+/// * `$term` - (Optional) The term used to describe the item the attributes belong to.
+macro_rules! impl_custom_attribute_methods {
+    ($self:ident . $attributes:ident, $term:literal) => {
+        $crate::attributes::impl_custom_attribute_methods!($self, &$self.$attributes, $term);
+    };
+    ($self:ident, $attributes:expr, $term:literal) => {
+        #[doc = concat!("Returns the custom attributes for this ", $term, ".")]
+        pub fn custom_attributes(&$self) -> &$crate::attributes::CustomAttributes {
+            $attributes
+        }
+
+        /// Gets a custom attribute by type.
+        ///
+        /// For dynamically accessing an attribute, see [`get_attribute_by_id`](Self::get_attribute_by_id).
+        pub fn get_attribute<T: $crate::Reflect>(&$self) -> Option<&T> {
+            $self.custom_attributes().get::<T>()
+        }
+
+        /// Gets a custom attribute by its [`TypeId`](std::any::TypeId).
+        ///
+        /// This is the dynamic equivalent of [`get_attribute`](Self::get_attribute).
+        pub fn get_attribute_by_id(&$self, id: ::std::any::TypeId) -> Option<&dyn $crate::Reflect> {
+            $self.custom_attributes().get_by_id(id)
+        }
+
+        #[doc = concat!("Returns `true` if this ", $term, " has a custom attribute of the specified type.")]
+        #[doc = "\n\nFor dynamically checking if an attribute exists, see [`has_attribute_by_id`](Self::has_attribute_by_id)."]
+        pub fn has_attribute<T: $crate::Reflect>(&$self) -> bool {
+            $self.custom_attributes().contains::<T>()
+        }
+
+        #[doc = concat!("Returns `true` if this ", $term, " has a custom attribute with the specified [`TypeId`](std::any::TypeId).")]
+        #[doc = "\n\nThis is the dynamic equivalent of [`has_attribute`](Self::has_attribute)"]
+        pub fn has_attribute_by_id(&$self, id: ::std::any::TypeId) -> bool {
+            $self.custom_attributes().contains_by_id(id)
+        }
+    };
+}
+
+pub(crate) use impl_custom_attribute_methods;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{std_traits::ReflectDefault, type_info::Typed, TypeInfo, VariantInfo};
+    use std::ops::RangeInclusive;
+
+    #[derive(Reflect, PartialEq, Debug)]
+    struct Tooltip(String);
+
+    impl Tooltip {
+        fn new(value: impl Into<String>) -> Self {
+            Self(value.into())
+        }
+    }
+
+    #[test]
+    fn should_get_custom_attribute() {
+        let attributes = CustomAttributes::default().with_attribute(0.0..=1.0);
+
+        let value = attributes.get::<RangeInclusive<f64>>().unwrap();
+        assert_eq!(&(0.0..=1.0), value);
+        assert!(attributes.contains::<RangeInclusive<f64>>());
+        assert!(!attributes.contains::<RangeInclusive<f32>>());
+    }
+
+    #[test]
+    fn should_get_custom_attribute_dynamically() {
+        let attributes = CustomAttributes::default().with_attribute(String::from("Hello, World!"));
+
+        let value = attributes.get_by_id(TypeId::of::<String>()).unwrap();
+        assert!(value
+            .reflect_partial_eq(&String::from("Hello, World!"))
+            .unwrap());
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_struct_container() {
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("My awesome custom attribute!"))]
+        struct Slider {
+            value: f32,
+        }
+
+        let TypeInfo::Struct(info) = Slider::type_info() else {
+            panic!("expected struct info");
+        };
+
+        let tooltip = info.get_attribute::<Tooltip>().unwrap();
+        assert_eq!(&Tooltip::new("My awesome custom attribute!"), tooltip);
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_struct_fields() {
+        #[derive(Reflect)]
+        struct Slider {
+            #[reflect(@Tooltip::new("Range: 0.0 to 1.0"))]
+            #[reflect(@0.0..=1.0_f32)]
+            value: f32,
+            #[reflect(ignore)]
+            _hidden: bool,
+        }
+
+        let TypeInfo::Struct(info) = Slider::type_info() else {
+            panic!("expected struct info");
+        };
+
+        let field = info.field("value").unwrap();
+
+        let range = field.get_attribute::<RangeInclusive<f32>>().unwrap();
+        assert_eq!(&(0.0..=1.0), range);
+
+        let tooltip = field.get_attribute::<Tooltip>().unwrap();
+        assert_eq!(&Tooltip::new("Range: 0.0 to 1.0"), tooltip);
+        assert_eq!(2, field.custom_attributes().len());
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_tuple_container() {
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("My awesome custom attribute!"))]
+        struct Slider(f32);
+
+        let TypeInfo::TupleStruct(info) = Slider::type_info() else {
+            panic!("expected tuple struct info");
+        };
+
+        let tooltip = info.get_attribute::<Tooltip>().unwrap();
+        assert_eq!(&Tooltip::new("My awesome custom attribute!"), tooltip);
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_tuple_struct_fields() {
+        #[derive(Reflect)]
+        struct Slider(#[reflect(@0.0..=1.0_f32)] f32);
+
+        let TypeInfo::TupleStruct(info) = Slider::type_info() else {
+            panic!("expected tuple struct info");
+        };
+
+        let field = info.field_at(0).unwrap();
+        let range = field.get_attribute::<RangeInclusive<f32>>().unwrap();
+        assert_eq!(&(0.0..=1.0), range);
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_enum_container() {
+        #[derive(Reflect)]
+        #[reflect(@Tooltip::new("My awesome custom attribute!"))]
+        enum Color {
+            Transparent,
+            Grayscale(f32),
+            Rgb { r: u8, g: u8, b: u8 },
+        }
+
+        let TypeInfo::Enum(info) = Color::type_info() else {
+            panic!("expected enum info");
+        };
+
+        let tooltip = info.get_attribute::<Tooltip>().unwrap();
+        assert_eq!(&Tooltip::new("My awesome custom attribute!"), tooltip);
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_on_enum_variants_and_fields() {
+        #[derive(Reflect)]
+        enum Display {
+            #[reflect(@Tooltip::new("Nothing to see here"))]
+            Toggle,
+            Slider(#[reflect(@0.0..=1.0_f32)] f32),
+            #[reflect(@Tooltip::new("Pick a color"))]
+            Picker {
+                #[reflect(@0_u8..=255_u8)]
+                r: u8,
+            },
+        }
+
+        let TypeInfo::Enum(info) = Display::type_info() else {
+            panic!("expected enum info");
+        };
+
+        let VariantInfo::Unit(toggle) = info.variant("Toggle").unwrap() else {
+            panic!("expected unit variant");
+        };
+        let tooltip = toggle.get_attribute::<Tooltip>().unwrap();
+        assert_eq!(&Tooltip::new("Nothing to see here"), tooltip);
+
+        let VariantInfo::Tuple(slider) = info.variant("Slider").unwrap() else {
+            panic!("expected tuple variant");
+        };
+        assert!(slider.custom_attributes().is_empty());
+        let range = slider
+            .field_at(0)
+            .unwrap()
+            .get_attribute::<RangeInclusive<f32>>()
+            .unwrap();
+        assert_eq!(&(0.0..=1.0), range);
+
+        let picker = info.variant("Picker").unwrap();
+        assert!(picker.has_attribute::<Tooltip>());
+        let VariantInfo::Struct(picker) = picker else {
+            panic!("expected struct variant");
+        };
+        let range = picker
+            .field("r")
+            .unwrap()
+            .get_attribute::<RangeInclusive<u8>>()
+            .unwrap();
+        assert_eq!(&(0..=255), range);
+    }
+
+    #[test]
+    fn should_derive_custom_attributes_alongside_other_attributes() {
+        #[derive(Reflect, Default)]
+        #[reflect(Default, @Tooltip::new("Container"))]
+        struct Slider {
+            #[reflect(default, @0.0..=1.0_f32)]
+            value: f32,
+        }
+
+        let TypeInfo::Struct(info) = Slider::type_info() else {
+            panic!("expected struct info");
+        };
+
+        assert!(info.has_attribute::<Tooltip>());
+        assert!(info
+            .field("value")
+            .unwrap()
+            .has_attribute::<RangeInclusive<f32>>());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::diff::{
+    DiffApplyError, DiffValue, ElementDiff, FieldDiff, ListDiffOp, MapDiffOp, ReflectDiff,
+    SetDiffOp,
+};
+use crate::{Enum, List, Map, Reflect, ReflectMut, Set, VariantType};
+
+/// Applies a [`ReflectDiff`] to the given value.
+///
+/// The diff is expected to have been created from a value of the same type as `target`
+/// (see [`diff`]).
+/// Only the changed parts of the value are updated.
+///
+/// # Errors
+///
+/// Returns an error if the diff does not match the shape of `target`,
+/// such as when a field, index, or key referenced by the diff does not exist.
+/// Note that changes made before the error occurred are not rolled back.
+///
+/// [`diff`]: crate::diff::diff
+pub fn apply_diff(target: &mut dyn Reflect, diff: &ReflectDiff) -> Result<(), DiffApplyError> {
+    // Note: the diff collections are iterated with `for` loops, since `Vec::iter`
+    // would otherwise resolve to the reflected `List::iter`.
+    match diff {
+        ReflectDiff::Unchanged => {}
+        ReflectDiff::Replaced { new, .. } => apply_replaced(target, new)?,
+        ReflectDiff::Struct(fields) => {
+            let type_name = target.type_name().to_string();
+            let ReflectMut::Struct(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds("struct", type_name));
+            };
+            for field in fields {
+                apply_field(target.field_mut(&field.name), field)?;
+            }
+        }
+        ReflectDiff::TupleStruct(fields) => {
+            let type_name = target.type_name().to_string();
+            let ReflectMut::TupleStruct(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds("tuple struct", type_name));
+            };
+            let len = target.field_len();
+            for element in fields {
+                apply_element(target.field_mut(element.index), element, len)?;
+            }
+        }
+        ReflectDiff::Tuple(fields) => {
+            let type_name = target.type_name().to_string();
+            let ReflectMut::Tuple(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds("tuple", type_name));
+            };
+            let len = target.field_len();
+            for element in fields {
+                apply_element(target.field_mut(element.index), element, len)?;
+            }
+        }
+        ReflectDiff::Array(elements) => {
+            let type_name = target.type_name().to_string();
+            let ReflectMut::Array(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds("array", type_name));
+            };
+            let len = target.len();
+            for element in elements {
+                apply_element(target.get_mut(element.index), element, len)?;
+            }
+        }
+        ReflectDiff::List(ops) => {
+            let type_name = target.type_name().to_string();
+            let ReflectMut::List(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds("list", type_name));
+            };
+            for op in ops {
+                apply_list_op(target, op)?;
+            }
+        }
+        ReflectDiff::Map(ops) => {
+            let type_name = target.type_name().to_string();
+            let ReflectMut::Map(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds("map", type_name));
+            };
+            for op in ops {
+                apply_map_op(target, op)?;
+            }
+        }
+        ReflectDiff::Set(ops) => {
+            let type_name = target.type_name().to_string();
+            let ReflectMut::Set(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds("set", type_name));
+            };
+            for op in ops {
+                apply_set_op(target, op)?;
+            }
+        }
+        ReflectDiff::StructVariant { variant, fields } => {
+            let target = enum_variant_mut(target, variant, VariantType::Struct)?;
+            for field in fields {
+                apply_field(target.field_mut(&field.name), field)?;
+            }
+        }
+        ReflectDiff::TupleVariant { variant, fields } => {
+            let target = enum_variant_mut(target, variant, VariantType::Tuple)?;
+            let len = target.field_len();
+            for element in fields {
+                apply_element(target.field_at_mut(element.index), element, len)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn mismatched_kinds(diff_kind: &'static str, type_name: String) -> DiffApplyError {
+    DiffApplyError::MismatchedKinds {
+        diff_kind,
+        type_name,
+    }
+}
+
+fn apply_replaced(target: &mut dyn Reflect, new: &DiffValue) -> Result<(), DiffApplyError> {
+    if target.type_name() != new.value().type_name() {
+        return Err(DiffApplyError::MismatchedTypes {
+            expected: target.type_name().to_string(),
+            received: new.value().type_name().to_string(),
+        });
+    }
+
+    target.apply(new.value());
+    Ok(())
+}
+
+fn apply_field(value: Option<&mut dyn Reflect>, field: &FieldDiff) -> Result<(), DiffApplyError> {
+    let value = value.ok_or_else(|| DiffApplyError::MissingField(field.name.clone()))?;
+    apply_diff(value, &field.diff)
+}
+
+fn apply_element(
+    value: Option<&mut dyn Reflect>,
+    element: &ElementDiff,
+    len: usize,
+) -> Result<(), DiffApplyError> {
+    let value = value.ok_or(DiffApplyError::IndexOutOfBounds {
+        index: element.index,
+        len,
+    })?;
+    apply_diff(value, &element.diff)
+}
+
+fn enum_variant_mut<'a>(
+    target: &'a mut dyn Reflect,
+    variant: &str,
+    variant_type: VariantType,
+) -> Result<&'a mut dyn Enum, DiffApplyError> {
+    let type_name = target.type_name().to_string();
+    let ReflectMut::Enum(target) = target.reflect_mut() else {
+        return Err(mismatched_kinds("enum", type_name));
+    };
+
+    if target.variant_name() != variant || target.variant_type() != variant_type {
+        return Err(DiffApplyError::MismatchedVariants {
+            expected: variant.to_string(),
+            received: target.variant_name().to_string(),
+        });
+    }
+
+    Ok(target)
+}
+
+fn apply_list_op(list: &mut dyn List, op: &ListDiffOp) -> Result<(), DiffApplyError> {
+    let len = list.len();
+    match op {
+        ListDiffOp::Insert { index, value } => {
+            if *index > len {
+                return Err(DiffApplyError::IndexOutOfBounds { index: *index, len });
+            }
+            list.insert(*index, value.value().clone_value());
+        }
+        ListDiffOp::Remove { index, .. } => {
+            if *index >= len {
+                return Err(DiffApplyError::IndexOutOfBounds { index: *index, len });
+            }
+            list.remove(*index);
+        }
+        ListDiffOp::Modify { index, diff } => {
+            let value = list
+                .get_mut(*index)
+                .ok_or(DiffApplyError::IndexOutOfBounds { index: *index, len })?;
+            apply_diff(value, diff)?;
+        }
+    }
+    Ok(())
+}
+
+fn apply_map_op(map: &mut dyn Map, op: &MapDiffOp) -> Result<(), DiffApplyError> {
+    match op {
+        MapDiffOp::Insert { key, value } => {
+            map.insert_boxed(key.value().clone_value(), value.value().clone_value());
+        }
+        MapDiffOp::Remove { key, .. } => {
+            map.remove(key.value()).ok_or_else(|| missing_key(key))?;
+        }
+        MapDiffOp::Modify { key, diff } => {
+            let value = map_get_mut(map, key.value()).ok_or_else(|| missing_key(key))?;
+            apply_diff(value, diff)?;
+        }
+    }
+    Ok(())
+}
+
+fn missing_key(key: &DiffValue) -> DiffApplyError {
+    DiffApplyError::MissingKey(format!("{key:?}"))
+}
+
+/// Gets the value for the given key, falling back to a linear search.
+///
+/// The fallback allows dynamic keys (such as those created by deserialization)
+/// to be matched against concrete keys using [`Reflect::reflect_partial_eq`].
+fn map_get_mut<'a>(map: &'a mut dyn Map, key: &dyn Reflect) -> Option<&'a mut dyn Reflect> {
+    if map.get(key).is_some() {
+        return map.get_mut(key);
+    }
+
+    let index = map
+        .iter()
+        .position(|(other, _)| other.reflect_partial_eq(key).unwrap_or(false))?;
+    map.get_at_mut(index).map(|(_, value)| value)
+}
+
+fn apply_set_op(set: &mut dyn Set, op: &SetDiffOp) -> Result<(), DiffApplyError> {
+    match op {
+        SetDiffOp::Insert(value) => {
+            set.insert_boxed(value.value().clone_value());
+        }
+        SetDiffOp::Remove(value) => {
+            if !set.remove(value.value()) {
+                return Err(DiffApplyError::MissingValue(format!("{value:?}")));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,275 @@
+use crate::diff::{
+    DiffValue, ElementDiff, FieldDiff, ListDiffOp, MapDiffOp, ReflectDiff, SetDiffOp,
+};
+use crate::{
+    Array, Enum, List, Map, Reflect, ReflectRef, Set, Struct, Tuple, TupleStruct, VariantType,
+};
+
+/// Computes the [`ReflectDiff`] between two reflected values.
+///
+/// The diff describes how to turn `old` into `new`.
+/// Structs, tuples, enums, lists, arrays, maps, and sets are compared field by field,
+/// while [value types] are compared using [`Reflect::reflect_partial_eq`].
+///
+/// If the two values have different types, or have a different shape
+/// (such as enums with different variants), the diff will be [`ReflectDiff::Replaced`].
+///
+/// Lists are compared position by position, without detecting moved elements.
+/// Inserting or removing an element anywhere but at the end of a list
+/// therefore produces a modification for every element after it,
+/// followed by a single insertion or removal at the end.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::Reflect;
+/// # use bevy_reflect::diff::{apply_diff, diff};
+/// #[derive(Reflect, Clone, PartialEq, Debug)]
+/// struct Player {
+///     name: String,
+///     health: f32,
+/// }
+///
+/// let old = Player { name: "Alice".to_string(), health: 100.0 };
+/// let new = Player { name: "Alice".to_string(), health: 75.0 };
+///
+/// let diff = diff(&old, &new);
+///
+/// let mut value = old.clone();
+/// apply_diff(&mut value, &diff).unwrap();
+/// assert_eq!(new, value);
+///
+/// apply_diff(&mut value, &diff.invert()).unwrap();
+/// assert_eq!(old, value);
+/// ```
+///
+/// [value types]: ReflectRef::Value
+pub fn diff(old: &dyn Reflect, new: &dyn Reflect) -> ReflectDiff {
+    if old.type_name() != new.type_name() {
+        return replaced(old, new);
+    }
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old_struct), ReflectRef::Struct(new_struct)) => {
+            diff_struct(old_struct, new_struct).unwrap_or_else(|| replaced(old, new))
+        }
+        (ReflectRef::TupleStruct(old_struct), ReflectRef::TupleStruct(new_struct)) => {
+            diff_tuple_struct(old_struct, new_struct).unwrap_or_else(|| replaced(old, new))
+        }
+        (ReflectRef::Tuple(old_tuple), ReflectRef::Tuple(new_tuple)) => {
+            diff_tuple(old_tuple, new_tuple).unwrap_or_else(|| replaced(old, new))
+        }
+        (ReflectRef::Array(old_array), ReflectRef::Array(new_array)) => {
+            diff_array(old_array, new_array).unwrap_or_else(|| replaced(old, new))
+        }
+        (ReflectRef::List(old_list), ReflectRef::List(new_list)) => diff_list(old_list, new_list),
+        (ReflectRef::Map(old_map), ReflectRef::Map(new_map)) => diff_map(old_map, new_map),
+        (ReflectRef::Set(old_set), ReflectRef::Set(new_set)) => diff_set(old_set, new_set),
+        (ReflectRef::Enum(old_enum), ReflectRef::Enum(new_enum)) => {
+            diff_enum(old_enum, new_enum).unwrap_or_else(|| replaced(old, new))
+        }
+        (ReflectRef::Value(_), ReflectRef::Value(_)) => {
+            if old.reflect_partial_eq(new).unwrap_or(false) {
+                ReflectDiff::Unchanged
+            } else {
+                replaced(old, new)
+            }
+        }
+        _ => replaced(old, new),
+    }
+}
+
+fn replaced(old: &dyn Reflect, new: &dyn Reflect) -> ReflectDiff {
+    ReflectDiff::Replaced {
+        old: DiffValue::new(old),
+        new: DiffValue::new(new),
+    }
+}
+
+/// Wraps the given changes in a diff, or returns [`ReflectDiff::Unchanged`] if there are none.
+fn changes<T>(changes: Vec<T>, wrap: impl FnOnce(Vec<T>) -> ReflectDiff) -> ReflectDiff {
+    if changes.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        wrap(changes)
+    }
+}
+
+/// Diffs the named fields yielded by the given iterator against `new_field`.
+///
+/// Returns `None` if a field does not exist in the new value.
+fn diff_named_fields<'a>(
+    old_fields: impl Iterator<Item = (&'a str, &'a dyn Reflect)>,
+    mut new_field: impl FnMut(&str) -> Option<&'a dyn Reflect>,
+) -> Option<Vec<FieldDiff>> {
+    let mut fields = Vec::new();
+    for (name, old_value) in old_fields {
+        let diff = diff(old_value, new_field(name)?);
+        if !diff.is_unchanged() {
+            fields.push(FieldDiff {
+                name: name.to_string(),
+                diff,
+            });
+        }
+    }
+    Some(fields)
+}
+
+/// Diffs the elements yielded by the given iterators pairwise.
+fn diff_elements<'a>(
+    old_elements: impl Iterator<Item = &'a dyn Reflect>,
+    new_elements: impl Iterator<Item = &'a dyn Reflect>,
+) -> Vec<ElementDiff> {
+    old_elements
+        .zip(new_elements)
+        .enumerate()
+        .filter_map(|(index, (old_value, new_value))| {
+            let diff = diff(old_value, new_value);
+            (!diff.is_unchanged()).then_some(ElementDiff { index, diff })
+        })
+        .collect()
+}
+
+fn diff_struct(old: &dyn Struct, new: &dyn Struct) -> Option<ReflectDiff> {
+    if old.field_len() != new.field_len() {
+        return None;
+    }
+
+    let old_fields =
+        (0..old.field_len()).filter_map(|index| Some((old.name_at(index)?, old.field_at(index)?)));
+    let fields = diff_named_fields(old_fields, |name| new.field(name))?;
+    Some(changes(fields, ReflectDiff::Struct))
+}
+
+fn diff_tuple_struct(old: &dyn TupleStruct, new: &dyn TupleStruct) -> Option<ReflectDiff> {
+    if old.field_len() != new.field_len() {
+        return None;
+    }
+
+    let fields = diff_elements(old.iter_fields(), new.iter_fields());
+    Some(changes(fields, ReflectDiff::TupleStruct))
+}
+
+fn diff_tuple(old: &dyn Tuple, new: &dyn Tuple) -> Option<ReflectDiff> {
+    if old.field_len() != new.field_len() {
+        return None;
+    }
+
+    let fields = diff_elements(old.iter_fields(), new.iter_fields());
+    Some(changes(fields, ReflectDiff::Tuple))
+}
+
+fn diff_array(old: &dyn Array, new: &dyn Array) -> Option<ReflectDiff> {
+    if old.len() != new.len() {
+        return None;
+    }
+
+    let elements = diff_elements(old.iter(), new.iter());
+    Some(changes(elements, ReflectDiff::Array))
+}
+
+/// Diffs two lists by comparing their elements positionally.
+///
+/// Elements present in both lists are modified in place,
+/// trailing elements are then either inserted or removed (from back to front).
+/// This keeps the diff cheap to compute, at the cost of larger diffs
+/// when elements are inserted or removed in the middle of a list.
+fn diff_list(old: &dyn List, new: &dyn List) -> ReflectDiff {
+    let mut ops = diff_elements(old.iter(), new.iter())
+        .into_iter()
+        .map(|ElementDiff { index, diff }| ListDiffOp::Modify { index, diff })
+        .collect::<Vec<_>>();
+
+    for index in (new.len()..old.len()).rev() {
+        ops.push(ListDiffOp::Remove {
+            index,
+            value: DiffValue::new(old.get(index).unwrap()),
+        });
+    }
+
+    for index in old.len()..new.len() {
+        ops.push(ListDiffOp::Insert {
+            index,
+            value: DiffValue::new(new.get(index).unwrap()),
+        });
+    }
+
+    changes(ops, ReflectDiff::List)
+}
+
+fn diff_map(old: &dyn Map, new: &dyn Map) -> ReflectDiff {
+    let mut ops = Vec::new();
+
+    for (key, old_value) in old.iter() {
+        match new.get(key) {
+            Some(new_value) => {
+                let diff = diff(old_value, new_value);
+                if !diff.is_unchanged() {
+                    ops.push(MapDiffOp::Modify {
+                        key: DiffValue::new(key),
+                        diff,
+                    });
+                }
+            }
+            None => ops.push(MapDiffOp::Remove {
+                key: DiffValue::new(key),
+                value: DiffValue::new(old_value),
+            }),
+        }
+    }
+
+    for (key, new_value) in new.iter() {
+        if old.get(key).is_none() {
+            ops.push(MapDiffOp::Insert {
+                key: DiffValue::new(key),
+                value: DiffValue::new(new_value),
+            });
+        }
+    }
+
+    changes(ops, ReflectDiff::Map)
+}
+
+fn diff_set(old: &dyn Set, new: &dyn Set) -> ReflectDiff {
+    let removed = old
+        .iter()
+        .filter(|value| !new.contains(*value))
+        .map(|value| SetDiffOp::Remove(DiffValue::new(value)));
+    let inserted = new
+        .iter()
+        .filter(|value| !old.contains(*value))
+        .map(|value| SetDiffOp::Insert(DiffValue::new(value)));
+
+    changes(removed.chain(inserted).collect(), ReflectDiff::Set)
+}
+
+fn diff_enum(old: &dyn Enum, new: &dyn Enum) -> Option<ReflectDiff> {
+    if old.variant_name() != new.variant_name() || old.field_len() != new.field_len() {
+        return None;
+    }
+
+    let variant = old.variant_name().to_string();
+    match old.variant_type() {
+        VariantType::Unit => Some(ReflectDiff::Unchanged),
+        VariantType::Struct => {
+            let old_fields = old
+                .iter_fields()
+                .filter_map(|field| Some((field.name()?, field.value())));
+            let fields = diff_named_fields(old_fields, |name| new.field(name))?;
+            Some(changes(fields, |fields| ReflectDiff::StructVariant {
+                variant,
+                fields,
+            }))
+        }
+        VariantType::Tuple => {
+            let fields = diff_elements(
+                old.iter_fields().map(|field| field.value()),
+                new.iter_fields().map(|field| field.value()),
+            );
+            Some(changes(fields, |fields| ReflectDiff::TupleVariant {
+                variant,
+                fields,
+            }))
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+/// An error that occurs when applying a [`ReflectDiff`] to a value.
+///
+/// [`ReflectDiff`]: crate::diff::ReflectDiff
+#[derive(Debug, Error, PartialEq)]
+pub enum DiffApplyError {
+    /// The diff describes a different kind of value than the target.
+    #[error("cannot apply a {diff_kind} diff to a value of type `{type_name}`")]
+    MismatchedKinds {
+        diff_kind: &'static str,
+        type_name: String,
+    },
+    /// A replacement value has a different type than the target.
+    #[error("cannot replace a value of type `{expected}` with a value of type `{received}`")]
+    MismatchedTypes { expected: String, received: String },
+    /// The target enum does not have the variant expected by the diff.
+    #[error("expected variant `{expected}` but found variant `{received}`")]
+    MismatchedVariants { expected: String, received: String },
+    /// The target does not have a field expected by the diff.
+    #[error("the field `{0}` does not exist")]
+    MissingField(String),
+    /// The diff refers to an index outside of the target's bounds.
+    #[error("the index {index} is out of bounds for a value of length {len}")]
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The target map does not contain a key expected by the diff.
+    #[error("the key `{0}` does not exist")]
+    MissingKey(String),
+    /// The target set does not contain a value expected by the diff.
+    #[error("the value `{0}` does not exist")]
+    MissingValue(String),
+}
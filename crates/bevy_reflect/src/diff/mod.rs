@@ -0,0 +1,292 @@
+//! Computing and applying the difference between two reflected values.
+//!
+//! A [`ReflectDiff`] describes how to turn one value into another value of the same type.
+//! It is created with [`diff`] and applied with [`apply_diff`]:
+//!
+//! ```
+//! # use bevy_reflect::Reflect;
+//! # use bevy_reflect::diff::{apply_diff, diff};
+//! let old = vec![1, 2, 3];
+//! let new = vec![1, 5, 3, 4];
+//!
+//! let diff = diff(&old, &new);
+//!
+//! let mut value = old.clone();
+//! apply_diff(&mut value, &diff).unwrap();
+//! assert_eq!(new, value);
+//! ```
+//!
+//! Since every change also records the original data, a diff can be [inverted],
+//! making it suitable for implementing undo and redo.
+//!
+//! # Serialization
+//!
+//! [`ReflectDiff`] implements [`Reflect`] and can therefore be serialized using the
+//! [reflection serializers].
+//! Values stored within the diff, such as inserted list elements, are serialized
+//! along with their type name, so their types must be registered in the [`TypeRegistry`].
+//! The types that make up the diff itself can be registered with [`ReflectDiff::register_types`].
+//!
+//! [inverted]: ReflectDiff::invert
+//! [`Reflect`]: crate::Reflect
+//! [reflection serializers]: crate::serde
+//! [`TypeRegistry`]: crate::TypeRegistry
+
+mod apply;
+mod compute;
+mod error;
+mod reflect_diff;
+mod value;
+
+pub use apply::*;
+pub use compute::*;
+pub use error::*;
+pub use reflect_diff::*;
+pub use value::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+    use crate::{FromReflect, Reflect, TypeRegistry};
+    use bevy_utils::{HashMap, HashSet};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: f32,
+        position: (f32, f32),
+        inventory: Vec<Item>,
+        stats: HashMap<String, u32>,
+        state: State,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Item(String, u32);
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum State {
+        Idle,
+        Walking { speed: f32 },
+        Attacking(u32),
+    }
+
+    fn player() -> Player {
+        Player {
+            name: "Alice".to_string(),
+            health: 100.0,
+            position: (0.0, 0.0),
+            inventory: vec![Item("Sword".to_string(), 1), Item("Potion".to_string(), 3)],
+            stats: [("strength".to_string(), 10), ("agility".to_string(), 5)]
+                .into_iter()
+                .collect(),
+            state: State::Walking { speed: 1.0 },
+        }
+    }
+
+    fn assert_roundtrip<T: Reflect + Clone + PartialEq + std::fmt::Debug>(old: &T, new: &T) {
+        let diff = diff(old, new);
+
+        let mut value = old.clone();
+        apply_diff(&mut value, &diff).unwrap();
+        assert_eq!(new, &value);
+
+        apply_diff(&mut value, &diff.invert()).unwrap();
+        assert_eq!(old, &value);
+    }
+
+    #[test]
+    fn should_be_unchanged_for_equal_values() {
+        assert!(diff(&player(), &player()).is_unchanged());
+    }
+
+    #[test]
+    fn should_diff_struct_fields() {
+        let old = player();
+        let mut new = player();
+        new.health = 50.0;
+        new.position.1 = 10.0;
+
+        let ReflectDiff::Struct(fields) = diff(&old, &new) else {
+            panic!("expected struct diff");
+        };
+        let names = fields.iter().map(|field| &*field.name).collect::<Vec<_>>();
+        assert_eq!(vec!["health", "position"], names);
+
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_enum_variants() {
+        let old = player();
+
+        let mut new = player();
+        new.state = State::Walking { speed: 2.0 };
+        let ReflectDiff::Struct(fields) = diff(&old, &new) else {
+            panic!("expected struct diff");
+        };
+        assert!(matches!(
+            fields[0].diff,
+            ReflectDiff::StructVariant { ref variant, .. } if variant == "Walking"
+        ));
+        assert_roundtrip(&old, &new);
+
+        new.state = State::Attacking(5);
+        let ReflectDiff::Struct(fields) = diff(&old, &new) else {
+            panic!("expected struct diff");
+        };
+        assert!(matches!(fields[0].diff, ReflectDiff::Replaced { .. }));
+        assert_roundtrip(&old, &new);
+
+        new.state = State::Idle;
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_lists() {
+        let old = vec![1, 2, 3, 4];
+
+        assert_roundtrip(&old, &vec![1, 5, 3, 4]);
+        assert_roundtrip(&old, &vec![1, 2]);
+        assert_roundtrip(&old, &vec![1, 2, 3, 4, 5, 6]);
+        assert_roundtrip(&old, &vec![]);
+        assert_roundtrip(&vec![], &old);
+        assert_roundtrip(&old, &vec![0, 1, 2, 3, 4]);
+
+        // elements are compared by position, so inserting at the front modifies every element
+        let ReflectDiff::List(ops) = diff(&vec![1, 2], &vec![0, 1, 2]) else {
+            panic!("expected list diff");
+        };
+        assert!(matches!(
+            ops[..],
+            [
+                ListDiffOp::Modify { index: 0, .. },
+                ListDiffOp::Modify { index: 1, .. },
+                ListDiffOp::Insert { index: 2, .. },
+            ]
+        ));
+
+        let old = player();
+        let mut new = player();
+        new.inventory[1].1 = 2;
+        new.inventory.push(Item("Shield".to_string(), 1));
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_arrays_and_tuples() {
+        assert_roundtrip(&[1, 2, 3], &[1, 0, 3]);
+        assert_roundtrip(&(1, "a".to_string()), &(2, "b".to_string()));
+        assert_roundtrip(&Item("Sword".to_string(), 1), &Item("Sword".to_string(), 2));
+    }
+
+    #[test]
+    fn should_diff_maps() {
+        let old = player();
+        let mut new = player();
+        new.stats.insert("strength".to_string(), 12);
+        new.stats.remove("agility");
+        new.stats.insert("luck".to_string(), 7);
+
+        let ReflectDiff::Struct(fields) = diff(&old, &new) else {
+            panic!("expected struct diff");
+        };
+        let ReflectDiff::Map(ops) = &fields[0].diff else {
+            panic!("expected map diff");
+        };
+        assert_eq!(3, ops.len());
+
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_diff_sets() {
+        let old = [1, 2, 3].into_iter().collect::<HashSet<i32>>();
+        let new = [2, 3, 4].into_iter().collect::<HashSet<i32>>();
+
+        let ReflectDiff::Set(ops) = diff(&old, &new) else {
+            panic!("expected set diff");
+        };
+        assert_eq!(2, ops.len());
+
+        assert_roundtrip(&old, &new);
+    }
+
+    #[test]
+    fn should_replace_mismatched_types() {
+        let diff = diff(&123_i32, &123_u32);
+        assert!(matches!(diff, ReflectDiff::Replaced { .. }));
+
+        let mut value = 0_i32;
+        assert_eq!(
+            Err(DiffApplyError::MismatchedTypes {
+                expected: "i32".to_string(),
+                received: "u32".to_string(),
+            }),
+            apply_diff(&mut value, &diff)
+        );
+    }
+
+    #[test]
+    fn should_error_on_mismatched_target() {
+        let old = player();
+        let mut new = player();
+        new.health = 0.0;
+        let diff = diff(&old, &new);
+
+        let mut value = vec![1, 2, 3];
+        assert!(matches!(
+            apply_diff(&mut value, &diff),
+            Err(DiffApplyError::MismatchedKinds {
+                diff_kind: "struct",
+                ..
+            })
+        ));
+
+        let diff = crate::diff::diff(&vec![1, 2, 3], &vec![1, 2]);
+        let mut value = Vec::<i32>::new();
+        assert_eq!(
+            Err(DiffApplyError::IndexOutOfBounds { index: 2, len: 0 }),
+            apply_diff(&mut value, &diff)
+        );
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_diff() {
+        let mut registry = TypeRegistry::default();
+        ReflectDiff::register_types(&mut registry);
+        registry.register::<Player>();
+        registry.register::<Item>();
+        registry.register::<State>();
+        registry.register::<(f32, f32)>();
+        registry.register::<Vec<Item>>();
+        registry.register::<HashMap<String, u32>>();
+
+        let old = player();
+        let mut new = player();
+        new.health = 25.0;
+        new.inventory.push(Item("Shield".to_string(), 1));
+        new.stats.remove("agility");
+        new.state = State::Attacking(3);
+
+        let diff = diff(&old, &new);
+
+        let serializer = TypedReflectSerializer::new(&diff, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+
+        let registration = registry.get(std::any::TypeId::of::<ReflectDiff>()).unwrap();
+        let deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let dynamic_output = deserializer.deserialize(&mut ron_deserializer).unwrap();
+        let deserialized = ReflectDiff::from_reflect(dynamic_output.as_ref()).unwrap();
+
+        let mut value = old.clone();
+        apply_diff(&mut value, &deserialized).unwrap();
+        assert_eq!(new, value);
+
+        apply_diff(&mut value, &deserialized.invert()).unwrap();
+        assert_eq!(old, value);
+    }
+}
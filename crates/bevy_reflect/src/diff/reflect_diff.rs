@@ -0,0 +1,243 @@
+use crate as bevy_reflect;
+use crate::diff::DiffValue;
+use crate::{Reflect, TypeRegistry};
+
+/// The difference between two reflected values of the same type.
+///
+/// A diff is created with [`diff`] and can be applied to a value with [`apply_diff`].
+/// Only the parts of a value that actually changed are stored,
+/// so applying a diff to a value other than the one it was created from
+/// will only update the changed fields.
+///
+/// Diffs are reversible: every change also stores the original data,
+/// allowing a diff to be [inverted] in order to undo it.
+///
+/// Since this type implements [`Reflect`], it can be serialized with the reflection serializers,
+/// such as [`TypedReflectSerializer`].
+/// Make sure to call [`ReflectDiff::register_types`] beforehand.
+///
+/// [`diff`]: crate::diff::diff
+/// [`apply_diff`]: crate::diff::apply_diff
+/// [inverted]: ReflectDiff::invert
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+#[derive(Reflect, Clone, Debug)]
+#[reflect(Debug, no_field_bounds)]
+pub enum ReflectDiff {
+    /// The two values are equal.
+    Unchanged,
+    /// The value was replaced entirely.
+    ///
+    /// This is used for [value types], for enums that changed variant,
+    /// and for values whose types or shapes do not match.
+    ///
+    /// [value types]: crate::ReflectRef::Value
+    Replaced { old: DiffValue, new: DiffValue },
+    /// The changed fields of a struct.
+    Struct(Vec<FieldDiff>),
+    /// The changed fields of a tuple struct.
+    TupleStruct(Vec<ElementDiff>),
+    /// The changed fields of a tuple.
+    Tuple(Vec<ElementDiff>),
+    /// The changed elements of an array.
+    Array(Vec<ElementDiff>),
+    /// The operations that transform one list into another.
+    ///
+    /// These operations must be applied in order.
+    /// Elements are compared by position, see [`diff`](crate::diff::diff).
+    List(Vec<ListDiffOp>),
+    /// The entries that were inserted, removed, or modified in a map.
+    Map(Vec<MapDiffOp>),
+    /// The values that were inserted or removed from a set.
+    Set(Vec<SetDiffOp>),
+    /// The changed fields of an enum's struct variant.
+    ///
+    /// Both values must have the same variant.
+    StructVariant {
+        variant: String,
+        fields: Vec<FieldDiff>,
+    },
+    /// The changed fields of an enum's tuple variant.
+    ///
+    /// Both values must have the same variant.
+    TupleVariant {
+        variant: String,
+        fields: Vec<ElementDiff>,
+    },
+}
+
+/// The diff of a named field.
+#[derive(Reflect, Clone, Debug)]
+#[reflect(Debug, no_field_bounds)]
+pub struct FieldDiff {
+    pub name: String,
+    pub diff: ReflectDiff,
+}
+
+/// The diff of an indexed field or element.
+#[derive(Reflect, Clone, Debug)]
+#[reflect(Debug, no_field_bounds)]
+pub struct ElementDiff {
+    pub index: usize,
+    pub diff: ReflectDiff,
+}
+
+/// A single operation on a list.
+#[derive(Reflect, Clone, Debug)]
+#[reflect(Debug, no_field_bounds)]
+pub enum ListDiffOp {
+    /// Insert a value at the given index.
+    Insert { index: usize, value: DiffValue },
+    /// Remove the value at the given index.
+    ///
+    /// The removed value is stored so that this operation can be inverted.
+    Remove { index: usize, value: DiffValue },
+    /// Modify the value at the given index.
+    Modify { index: usize, diff: ReflectDiff },
+}
+
+/// A single operation on a map.
+#[derive(Reflect, Clone, Debug)]
+#[reflect(Debug, no_field_bounds)]
+pub enum MapDiffOp {
+    /// Insert a new entry.
+    Insert { key: DiffValue, value: DiffValue },
+    /// Remove the entry with the given key.
+    ///
+    /// The removed value is stored so that this operation can be inverted.
+    Remove { key: DiffValue, value: DiffValue },
+    /// Modify the value of the entry with the given key.
+    Modify { key: DiffValue, diff: ReflectDiff },
+}
+
+/// A single operation on a set.
+#[derive(Reflect, Clone, Debug)]
+#[reflect(Debug)]
+pub enum SetDiffOp {
+    /// Insert a value.
+    Insert(DiffValue),
+    /// Remove a value.
+    Remove(DiffValue),
+}
+
+impl ReflectDiff {
+    /// Returns `true` if this diff contains no changes.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    /// Returns a diff that undoes the changes made by this one.
+    ///
+    /// Applying a diff followed by its inverse leaves the value unchanged.
+    pub fn invert(&self) -> Self {
+        match self {
+            Self::Unchanged => Self::Unchanged,
+            Self::Replaced { old, new } => Self::Replaced {
+                old: new.clone(),
+                new: old.clone(),
+            },
+            Self::Struct(fields) => Self::Struct(invert_fields(fields)),
+            Self::TupleStruct(fields) => Self::TupleStruct(invert_elements(fields)),
+            Self::Tuple(fields) => Self::Tuple(invert_elements(fields)),
+            Self::Array(elements) => Self::Array(invert_elements(elements)),
+            Self::List(ops) => Self::List(ops.iter().rev().map(ListDiffOp::invert).collect()),
+            Self::Map(ops) => Self::Map(ops.iter().rev().map(MapDiffOp::invert).collect()),
+            Self::Set(ops) => Self::Set(ops.iter().rev().map(SetDiffOp::invert).collect()),
+            Self::StructVariant { variant, fields } => Self::StructVariant {
+                variant: variant.clone(),
+                fields: invert_fields(fields),
+            },
+            Self::TupleVariant { variant, fields } => Self::TupleVariant {
+                variant: variant.clone(),
+                fields: invert_elements(fields),
+            },
+        }
+    }
+
+    /// Registers all types needed to (de)serialize a [`ReflectDiff`].
+    ///
+    /// Note that the types of the values stored within the diff must be registered separately.
+    pub fn register_types(registry: &mut TypeRegistry) {
+        registry.register::<ReflectDiff>();
+        registry.register::<FieldDiff>();
+        registry.register::<ElementDiff>();
+        registry.register::<ListDiffOp>();
+        registry.register::<MapDiffOp>();
+        registry.register::<SetDiffOp>();
+        registry.register::<DiffValue>();
+        registry.register::<Vec<FieldDiff>>();
+        registry.register::<Vec<ElementDiff>>();
+        registry.register::<Vec<ListDiffOp>>();
+        registry.register::<Vec<MapDiffOp>>();
+        registry.register::<Vec<SetDiffOp>>();
+    }
+}
+
+impl ListDiffOp {
+    /// Returns the operation that undoes this one.
+    pub fn invert(&self) -> Self {
+        match self {
+            Self::Insert { index, value } => Self::Remove {
+                index: *index,
+                value: value.clone(),
+            },
+            Self::Remove { index, value } => Self::Insert {
+                index: *index,
+                value: value.clone(),
+            },
+            Self::Modify { index, diff } => Self::Modify {
+                index: *index,
+                diff: diff.invert(),
+            },
+        }
+    }
+}
+
+impl MapDiffOp {
+    /// Returns the operation that undoes this one.
+    pub fn invert(&self) -> Self {
+        match self {
+            Self::Insert { key, value } => Self::Remove {
+                key: key.clone(),
+                value: value.clone(),
+            },
+            Self::Remove { key, value } => Self::Insert {
+                key: key.clone(),
+                value: value.clone(),
+            },
+            Self::Modify { key, diff } => Self::Modify {
+                key: key.clone(),
+                diff: diff.invert(),
+            },
+        }
+    }
+}
+
+impl SetDiffOp {
+    /// Returns the operation that undoes this one.
+    pub fn invert(&self) -> Self {
+        match self {
+            Self::Insert(value) => Self::Remove(value.clone()),
+            Self::Remove(value) => Self::Insert(value.clone()),
+        }
+    }
+}
+
+fn invert_fields(fields: &[FieldDiff]) -> Vec<FieldDiff> {
+    fields
+        .iter()
+        .map(|field| FieldDiff {
+            name: field.name.clone(),
+            diff: field.diff.invert(),
+        })
+        .collect()
+}
+
+fn invert_elements(elements: &[ElementDiff]) -> Vec<ElementDiff> {
+    elements
+        .iter()
+        .map(|element| ElementDiff {
+            index: element.index,
+            diff: element.diff.invert(),
+        })
+        .collect()
+}
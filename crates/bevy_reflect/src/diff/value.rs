@@ -0,0 +1,78 @@
+use crate as bevy_reflect;
+use crate::serde::{
+    DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry,
+    ReflectSerializer, SerializeWithRegistry, UntypedReflectDeserializer,
+};
+use crate::{Reflect, TypeRegistry};
+use serde::de::DeserializeSeed;
+use serde::{Deserializer, Serialize, Serializer};
+use std::fmt::{Debug, Formatter};
+
+/// A reflected value stored within a [`ReflectDiff`].
+///
+/// This is used for values that cannot be broken down into smaller diffs,
+/// such as replaced values or elements inserted into a list.
+///
+/// Since the concrete type of the value is not known statically,
+/// it is (de)serialized along with its [type name] using the [`TypeRegistry`].
+///
+/// [`ReflectDiff`]: crate::diff::ReflectDiff
+/// [type name]: std::any::type_name
+#[derive(Reflect)]
+#[reflect_value(Debug, SerializeWithRegistry, DeserializeWithRegistry)]
+pub struct DiffValue(Box<dyn Reflect>);
+
+impl DiffValue {
+    /// Creates a new [`DiffValue`] from a cloned copy of the given value.
+    pub fn new(value: &dyn Reflect) -> Self {
+        Self(value.clone_value())
+    }
+
+    /// Returns a reference to the underlying value.
+    pub fn value(&self) -> &dyn Reflect {
+        &*self.0
+    }
+
+    /// Returns the underlying value.
+    pub fn into_inner(self) -> Box<dyn Reflect> {
+        self.0
+    }
+}
+
+impl From<Box<dyn Reflect>> for DiffValue {
+    fn from(value: Box<dyn Reflect>) -> Self {
+        Self(value)
+    }
+}
+
+impl Clone for DiffValue {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_value())
+    }
+}
+
+impl Debug for DiffValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.debug(f)
+    }
+}
+
+impl SerializeWithRegistry for DiffValue {
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ReflectSerializer::new(self.value(), registry).serialize(serializer)
+    }
+}
+
+impl DeserializeWithRegistry for DiffValue {
+    fn deserialize<'de, D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        UntypedReflectDeserializer::new(registry)
+            .deserialize(deserializer)
+            .map(Self)
+    }
+}
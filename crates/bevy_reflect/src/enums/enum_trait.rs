@@ -1,7 +1,9 @@
-use crate::{DynamicEnum, Reflect, VariantInfo, VariantType};
+use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
+use crate::{DynamicEnum, Generics, Reflect, VariantInfo, VariantType};
 use bevy_utils::HashMap;
 use std::any::{Any, TypeId};
 use std::slice::Iter;
+use std::sync::Arc;
 
 /// A trait used to power [enum-like] operations via [reflection].
 ///
@@ -139,6 +141,8 @@ pub struct EnumInfo {
     variants: Box<[VariantInfo]>,
     variant_names: Box<[&'static str]>,
     variant_indices: HashMap<&'static str, usize>,
+    custom_attributes: Arc<CustomAttributes>,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -167,11 +171,26 @@ impl EnumInfo {
             variants: variants.to_vec().into_boxed_slice(),
             variant_names,
             variant_indices,
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the custom attributes for this enum.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes: Arc::new(custom_attributes),
+            ..self
+        }
+    }
+
+    /// Sets the generic parameters for this enum.
+    pub fn with_generics(self, generics: Generics) -> Self {
+        Self { generics, ..self }
+    }
+
     /// Sets the docstring for this enum.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -248,6 +267,13 @@ impl EnumInfo {
         TypeId::of::<T>() == self.type_id
     }
 
+    impl_custom_attribute_methods!(self.custom_attributes, "enum");
+
+    /// The generic parameters of this enum.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
     /// The docstring of this enum, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
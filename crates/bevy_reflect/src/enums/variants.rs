@@ -1,6 +1,8 @@
+use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
 use crate::{NamedField, UnnamedField};
 use bevy_utils::HashMap;
 use std::slice::Iter;
+use std::sync::Arc;
 
 /// Describes the form of an enum variant.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -73,6 +75,25 @@ impl VariantInfo {
         }
     }
 
+    /// The names this variant was previously known by.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Self::Struct(info) => info.aliases(),
+            Self::Tuple(info) => info.aliases(),
+            Self::Unit(info) => info.aliases(),
+        }
+    }
+
+    impl_custom_attribute_methods!(
+        self,
+        match self {
+            Self::Struct(info) => info.custom_attributes(),
+            Self::Tuple(info) => info.custom_attributes(),
+            Self::Unit(info) => info.custom_attributes(),
+        },
+        "variant"
+    );
+
     /// The docstring of the underlying variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -91,6 +112,8 @@ pub struct StructVariantInfo {
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
+    custom_attributes: Arc<CustomAttributes>,
+    aliases: &'static [&'static str],
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -105,11 +128,28 @@ impl StructVariantInfo {
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            aliases: &[],
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the names this variant was previously known by.
+    ///
+    /// These are accepted in place of the variant name when deserializing.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// Sets the custom attributes for this variant.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes: Arc::new(custom_attributes),
+            ..self
+        }
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -121,6 +161,13 @@ impl StructVariantInfo {
         self.name
     }
 
+    /// The names this variant was previously known by.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    impl_custom_attribute_methods!(self.custom_attributes, "variant");
+
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
@@ -173,6 +220,8 @@ impl StructVariantInfo {
 pub struct TupleVariantInfo {
     name: &'static str,
     fields: Box<[UnnamedField]>,
+    custom_attributes: Arc<CustomAttributes>,
+    aliases: &'static [&'static str],
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -183,11 +232,28 @@ impl TupleVariantInfo {
         Self {
             name,
             fields: fields.to_vec().into_boxed_slice(),
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            aliases: &[],
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the names this variant was previously known by.
+    ///
+    /// These are accepted in place of the variant name when deserializing.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// Sets the custom attributes for this variant.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes: Arc::new(custom_attributes),
+            ..self
+        }
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -199,6 +265,13 @@ impl TupleVariantInfo {
         self.name
     }
 
+    /// The names this variant was previously known by.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    impl_custom_attribute_methods!(self.custom_attributes, "variant");
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
@@ -225,6 +298,8 @@ impl TupleVariantInfo {
 #[derive(Clone, Debug)]
 pub struct UnitVariantInfo {
     name: &'static str,
+    custom_attributes: Arc<CustomAttributes>,
+    aliases: &'static [&'static str],
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -234,11 +309,28 @@ impl UnitVariantInfo {
     pub fn new(name: &'static str) -> Self {
         Self {
             name,
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            aliases: &[],
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the names this variant was previously known by.
+    ///
+    /// These are accepted in place of the variant name when deserializing.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// Sets the custom attributes for this variant.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes: Arc::new(custom_attributes),
+            ..self
+        }
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -250,6 +342,13 @@ impl UnitVariantInfo {
         self.name
     }
 
+    /// The names this variant was previously known by.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    impl_custom_attribute_methods!(self.custom_attributes, "variant");
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
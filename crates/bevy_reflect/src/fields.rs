@@ -1,5 +1,7 @@
+use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
 use crate::Reflect;
 use std::any::{Any, TypeId};
+use std::sync::Arc;
 
 /// The named field of a reflected struct.
 #[derive(Clone, Debug)]
@@ -7,6 +9,8 @@ pub struct NamedField {
     name: &'static str,
     type_name: &'static str,
     type_id: TypeId,
+    custom_attributes: Arc<CustomAttributes>,
+    aliases: &'static [&'static str],
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -18,11 +22,28 @@ impl NamedField {
             name,
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            aliases: &[],
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the names this field was previously known by.
+    ///
+    /// These are accepted in place of the field name when deserializing.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
+    /// Sets the custom attributes for this field.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes: Arc::new(custom_attributes),
+            ..self
+        }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -34,6 +55,11 @@ impl NamedField {
         self.name
     }
 
+    /// The names this field was previously known by.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// The [type name] of the field.
     ///
     /// [type name]: std::any::type_name
@@ -51,6 +77,8 @@ impl NamedField {
         TypeId::of::<T>() == self.type_id
     }
 
+    impl_custom_attribute_methods!(self.custom_attributes, "field");
+
     /// The docstring of this field, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -64,6 +92,7 @@ pub struct UnnamedField {
     index: usize,
     type_name: &'static str,
     type_id: TypeId,
+    custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -74,11 +103,20 @@ impl UnnamedField {
             index,
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the custom attributes for this field.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes: Arc::new(custom_attributes),
+            ..self
+        }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -107,6 +145,8 @@ impl UnnamedField {
         TypeId::of::<T>() == self.type_id
     }
 
+    impl_custom_attribute_methods!(self.custom_attributes, "field");
+
     /// The docstring of this field, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
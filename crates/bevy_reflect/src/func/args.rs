@@ -0,0 +1,283 @@
+use crate::func::ArgError;
+use crate::{FromReflect, Reflect, TypeInfo, Typed};
+use std::borrow::Cow;
+
+/// The way in which an argument is passed to a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ownership {
+    /// The argument is passed by value.
+    Owned,
+    /// The argument is passed by reference (`&T`).
+    Ref,
+    /// The argument is passed by mutable reference (`&mut T`).
+    Mut,
+}
+
+/// A single argument passed to a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug)]
+pub enum Arg<'a> {
+    /// An owned, boxed value.
+    Owned(Box<dyn Reflect>),
+    /// A reference to a value.
+    Ref(&'a dyn Reflect),
+    /// A mutable reference to a value.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> Arg<'a> {
+    /// Returns the [`Ownership`] of this argument.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            Arg::Owned(_) => Ownership::Owned,
+            Arg::Ref(_) => Ownership::Ref,
+            Arg::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// Returns a reference to the underlying value.
+    pub fn value(&self) -> &dyn Reflect {
+        match self {
+            Arg::Owned(value) => value.as_ref(),
+            Arg::Ref(value) => *value,
+            Arg::Mut(value) => *value,
+        }
+    }
+}
+
+/// An ordered list of arguments passed to a [`DynamicFunction`].
+///
+/// ```
+/// # use bevy_reflect::func::ArgList;
+/// let name = String::from("hello");
+/// let mut list = Vec::<i32>::new();
+/// let args = ArgList::new()
+///     .push_owned(123_i32)
+///     .push_ref(&name)
+///     .push_mut(&mut list);
+/// assert_eq!(3, args.len());
+/// ```
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Default)]
+pub struct ArgList<'a>(Vec<Arg<'a>>);
+
+impl<'a> ArgList<'a> {
+    /// Create a new, empty list of arguments.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Append an [`Arg`] to the end of the list.
+    pub fn push_arg(mut self, arg: Arg<'a>) -> Self {
+        self.0.push(arg);
+        self
+    }
+
+    /// Append an owned value to the end of the list.
+    pub fn push_owned<T: Reflect>(self, value: T) -> Self {
+        self.push_arg(Arg::Owned(Box::new(value)))
+    }
+
+    /// Append a boxed value to the end of the list.
+    pub fn push_boxed(self, value: Box<dyn Reflect>) -> Self {
+        self.push_arg(Arg::Owned(value))
+    }
+
+    /// Append a reference to the end of the list.
+    pub fn push_ref(self, value: &'a dyn Reflect) -> Self {
+        self.push_arg(Arg::Ref(value))
+    }
+
+    /// Append a mutable reference to the end of the list.
+    pub fn push_mut(self, value: &'a mut dyn Reflect) -> Self {
+        self.push_arg(Arg::Mut(value))
+    }
+
+    /// Returns the number of arguments in the list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list contains no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the arguments in the list.
+    pub fn iter(&self) -> impl Iterator<Item = &Arg<'a>> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for ArgList<'a> {
+    type Item = Arg<'a>;
+    type IntoIter = std::vec::IntoIter<Arg<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> FromIterator<Arg<'a>> for ArgList<'a> {
+    fn from_iter<I: IntoIterator<Item = Arg<'a>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Type information about a single argument of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    index: usize,
+    name: Option<Cow<'static, str>>,
+    ownership: Ownership,
+    type_info: &'static TypeInfo,
+}
+
+impl ArgInfo {
+    /// Create a new [`ArgInfo`] for the argument at the given index.
+    pub fn new<T: FromArg<M>, M>(index: usize) -> Self {
+        Self {
+            index,
+            name: None,
+            ownership: T::OWNERSHIP,
+            type_info: T::type_info(),
+        }
+    }
+
+    /// Sets the name of the argument.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The position of the argument in the function signature.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the argument, if known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The way in which the argument is passed.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+
+    /// The [`TypeInfo`] of the argument's underlying type.
+    ///
+    /// For references, this is the type being referenced.
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+}
+
+/// Marker types used to distinguish the implementations of [`FromArg`].
+pub mod ownership {
+    /// Marker for arguments passed by value.
+    pub struct Owned;
+    /// Marker for arguments passed by reference.
+    pub struct Ref;
+    /// Marker for arguments passed by mutable reference.
+    pub struct Mut;
+}
+
+/// A trait for types that can be created from an [`Arg`].
+///
+/// This is implemented for all types implementing [`FromReflect`] and [`Typed`],
+/// as well as for references to types implementing [`Reflect`] and [`Typed`].
+pub trait FromArg<Marker> {
+    /// The type created from an argument that lives for `'a`.
+    type Item<'a>;
+
+    /// The way in which this argument is expected to be passed.
+    const OWNERSHIP: Ownership;
+
+    /// Returns the [`TypeInfo`] of the underlying type.
+    fn type_info() -> &'static TypeInfo;
+
+    /// Create the argument at position `index` from the given [`Arg`].
+    fn from_arg(arg: Arg<'_>, index: usize) -> Result<Self::Item<'_>, ArgError>;
+}
+
+impl<T: FromReflect + Typed> FromArg<ownership::Owned> for T {
+    type Item<'a> = T;
+
+    const OWNERSHIP: Ownership = Ownership::Owned;
+
+    fn type_info() -> &'static TypeInfo {
+        T::type_info()
+    }
+
+    fn from_arg(arg: Arg<'_>, index: usize) -> Result<Self::Item<'_>, ArgError> {
+        match arg {
+            Arg::Owned(value) => T::take_from_reflect(value)
+                .map_err(|value| ArgError::invalid_type::<T>(index, value.as_ref())),
+            Arg::Ref(value) => {
+                T::from_reflect(value).ok_or_else(|| ArgError::invalid_type::<T>(index, value))
+            }
+            Arg::Mut(value) => {
+                T::from_reflect(value).ok_or_else(|| ArgError::invalid_type::<T>(index, value))
+            }
+        }
+    }
+}
+
+impl<T: Reflect + Typed> FromArg<ownership::Ref> for &T {
+    type Item<'a> = &'a T;
+
+    const OWNERSHIP: Ownership = Ownership::Ref;
+
+    fn type_info() -> &'static TypeInfo {
+        T::type_info()
+    }
+
+    fn from_arg(arg: Arg<'_>, index: usize) -> Result<Self::Item<'_>, ArgError> {
+        let value: &dyn Reflect = match arg {
+            Arg::Ref(value) => value,
+            Arg::Mut(value) => value,
+            Arg::Owned(_) => {
+                return Err(ArgError::InvalidOwnership {
+                    index,
+                    expected: Ownership::Ref,
+                    received: Ownership::Owned,
+                })
+            }
+        };
+        value
+            .downcast_ref()
+            .ok_or_else(|| ArgError::invalid_type::<T>(index, value))
+    }
+}
+
+impl<T: Reflect + Typed> FromArg<ownership::Mut> for &mut T {
+    type Item<'a> = &'a mut T;
+
+    const OWNERSHIP: Ownership = Ownership::Mut;
+
+    fn type_info() -> &'static TypeInfo {
+        T::type_info()
+    }
+
+    fn from_arg(arg: Arg<'_>, index: usize) -> Result<Self::Item<'_>, ArgError> {
+        match arg {
+            Arg::Mut(value) => {
+                if value.is::<T>() {
+                    Ok(value.downcast_mut().unwrap())
+                } else {
+                    Err(ArgError::invalid_type::<T>(index, value))
+                }
+            }
+            arg => Err(ArgError::InvalidOwnership {
+                index,
+                expected: Ownership::Mut,
+                received: arg.ownership(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::func::Ownership;
+use crate::Reflect;
+use std::borrow::Cow;
+use thiserror::Error;
+
+/// An error that occurs when converting an [`Arg`] into a concrete argument.
+///
+/// [`Arg`]: crate::func::Arg
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ArgError {
+    /// The argument could not be converted to the expected type.
+    #[error("expected argument {index} to be of type `{expected}` but received `{received}`")]
+    InvalidType {
+        index: usize,
+        expected: Cow<'static, str>,
+        received: String,
+    },
+    /// The argument was passed with a different [`Ownership`] than the function expects.
+    #[error("expected argument {index} to be passed as {expected:?} but received {received:?}")]
+    InvalidOwnership {
+        index: usize,
+        expected: Ownership,
+        received: Ownership,
+    },
+}
+
+impl ArgError {
+    pub(crate) fn invalid_type<T>(index: usize, received: &dyn Reflect) -> Self {
+        ArgError::InvalidType {
+            index,
+            expected: Cow::Borrowed(std::any::type_name::<T>()),
+            received: received.type_name().to_string(),
+        }
+    }
+}
+
+/// An error that occurs when calling a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FunctionError {
+    /// The function was called with the wrong number of arguments.
+    #[error("expected {expected} arguments but received {received}")]
+    ArgCount { expected: usize, received: usize },
+    /// One of the arguments was invalid.
+    #[error(transparent)]
+    Arg(#[from] ArgError),
+}
+
+/// An error that occurs when registering a function in the [`TypeRegistry`].
+///
+/// [`TypeRegistry`]: crate::TypeRegistry
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FunctionRegistrationError {
+    /// A function with the same name has already been registered.
+    #[error("a function named `{0}` is already registered")]
+    DuplicateName(Cow<'static, str>),
+    /// The function has no name and none was provided.
+    #[error("anonymous functions must be registered with a name")]
+    MissingName,
+}
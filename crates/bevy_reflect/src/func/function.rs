@@ -0,0 +1,114 @@
+use crate::func::{ArgList, FunctionError, FunctionInfo};
+use crate::Reflect;
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// A dynamically callable function.
+///
+/// A `DynamicFunction` is usually created with [`IntoFunction`],
+/// and called with an [`ArgList`] of reflected arguments.
+/// Its result is returned as a boxed [`Reflect`] value.
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoFunction};
+/// fn greet(name: &String) -> String {
+///     format!("Hello, {name}!")
+/// }
+///
+/// let func = greet.into_function();
+/// let name = String::from("Bevy");
+/// let value = func.call(ArgList::new().push_ref(&name)).unwrap();
+/// assert_eq!("Hello, Bevy!", value.take::<String>().unwrap());
+/// ```
+///
+/// [`IntoFunction`]: crate::func::IntoFunction
+#[derive(Clone)]
+pub struct DynamicFunction {
+    info: FunctionInfo,
+    func: Arc<
+        dyn for<'a> Fn(ArgList<'a>) -> Result<Box<dyn Reflect>, FunctionError>
+            + Send
+            + Sync
+            + 'static,
+    >,
+}
+
+impl DynamicFunction {
+    /// Create a new [`DynamicFunction`] from a closure operating on an [`ArgList`].
+    ///
+    /// The given `info` should describe the arguments expected by `func`.
+    pub fn new<F>(func: F, info: FunctionInfo) -> Self
+    where
+        F: for<'a> Fn(ArgList<'a>) -> Result<Box<dyn Reflect>, FunctionError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            info,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.info = self.info.with_name(name);
+        self
+    }
+
+    /// Sets the names of the function's arguments, in order.
+    pub fn with_arg_names<I>(mut self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        self.info = self.info.with_arg_names(names);
+        self
+    }
+
+    /// Call the function with the given arguments.
+    ///
+    /// Returns an error if the number of arguments doesn't match the function signature,
+    /// or if any argument can't be converted into the expected type.
+    pub fn call(&self, args: ArgList) -> Result<Box<dyn Reflect>, FunctionError> {
+        (self.func)(args)
+    }
+
+    /// Type information about the function.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// The name of the function, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.info.name()
+    }
+}
+
+impl Debug for DynamicFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DynamicFunction(fn {}(", self.name().unwrap_or("_"))?;
+        for (index, arg) in self.info.args().iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            let prefix = match arg.ownership() {
+                crate::func::Ownership::Owned => "",
+                crate::func::Ownership::Ref => "&",
+                crate::func::Ownership::Mut => "&mut ",
+            };
+            write!(
+                f,
+                "{}: {prefix}{}",
+                arg.name().unwrap_or("_"),
+                arg.type_info().type_name()
+            )?;
+        }
+        write!(
+            f,
+            ") -> {})",
+            self.info.return_info().type_info().type_name()
+        )
+    }
+}
@@ -0,0 +1,90 @@
+use crate::func::ArgInfo;
+use crate::{TypeInfo, Typed};
+use std::borrow::Cow;
+
+/// Type information about a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    name: Option<Cow<'static, str>>,
+    args: Vec<ArgInfo>,
+    return_info: ReturnInfo,
+}
+
+impl FunctionInfo {
+    /// Create a new [`FunctionInfo`] from its name, arguments, and return value.
+    pub fn new(
+        name: Option<Cow<'static, str>>,
+        args: Vec<ArgInfo>,
+        return_info: ReturnInfo,
+    ) -> Self {
+        Self {
+            name,
+            args,
+            return_info,
+        }
+    }
+
+    /// Sets the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the names of the function's arguments, in order.
+    ///
+    /// Extra names are ignored.
+    pub fn with_arg_names<I>(mut self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        for (arg, name) in self.args.iter_mut().zip(names) {
+            *arg = arg.clone().with_name(name);
+        }
+        self
+    }
+
+    /// The name of the function.
+    ///
+    /// For functions converted with [`IntoFunction`], this is the function's full type name.
+    /// Closures have no name unless one is given.
+    ///
+    /// [`IntoFunction`]: crate::func::IntoFunction
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The arguments of the function.
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// Type information about the function's return value.
+    pub fn return_info(&self) -> &ReturnInfo {
+        &self.return_info
+    }
+}
+
+/// Type information about the return value of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ReturnInfo {
+    type_info: &'static TypeInfo,
+}
+
+impl ReturnInfo {
+    /// Create a new [`ReturnInfo`] for a function returning `T`.
+    pub fn new<T: Typed>() -> Self {
+        Self {
+            type_info: T::type_info(),
+        }
+    }
+
+    /// The [`TypeInfo`] of the returned type.
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+}
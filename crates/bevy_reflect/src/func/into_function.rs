@@ -0,0 +1,80 @@
+use crate::func::{
+    ArgInfo, ArgList, DynamicFunction, FromArg, FunctionError, FunctionInfo, ReturnInfo,
+};
+use crate::{Reflect, Typed};
+use bevy_utils::all_tuples;
+use std::borrow::Cow;
+
+/// A trait for converting functions and closures into a [`DynamicFunction`].
+///
+/// This is implemented for any function or closure with up to 12 arguments,
+/// where each argument implements [`FromArg`] and the return type implements [`Reflect`] and [`Typed`].
+/// The function must also be `Send`, `Sync`, and `'static`.
+///
+/// The `Marker` type parameter is only used to distinguish the implementations
+/// and can be ignored.
+pub trait IntoFunction<Marker> {
+    /// Convert `self` into a [`DynamicFunction`].
+    fn into_function(self) -> DynamicFunction;
+}
+
+impl IntoFunction<()> for DynamicFunction {
+    fn into_function(self) -> DynamicFunction {
+        self
+    }
+}
+
+/// Returns the name of the function type `F`, or `None` if it is a closure.
+fn function_name<F>() -> Option<Cow<'static, str>> {
+    let name = std::any::type_name::<F>();
+    if name.contains("{{closure}}") {
+        None
+    } else {
+        Some(Cow::Borrowed(name))
+    }
+}
+
+macro_rules! impl_into_function {
+    ($(($Arg:ident, $Marker:ident, $arg:ident)),*) => {
+        impl<$($Arg, $Marker,)* R, F> IntoFunction<(fn($($Arg,)*) -> R, $($Marker,)*)> for F
+        where
+            $($Arg: FromArg<$Marker>,)*
+            R: Reflect + Typed,
+            F: Fn($($Arg),*) -> R + Send + Sync + 'static,
+            F: for<'a> Fn($(<$Arg as FromArg<$Marker>>::Item<'a>),*) -> R,
+        {
+            #[allow(unused_variables, unused_mut, unused_assignments)]
+            fn into_function(self) -> DynamicFunction {
+                let mut index = 0;
+                let args = vec![$({
+                    index += 1;
+                    ArgInfo::new::<$Arg, $Marker>(index - 1)
+                }),*];
+                let info = FunctionInfo::new(function_name::<F>(), args, ReturnInfo::new::<R>());
+                let expected = info.args().len();
+
+                DynamicFunction::new(
+                    move |args: ArgList| {
+                        if args.len() != expected {
+                            return Err(FunctionError::ArgCount {
+                                expected,
+                                received: args.len(),
+                            });
+                        }
+
+                        let mut args = args.into_iter();
+                        let mut index = 0;
+                        $(
+                            let $arg = <$Arg as FromArg<$Marker>>::from_arg(args.next().unwrap(), index)?;
+                            index += 1;
+                        )*
+                        Ok(Box::new((self)($($arg),*)) as Box<dyn Reflect>)
+                    },
+                    info,
+                )
+            }
+        }
+    };
+}
+
+all_tuples!(impl_into_function, 0, 12, A, M, a);
@@ -0,0 +1,252 @@
+//! Reflection for functions and methods.
+//!
+//! Any function or closure whose arguments and return value are reflectable can be
+//! converted into a [`DynamicFunction`] using the [`IntoFunction`] trait.
+//! A `DynamicFunction` can then be called with an [`ArgList`] of reflected values,
+//! returning its result as a `Box<dyn Reflect>`.
+//!
+//! ```
+//! # use bevy_reflect::func::{ArgList, IntoFunction};
+//! fn add(a: i32, b: i32) -> i32 {
+//!     a + b
+//! }
+//!
+//! let func = add.into_function();
+//! let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+//! let value = func.call(args).unwrap();
+//! assert_eq!(value.take::<i32>().unwrap(), 100);
+//! ```
+//!
+//! # Arguments
+//!
+//! Arguments may be passed to a function as owned values, as references, or as mutable references,
+//! depending on the signature of the function.
+//! Owned arguments are created from their reflected value using [`FromReflect`],
+//! while references are downcast directly to the concrete type.
+//!
+//! # Return Values
+//!
+//! A reflected function must return an owned value that implements [`Reflect`] and [`Typed`].
+//! Functions returning references are not supported.
+//!
+//! # Registration
+//!
+//! Functions can be added to the [`TypeRegistry`] using [`TypeRegistry::register_function`],
+//! where they can later be looked up by name.
+//! Methods of a type can be registered by marking them with `#[reflect]` inside an impl block
+//! annotated with the [`reflect_methods`] attribute, and calling [`TypeRegistry::register_methods`].
+//! Registered methods are named after the [type path] of their type, e.g. `my_crate::Player::heal`.
+//!
+//! ```
+//! # use bevy_reflect::{func::ArgList, reflect_methods, Reflect, TypePath, TypeRegistry};
+//! #[derive(Reflect)]
+//! struct Player {
+//!     health: f32,
+//! }
+//!
+//! #[reflect_methods]
+//! impl Player {
+//!     #[reflect]
+//!     fn heal(&mut self, amount: f32) {
+//!         self.health += amount;
+//!     }
+//! }
+//!
+//! let mut registry = TypeRegistry::default();
+//! registry.register_methods::<Player>();
+//!
+//! let heal = registry
+//!     .get_function(&format!("{}::heal", Player::type_path()))
+//!     .unwrap();
+//! let mut player = Player { health: 10.0 };
+//! heal.call(ArgList::new().push_mut(&mut player).push_owned(5.0_f32))
+//!     .unwrap();
+//! assert_eq!(15.0, player.health);
+//! ```
+//!
+//! [`FromReflect`]: crate::FromReflect
+//! [`Reflect`]: crate::Reflect
+//! [`Typed`]: crate::Typed
+//! [`TypeRegistry`]: crate::TypeRegistry
+//! [`TypeRegistry::register_function`]: crate::TypeRegistry::register_function
+//! [`TypeRegistry::register_methods`]: crate::TypeRegistry::register_methods
+//! [`reflect_methods`]: macro@crate::reflect_methods
+//! [type path]: crate::TypePath
+
+mod args;
+mod error;
+mod function;
+mod info;
+mod into_function;
+
+pub use args::*;
+pub use error::*;
+pub use function::*;
+pub use info::*;
+pub use into_function::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{Reflect, TypeRegistry};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Player {
+        health: f32,
+    }
+
+    #[bevy_reflect::reflect_methods]
+    impl Player {
+        #[reflect]
+        fn heal(&mut self, amount: f32) {
+            self.health += amount;
+        }
+
+        #[reflect]
+        fn health(&self) -> f32 {
+            self.health
+        }
+
+        #[allow(dead_code)]
+        fn not_reflected(&self) {}
+    }
+
+    #[test]
+    fn should_call_function() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        let func = add.into_function();
+        let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+        let value = func.call(args).unwrap();
+        assert_eq!(Some(&100), value.downcast_ref::<i32>());
+    }
+
+    #[test]
+    fn should_call_closure() {
+        let offset = 10_i32;
+        let func = (move |value: i32| value + offset).into_function();
+        assert_eq!(None, func.info().name());
+
+        let value = func.call(ArgList::new().push_owned(5_i32)).unwrap();
+        assert_eq!(Some(&15), value.downcast_ref::<i32>());
+    }
+
+    #[test]
+    fn should_pass_references() {
+        fn append(list: &mut Vec<i32>, value: &i32) {
+            list.push(*value);
+        }
+
+        let mut list = vec![1, 2];
+        let func = append.into_function();
+        let args = ArgList::new().push_mut(&mut list).push_ref(&3_i32);
+        func.call(args).unwrap();
+        assert_eq!(vec![1, 2, 3], list);
+    }
+
+    #[test]
+    fn should_provide_function_info() {
+        #[allow(clippy::ptr_arg)]
+        fn concat(a: String, b: &String) -> String {
+            a + b
+        }
+
+        let func = concat.into_function();
+        let info = func.info();
+        assert!(info.name().unwrap().ends_with("concat"));
+        assert_eq!(2, info.args().len());
+        assert_eq!(Ownership::Owned, info.args()[0].ownership());
+        assert_eq!(Ownership::Ref, info.args()[1].ownership());
+        assert!(info.args()[1].type_info().is::<String>());
+        assert!(info.return_info().type_info().is::<String>());
+    }
+
+    #[test]
+    fn should_error_on_invalid_args() {
+        fn double(value: i32) -> i32 {
+            value * 2
+        }
+
+        let func = double.into_function();
+        assert_eq!(
+            Err(FunctionError::ArgCount {
+                expected: 1,
+                received: 0
+            }),
+            func.call(ArgList::new()).map(|_| ())
+        );
+        assert!(matches!(
+            func.call(ArgList::new().push_owned(String::from("foo"))),
+            Err(FunctionError::Arg(ArgError::InvalidType { index: 0, .. }))
+        ));
+
+        fn negate(value: &mut i32) {
+            *value = -*value;
+        }
+
+        let func = negate.into_function();
+        assert_eq!(
+            Err(FunctionError::Arg(ArgError::InvalidOwnership {
+                index: 0,
+                expected: Ownership::Mut,
+                received: Ownership::Ref
+            })),
+            func.call(ArgList::new().push_ref(&1_i32)).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn should_register_functions() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry
+            .register_function_with_name("math::add", add)
+            .unwrap();
+        assert_eq!(
+            Err(FunctionRegistrationError::MissingName),
+            registry.register_function(|a: i32| a)
+        );
+        assert_eq!(
+            Err(FunctionRegistrationError::DuplicateName("math::add".into())),
+            registry.register_function_with_name("math::add", add)
+        );
+
+        let func = registry.get_function("math::add").unwrap();
+        let value = func
+            .call(ArgList::new().push_owned(1_i32).push_owned(2_i32))
+            .unwrap();
+        assert_eq!(Some(&3), value.downcast_ref::<i32>());
+    }
+
+    #[test]
+    fn should_register_methods() {
+        let mut registry = TypeRegistry::empty();
+        registry.register_methods::<Player>();
+
+        let mut player = Player { health: 10.0 };
+        let heal = registry
+            .get_function("bevy_reflect::func::tests::Player::heal")
+            .unwrap();
+        assert_eq!(Some("self"), heal.info().args()[0].name());
+        assert_eq!(Some("amount"), heal.info().args()[1].name());
+        heal.call(ArgList::new().push_mut(&mut player).push_owned(5.0_f32))
+            .unwrap();
+        assert_eq!(Player { health: 15.0 }, player);
+
+        let health = registry
+            .get_function("bevy_reflect::func::tests::Player::health")
+            .unwrap();
+        let value = health.call(ArgList::new().push_ref(&player)).unwrap();
+        assert_eq!(Some(&15.0), value.downcast_ref::<f32>());
+
+        assert!(registry
+            .get_function("bevy_reflect::func::tests::Player::not_reflected")
+            .is_none());
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{Reflect, TypePath};
+use std::any::{Any, TypeId};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// The generic parameters of a type, as stored in its [`TypeInfo`].
+///
+/// Each parameter records the concrete argument it was given,
+/// allowing a type such as `Handle<Image>` to be recognized as `Handle<T>` with `T = Image`.
+///
+/// Lifetime parameters are not included.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{GenericInfo, Reflect, Typed};
+/// #[derive(Reflect)]
+/// struct Foo<T, const N: usize> {
+///     values: [T; N],
+/// }
+///
+/// let generics = Foo::<f32, 3>::type_info().generics();
+///
+/// let GenericInfo::Type(t) = generics.get_named("T").unwrap() else {
+///     panic!("expected type parameter");
+/// };
+/// assert!(t.is::<f32>());
+/// assert_eq!("f32", t.type_path());
+///
+/// let GenericInfo::Const(n) = generics.get_named("N").unwrap() else {
+///     panic!("expected const parameter");
+/// };
+/// assert!(n.is::<usize>());
+/// assert_eq!(Some(&3), n.value().downcast_ref::<usize>());
+/// ```
+///
+/// [`TypeInfo`]: crate::TypeInfo
+#[derive(Debug, Clone, Default)]
+pub struct Generics(Box<[GenericInfo]>);
+
+impl Generics {
+    /// Creates an empty set of generic parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a generic parameter.
+    pub fn with(self, info: impl Into<GenericInfo>) -> Self {
+        let mut params = self.0.into_vec();
+        params.push(info.into());
+        Self(params.into_boxed_slice())
+    }
+
+    /// Returns the generic parameter with the given name, if any.
+    pub fn get_named(&self, name: &str) -> Option<&GenericInfo> {
+        self.0.iter().find(|info| info.name() == name)
+    }
+}
+
+impl Deref for Generics {
+    type Target = [GenericInfo];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromIterator<GenericInfo> for Generics {
+    fn from_iter<I: IntoIterator<Item = GenericInfo>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// A single generic parameter of a type.
+#[derive(Debug, Clone)]
+pub enum GenericInfo {
+    /// A type parameter, such as `T`.
+    Type(TypeParamInfo),
+    /// A const parameter, such as `const N: usize`.
+    Const(ConstParamInfo),
+}
+
+impl GenericInfo {
+    /// The name of the parameter, such as `T` or `N`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Type(info) => info.name(),
+            Self::Const(info) => info.name(),
+        }
+    }
+
+    /// Returns `true` if this is a const parameter.
+    pub fn is_const(&self) -> bool {
+        matches!(self, Self::Const(_))
+    }
+
+    /// The [`TypeId`] of the type argument, or of the const parameter's type.
+    pub fn type_id(&self) -> TypeId {
+        match self {
+            Self::Type(info) => info.type_id(),
+            Self::Const(info) => info.type_id(),
+        }
+    }
+
+    /// The [type path] of the type argument, or of the const parameter's type.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        match self {
+            Self::Type(info) => info.type_path(),
+            Self::Const(info) => info.type_path(),
+        }
+    }
+
+    /// Check if the given type matches [`GenericInfo::type_id`].
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id()
+    }
+}
+
+impl From<TypeParamInfo> for GenericInfo {
+    fn from(info: TypeParamInfo) -> Self {
+        Self::Type(info)
+    }
+}
+
+impl From<ConstParamInfo> for GenericInfo {
+    fn from(info: ConstParamInfo) -> Self {
+        Self::Const(info)
+    }
+}
+
+/// A type parameter, such as `T` in `Vec<T>`.
+#[derive(Debug, Clone)]
+pub struct TypeParamInfo {
+    name: &'static str,
+    type_id: TypeId,
+    type_path: &'static str,
+    default: Option<(TypeId, &'static str)>,
+}
+
+impl TypeParamInfo {
+    /// Create a new [`TypeParamInfo`] with the given name and argument.
+    pub fn new<T: TypePath + ?Sized>(name: &'static str) -> Self {
+        Self {
+            name,
+            type_id: TypeId::of::<T>(),
+            type_path: T::type_path(),
+            default: None,
+        }
+    }
+
+    /// Sets the default type of this parameter.
+    pub fn with_default<T: TypePath + ?Sized>(self) -> Self {
+        Self {
+            default: Some((TypeId::of::<T>(), T::type_path())),
+            ..self
+        }
+    }
+
+    /// The name of the parameter.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The [`TypeId`] of the type argument.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The [type path] of the type argument.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// Check if the given type matches the type argument.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+
+    /// The [`TypeId`] of the default type, if any.
+    pub fn default_type_id(&self) -> Option<TypeId> {
+        self.default.map(|(type_id, _)| type_id)
+    }
+
+    /// The [type path] of the default type, if any.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn default_type_path(&self) -> Option<&'static str> {
+        self.default.map(|(_, type_path)| type_path)
+    }
+
+    /// Check if the given type matches the default type.
+    pub fn default_is<T: Any>(&self) -> bool {
+        self.default_type_id() == Some(TypeId::of::<T>())
+    }
+}
+
+/// A const parameter, such as `N` in `[T; N]`.
+#[derive(Debug, Clone)]
+pub struct ConstParamInfo {
+    name: &'static str,
+    type_id: TypeId,
+    type_path: &'static str,
+    value: Arc<dyn Reflect>,
+    default: Option<Arc<dyn Reflect>>,
+}
+
+impl ConstParamInfo {
+    /// Create a new [`ConstParamInfo`] with the given name and value.
+    pub fn new<T: Reflect + TypePath>(name: &'static str, value: T) -> Self {
+        Self {
+            name,
+            type_id: TypeId::of::<T>(),
+            type_path: T::type_path(),
+            value: Arc::new(value),
+            default: None,
+        }
+    }
+
+    /// Sets the default value of this parameter.
+    pub fn with_default<T: Reflect>(self, default: T) -> Self {
+        Self {
+            default: Some(Arc::new(default)),
+            ..self
+        }
+    }
+
+    /// The name of the parameter.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The [`TypeId`] of the parameter's type.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The [type path] of the parameter's type.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// Check if the given type matches the parameter's type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+
+    /// The value given to the parameter.
+    pub fn value(&self) -> &dyn Reflect {
+        &*self.value
+    }
+
+    /// The default value of the parameter, if any.
+    pub fn default(&self) -> Option<&dyn Reflect> {
+        self.default.as_deref()
+    }
+}
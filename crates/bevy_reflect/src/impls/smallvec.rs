@@ -4,9 +4,9 @@ use std::any::Any;
 
 use crate::utility::GenericTypeInfoCell;
 use crate::{
-    self as bevy_reflect, FromReflect, FromType, GetTypeRegistration, List, ListInfo, ListIter,
-    Reflect, ReflectFromPtr, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath,
-    TypeRegistration, Typed,
+    self as bevy_reflect, FromReflect, FromType, Generics, GetTypeRegistration, List, ListInfo,
+    ListIter, Reflect, ReflectFromPtr, ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
+    TypeParamInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
 };
 
 impl<T: smallvec::Array + TypePath + Send + Sync> List for SmallVec<T>
@@ -148,7 +148,10 @@ where
 {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-        CELL.get_or_insert::<Self, _>(|| TypeInfo::List(ListInfo::new::<Self, T::Item>()))
+        CELL.get_or_insert::<Self, _>(|| {
+            let generics = Generics::new().with(TypeParamInfo::new::<T>("A"));
+            TypeInfo::List(ListInfo::new::<Self, T::Item>().with_generics(generics))
+        })
     }
 }
 
@@ -173,11 +176,15 @@ where
 
 impl<T: smallvec::Array + TypePath + Send + Sync> GetTypeRegistration for SmallVec<T>
 where
-    T::Item: FromReflect,
+    T::Item: FromReflect + GetTypeRegistration,
 {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<SmallVec<T>>();
         registration.insert::<ReflectFromPtr>(FromType::<SmallVec<T>>::from_type());
         registration
     }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T::Item>();
+    }
 }
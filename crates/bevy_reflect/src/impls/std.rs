@@ -1,10 +1,11 @@
 use crate::std_traits::ReflectDefault;
 use crate::{self as bevy_reflect, ReflectFromPtr, ReflectFromReflect, ReflectOwned};
 use crate::{
-    impl_type_path, map_apply, map_partial_eq, Array, ArrayInfo, ArrayIter, DynamicEnum,
-    DynamicMap, Enum, EnumInfo, FromReflect, FromType, GetTypeRegistration, List, ListInfo,
-    ListIter, Map, MapInfo, MapIter, Reflect, ReflectDeserialize, ReflectMut, ReflectRef,
-    ReflectSerialize, TupleVariantInfo, TypeInfo, TypePath, TypeRegistration, Typed,
+    impl_type_path, map_apply, map_partial_eq, set_apply, set_partial_eq, Array, ArrayInfo,
+    ArrayIter, ConstParamInfo, DynamicEnum, DynamicMap, DynamicSet, Enum, EnumInfo, FromReflect,
+    FromType, Generics, GetTypeRegistration, List, ListInfo, ListIter, Map, MapInfo, MapIter,
+    Reflect, ReflectDeserialize, ReflectMut, ReflectRef, ReflectSerialize, Set, SetInfo,
+    TupleVariantInfo, TypeInfo, TypeParamInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
     UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter, VariantInfo, VariantType,
 };
 
@@ -99,7 +100,6 @@ impl_reflect_value!(
     ::core::result::Result < T: Clone + Reflect + TypePath,
     E: Clone + Reflect + TypePath > ()
 );
-impl_reflect_value!(::bevy_utils::HashSet<T: Hash + Eq + Clone + Send + Sync>());
 impl_reflect_value!(::core::ops::Range<T: Clone + Send + Sync>());
 impl_reflect_value!(::core::ops::RangeInclusive<T: Clone + Send + Sync>());
 impl_reflect_value!(::core::ops::RangeFrom<T: Clone + Send + Sync>());
@@ -344,18 +344,25 @@ macro_rules! impl_reflect_for_veclike {
         impl<T: FromReflect + TypePath> Typed for $ty {
             fn type_info() -> &'static TypeInfo {
                 static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-                CELL.get_or_insert::<Self, _>(|| TypeInfo::List(ListInfo::new::<Self, T>()))
+                CELL.get_or_insert::<Self, _>(|| {
+                    let generics = Generics::new().with(TypeParamInfo::new::<T>("T"));
+                    TypeInfo::List(ListInfo::new::<Self, T>().with_generics(generics))
+                })
             }
         }
 
         impl_type_path!($ty where T: FromReflect);
 
-        impl<T: FromReflect + TypePath> GetTypeRegistration for $ty {
+        impl<T: FromReflect + TypePath + GetTypeRegistration> GetTypeRegistration for $ty {
             fn get_type_registration() -> TypeRegistration {
                 let mut registration = TypeRegistration::of::<$ty>();
                 registration.insert::<ReflectFromPtr>(FromType::<$ty>::from_type());
                 registration
             }
+
+            fn register_type_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<T>();
+            }
         }
 
         impl<T: FromReflect + TypePath> FromReflect for $ty {
@@ -392,7 +399,7 @@ impl_reflect_for_veclike!(
 );
 
 macro_rules! impl_reflect_for_hashmap {
-    ($ty:path) => {
+    ($ty:path, $default_hasher:ty) => {
         impl<K, V, S> Map for $ty
         where
             K: FromReflect + TypePath + Eq + Hash,
@@ -564,14 +571,20 @@ macro_rules! impl_reflect_for_hashmap {
         {
             fn type_info() -> &'static TypeInfo {
                 static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-                CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
+                CELL.get_or_insert::<Self, _>(|| {
+                    let generics = Generics::new()
+                        .with(TypeParamInfo::new::<K>("K"))
+                        .with(TypeParamInfo::new::<V>("V"))
+                        .with(TypeParamInfo::new::<S>("S").with_default::<$default_hasher>());
+                    TypeInfo::Map(MapInfo::new::<Self, K, V>().with_generics(generics))
+                })
             }
         }
 
         impl<K, V, S> GetTypeRegistration for $ty
         where
-            K: FromReflect + TypePath + Eq + Hash,
-            V: FromReflect + TypePath,
+            K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+            V: FromReflect + TypePath + GetTypeRegistration,
             S: TypePath + BuildHasher + Send + Sync,
         {
             fn get_type_registration() -> TypeRegistration {
@@ -579,6 +592,11 @@ macro_rules! impl_reflect_for_hashmap {
                 registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
                 registration
             }
+
+            fn register_type_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<K>();
+                registry.register::<V>();
+            }
         }
 
         impl<K, V, S> FromReflect for $ty
@@ -604,7 +622,10 @@ macro_rules! impl_reflect_for_hashmap {
     };
 }
 
-impl_reflect_for_hashmap!(::std::collections::HashMap<K, V, S>);
+impl_reflect_for_hashmap!(
+    ::std::collections::HashMap<K, V, S>,
+    ::std::collections::hash_map::RandomState
+);
 impl_type_path!(::std::collections::hash_map::RandomState);
 impl_type_path!(
     ::std::collections::HashMap<K, V, S>
@@ -614,7 +635,10 @@ impl_type_path!(
         S: BuildHasher + Send + Sync + 'static,
 );
 
-impl_reflect_for_hashmap!(bevy_utils::hashbrown::HashMap<K, V, S>);
+impl_reflect_for_hashmap!(
+    bevy_utils::hashbrown::HashMap<K, V, S>,
+    ::bevy_utils::hashbrown::hash_map::DefaultHashBuilder
+);
 impl_type_path!(::bevy_utils::hashbrown::hash_map::DefaultHashBuilder);
 impl_type_path!(
     ::bevy_utils::hashbrown::HashMap<K, V, S>
@@ -624,6 +648,223 @@ impl_type_path!(
         S: BuildHasher + Send + Sync + 'static,
 );
 
+macro_rules! impl_reflect_for_set {
+    ($ty:path, <$($param:ident $(= $default:ty)?),*>, [$($bounds:tt)*], $new:expr) => {
+        impl<$($param),*> Set for $ty
+        where
+            $($bounds)*
+        {
+            fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
+                value
+                    .downcast_ref::<V>()
+                    .and_then(|value| Self::get(self, value))
+                    .map(|value| value as &dyn Reflect)
+            }
+
+            fn len(&self) -> usize {
+                Self::len(self)
+            }
+
+            fn iter(&self) -> Box<dyn Iterator<Item = &dyn Reflect> + '_> {
+                Box::new(Self::iter(self).map(|value| value as &dyn Reflect))
+            }
+
+            fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+                self.into_iter()
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+                    .collect()
+            }
+
+            fn clone_dynamic(&self) -> DynamicSet {
+                let mut dynamic_set = DynamicSet::default();
+                dynamic_set.set_represented_type(self.get_represented_type_info());
+                for v in self {
+                    let value = V::from_reflect(v).unwrap_or_else(|| {
+                        panic!("Attempted to clone invalid value of type {}.", v.type_name())
+                    });
+                    dynamic_set.insert_boxed(Box::new(value));
+                }
+                dynamic_set
+            }
+
+            fn insert_boxed(&mut self, value: Box<dyn Reflect>) -> bool {
+                let value = V::take_from_reflect(value).unwrap_or_else(|value| {
+                    panic!(
+                        "Attempted to insert invalid value of type {}.",
+                        value.type_name()
+                    )
+                });
+                self.insert(value)
+            }
+
+            fn remove(&mut self, value: &dyn Reflect) -> bool {
+                let mut from_reflect = None;
+                value
+                    .downcast_ref::<V>()
+                    .or_else(|| {
+                        from_reflect = V::from_reflect(value);
+                        from_reflect.as_ref()
+                    })
+                    .map_or(false, |value| Self::remove(self, value))
+            }
+        }
+
+        impl<$($param),*> Reflect for $ty
+        where
+            $($bounds)*
+        {
+            fn type_name(&self) -> &str {
+                std::any::type_name::<Self>()
+            }
+
+            fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            fn apply(&mut self, value: &dyn Reflect) {
+                set_apply(self, value);
+            }
+
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            fn reflect_ref(&self) -> ReflectRef {
+                ReflectRef::Set(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut {
+                ReflectMut::Set(self)
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Set(self)
+            }
+
+            fn clone_value(&self) -> Box<dyn Reflect> {
+                Box::new(self.clone_dynamic())
+            }
+
+            fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                set_partial_eq(self, value)
+            }
+        }
+
+        impl<$($param),*> Typed for $ty
+        where
+            $($bounds)*
+        {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let generics = Generics::new()
+                        $(.with(TypeParamInfo::new::<$param>(stringify!($param))$(.with_default::<$default>())?))*;
+                    TypeInfo::Set(SetInfo::new::<Self, V>().with_generics(generics))
+                })
+            }
+        }
+
+        impl<$($param),*> GetTypeRegistration for $ty
+        where
+            $($bounds)*
+            V: GetTypeRegistration,
+        {
+            fn get_type_registration() -> TypeRegistration {
+                let mut registration = TypeRegistration::of::<Self>();
+                registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+                registration
+            }
+
+            fn register_type_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<V>();
+            }
+        }
+
+        impl<$($param),*> FromReflect for $ty
+        where
+            $($bounds)*
+        {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                if let ReflectRef::Set(ref_set) = reflect.reflect_ref() {
+                    let mut new_set: Self = $new(ref_set.len());
+                    for value in ref_set.iter() {
+                        new_set.insert(V::from_reflect(value)?);
+                    }
+                    Some(new_set)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+impl_reflect_for_set!(
+    ::std::collections::HashSet<V, S>,
+    <V, S = ::std::collections::hash_map::RandomState>,
+    [
+        V: FromReflect + TypePath + Eq + Hash,
+        S: TypePath + BuildHasher + Default + Send + Sync,
+    ],
+    |capacity| Self::with_capacity_and_hasher(capacity, S::default())
+);
+impl_type_path!(
+    ::std::collections::HashSet<V, S>
+    where
+        V: FromReflect + Eq + Hash,
+        S: BuildHasher + Send + Sync + 'static,
+);
+
+impl_reflect_for_set!(
+    ::bevy_utils::hashbrown::HashSet<V, S>,
+    <V, S = ::bevy_utils::hashbrown::hash_map::DefaultHashBuilder>,
+    [
+        V: FromReflect + TypePath + Eq + Hash,
+        S: TypePath + BuildHasher + Default + Send + Sync,
+    ],
+    |capacity| Self::with_capacity_and_hasher(capacity, S::default())
+);
+impl_type_path!(
+    ::bevy_utils::hashbrown::HashSet<V, S>
+    where
+        V: FromReflect + Eq + Hash,
+        S: BuildHasher + Send + Sync + 'static,
+);
+
+impl_reflect_for_set!(
+    ::std::collections::BTreeSet<V>,
+    <V>,
+    [V: FromReflect + TypePath + Ord,],
+    |_| Self::new()
+);
+impl_type_path!(::std::collections::BTreeSet<V> where V: FromReflect + Ord);
+
 impl<T: Reflect + TypePath, const N: usize> Array for [T; N] {
     #[inline]
     fn get(&self, index: usize) -> Option<&dyn Reflect> {
@@ -752,7 +993,12 @@ impl<T: FromReflect + TypePath, const N: usize> FromReflect for [T; N] {
 impl<T: Reflect + TypePath, const N: usize> Typed for [T; N] {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-        CELL.get_or_insert::<Self, _>(|| TypeInfo::Array(ArrayInfo::new::<Self, T>(N)))
+        CELL.get_or_insert::<Self, _>(|| {
+            let generics = Generics::new()
+                .with(TypeParamInfo::new::<T>("T"))
+                .with(ConstParamInfo::new::<usize>("N", N));
+            TypeInfo::Array(ArrayInfo::new::<Self, T>(N).with_generics(generics))
+        })
     }
 }
 
@@ -776,10 +1022,14 @@ impl<T: Reflect + TypePath, const N: usize> TypePath for [T; N] {
 macro_rules! impl_array_get_type_registration {
     ($($N:expr)+) => {
         $(
-            impl<T: Reflect + TypePath> GetTypeRegistration for [T; $N] {
+            impl<T: Reflect + TypePath + GetTypeRegistration> GetTypeRegistration for [T; $N] {
                 fn get_type_registration() -> TypeRegistration {
                     TypeRegistration::of::<[T; $N]>()
                 }
+
+                fn register_type_dependencies(registry: &mut TypeRegistry) {
+                    registry.register::<T>();
+                }
             }
         )+
     };
@@ -792,10 +1042,14 @@ impl_array_get_type_registration! {
     30 31 32
 }
 
-impl<T: FromReflect + TypePath> GetTypeRegistration for Option<T> {
+impl<T: FromReflect + TypePath + GetTypeRegistration> GetTypeRegistration for Option<T> {
     fn get_type_registration() -> TypeRegistration {
         TypeRegistration::of::<Option<T>>()
     }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
 }
 
 impl<T: FromReflect + TypePath> Enum for Option<T> {
@@ -1028,10 +1282,11 @@ impl<T: FromReflect + TypePath> Typed for Option<T> {
             let none_variant = VariantInfo::Unit(UnitVariantInfo::new("None"));
             let some_variant =
                 VariantInfo::Tuple(TupleVariantInfo::new("Some", &[UnnamedField::new::<T>(0)]));
-            TypeInfo::Enum(EnumInfo::new::<Self>(
-                "Option",
-                &[none_variant, some_variant],
-            ))
+            let generics = Generics::new().with(TypeParamInfo::new::<T>("T"));
+            TypeInfo::Enum(
+                EnumInfo::new::<Self>("Option", &[none_variant, some_variant])
+                    .with_generics(generics),
+            )
         })
     }
 }
@@ -1318,14 +1573,23 @@ impl<T: FromReflect + Clone + TypePath> Reflect for Cow<'static, [T]> {
 impl<T: FromReflect + Clone + TypePath> Typed for Cow<'static, [T]> {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
-        CELL.get_or_insert::<Self, _>(|| TypeInfo::List(ListInfo::new::<Self, T>()))
+        CELL.get_or_insert::<Self, _>(|| {
+            let generics = Generics::new().with(TypeParamInfo::new::<[T]>("B"));
+            TypeInfo::List(ListInfo::new::<Self, T>().with_generics(generics))
+        })
     }
 }
 
-impl<T: FromReflect + Clone + TypePath> GetTypeRegistration for Cow<'static, [T]> {
+impl<T: FromReflect + Clone + TypePath + GetTypeRegistration> GetTypeRegistration
+    for Cow<'static, [T]>
+{
     fn get_type_registration() -> TypeRegistration {
         TypeRegistration::of::<Cow<'static, [T]>>()
     }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
 }
 
 impl<T: FromReflect + Clone + TypePath> FromReflect for Cow<'static, [T]> {
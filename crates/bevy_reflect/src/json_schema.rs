@@ -0,0 +1,574 @@
+//! Exports the types of a [`TypeRegistry`] as [JSON Schema] documents.
+//!
+//! The generated schemas describe the format produced by the reflection serializers
+//! (such as [`TypedReflectSerializer`]) when used with a JSON serializer.
+//! This allows external tools to validate serialized data, like scene files,
+//! and to generate editor forms for registered types.
+//!
+//! Each document is self-contained:
+//! structs, tuple structs, and enums are stored in the document's `$defs`
+//! and referenced by their [type name],
+//! while all other kinds of types are described inline.
+//!
+//! Types that are not registered are described by an empty schema, accepting any value.
+//!
+//! Note that types which provide their own [`Serialize`] implementation
+//! (registered with [`ReflectSerialize`]) are described using their reflected shape,
+//! which may not match the output of their custom implementation.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::{json_schema::export_json_schema, Reflect, TypeRegistry};
+//! # use serde_json::json;
+//! #[derive(Reflect)]
+//! struct Health(f32);
+//!
+//! let mut registry = TypeRegistry::new();
+//! registry.register::<Health>();
+//!
+//! let registration = registry.get(std::any::TypeId::of::<Health>()).unwrap();
+//! let schema = export_json_schema(registration, &registry);
+//!
+//! assert_eq!(json!("Health"), schema["title"]);
+//! ```
+//!
+//! [JSON Schema]: https://json-schema.org/
+//! [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+//! [type name]: std::any::type_name
+//! [`Serialize`]: ::serde::Serialize
+//! [`ReflectSerialize`]: crate::ReflectSerialize
+
+use crate::serde::SerializationData;
+use crate::{
+    EnumInfo, NamedField, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, VariantInfo,
+};
+use bevy_utils::get_short_name;
+use serde_json::{json, Map, Value};
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The JSON Schema dialect used by the exported documents.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Exports a JSON Schema document for every type in the registry.
+///
+/// The documents are keyed by the [type name] of their type.
+///
+/// [type name]: std::any::type_name
+pub fn export_json_schemas(registry: &TypeRegistry) -> BTreeMap<&'static str, Value> {
+    registry
+        .iter()
+        .map(|registration| {
+            (
+                registration.type_name(),
+                export_json_schema(registration, registry),
+            )
+        })
+        .collect()
+}
+
+/// Exports a JSON Schema document for the given registered type.
+///
+/// See the [module-level documentation] for details.
+///
+/// [module-level documentation]: crate::json_schema
+pub fn export_json_schema(registration: &TypeRegistration, registry: &TypeRegistry) -> Value {
+    let mut builder = SchemaBuilder {
+        registry,
+        definitions: Map::new(),
+    };
+    let schema = builder.schema(registration.type_info());
+
+    let mut document = Map::new();
+    document.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+    document.insert("title".to_string(), json!(registration.short_name()));
+    if let Value::Object(schema) = schema {
+        document.extend(schema);
+    }
+    if !builder.definitions.is_empty() {
+        document.insert("$defs".to_string(), Value::Object(builder.definitions));
+    }
+
+    Value::Object(document)
+}
+
+struct SchemaBuilder<'a> {
+    registry: &'a TypeRegistry,
+    definitions: Map<String, Value>,
+}
+
+impl<'a> SchemaBuilder<'a> {
+    /// Returns the schema of the given type.
+    ///
+    /// Structs, tuple structs, and enums are added to the definitions and returned as a reference.
+    fn schema(&mut self, info: &'static TypeInfo) -> Value {
+        match info {
+            TypeInfo::Struct(_) | TypeInfo::TupleStruct(_) => self.definition_ref(info),
+            TypeInfo::Enum(enum_info) if is_option(enum_info) => {
+                let some = match enum_info.variant("Some") {
+                    Some(VariantInfo::Tuple(variant)) => {
+                        self.unnamed_field_schema(variant.field_at(0).unwrap())
+                    }
+                    _ => json!({}),
+                };
+                json!({ "anyOf": [{ "type": "null" }, some] })
+            }
+            TypeInfo::Enum(_) => self.definition_ref(info),
+            TypeInfo::Tuple(info) => {
+                let fields = info
+                    .iter()
+                    .map(|field| self.unnamed_field_schema(field))
+                    .collect();
+                tuple_schema(fields)
+            }
+            TypeInfo::List(info) => json!({
+                "type": "array",
+                "items": self.type_schema(info.item_type_id(), info.item_type_name()),
+            }),
+            TypeInfo::Array(info) => json!({
+                "type": "array",
+                "items": self.type_schema(info.item_type_id(), info.item_type_name()),
+                "minItems": info.capacity(),
+                "maxItems": info.capacity(),
+            }),
+            TypeInfo::Set(info) => json!({
+                "type": "array",
+                "items": self.type_schema(info.value_type_id(), info.value_type_name()),
+                "uniqueItems": true,
+            }),
+            TypeInfo::Map(info) => json!({
+                "type": "object",
+                "additionalProperties": self.type_schema(info.value_type_id(), info.value_type_name()),
+            }),
+            TypeInfo::Value(info) => value_schema(info.type_id()),
+        }
+    }
+
+    /// Returns the schema of the type with the given [`TypeId`], if it is registered.
+    fn type_schema(&mut self, type_id: TypeId, type_name: &str) -> Value {
+        match self.registry.get_type_info(type_id) {
+            Some(info) => self.schema(info),
+            None => json!({ "description": format!("unregistered type `{type_name}`") }),
+        }
+    }
+
+    fn named_field_schema(&mut self, field: &NamedField) -> Value {
+        #[allow(unused_mut)]
+        let mut schema = self.type_schema(field.type_id(), field.type_name());
+        #[cfg(feature = "documentation")]
+        describe(&mut schema, field.docs());
+        schema
+    }
+
+    fn unnamed_field_schema(&mut self, field: &UnnamedField) -> Value {
+        #[allow(unused_mut)]
+        let mut schema = self.type_schema(field.type_id(), field.type_name());
+        #[cfg(feature = "documentation")]
+        describe(&mut schema, field.docs());
+        schema
+    }
+
+    /// Adds the given type to the definitions, if needed, and returns a reference to it.
+    fn definition_ref(&mut self, info: &'static TypeInfo) -> Value {
+        let type_name = info.type_name();
+        if !self.definitions.contains_key(type_name) {
+            // Reserve the entry first so that recursive types don't get defined twice
+            self.definitions.insert(type_name.to_string(), Value::Null);
+            let definition = self.definition(info);
+            self.definitions.insert(type_name.to_string(), definition);
+        }
+
+        json!({ "$ref": definition_pointer(type_name) })
+    }
+
+    fn definition(&mut self, info: &'static TypeInfo) -> Value {
+        let serialization_data = self
+            .registry
+            .get(info.type_id())
+            .and_then(|registration| registration.data::<SerializationData>());
+        let is_ignored = |index: usize| {
+            serialization_data
+                .map(|data| data.is_ignored_field(index))
+                .unwrap_or(false)
+        };
+
+        let mut schema = match info {
+            TypeInfo::Struct(info) => {
+                let fields = info
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !is_ignored(*index))
+                    .map(|(_, field)| (field.name(), self.named_field_schema(field)))
+                    .collect();
+                object_schema(fields)
+            }
+            TypeInfo::TupleStruct(info) => {
+                let fields = info
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !is_ignored(*index))
+                    .map(|(_, field)| self.unnamed_field_schema(field))
+                    .collect();
+                tuple_schema(fields)
+            }
+            TypeInfo::Enum(info) => {
+                let variants = info
+                    .iter()
+                    .map(|variant| self.variant_schema(variant))
+                    .collect::<Vec<_>>();
+                json!({ "oneOf": variants })
+            }
+            _ => unreachable!("only structs, tuple structs, and enums are stored as definitions"),
+        };
+
+        if let Value::Object(schema) = &mut schema {
+            schema.insert("title".to_string(), json!(get_short_name(info.type_name())));
+        }
+        #[cfg(feature = "documentation")]
+        describe(&mut schema, info.docs());
+        schema
+    }
+
+    /// Returns the schema of an externally tagged enum variant.
+    fn variant_schema(&mut self, variant: &VariantInfo) -> Value {
+        let value = match variant {
+            VariantInfo::Unit(variant) => {
+                #[allow(unused_mut)]
+                let mut schema = json!({ "const": variant.name() });
+                #[cfg(feature = "documentation")]
+                describe(&mut schema, variant.docs());
+                return schema;
+            }
+            VariantInfo::Tuple(variant) if variant.field_len() == 1 => {
+                self.unnamed_field_schema(variant.field_at(0).unwrap())
+            }
+            VariantInfo::Tuple(variant) => {
+                let fields = variant
+                    .iter()
+                    .map(|field| self.unnamed_field_schema(field))
+                    .collect();
+                tuple_schema(fields)
+            }
+            VariantInfo::Struct(variant) => {
+                let fields = variant
+                    .iter()
+                    .map(|field| (field.name(), self.named_field_schema(field)))
+                    .collect();
+                object_schema(fields)
+            }
+        };
+
+        #[allow(unused_mut)]
+        let mut schema = object_schema(vec![(variant.name(), value)]);
+        #[cfg(feature = "documentation")]
+        describe(&mut schema, variant.docs());
+        schema
+    }
+}
+
+/// Returns the schema of an object with the given required properties.
+fn object_schema(fields: Vec<(&str, Value)>) -> Value {
+    let required = fields.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    let properties = fields
+        .iter()
+        .map(|(name, schema)| (name.to_string(), schema.clone()))
+        .collect::<Map<_, _>>();
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Returns the schema of a fixed-length array containing the given items.
+fn tuple_schema(fields: Vec<Value>) -> Value {
+    let len = fields.len();
+    json!({
+        "type": "array",
+        "prefixItems": fields,
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+/// Returns the schema of a primitive value type.
+///
+/// Value types that aren't known are described by an empty schema.
+fn value_schema(type_id: TypeId) -> Value {
+    macro_rules! bounded_integer {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return json!({ "type": "integer", "minimum": <$ty>::MIN, "maximum": <$ty>::MAX });
+                }
+            )*
+        };
+    }
+
+    bounded_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+    if type_id == TypeId::of::<i128>() {
+        json!({ "type": "integer" })
+    } else if type_id == TypeId::of::<u128>() {
+        json!({ "type": "integer", "minimum": 0 })
+    } else if type_id == TypeId::of::<f32>() || type_id == TypeId::of::<f64>() {
+        json!({ "type": "number" })
+    } else if type_id == TypeId::of::<bool>() {
+        json!({ "type": "boolean" })
+    } else if type_id == TypeId::of::<char>() {
+        json!({ "type": "string", "minLength": 1, "maxLength": 1 })
+    } else if type_id == TypeId::of::<String>()
+        || type_id == TypeId::of::<&'static str>()
+        || type_id == TypeId::of::<Cow<'static, str>>()
+        || type_id == TypeId::of::<PathBuf>()
+    {
+        json!({ "type": "string" })
+    } else {
+        json!({})
+    }
+}
+
+fn is_option(info: &EnumInfo) -> bool {
+    info.type_name().starts_with("core::option::Option")
+}
+
+/// Returns the JSON pointer to the definition with the given name, encoded as a URI fragment.
+fn definition_pointer(name: &str) -> String {
+    let mut pointer = String::from("#/$defs/");
+    for c in name.chars() {
+        match c {
+            '~' => pointer.push_str("~0"),
+            '/' => pointer.push_str("~1"),
+            c if c.is_ascii_alphanumeric() || "-._:!$&'()*+,;=@".contains(c) => pointer.push(c),
+            c => {
+                let mut buffer = [0; 4];
+                for byte in c.encode_utf8(&mut buffer).bytes() {
+                    pointer.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    pointer
+}
+
+/// Adds the given docs to the schema as its description.
+#[cfg(feature = "documentation")]
+fn describe(schema: &mut Value, docs: Option<&str>) {
+    if let (Value::Object(schema), Some(docs)) = (schema, docs) {
+        schema.insert("description".to_string(), json!(docs.trim()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::TypedReflectSerializer;
+    use crate::Reflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect)]
+    struct Player {
+        name: String,
+        health: Option<u8>,
+        position: (f32, f32),
+        inventory: Vec<Item>,
+        stats: HashMap<String, u32>,
+        state: State,
+        #[reflect(skip_serializing)]
+        cache: u32,
+    }
+
+    #[derive(Reflect)]
+    struct Item(String, u32);
+
+    #[derive(Reflect)]
+    enum State {
+        Idle,
+        Walking { speed: f32 },
+        Attacking(u32),
+        Blocking(bool, f32),
+    }
+
+    #[derive(Reflect)]
+    #[reflect(no_field_bounds)]
+    struct Node {
+        value: i32,
+        children: Vec<Node>,
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        registry.register::<Item>();
+        registry.register::<State>();
+        registry.register::<Option<u8>>();
+        registry.register::<(f32, f32)>();
+        registry.register::<Vec<Item>>();
+        registry.register::<HashMap<String, u32>>();
+        registry.register::<Node>();
+        registry.register::<Vec<Node>>();
+        registry
+    }
+
+    fn schema_of<T: Reflect>(registry: &TypeRegistry) -> Value {
+        export_json_schema(registry.get(TypeId::of::<T>()).unwrap(), registry)
+    }
+
+    #[test]
+    fn should_export_struct_schema() {
+        let registry = get_registry();
+        let schema = schema_of::<Player>(&registry);
+
+        let player = definition_pointer(std::any::type_name::<Player>());
+        assert_eq!(JSON_SCHEMA_DIALECT, schema["$schema"]);
+        assert_eq!("Player", schema["title"]);
+        assert_eq!(player, schema["$ref"]);
+
+        let definitions = schema["$defs"].as_object().unwrap();
+        let definition = &definitions[std::any::type_name::<Player>()];
+        assert_eq!(
+            json!(["name", "health", "position", "inventory", "stats", "state"]),
+            definition["required"]
+        );
+        assert_eq!(json!(false), definition["additionalProperties"]);
+
+        let properties = &definition["properties"];
+        assert_eq!(json!({ "type": "string" }), properties["name"]);
+        assert_eq!(
+            json!({ "anyOf": [{ "type": "null" }, { "type": "integer", "minimum": 0, "maximum": 255 }] }),
+            properties["health"]
+        );
+        assert_eq!(
+            json!({
+                "type": "array",
+                "prefixItems": [{ "type": "number" }, { "type": "number" }],
+                "minItems": 2,
+                "maxItems": 2,
+            }),
+            properties["position"]
+        );
+        assert_eq!(
+            json!({
+                "type": "array",
+                "items": { "$ref": definition_pointer(std::any::type_name::<Item>()) },
+            }),
+            properties["inventory"]
+        );
+        assert_eq!(
+            json!({
+                "type": "object",
+                "additionalProperties": { "type": "integer", "minimum": 0, "maximum": u32::MAX },
+            }),
+            properties["stats"]
+        );
+        assert!(properties.get("cache").is_none());
+
+        assert!(definitions.contains_key(std::any::type_name::<Item>()));
+        assert!(definitions.contains_key(std::any::type_name::<State>()));
+    }
+
+    #[test]
+    fn should_export_externally_tagged_enum_schema() {
+        let registry = get_registry();
+        let schema = schema_of::<State>(&registry);
+
+        let definition = &schema["$defs"][std::any::type_name::<State>()];
+        assert_eq!(
+            json!({
+                "title": "State",
+                "oneOf": [
+                    { "const": "Idle" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Walking": {
+                                "type": "object",
+                                "properties": { "speed": { "type": "number" } },
+                                "required": ["speed"],
+                                "additionalProperties": false,
+                            },
+                        },
+                        "required": ["Walking"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Attacking": { "type": "integer", "minimum": 0, "maximum": u32::MAX },
+                        },
+                        "required": ["Attacking"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Blocking": {
+                                "type": "array",
+                                "prefixItems": [{ "type": "boolean" }, { "type": "number" }],
+                                "minItems": 2,
+                                "maxItems": 2,
+                            },
+                        },
+                        "required": ["Blocking"],
+                        "additionalProperties": false,
+                    },
+                ],
+            }),
+            *definition
+        );
+    }
+
+    #[test]
+    fn should_export_recursive_types() {
+        let registry = get_registry();
+        let schema = schema_of::<Node>(&registry);
+
+        let node = definition_pointer(std::any::type_name::<Node>());
+        let definitions = schema["$defs"].as_object().unwrap();
+        assert_eq!(1, definitions.len());
+        assert_eq!(
+            json!({ "type": "array", "items": { "$ref": node } }),
+            definitions[std::any::type_name::<Node>()]["properties"]["children"]
+        );
+    }
+
+    #[test]
+    fn should_export_schemas_for_all_registered_types() {
+        let registry = get_registry();
+        let schemas = export_json_schemas(&registry);
+
+        assert_eq!(registry.iter().count(), schemas.len());
+        assert_eq!("boolean", schemas["bool"]["type"]);
+        assert_eq!("Node", schemas[std::any::type_name::<Node>()]["title"]);
+    }
+
+    #[test]
+    fn should_match_serialized_output() {
+        let registry = get_registry();
+        let value = State::Walking { speed: 1.5 };
+
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        let output = serde_json::to_value(serializer).unwrap();
+        assert_eq!(json!({ "Walking": { "speed": 1.5 } }), output);
+
+        let item = Item("sword".to_string(), 1);
+        let serializer = TypedReflectSerializer::new(&item, &registry);
+        let output = serde_json::to_value(serializer).unwrap();
+        assert_eq!(json!(["sword", 1]), output);
+    }
+
+    #[test]
+    fn should_encode_definition_pointers() {
+        assert_eq!(
+            "#/$defs/alloc::vec::Vec%3Cfoo~1bar%3E",
+            definition_pointer("alloc::vec::Vec<foo/bar>")
+        );
+        assert_eq!("#/$defs/%5Bf32;%203%5D", definition_pointer("[f32; 3]"));
+    }
+}
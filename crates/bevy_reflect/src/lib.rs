@@ -65,6 +65,7 @@
 //! * [`Array`]
 //! * [`List`]
 //! * [`Map`]
+//! * [`Set`]
 //! * [`Struct`]
 //! * [`TupleStruct`]
 //! * [`Enum`]
@@ -125,6 +126,7 @@
 //! * [`DynamicArray`]
 //! * [`DynamicList`]
 //! * [`DynamicMap`]
+//! * [`DynamicSet`]
 //! * [`DynamicStruct`]
 //! * [`DynamicTupleStruct`]
 //! * [`DynamicEnum`]
@@ -295,6 +297,29 @@
 //! }
 //! ```
 //!
+//! Registering a type also registers the types it depends on, such as the types of its fields.
+//! This is done through [`GetTypeRegistration::register_type_dependencies`],
+//! which the derive macro implements for each of the type's reflected fields
+//! that implements [`GetTypeRegistration`].
+//! Fields whose type depends on a generic parameter are not registered this way.
+//!
+//! ```
+//! # use bevy_reflect::{Reflect, TypeRegistry};
+//! #[derive(Reflect)]
+//! struct Inventory {
+//!   items: Vec<Item>,
+//! }
+//!
+//! #[derive(Reflect)]
+//! struct Item(u32);
+//!
+//! let mut registry = TypeRegistry::empty();
+//! registry.register::<Inventory>();
+//!
+//! assert!(registry.contains(std::any::TypeId::of::<Vec<Item>>()));
+//! assert!(registry.contains(std::any::TypeId::of::<Item>()));
+//! ```
+//!
 //! ## Reflecting Traits
 //!
 //! Type data doesn't have to be tied to a trait, but it's often extremely useful to create trait type data.
@@ -399,17 +424,19 @@
 //! Another limitation is the inability to fully reflect functions and methods.
 //! Most languages offer some way of calling methods dynamically,
 //! but Rust makes this very difficult to do.
-//! For non-generic methods, this can be done by registering custom [type data] that
-//! contains function pointers.
+//! Non-generic functions and methods can be converted into a [`DynamicFunction`]
+//! and registered in the [type registry] (see the [`func`] module),
+//! but only if all their arguments and return values are reflectable.
 //! For generic methods, the same can be done but will typically require manual monomorphization
 //! (i.e. manually specifying the types the generic method can take).
 //!
 //! ## Manual Registration
 //!
 //! Since Rust doesn't provide built-in support for running initialization code before `main`,
-//! there is no way for `bevy_reflect` to automatically register types into the [type registry].
-//! This means types must manually be registered, including their desired monomorphized
-//! representations if generic.
+//! types must be manually registered into the [type registry] by default,
+//! including their desired monomorphized representations if generic.
+//!
+//! The [`auto_register`](#auto_register) feature lifts this requirement on supported platforms.
 //!
 //! # Features
 //!
@@ -437,6 +464,42 @@
 //! This can be useful for generating documentation for scripting language interop or
 //! for displaying tooltips in an editor.
 //!
+//! ## `json_schema`
+//!
+//! | Default | Dependencies    |
+//! | :-----: | :-------------: |
+//! | ❌      | [`serde_json`]  |
+//!
+//! This feature enables exporting the types of a [`TypeRegistry`] as [JSON Schema] documents
+//! using the [`json_schema`] module.
+//! The schemas describe the JSON output of the reflection serializers,
+//! allowing external tools to validate serialized data and generate editor forms.
+//!
+//! ## `binary`
+//!
+//! | Default | Dependencies  |
+//! | :-----: | :-----------: |
+//! | ❌      | [`bincode`]   |
+//!
+//! This feature enables the [`BinaryReflectSerializer`] and [`BinaryReflectDeserializer`],
+//! which store reflected values in a compact binary format.
+//! Types are written once per document and referred to by index,
+//! and struct fields are written positionally rather than by name.
+//!
+//! ## `auto_register`
+//!
+//! | Default | Dependencies                                  |
+//! | :-----: | :-------------------------------------------: |
+//! | ❌      | [`inventory`], [`bevy_reflect_derive/auto_register`] |
+//!
+//! This feature collects every non-generic type that [derives `Reflect`] at link time,
+//! so that they can all be registered at once with [`TypeRegistry::register_derived_types`].
+//! Generic types can be included by listing their concrete instantiations
+//! with the [`auto_register_types!`] macro.
+//!
+//! Link-time collection relies on platform support from the [`inventory`] crate.
+//! On unsupported platforms, such as WebAssembly, nothing is collected and types must still be registered manually.
+//!
 //! [Reflection]: https://en.wikipedia.org/wiki/Reflective_programming
 //! [Bevy]: https://bevyengine.org/
 //! [limitations]: #limitations
@@ -463,16 +526,29 @@
 //! [`smallvec`]: https://docs.rs/smallvec/latest/smallvec/
 //! [orphan rule]: https://doc.rust-lang.org/book/ch10-02-traits.html#implementing-a-trait-on-a-type:~:text=But%20we%20can%E2%80%99t,implementation%20to%20use.
 //! [`bevy_reflect_derive/documentation`]: bevy_reflect_derive
+//! [`serde_json`]: https://docs.rs/serde_json/latest/serde_json/
+//! [JSON Schema]: https://json-schema.org/
+//! [`bincode`]: https://docs.rs/bincode/latest/bincode/
+//! [`BinaryReflectSerializer`]: serde::BinaryReflectSerializer
+//! [`BinaryReflectDeserializer`]: serde::BinaryReflectDeserializer
+//! [`inventory`]: https://docs.rs/inventory/latest/inventory/
+//! [`bevy_reflect_derive/auto_register`]: bevy_reflect_derive
+//! [derives `Reflect`]: derive@crate::Reflect
+//! [`auto_register_types!`]: crate::auto_register_types
 //! [derive `Reflect`]: derive@crate::Reflect
+//! [`DynamicFunction`]: func::DynamicFunction
 #![allow(clippy::type_complexity)]
 
 mod array;
 mod fields;
 mod from_reflect;
+mod generics;
 mod list;
 mod map;
 mod path;
 mod reflect;
+mod remote;
+mod set;
 mod struct_trait;
 mod tuple;
 mod tuple_struct;
@@ -504,7 +580,12 @@ mod impls {
     pub use self::uuid::*;
 }
 
+pub mod attributes;
+pub mod diff;
 mod enums;
+pub mod func;
+#[cfg(feature = "json_schema")]
+pub mod json_schema;
 pub mod serde;
 pub mod std_traits;
 pub mod utility;
@@ -522,11 +603,14 @@ pub use array::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
+pub use generics::*;
 pub use impls::*;
 pub use list::*;
 pub use map::*;
 pub use path::*;
 pub use reflect::*;
+pub use remote::*;
+pub use set::*;
 pub use struct_trait::*;
 pub use tuple::*;
 pub use tuple_struct::*;
@@ -542,7 +626,8 @@ extern crate alloc;
 
 #[doc(hidden)]
 pub mod __macro_exports {
-    use crate::Uuid;
+    use crate::{GetTypeRegistration, TypeRegistry, Uuid};
+    use std::marker::PhantomData;
 
     /// Generates a new UUID from the given UUIDs `a` and `b`,
     /// where the bytes are generated by a bitwise `a ^ b.rotate_right(1)`.
@@ -568,6 +653,57 @@ pub mod __macro_exports {
 
         Uuid::from_bytes(new)
     }
+
+    /// A field type the `Reflect` derive registers as a dependency of its type.
+    ///
+    /// The derive calls `register_type_dependency` on a `&&TypeDependency<T>`.
+    /// Method resolution picks [`RegisterTypeDependency`] if `T` implements [`GetTypeRegistration`],
+    /// and falls back to [`SkipTypeDependency`] otherwise,
+    /// so that field types are not required to be registrable.
+    /// This is decided where the type is derived, and always falls back for field types
+    /// depending on a generic parameter.
+    pub struct TypeDependency<T>(PhantomData<fn() -> T>);
+
+    impl<T> TypeDependency<T> {
+        pub fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<T> Default for TypeDependency<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub trait RegisterTypeDependency {
+        fn register_type_dependency(&self, registry: &mut TypeRegistry);
+    }
+
+    impl<T: GetTypeRegistration> RegisterTypeDependency for &TypeDependency<T> {
+        fn register_type_dependency(&self, registry: &mut TypeRegistry) {
+            registry.register::<T>();
+        }
+    }
+
+    pub trait SkipTypeDependency {
+        fn register_type_dependency(&self, _registry: &mut TypeRegistry) {}
+    }
+
+    impl<T> SkipTypeDependency for TypeDependency<T> {}
+
+    /// Support for automatically registering reflected types.
+    #[cfg(feature = "auto_register")]
+    pub mod auto_register {
+        use crate::TypeRegistry;
+
+        pub use inventory;
+
+        /// A registration function submitted by the `Reflect` derive for a non-generic type.
+        pub struct AutomaticReflectRegistration(pub fn(&mut TypeRegistry));
+
+        inventory::collect!(AutomaticReflectRegistration);
+    }
 }
 
 #[cfg(test)]
@@ -1492,6 +1628,23 @@ mod tests {
         let info = value.get_represented_type_info().unwrap();
         assert!(info.is::<MyMap>());
 
+        // Set
+        type MySet = bevy_utils::HashSet<usize>;
+
+        let info = MySet::type_info();
+        if let TypeInfo::Set(info) = info {
+            assert!(info.is::<MySet>());
+            assert!(info.value_is::<usize>());
+            assert_eq!(std::any::type_name::<MySet>(), info.type_name());
+            assert_eq!(std::any::type_name::<usize>(), info.value_type_name());
+        } else {
+            panic!("Expected `TypeInfo::Set`");
+        }
+
+        let value: &dyn Reflect = &MySet::default();
+        let info = value.get_represented_type_info().unwrap();
+        assert!(info.is::<MySet>());
+
         // Value
         type MyValue = String;
 
@@ -1529,6 +1682,235 @@ mod tests {
         get_type_registration::<TestStruct>();
     }
 
+    #[test]
+    fn should_permit_recursive_types_without_field_bounds() {
+        #[derive(Reflect)]
+        #[reflect(no_field_bounds)]
+        struct Node {
+            value: i32,
+            children: Vec<Node>,
+        }
+
+        let node = Node {
+            value: 1,
+            children: vec![Node {
+                value: 2,
+                children: Vec::new(),
+            }],
+        };
+
+        let cloned = Node::from_reflect(&node).unwrap();
+        assert_eq!(2, cloned.children[0].value);
+
+        // generic parameters keep the bounds declared on the type itself
+        #[derive(Reflect)]
+        #[reflect(no_field_bounds)]
+        enum Tree<T: FromReflect + TypePath> {
+            Leaf(T),
+            Branch(Vec<Tree<T>>),
+        }
+
+        let tree = Tree::Branch(vec![Tree::Leaf(1_u8), Tree::Branch(Vec::new())]);
+        let Tree::Branch(children) = Tree::<u8>::from_reflect(&tree).unwrap() else {
+            panic!("expected branch");
+        };
+        assert!(matches!(children[..], [Tree::Leaf(1), Tree::Branch(_)]));
+    }
+
+    mod external_crate {
+        #[derive(Debug, PartialEq)]
+        pub struct Position {
+            pub x: f32,
+            pub y: f32,
+        }
+
+        #[derive(Debug, PartialEq)]
+        pub struct Pair<T>(pub T, pub T);
+
+        #[derive(Debug, PartialEq)]
+        pub enum Shape {
+            Point,
+            Circle(f32),
+            Rect { min: Position, max: Position },
+        }
+    }
+
+    #[reflect_remote(external_crate::Position)]
+    struct PositionWrapper {
+        x: f32,
+        y: f32,
+    }
+
+    #[reflect_remote(external_crate::Pair<T>)]
+    struct PairWrapper<T: FromReflect + TypePath>(T, T);
+
+    #[reflect_remote(external_crate::Shape)]
+    enum ShapeWrapper {
+        Point,
+        Circle(f32),
+        Rect {
+            #[reflect(remote = PositionWrapper)]
+            min: external_crate::Position,
+            #[reflect(remote = PositionWrapper)]
+            max: external_crate::Position,
+        },
+    }
+
+    #[test]
+    fn should_reflect_remote_types() {
+        let mut position = PositionWrapper(external_crate::Position { x: 1.0, y: 2.0 });
+        *position.get_field_mut::<f32>("y").unwrap() = 3.0;
+        assert_eq!(external_crate::Position { x: 1.0, y: 3.0 }, position.0);
+
+        let pair = PairWrapper(external_crate::Pair(1_usize, 2));
+        assert_eq!(Some(&2), pair.get_field::<usize>(1));
+        assert_eq!(
+            "bevy_reflect::tests::PairWrapper<usize>",
+            <PairWrapper<usize> as TypePath>::type_path()
+        );
+
+        let mut shape = ShapeWrapper(external_crate::Shape::Circle(1.0));
+        let patch = DynamicEnum::from(ShapeWrapper(external_crate::Shape::Circle(2.0)));
+        shape.apply(&patch);
+        assert_eq!(external_crate::Shape::Circle(2.0), shape.0);
+
+        let patch = DynamicEnum::from(ShapeWrapper(external_crate::Shape::Point));
+        shape.apply(&patch);
+        assert_eq!(external_crate::Shape::Point, shape.0);
+
+        let info = <ShapeWrapper as Typed>::type_info();
+        let TypeInfo::Enum(info) = info else {
+            panic!("expected enum info");
+        };
+        let VariantInfo::Struct(rect) = info.variant("Rect").unwrap() else {
+            panic!("expected struct variant");
+        };
+        assert!(rect.field("min").unwrap().is::<PositionWrapper>());
+    }
+
+    #[test]
+    fn should_reflect_remote_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Player {
+            #[reflect(remote = PositionWrapper)]
+            position: external_crate::Position,
+            #[reflect(remote = ShapeWrapper)]
+            shape: external_crate::Shape,
+        }
+
+        let mut player = Player {
+            position: external_crate::Position { x: 1.0, y: 2.0 },
+            shape: external_crate::Shape::Rect {
+                min: external_crate::Position { x: 0.0, y: 0.0 },
+                max: external_crate::Position { x: 1.0, y: 1.0 },
+            },
+        };
+
+        let position = player.field("position").unwrap();
+        assert!(position.is::<PositionWrapper>());
+        assert_eq!(Some(1.0), position.path::<f32>("x").ok().copied());
+
+        *player.path_mut::<f32>("shape.max.y").unwrap() = 5.0;
+        assert!(matches!(
+            player.shape,
+            external_crate::Shape::Rect {
+                max: external_crate::Position { y, .. },
+                ..
+            } if y == 5.0
+        ));
+
+        let cloned = Player::from_reflect(&*player.clone_value()).unwrap();
+        assert_eq!(player, cloned);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<PositionWrapper>();
+        registry.register::<ShapeWrapper>();
+        registry.register::<f32>();
+
+        let serializer = ReflectSerializer::new(&player, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"{"bevy_reflect::tests::should_reflect_remote_fields::Player":(position:(x:1.0,y:2.0),shape:Rect(min:(x:0.0,y:0.0),max:(x:1.0,y:5.0)))}"#;
+        assert_eq!(expected, output);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let value = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(player, Player::from_reflect(&*value).unwrap());
+    }
+
+    #[test]
+    fn should_reflect_generics() {
+        #[derive(Reflect)]
+        struct Foo<T, U = String, const N: usize = 4> {
+            value: T,
+            values: [U; N],
+        }
+
+        #[derive(Reflect)]
+        enum Bar<T> {
+            Value(T),
+        }
+
+        let generics = Foo::<i32, bool, 2>::type_info().generics();
+        assert_eq!(3, generics.len());
+        assert_eq!(
+            vec!["T", "U", "N"],
+            generics.iter().map(GenericInfo::name).collect::<Vec<_>>()
+        );
+
+        let GenericInfo::Type(t) = &generics[0] else {
+            panic!("expected type parameter");
+        };
+        assert!(t.is::<i32>());
+        assert_eq!("i32", t.type_path());
+        assert_eq!(None, t.default_type_id());
+
+        let GenericInfo::Type(u) = generics.get_named("U").unwrap() else {
+            panic!("expected type parameter");
+        };
+        assert!(u.is::<bool>());
+        assert!(u.default_is::<String>());
+        assert_eq!(Some("String"), u.default_type_path());
+
+        let GenericInfo::Const(n) = generics.get_named("N").unwrap() else {
+            panic!("expected const parameter");
+        };
+        assert!(n.is::<usize>());
+        assert_eq!(Some(&2), n.value().downcast_ref::<usize>());
+        assert_eq!(Some(&4), n.default().unwrap().downcast_ref::<usize>());
+
+        let generics = Bar::<f32>::type_info().generics();
+        assert!(generics.get_named("T").unwrap().is::<f32>());
+
+        #[derive(Reflect)]
+        struct NotGeneric;
+        assert!(NotGeneric::type_info().generics().is_empty());
+
+        // Manual implementations
+        let generics = <Vec<u8> as Typed>::type_info().generics();
+        assert!(generics.get_named("T").unwrap().is::<u8>());
+
+        let generics = <HashMap<String, u8> as Typed>::type_info().generics();
+        assert!(generics.get_named("K").unwrap().is::<String>());
+        assert!(generics.get_named("V").unwrap().is::<u8>());
+        let GenericInfo::Type(s) = generics.get_named("S").unwrap() else {
+            panic!("expected type parameter");
+        };
+        assert!(s.default_is::<bevy_utils::hashbrown::hash_map::DefaultHashBuilder>());
+
+        let generics = <[u8; 3] as Typed>::type_info().generics();
+        assert!(generics.get_named("T").unwrap().is::<u8>());
+        let GenericInfo::Const(n) = generics.get_named("N").unwrap() else {
+            panic!("expected const parameter");
+        };
+        assert_eq!(Some(&3), n.value().downcast_ref::<usize>());
+
+        let generics = <Option<u8> as Typed>::type_info().generics();
+        assert!(generics.get_named("T").unwrap().is::<u8>());
+    }
+
     #[test]
     fn should_permit_valid_represented_type_for_dynamic() {
         let type_info = <[i32; 2] as Typed>::type_info();
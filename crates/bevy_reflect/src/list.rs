@@ -6,7 +6,8 @@ use bevy_reflect_derive::impl_type_path;
 
 use crate::utility::reflect_hasher;
 use crate::{
-    self as bevy_reflect, FromReflect, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
+    self as bevy_reflect, FromReflect, Generics, Reflect, ReflectMut, ReflectOwned, ReflectRef,
+    TypeInfo,
 };
 
 /// A trait used to power [list-like] operations via [reflection].
@@ -112,6 +113,7 @@ pub struct ListInfo {
     type_id: TypeId,
     item_type_name: &'static str,
     item_type_id: TypeId,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -124,11 +126,17 @@ impl ListInfo {
             type_id: TypeId::of::<TList>(),
             item_type_name: std::any::type_name::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the generic parameters for this list.
+    pub fn with_generics(self, generics: Generics) -> Self {
+        Self { generics, ..self }
+    }
+
     /// Sets the docstring for this list.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -169,6 +177,11 @@ impl ListInfo {
         TypeId::of::<T>() == self.item_type_id
     }
 
+    /// The generic parameters of this list.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
     /// The docstring of this list, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
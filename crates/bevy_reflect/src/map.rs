@@ -5,7 +5,9 @@ use std::hash::Hash;
 use bevy_reflect_derive::impl_type_path;
 use bevy_utils::{Entry, HashMap};
 
-use crate::{self as bevy_reflect, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo};
+use crate::{
+    self as bevy_reflect, Generics, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
+};
 
 /// A trait used to power [map-like] operations via [reflection].
 ///
@@ -99,6 +101,7 @@ pub struct MapInfo {
     key_type_id: TypeId,
     value_type_name: &'static str,
     value_type_id: TypeId,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -113,11 +116,17 @@ impl MapInfo {
             key_type_id: TypeId::of::<TKey>(),
             value_type_name: std::any::type_name::<TValue>(),
             value_type_id: TypeId::of::<TValue>(),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the generic parameters for this map.
+    pub fn with_generics(self, generics: Generics) -> Self {
+        Self { generics, ..self }
+    }
+
     /// Sets the docstring for this map.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -175,6 +184,11 @@ impl MapInfo {
         TypeId::of::<T>() == self.value_type_id
     }
 
+    /// The generic parameters of this map.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
     /// The docstring of this map, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -57,6 +57,7 @@ pub(super) enum TypeShape {
     List,
     Array,
     Map,
+    Set,
     Enum,
     Value,
     Unit,
@@ -71,6 +72,7 @@ impl fmt::Display for TypeShape {
             TypeShape::List => "list",
             TypeShape::Array => "array",
             TypeShape::Map => "map",
+            TypeShape::Set => "set",
             TypeShape::Enum => "enum",
             TypeShape::Value => "value",
             TypeShape::Unit => "unit",
@@ -87,6 +89,7 @@ impl<'a> From<ReflectRef<'a>> for TypeShape {
             ReflectRef::List(_) => TypeShape::List,
             ReflectRef::Array(_) => TypeShape::Array,
             ReflectRef::Map(_) => TypeShape::Map,
+            ReflectRef::Set(_) => TypeShape::Set,
             ReflectRef::Enum(_) => TypeShape::Enum,
             ReflectRef::Value(_) => TypeShape::Value,
         }
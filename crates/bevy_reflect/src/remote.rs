@@ -0,0 +1,54 @@
+/// A trait for the wrappers generated by [`#[reflect_remote]`](crate::reflect_remote).
+///
+/// Since the orphan rule prevents implementing [`Reflect`] for types from other crates,
+/// such types are reflected through a `#[repr(transparent)]` wrapper.
+/// This trait allows converting between the wrapper and the [remote type] for free,
+/// which is used to reflect fields marked with `#[reflect(remote = ...)]`.
+///
+/// This trait is not meant to be implemented manually.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{reflect_remote, GetField, ReflectRemote};
+/// mod external_crate {
+///     pub struct Position {
+///         pub x: f32,
+///         pub y: f32,
+///     }
+/// }
+///
+/// #[reflect_remote(external_crate::Position)]
+/// struct PositionWrapper {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let position = external_crate::Position { x: 1.0, y: 2.0 };
+/// let wrapper = PositionWrapper::as_wrapper(&position);
+/// assert_eq!(Some(&2.0), wrapper.get_field::<f32>("y"));
+///
+/// let position: external_crate::Position = PositionWrapper(position).into_remote();
+/// assert_eq!(1.0, position.x);
+/// ```
+///
+/// [`Reflect`]: crate::Reflect
+/// [remote type]: ReflectRemote::Remote
+pub trait ReflectRemote: Sized {
+    /// The foreign type being reflected.
+    type Remote;
+
+    /// Returns a reference to the wrapped remote value.
+    fn as_remote(&self) -> &Self::Remote;
+    /// Returns a mutable reference to the wrapped remote value.
+    fn as_remote_mut(&mut self) -> &mut Self::Remote;
+    /// Returns the wrapped remote value.
+    fn into_remote(self) -> Self::Remote;
+
+    /// Interprets a reference to the remote value as a reference to this wrapper.
+    fn as_wrapper(remote: &Self::Remote) -> &Self;
+    /// Interprets a mutable reference to the remote value as a mutable reference to this wrapper.
+    fn as_wrapper_mut(remote: &mut Self::Remote) -> &mut Self;
+    /// Wraps the remote value.
+    fn into_wrapper(remote: Self::Remote) -> Self;
+}
@@ -0,0 +1,364 @@
+use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{Reflect, TypeRegistration, TypeRegistry};
+use bevy_utils::HashMap;
+use bincode::Options;
+use serde::de::{DeserializeSeed, Error, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Serialize, Serializer};
+use std::fmt::Formatter;
+use std::io::{Read, Write};
+
+/// The [`bincode`] options used by [`BinaryReflectSerializer::to_bytes`] and [`BinaryReflectDeserializer::from_bytes`].
+fn binary_options() -> impl Options {
+    bincode::DefaultOptions::new()
+}
+
+/// A serializer for a document of reflected values in a compact binary format.
+///
+/// Rather than writing the type name alongside every value like [`ReflectSerializer`],
+/// the document begins with a table of the type names it contains,
+/// and each value refers to its type by its index into that table.
+///
+/// Values are written using the [`TypedReflectSerializer`], so struct fields are encoded
+/// positionally in the order given by their [`StructInfo`], without their names.
+///
+/// The document can be written to bytes with [`to_bytes`](Self::to_bytes),
+/// or serialized with any other non-self-describing format.
+/// It is read back using a [`BinaryReflectDeserializer`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::serde::{BinaryReflectDeserializer, BinaryReflectSerializer};
+/// #[derive(Reflect)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Position>();
+///
+/// let a = Position { x: 1.0, y: 2.0 };
+/// let b = Position { x: 3.0, y: 4.0 };
+///
+/// let mut serializer = BinaryReflectSerializer::new(&registry);
+/// serializer.push(&a);
+/// serializer.push(&b);
+/// let bytes = serializer.to_bytes().unwrap();
+///
+/// let values = BinaryReflectDeserializer::new(&registry)
+///     .from_bytes(&bytes)
+///     .unwrap();
+/// assert_eq!(2, values.len());
+/// assert!(values[1].reflect_partial_eq(&b).unwrap_or_default());
+/// ```
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`StructInfo`]: crate::StructInfo
+pub struct BinaryReflectSerializer<'a> {
+    values: Vec<&'a dyn Reflect>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> BinaryReflectSerializer<'a> {
+    /// Creates an empty document.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            values: Vec::new(),
+            registry,
+        }
+    }
+
+    /// Adds a value to the end of the document.
+    pub fn push(&mut self, value: &'a dyn Reflect) {
+        self.values.push(value);
+    }
+
+    /// Serializes the document to bytes.
+    pub fn to_bytes(&self) -> bincode::Result<Vec<u8>> {
+        binary_options().serialize(self)
+    }
+
+    /// Serializes the document to the given writer.
+    pub fn to_writer<W: Write>(&self, writer: W) -> bincode::Result<()> {
+        binary_options().serialize_into(writer, self)
+    }
+}
+
+impl<'a> Serialize for BinaryReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut type_names = Vec::new();
+        let mut type_ids = HashMap::new();
+        let mut entries = Vec::with_capacity(self.values.len());
+        for value in &self.values {
+            let type_name = value.type_name();
+            let type_id = *type_ids.entry(type_name).or_insert_with(|| {
+                type_names.push(type_name);
+                type_names.len() as u32 - 1
+            });
+            entries.push(BinaryEntrySerializer {
+                type_id,
+                value: TypedReflectSerializer::new(*value, self.registry),
+            });
+        }
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&type_names)?;
+        state.serialize_element(&entries)?;
+        state.end()
+    }
+}
+
+struct BinaryEntrySerializer<'a> {
+    type_id: u32,
+    value: TypedReflectSerializer<'a>,
+}
+
+impl<'a> Serialize for BinaryEntrySerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&self.type_id)?;
+        state.serialize_element(&self.value)?;
+        state.end()
+    }
+}
+
+/// A deserializer for documents written by the [`BinaryReflectSerializer`].
+///
+/// This returns the values of the document in order.
+/// Like the [`UntypedReflectDeserializer`], non-value types are returned as their dynamic equivalents,
+/// and will need to be converted using [`FromReflect`].
+///
+/// [`UntypedReflectDeserializer`]: crate::serde::UntypedReflectDeserializer
+/// [`FromReflect`]: crate::FromReflect
+pub struct BinaryReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> BinaryReflectDeserializer<'a> {
+    /// Creates a deserializer resolving the document's types with the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+
+    /// Deserializes a document from bytes.
+    pub fn from_bytes(self, bytes: &[u8]) -> bincode::Result<Vec<Box<dyn Reflect>>> {
+        binary_options().deserialize_seed(self, bytes)
+    }
+
+    /// Deserializes a document from the given reader.
+    pub fn from_reader<R: Read>(self, reader: R) -> bincode::Result<Vec<Box<dyn Reflect>>> {
+        binary_options().deserialize_from_seed(self, reader)
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryReflectDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(
+            2,
+            BinaryDocumentVisitor {
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+struct BinaryDocumentVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryDocumentVisitor<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a type table followed by the reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let type_names: Vec<String> = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let registrations = type_names
+            .iter()
+            .map(|type_name| {
+                self.registry.get_with_name(type_name).ok_or_else(|| {
+                    Error::custom(format_args!("No registration found for `{type_name}`"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        seq.next_element_seed(BinaryEntriesDeserializer {
+            registrations: &registrations,
+            registry: self.registry,
+        })?
+        .ok_or_else(|| Error::invalid_length(1, &self))
+    }
+}
+
+struct BinaryEntriesDeserializer<'a, 'b> {
+    registrations: &'b [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'b, 'de> DeserializeSeed<'de> for BinaryEntriesDeserializer<'a, 'b> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'b, 'de> Visitor<'de> for BinaryEntriesDeserializer<'a, 'b> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a sequence of reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(BinaryEntryDeserializer {
+            registrations: self.registrations,
+            registry: self.registry,
+        })? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+struct BinaryEntryDeserializer<'a, 'b> {
+    registrations: &'b [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'b, 'de> DeserializeSeed<'de> for BinaryEntryDeserializer<'a, 'b> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'b, 'de> Visitor<'de> for BinaryEntryDeserializer<'a, 'b> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a type id followed by a reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let type_id: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let registration = self
+            .registrations
+            .get(type_id as usize)
+            .ok_or_else(|| Error::custom(format_args!("invalid type id {type_id}")))?;
+
+        seq.next_element_seed(TypedReflectDeserializer::new(registration, self.registry))?
+            .ok_or_else(|| Error::invalid_length(1, &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serde::de::tests::{get_my_struct, get_registry, MyStruct, SomeUnitStruct};
+    use crate::serde::ReflectSerializer;
+    use crate::FromReflect;
+
+    #[test]
+    fn should_round_trip() {
+        let registry = get_registry();
+        let value = get_my_struct();
+        let unit = SomeUnitStruct;
+
+        let mut serializer = BinaryReflectSerializer::new(&registry);
+        serializer.push(&value);
+        serializer.push(&unit);
+        serializer.push(&value);
+        let bytes = serializer.to_bytes().unwrap();
+
+        let output = BinaryReflectDeserializer::new(&registry)
+            .from_bytes(&bytes)
+            .unwrap();
+        assert_eq!(3, output.len());
+        assert_eq!(value, MyStruct::from_reflect(output[0].as_ref()).unwrap());
+        assert_eq!(
+            unit,
+            SomeUnitStruct::from_reflect(output[1].as_ref()).unwrap()
+        );
+        assert_eq!(value, MyStruct::from_reflect(output[2].as_ref()).unwrap());
+    }
+
+    #[test]
+    fn should_write_type_names_once() {
+        let registry = get_registry();
+        let value = get_my_struct();
+
+        let mut serializer = BinaryReflectSerializer::new(&registry);
+        serializer.push(&value);
+        serializer.push(&value);
+        let bytes = serializer.to_bytes().unwrap();
+
+        let type_name = std::any::type_name::<MyStruct>().as_bytes();
+        let occurrences = bytes
+            .windows(type_name.len())
+            .filter(|window| *window == type_name)
+            .count();
+        assert_eq!(1, occurrences);
+
+        let text = ron::ser::to_string(&ReflectSerializer::new(&value, &registry)).unwrap();
+        assert!(bytes.len() < text.len());
+    }
+
+    #[test]
+    fn should_error_on_unregistered_type() {
+        let registry = get_registry();
+        let value = get_my_struct();
+
+        let mut serializer = BinaryReflectSerializer::new(&registry);
+        serializer.push(&value);
+        let bytes = serializer.to_bytes().unwrap();
+
+        let error = BinaryReflectDeserializer::new(&TypeRegistry::default())
+            .from_bytes(&bytes)
+            .unwrap_err();
+        assert_eq!(
+            format!(
+                "No registration found for `{}`",
+                std::any::type_name::<MyStruct>()
+            ),
+            error.to_string()
+        );
+    }
+}
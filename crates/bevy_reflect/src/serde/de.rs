@@ -1,10 +1,12 @@
-use crate::serde::SerializationData;
+use crate::serde::{
+    MigrationError, ReflectDeserializeWithRegistry, SerializationData, VersionData, VERSION_FIELD,
+};
 use crate::{
-    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, Tuple, TupleInfo, TupleStruct,
-    TupleStructInfo, TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField,
-    VariantInfo,
+    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct,
+    DynamicTuple, DynamicTupleStruct, DynamicVariant, EnumInfo, List, ListInfo, Map, MapInfo,
+    NamedField, Reflect, ReflectDeserialize, ReflectFromReflect, Set, SetInfo, Struct, StructInfo,
+    StructVariantInfo, Tuple, TupleInfo, TupleStruct, TupleStructInfo, TupleVariantInfo, TypeInfo,
+    TypeRegistration, TypeRegistry, UnnamedField, VariantInfo,
 };
 use erased_serde::Deserializer;
 use serde::de::{
@@ -13,7 +15,9 @@ use serde::de::{
 };
 use serde::Deserialize;
 use std::any::TypeId;
+use std::cell::RefCell;
 use std::fmt;
+use std::fmt::Write;
 use std::fmt::{Debug, Display, Formatter};
 use std::slice::Iter;
 
@@ -328,6 +332,7 @@ impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
         let value = map.next_value_seed(TypedReflectDeserializer {
             registration,
             registry: self.registry,
+            skipped: None,
         })?;
 
         if map.next_key::<IgnoredAny>()?.is_some() {
@@ -359,6 +364,7 @@ impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
 pub struct TypedReflectDeserializer<'a> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a> TypedReflectDeserializer<'a> {
@@ -366,10 +372,86 @@ impl<'a> TypedReflectDeserializer<'a> {
         Self {
             registration,
             registry,
+            skipped: None,
+        }
+    }
+
+    /// Creates a deserializer which skips unknown struct fields instead of failing,
+    /// recording their path in `skipped`.
+    ///
+    /// Skipping a field requires a self-describing format, such as RON or JSON.
+    pub fn lenient(
+        registration: &'a TypeRegistration,
+        registry: &'a TypeRegistry,
+        skipped: &'a SkippedFields,
+    ) -> Self {
+        Self {
+            registration,
+            registry,
+            skipped: Some(skipped),
         }
     }
 }
 
+/// The unknown fields skipped by a [lenient] [`TypedReflectDeserializer`].
+///
+/// Fields are identified by their path from the deserialized value,
+/// such as `inventory.items[2].weight`.
+///
+/// [lenient]: TypedReflectDeserializer::lenient
+#[derive(Default, Debug)]
+pub struct SkippedFields {
+    path: RefCell<String>,
+    fields: RefCell<Vec<String>>,
+}
+
+impl SkippedFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes and returns the paths of the fields skipped so far.
+    pub fn take(&self) -> Vec<String> {
+        std::mem::take(&mut self.fields.borrow_mut())
+    }
+
+    fn skip(&self, name: &str) {
+        let mut path = self.path.borrow().clone();
+        push_path_segment(&mut path, PathSegment::Name(name));
+        self.fields.borrow_mut().push(path);
+    }
+}
+
+/// A segment of the path of a field, as recorded by [`SkippedFields`].
+enum PathSegment<'a> {
+    Name(&'a str),
+    Index(usize),
+}
+
+fn push_path_segment(path: &mut String, segment: PathSegment) {
+    match segment {
+        PathSegment::Name(name) if path.is_empty() => path.push_str(name),
+        PathSegment::Name(name) => write!(path, ".{name}").unwrap(),
+        PathSegment::Index(index) => write!(path, "[{index}]").unwrap(),
+    }
+}
+
+/// Runs `f` with the given segment appended to the current path of `skipped`, if any.
+fn with_path_segment<T>(
+    skipped: Option<&SkippedFields>,
+    segment: PathSegment,
+    f: impl FnOnce() -> T,
+) -> T {
+    let Some(skipped) = skipped else {
+        return f();
+    };
+    let len = skipped.path.borrow().len();
+    push_path_segment(&mut skipped.path.borrow_mut(), segment);
+    let value = f();
+    skipped.path.borrow_mut().truncate(len);
+    value
+}
+
 impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
     type Value = Box<dyn Reflect>;
 
@@ -385,27 +467,46 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
             return Ok(value);
         }
 
+        // Handle types that need the registry in order to be deserialized
+        if let Some(deserialize_with_registry) =
+            self.registration.data::<ReflectDeserializeWithRegistry>()
+        {
+            return deserialize_with_registry.deserialize(deserializer, self.registry);
+        }
+
         match self.registration.type_info() {
             TypeInfo::Struct(struct_info) => {
+                let field_names = match self.registration.data::<VersionData>() {
+                    Some(version_data) => version_data.serialized_field_names(struct_info),
+                    None => struct_info.field_names(),
+                };
                 let mut dynamic_struct = deserializer.deserialize_struct(
                     struct_info.name(),
-                    struct_info.field_names(),
+                    field_names,
                     StructVisitor {
                         struct_info,
                         registration: self.registration,
                         registry: self.registry,
+                        skipped: self.skipped,
                     },
                 )?;
                 dynamic_struct.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_struct))
             }
             TypeInfo::TupleStruct(tuple_struct_info) => {
+                let len = match self.registration.data::<VersionData>() {
+                    Some(version_data) => {
+                        version_data.max_field_len(tuple_struct_info.field_len()) + 1
+                    }
+                    None => tuple_struct_info.field_len(),
+                };
                 let mut dynamic_tuple_struct = deserializer.deserialize_tuple_struct(
                     tuple_struct_info.name(),
-                    tuple_struct_info.field_len(),
+                    len,
                     TupleStructVisitor {
                         tuple_struct_info,
                         registry: self.registry,
+                        skipped: self.skipped,
                         registration: self.registration,
                     },
                 )?;
@@ -416,6 +517,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
                     list_info,
                     registry: self.registry,
+                    skipped: self.skipped,
                 })?;
                 dynamic_list.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_list))
@@ -426,6 +528,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     ArrayVisitor {
                         array_info,
                         registry: self.registry,
+                        skipped: self.skipped,
                     },
                 )?;
                 dynamic_array.set_represented_type(Some(self.registration.type_info()));
@@ -435,16 +538,27 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
                     map_info,
                     registry: self.registry,
+                    skipped: self.skipped,
                 })?;
                 dynamic_map.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_map))
             }
+            TypeInfo::Set(set_info) => {
+                let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
+                    set_info,
+                    registry: self.registry,
+                    skipped: self.skipped,
+                })?;
+                dynamic_set.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_set))
+            }
             TypeInfo::Tuple(tuple_info) => {
                 let mut dynamic_tuple = deserializer.deserialize_tuple(
                     tuple_info.field_len(),
                     TupleVisitor {
                         tuple_info,
                         registry: self.registry,
+                        skipped: self.skipped,
                     },
                 )?;
                 dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
@@ -456,17 +570,26 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     deserializer.deserialize_option(OptionVisitor {
                         enum_info,
                         registry: self.registry,
+                        skipped: self.skipped,
                     })?
-                } else {
-                    deserializer.deserialize_enum(
-                        enum_info.name(),
-                        enum_info.variant_names(),
-                        EnumVisitor {
+                } else if self.registration.data::<VersionData>().is_some() {
+                    deserializer.deserialize_tuple(
+                        2,
+                        VersionedEnumVisitor {
                             enum_info,
                             registration: self.registration,
                             registry: self.registry,
+                            skipped: self.skipped,
                         },
                     )?
+                } else {
+                    EnumVisitor {
+                        enum_info,
+                        registration: self.registration,
+                        registry: self.registry,
+                        skipped: self.skipped,
+                    }
+                    .deserialize(deserializer)?
                 };
                 dynamic_enum.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_enum))
@@ -485,6 +608,7 @@ struct StructVisitor<'a> {
     struct_info: &'static StructInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
@@ -498,21 +622,111 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registry)
+        let Some(version_data) = self.registration.data::<VersionData>() else {
+            return visit_struct(
+                &mut map,
+                self.struct_info,
+                None,
+                self.registry,
+                self.skipped,
+            );
+        };
+
+        let mut data_version = 0;
+        let dynamic_struct = visit_struct(
+            &mut map,
+            self.struct_info,
+            Some((version_data, &mut data_version)),
+            self.registry,
+            self.skipped,
+        )?;
+        self.migrate(version_data, data_version, dynamic_struct)
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let Some(version_data) = self.registration.data::<VersionData>() else {
+            return self.visit_fields(&mut seq);
+        };
+
+        let data_version = next_version(&mut seq, version_data, self.struct_info.type_name())?;
+        let dynamic_struct = match version_data.fields_at(data_version) {
+            Some(fields) => self.visit_legacy_fields(&mut seq, version_data, fields)?,
+            None => self.visit_fields(&mut seq)?,
+        };
+        self.migrate(version_data, data_version, dynamic_struct)
+    }
+}
+
+impl<'a> StructVisitor<'a> {
+    /// Migrates data of the given version, dropping any legacy fields once done.
+    fn migrate<E: Error>(
+        &self,
+        version_data: &VersionData,
+        data_version: u32,
+        mut dynamic_struct: DynamicStruct,
+    ) -> Result<DynamicStruct, E> {
+        migrate(
+            version_data,
+            data_version,
+            &mut dynamic_struct,
+            self.struct_info.type_name(),
+        )?;
+
+        let mut output = DynamicStruct::default();
+        for field in self.struct_info.iter() {
+            if let Some(value) = dynamic_struct.field(field.name()) {
+                output.insert_boxed(field.name(), value.clone_value());
+            }
+        }
+        Ok(output)
+    }
+
+    /// Reads the fields of an older version from a sequence, in the order they were declared.
+    fn visit_legacy_fields<'de, A>(
+        &self,
+        seq: &mut A,
+        version_data: &VersionData,
+        fields: &[&str],
+    ) -> Result<DynamicStruct, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut output = DynamicStruct::default();
+        for (index, name) in fields.iter().enumerate() {
+            let field =
+                find_field(self.struct_info, Some(version_data), name).ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "unknown field `{}` in the migration fields of {}",
+                        name,
+                        self.struct_info.type_name()
+                    ))
+                })?;
+            let registration = get_registration(field.type_id(), field.type_name(), self.registry)?;
+            let value = seq
+                .next_element_seed(TypedReflectDeserializer {
+                    registration,
+                    registry: self.registry,
+                    skipped: self.skipped,
+                })?
+                .ok_or_else(|| Error::invalid_length(index, &fields.len().to_string().as_str()))?;
+            output.insert_boxed(field.name(), value);
+        }
+        Ok(output)
+    }
+
+    /// Reads the current fields of the struct from a sequence.
+    fn visit_fields<'de, A>(&self, seq: &mut A) -> Result<DynamicStruct, A::Error>
     where
         A: SeqAccess<'de>,
     {
         let mut index = 0usize;
         let mut output = DynamicStruct::default();
 
-        let ignored_len = self
-            .registration
-            .data::<SerializationData>()
-            .map(|data| data.len())
-            .unwrap_or(0);
+        let serialization_data = self.registration.data::<SerializationData>();
+        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
         let field_len = self.struct_info.field_len().saturating_sub(ignored_len);
 
         if field_len == 0 {
@@ -520,18 +734,26 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
             return Ok(output);
         }
 
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
-            registration: self
-                .struct_info
-                .get_field_registration(index, self.registry)?,
-            registry: self.registry,
-        })? {
+        while index < self.struct_info.field_len() {
+            // Skipped fields are not present in the sequence
+            if serialization_data.is_some_and(|data| data.is_ignored_field(index)) {
+                index += 1;
+                continue;
+            }
+
+            let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
+                registration: self
+                    .struct_info
+                    .get_field_registration(index, self.registry)?,
+                registry: self.registry,
+                skipped: self.skipped,
+            })?
+            else {
+                break;
+            };
             let name = self.struct_info.field_at(index).unwrap().name();
             output.insert_boxed(name, value);
             index += 1;
-            if index >= self.struct_info.field_len() {
-                break;
-            }
         }
 
         Ok(output)
@@ -541,6 +763,7 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
 struct TupleStructVisitor<'a> {
     tuple_struct_info: &'static TupleStructInfo,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
     registration: &'a TypeRegistration,
 }
 
@@ -552,6 +775,78 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
     }
 
     fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let Some(version_data) = self.registration.data::<VersionData>() else {
+            return self.visit_fields(&mut seq);
+        };
+
+        let type_name = self.tuple_struct_info.type_name();
+        let data_version = next_version(&mut seq, version_data, type_name)?;
+        let mut tuple_struct = match version_data.fields_at(data_version) {
+            Some(fields) => self.visit_legacy_fields(&mut seq, version_data, fields)?,
+            None => self.visit_fields(&mut seq)?,
+        };
+        migrate(version_data, data_version, &mut tuple_struct, type_name)?;
+
+        // Drop any legacy fields that were only needed by the migrations
+        let mut output = DynamicTupleStruct::default();
+        for field in tuple_struct
+            .iter_fields()
+            .take(self.tuple_struct_info.field_len())
+        {
+            output.insert_boxed(field.clone_value());
+        }
+        Ok(output)
+    }
+}
+
+impl<'a> TupleStructVisitor<'a> {
+    /// Reads the fields of an older version, in the order they were declared.
+    ///
+    /// Current fields are given by their index, and legacy fields by their name.
+    fn visit_legacy_fields<'de, V>(
+        &self,
+        seq: &mut V,
+        version_data: &VersionData,
+        fields: &[&str],
+    ) -> Result<DynamicTupleStruct, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut tuple_struct = DynamicTupleStruct::default();
+        for (index, name) in fields.iter().enumerate() {
+            let field = match name.parse::<usize>() {
+                Ok(field_index) => self
+                    .tuple_struct_info
+                    .field_at(field_index)
+                    .map(|field| (field.type_id(), field.type_name())),
+                Err(_) => version_data
+                    .legacy_field(name)
+                    .map(|field| (field.type_id(), field.type_name())),
+            };
+            let (type_id, type_name) = field.ok_or_else(|| {
+                Error::custom(format_args!(
+                    "unknown field `{}` in the migration fields of {}",
+                    name,
+                    self.tuple_struct_info.type_name()
+                ))
+            })?;
+            let value = seq
+                .next_element_seed(TypedReflectDeserializer {
+                    registration: get_registration(type_id, type_name, self.registry)?,
+                    registry: self.registry,
+                    skipped: self.skipped,
+                })?
+                .ok_or_else(|| Error::invalid_length(index, &fields.len().to_string().as_str()))?;
+            tuple_struct.insert_boxed(value);
+        }
+        Ok(tuple_struct)
+    }
+
+    /// Reads the current fields of the tuple struct.
+    fn visit_fields<'de, V>(&self, seq: &mut V) -> Result<DynamicTupleStruct, V::Error>
     where
         V: SeqAccess<'de>,
     {
@@ -584,9 +879,12 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
             get_registration(field.type_id(), field.type_name(), self.registry)
         };
 
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
-            registration: get_field_registration(index)?,
-            registry: self.registry,
+        while let Some(value) = with_path_segment(self.skipped, PathSegment::Index(index), || {
+            seq.next_element_seed(TypedReflectDeserializer {
+                registration: get_field_registration(index)?,
+                registry: self.registry,
+                skipped: self.skipped,
+            })
         })? {
             tuple_struct.insert_boxed(value);
             index += 1;
@@ -614,6 +912,7 @@ impl<'a, 'de> Visitor<'de> for TupleStructVisitor<'a> {
 struct TupleVisitor<'a> {
     tuple_info: &'static TupleInfo,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
@@ -627,13 +926,14 @@ impl<'a, 'de> Visitor<'de> for TupleVisitor<'a> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registry)
+        visit_tuple(&mut seq, self.tuple_info, self.registry, self.skipped)
     }
 }
 
 struct ArrayVisitor<'a> {
     array_info: &'static ArrayInfo,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
@@ -653,10 +953,15 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
             self.array_info.item_type_name(),
             self.registry,
         )?;
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
-            registration,
-            registry: self.registry,
-        })? {
+        while let Some(value) =
+            with_path_segment(self.skipped, PathSegment::Index(vec.len()), || {
+                seq.next_element_seed(TypedReflectDeserializer {
+                    registration,
+                    registry: self.registry,
+                    skipped: self.skipped,
+                })
+            })?
+        {
             vec.push(value);
         }
 
@@ -674,6 +979,7 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
 struct ListVisitor<'a> {
     list_info: &'static ListInfo,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
@@ -693,10 +999,15 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
             self.list_info.item_type_name(),
             self.registry,
         )?;
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
-            registration,
-            registry: self.registry,
-        })? {
+        while let Some(value) =
+            with_path_segment(self.skipped, PathSegment::Index(list.len()), || {
+                seq.next_element_seed(TypedReflectDeserializer {
+                    registration,
+                    registry: self.registry,
+                    skipped: self.skipped,
+                })
+            })?
+        {
             list.push_box(value);
         }
         Ok(list)
@@ -706,6 +1017,7 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
 struct MapVisitor<'a> {
     map_info: &'static MapInfo,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
@@ -733,10 +1045,12 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
         while let Some(key) = map.next_key_seed(TypedReflectDeserializer {
             registration: key_registration,
             registry: self.registry,
+            skipped: self.skipped,
         })? {
             let value = map.next_value_seed(TypedReflectDeserializer {
                 registration: value_registration,
                 registry: self.registry,
+                skipped: self.skipped,
             })?;
             dynamic_map.insert_boxed(key, value);
         }
@@ -745,10 +1059,97 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
     }
 }
 
+struct SetVisitor<'a> {
+    set_info: &'static SetInfo,
+    registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
+}
+
+impl<'a, 'de> Visitor<'de> for SetVisitor<'a> {
+    type Value = DynamicSet;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("reflected set value")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut dynamic_set = DynamicSet::default();
+        let value_registration = get_registration(
+            self.set_info.value_type_id(),
+            self.set_info.value_type_name(),
+            self.registry,
+        )?;
+        let from_reflect = value_registration.data::<ReflectFromReflect>();
+        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
+            registration: value_registration,
+            registry: self.registry,
+            skipped: self.skipped,
+        })? {
+            // Dynamic values can't be hashed, so convert them to their concrete type when possible
+            let value = from_reflect
+                .and_then(|from_reflect| from_reflect.from_reflect(value.as_ref()))
+                .unwrap_or(value);
+            dynamic_set.insert_boxed(value);
+        }
+
+        Ok(dynamic_set)
+    }
+}
+
 struct EnumVisitor<'a> {
     enum_info: &'static EnumInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EnumVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(self.enum_info.name(), self.enum_info.variant_names(), self)
+    }
+}
+
+/// Deserializes a versioned enum, which is written as a tuple of its version and value.
+struct VersionedEnumVisitor<'a> {
+    enum_info: &'static EnumInfo,
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
+}
+
+impl<'a, 'de> Visitor<'de> for VersionedEnumVisitor<'a> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("versioned reflected enum value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version_data = self.registration.data::<VersionData>().unwrap();
+        let type_name = self.enum_info.type_name();
+        let data_version = next_version(&mut seq, version_data, type_name)?;
+        let mut dynamic_enum = seq
+            .next_element_seed(EnumVisitor {
+                enum_info: self.enum_info,
+                registration: self.registration,
+                registry: self.registry,
+                skipped: self.skipped,
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        migrate(version_data, data_version, &mut dynamic_enum, type_name)?;
+        Ok(dynamic_enum)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
@@ -776,6 +1177,7 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                         struct_info,
                         registration: self.registration,
                         registry: self.registry,
+                        skipped: self.skipped,
                     },
                 )?
                 .into(),
@@ -783,9 +1185,12 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                 let field = tuple_info.field_at(0).unwrap();
                 let registration =
                     get_registration(field.type_id(), field.type_name(), self.registry)?;
-                let value = variant.newtype_variant_seed(TypedReflectDeserializer {
-                    registration,
-                    registry: self.registry,
+                let value = with_path_segment(self.skipped, PathSegment::Index(0), || {
+                    variant.newtype_variant_seed(TypedReflectDeserializer {
+                        registration,
+                        registry: self.registry,
+                        skipped: self.skipped,
+                    })
                 })?;
                 let mut dynamic_tuple = DynamicTuple::default();
                 dynamic_tuple.insert_boxed(value);
@@ -798,6 +1203,7 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                         tuple_info,
                         registration: self.registration,
                         registry: self.registry,
+                        skipped: self.skipped,
                     },
                 )?
                 .into(),
@@ -832,7 +1238,12 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
             where
                 E: Error,
             {
-                self.0.variant(variant_name).ok_or_else(|| {
+                let variant = self.0.variant(variant_name).or_else(|| {
+                    self.0
+                        .iter()
+                        .find(|variant| variant.aliases().contains(&variant_name))
+                });
+                variant.ok_or_else(|| {
                     let names = self.0.iter().map(|variant| variant.name());
                     Error::custom(format_args!(
                         "unknown variant `{}`, expected one of {:?}",
@@ -864,6 +1275,7 @@ struct StructVariantVisitor<'a> {
     struct_info: &'static StructVariantInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
@@ -877,7 +1289,13 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registry)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            None,
+            self.registry,
+            self.skipped,
+        )
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -904,6 +1322,7 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
                 .struct_info
                 .get_field_registration(index, self.registry)?,
             registry: self.registry,
+            skipped: self.skipped,
         })? {
             let name = self.struct_info.field_at(index).unwrap().name();
             output.insert_boxed(name, value);
@@ -921,6 +1340,7 @@ struct TupleVariantVisitor<'a> {
     tuple_info: &'static TupleVariantInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
@@ -946,13 +1366,14 @@ impl<'a, 'de> Visitor<'de> for TupleVariantVisitor<'a> {
             return Ok(DynamicTuple::default());
         }
 
-        visit_tuple(&mut seq, self.tuple_info, self.registry)
+        visit_tuple(&mut seq, self.tuple_info, self.registry, self.skipped)
     }
 }
 
 struct OptionVisitor<'a> {
     enum_info: &'static EnumInfo,
     registry: &'a TypeRegistry,
+    skipped: Option<&'a SkippedFields>,
 }
 
 impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
@@ -976,6 +1397,7 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
                 let de = TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    skipped: self.skipped,
                 };
                 let mut value = DynamicTuple::default();
                 value.insert_boxed(de.deserialize(deserializer)?);
@@ -1000,40 +1422,118 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
     }
 }
 
+/// Deserializes the fields of a struct-like value from a map.
+///
+/// Fields may be given by their name or by one of their aliases.
+/// For versioned types, the version entry is written to the given `u32`
+/// and any legacy fields declared by migrations are accepted as well.
 fn visit_struct<'de, T, V>(
     map: &mut V,
     info: &'static T,
+    mut versioning: Option<(&VersionData, &mut u32)>,
     registry: &TypeRegistry,
+    skipped: Option<&SkippedFields>,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
     V: MapAccess<'de>,
 {
+    let version_data = versioning.as_ref().map(|(version_data, _)| *version_data);
     let mut dynamic_struct = DynamicStruct::default();
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field = info.get_field(&key).ok_or_else(|| {
-            let fields = info.iter_fields().map(|field| field.name());
-            Error::custom(format_args!(
-                "unknown field `{}`, expected one of {:?}",
-                key,
-                ExpectedValues(fields.collect())
-            ))
-        })?;
+        if let Some((_, data_version)) = versioning.as_mut() {
+            if key == VERSION_FIELD {
+                **data_version = map.next_value()?;
+                continue;
+            }
+        }
+
+        let field = match (find_field(info, version_data, &key), skipped) {
+            (Some(field), _) => field,
+            (None, Some(skipped)) => {
+                map.next_value::<IgnoredAny>()?;
+                skipped.skip(&key);
+                continue;
+            }
+            (None, None) => {
+                let fields = info.iter_fields().map(|field| field.name());
+                return Err(Error::custom(format_args!(
+                    "unknown field `{}`, expected one of {:?}",
+                    key,
+                    ExpectedValues(fields.collect())
+                )));
+            }
+        };
         let registration = get_registration(field.type_id(), field.type_name(), registry)?;
-        let value = map.next_value_seed(TypedReflectDeserializer {
-            registration,
-            registry,
+        let value = with_path_segment(skipped, PathSegment::Name(field.name()), || {
+            map.next_value_seed(TypedReflectDeserializer {
+                registration,
+                registry,
+                skipped,
+            })
         })?;
-        dynamic_struct.insert_boxed(&key, value);
+        dynamic_struct.insert_boxed(field.name(), value);
     }
 
     Ok(dynamic_struct)
 }
 
+/// Returns the field with the given name or alias,
+/// or the legacy field with the given name if the type is versioned.
+fn find_field<'a, T: StructLikeInfo>(
+    info: &'a T,
+    version_data: Option<&'a VersionData>,
+    name: &str,
+) -> Option<&'a NamedField> {
+    info.get_field(name)
+        .or_else(|| {
+            info.iter_fields()
+                .find(|field| field.aliases().contains(&name))
+        })
+        .or_else(|| version_data.and_then(|version_data| version_data.legacy_field(name)))
+}
+
+/// Reads the version at the start of a versioned value in a sequence.
+fn next_version<'de, A>(
+    seq: &mut A,
+    version_data: &VersionData,
+    type_name: &str,
+) -> Result<u32, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    let data_version = seq
+        .next_element::<u32>()?
+        .ok_or_else(|| Error::invalid_length(0, &"a version"))?;
+    version_data
+        .check_version(data_version)
+        .map_err(|err| migration_error(type_name, data_version, err))?;
+    Ok(data_version)
+}
+
+/// Runs the migrations of a versioned value read at the given version.
+fn migrate<E: Error>(
+    version_data: &VersionData,
+    data_version: u32,
+    value: &mut dyn Reflect,
+    type_name: &str,
+) -> Result<(), E> {
+    version_data
+        .migrate(data_version, value)
+        .map_err(|err| migration_error(type_name, data_version, err))
+}
+
+fn migration_error<E: Error>(type_name: &str, data_version: u32, err: MigrationError) -> E {
+    Error::custom(format_args!(
+        "failed to migrate {type_name} from version {data_version}: {err}"
+    ))
+}
+
 fn visit_tuple<'de, T, V>(
     seq: &mut V,
     info: &T,
     registry: &TypeRegistry,
+    skipped: Option<&SkippedFields>,
 ) -> Result<DynamicTuple, V::Error>
 where
     T: TupleLikeInfo,
@@ -1049,9 +1549,12 @@ where
         get_registration(field.type_id(), field.type_name(), registry)
     };
 
-    while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
-        registration: get_field_registration(index)?,
-        registry,
+    while let Some(value) = with_path_segment(skipped, PathSegment::Index(index), || {
+        seq.next_element_seed(TypedReflectDeserializer {
+            registration: get_field_registration(index)?,
+            registry,
+            skipped,
+        })
     })? {
         tuple.insert_boxed(value);
         index += 1;
@@ -1084,7 +1587,7 @@ fn get_registration<'a, E: Error>(
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use bincode::Options;
     use std::any::TypeId;
     use std::f32::consts::PI;
@@ -1095,11 +1598,13 @@ mod tests {
     use bevy_utils::HashMap;
 
     use crate as bevy_reflect;
-    use crate::serde::{TypedReflectDeserializer, UntypedReflectDeserializer};
+    use crate::serde::{
+        SkippedFields, TypedReflectDeserializer, TypedReflectSerializer, UntypedReflectDeserializer,
+    };
     use crate::{DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypeRegistry};
 
     #[derive(Reflect, Debug, PartialEq)]
-    struct MyStruct {
+    pub(crate) struct MyStruct {
         primitive_value: i8,
         option_value: Option<String>,
         option_value_complex: Option<SomeStruct>,
@@ -1122,27 +1627,27 @@ mod tests {
     }
 
     #[derive(Reflect, Debug, PartialEq)]
-    struct SomeStruct {
+    pub(crate) struct SomeStruct {
         foo: i64,
     }
 
     #[derive(Reflect, Debug, PartialEq)]
-    struct SomeTupleStruct(String);
+    pub(crate) struct SomeTupleStruct(String);
 
     #[derive(Reflect, Debug, PartialEq)]
-    struct SomeUnitStruct;
+    pub(crate) struct SomeUnitStruct;
 
     #[derive(Reflect, Debug, PartialEq)]
-    struct SomeIgnoredStruct {
+    pub(crate) struct SomeIgnoredStruct {
         #[reflect(ignore)]
         ignored: i32,
     }
 
     #[derive(Reflect, Debug, PartialEq)]
-    struct SomeIgnoredTupleStruct(#[reflect(ignore)] i32);
+    pub(crate) struct SomeIgnoredTupleStruct(#[reflect(ignore)] i32);
 
     #[derive(Reflect, Debug, PartialEq, Deserialize)]
-    struct SomeDeserializableStruct {
+    pub(crate) struct SomeDeserializableStruct {
         foo: i64,
     }
 
@@ -1151,14 +1656,14 @@ mod tests {
     /// For testing purposes, this is just the auto-generated one from deriving.
     #[derive(Reflect, Debug, PartialEq, Deserialize)]
     #[reflect(Deserialize)]
-    struct CustomDeserialize {
+    pub(crate) struct CustomDeserialize {
         value: usize,
         #[serde(rename = "renamed")]
         inner_struct: SomeDeserializableStruct,
     }
 
     #[derive(Reflect, Debug, PartialEq)]
-    enum SomeEnum {
+    pub(crate) enum SomeEnum {
         Unit,
         NewType(usize),
         Tuple(f32, f32),
@@ -1166,7 +1671,7 @@ mod tests {
     }
 
     #[derive(Reflect, Debug, PartialEq)]
-    enum SomeIgnoredEnum {
+    pub(crate) enum SomeIgnoredEnum {
         Tuple(#[reflect(ignore)] f32, #[reflect(ignore)] f32),
         Struct {
             #[reflect(ignore)]
@@ -1174,7 +1679,7 @@ mod tests {
         },
     }
 
-    fn get_registry() -> TypeRegistry {
+    pub(crate) fn get_registry() -> TypeRegistry {
         let mut registry = TypeRegistry::default();
         registry.register::<MyStruct>();
         registry.register::<SomeStruct>();
@@ -1203,12 +1708,11 @@ mod tests {
         registry
     }
 
-    #[test]
-    fn should_deserialize() {
+    pub(crate) fn get_my_struct() -> MyStruct {
         let mut map = HashMap::new();
         map.insert(64, 32);
 
-        let expected = MyStruct {
+        MyStruct {
             primitive_value: 123,
             option_value: Some(String::from("Hello world!")),
             option_value_complex: Some(SomeStruct { foo: 123 }),
@@ -1235,7 +1739,12 @@ mod tests {
                 value: 100,
                 inner_struct: SomeDeserializableStruct { foo: 101 },
             },
-        };
+        }
+    }
+
+    #[test]
+    fn should_deserialize() {
+        let expected = get_my_struct();
 
         let input = r#"{
             "bevy_reflect::serde::de::tests::MyStruct": (
@@ -1335,6 +1844,63 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_skip_unknown_fields_when_lenient() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Item {
+            name: String,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Inventory {
+            items: Vec<Item>,
+            gold: u32,
+        }
+
+        let expected = Inventory {
+            items: vec![
+                Item {
+                    name: String::from("sword"),
+                },
+                Item {
+                    name: String::from("shield"),
+                },
+            ],
+            gold: 10,
+        };
+
+        let input = r#"(
+            items: [
+                (name: "sword"),
+                (name: "shield", weight: (value: 2.5)),
+            ],
+            silver: 5,
+            gold: 10,
+        )"#;
+
+        let mut registry = get_registry();
+        registry.register::<Inventory>();
+        registry.register::<Item>();
+        registry.register::<Vec<Item>>();
+        let registration = registry.get(TypeId::of::<Inventory>()).unwrap();
+
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let error = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut ron_deserializer)
+            .unwrap_err();
+        assert!(error.to_string().contains("unknown field `weight`"));
+
+        let skipped = SkippedFields::new();
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = TypedReflectDeserializer::lenient(registration, &registry, &skipped)
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let output = <Inventory as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(expected, output);
+        assert_eq!(vec!["items[1].weight", "silver"], skipped.take());
+    }
+
     #[test]
     fn should_deserialize_option() {
         #[derive(Reflect, Debug, PartialEq)]
@@ -1463,39 +2029,57 @@ mod tests {
     }
 
     #[test]
-    fn should_deserialize_non_self_describing_binary() {
-        let mut map = HashMap::new();
-        map.insert(64, 32);
+    fn should_deserialize_set() {
+        #[derive(Reflect, Debug, PartialEq, Eq, Hash)]
+        #[reflect(Hash, PartialEq)]
+        struct Tag {
+            name: String,
+        }
 
-        let expected = MyStruct {
-            primitive_value: 123,
-            option_value: Some(String::from("Hello world!")),
-            option_value_complex: Some(SomeStruct { foo: 123 }),
-            tuple_value: (PI, 1337),
-            list_value: vec![-2, -1, 0, 1, 2],
-            array_value: [-2, -1, 0, 1, 2],
-            map_value: map,
-            struct_value: SomeStruct { foo: 999999999 },
-            tuple_struct_value: SomeTupleStruct(String::from("Tuple Struct")),
-            unit_struct: SomeUnitStruct,
-            unit_enum: SomeEnum::Unit,
-            newtype_enum: SomeEnum::NewType(123),
-            tuple_enum: SomeEnum::Tuple(1.23, 3.21),
-            struct_enum: SomeEnum::Struct {
-                foo: String::from("Struct variant value"),
-            },
-            ignored_struct: SomeIgnoredStruct { ignored: 0 },
-            ignored_tuple_struct: SomeIgnoredTupleStruct(0),
-            ignored_struct_variant: SomeIgnoredEnum::Struct {
-                foo: String::default(),
-            },
-            ignored_tuple_variant: SomeIgnoredEnum::Tuple(0.0, 0.0),
-            custom_deserialize: CustomDeserialize {
-                value: 100,
-                inner_struct: SomeDeserializableStruct { foo: 101 },
-            },
+        #[derive(Reflect, Debug, PartialEq)]
+        struct SetTest {
+            tags: bevy_utils::HashSet<Tag>,
+        }
+
+        let expected = SetTest {
+            tags: bevy_utils::HashSet::from_iter([
+                Tag {
+                    name: String::from("foo"),
+                },
+                Tag {
+                    name: String::from("bar"),
+                },
+            ]),
         };
 
+        let mut registry = get_registry();
+        registry.register::<SetTest>();
+        registry.register::<Tag>();
+        registry.register::<bevy_utils::HashSet<Tag>>();
+
+        let input = r#"{
+            "bevy_reflect::serde::de::tests::should_deserialize_set::SetTest": (
+                tags: [
+                    (name: "foo"),
+                    (name: "bar"),
+                ],
+            ),
+        }"#;
+
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let output = <SetTest as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn should_deserialize_non_self_describing_binary() {
+        let expected = get_my_struct();
+
         let registry = get_registry();
 
         let input = vec![
@@ -1526,38 +2110,53 @@ mod tests {
     }
 
     #[test]
-    fn should_deserialize_self_describing_binary() {
-        let mut map = HashMap::new();
-        map.insert(64, 32);
+    fn should_deserialize_non_self_describing_binary_with_skipped_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Skipping {
+            first: u8,
+            #[reflect(skip_serializing)]
+            #[reflect(default = "skipped_default")]
+            skipped: u32,
+            last: String,
+        }
 
-        let expected = MyStruct {
-            primitive_value: 123,
-            option_value: Some(String::from("Hello world!")),
-            option_value_complex: Some(SomeStruct { foo: 123 }),
-            tuple_value: (PI, 1337),
-            list_value: vec![-2, -1, 0, 1, 2],
-            array_value: [-2, -1, 0, 1, 2],
-            map_value: map,
-            struct_value: SomeStruct { foo: 999999999 },
-            tuple_struct_value: SomeTupleStruct(String::from("Tuple Struct")),
-            unit_struct: SomeUnitStruct,
-            unit_enum: SomeEnum::Unit,
-            newtype_enum: SomeEnum::NewType(123),
-            tuple_enum: SomeEnum::Tuple(1.23, 3.21),
-            struct_enum: SomeEnum::Struct {
-                foo: String::from("Struct variant value"),
-            },
-            ignored_struct: SomeIgnoredStruct { ignored: 0 },
-            ignored_tuple_struct: SomeIgnoredTupleStruct(0),
-            ignored_struct_variant: SomeIgnoredEnum::Struct {
-                foo: String::default(),
-            },
-            ignored_tuple_variant: SomeIgnoredEnum::Tuple(0.0, 0.0),
-            custom_deserialize: CustomDeserialize {
-                value: 100,
-                inner_struct: SomeDeserializableStruct { foo: 101 },
-            },
+        fn skipped_default() -> u32 {
+            7
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Skipping>();
+        registry.register::<u8>();
+        registry.register::<u32>();
+        registry.register::<String>();
+        let registration = registry.get(TypeId::of::<Skipping>()).unwrap();
+
+        let value = Skipping {
+            first: 1,
+            skipped: 123,
+            last: String::from("last"),
+        };
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        let input = bincode::serialize(&serializer).unwrap();
+
+        let deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let dynamic_output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(deserializer, &input)
+            .unwrap();
+
+        let output = <Skipping as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        let expected = Skipping {
+            first: 1,
+            skipped: 7,
+            last: String::from("last"),
         };
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn should_deserialize_self_describing_binary() {
+        let expected = get_my_struct();
 
         let registry = get_registry();
 
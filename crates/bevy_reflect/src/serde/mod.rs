@@ -1,10 +1,18 @@
+#[cfg(feature = "binary")]
+mod binary;
 mod de;
 mod ser;
 mod type_data;
+mod version;
+mod with_registry;
 
+#[cfg(feature = "binary")]
+pub use binary::*;
 pub use de::*;
 pub use ser::*;
 pub use type_data::*;
+pub use version::*;
+pub use with_registry::*;
 
 #[cfg(test)]
 mod tests {
@@ -12,9 +20,10 @@ mod tests {
     use crate::{
         serde::{ReflectSerializer, UntypedReflectDeserializer},
         type_registry::TypeRegistry,
-        DynamicStruct, Reflect,
+        DynamicStruct, FromReflect, Reflect,
     };
     use serde::de::DeserializeSeed;
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_serialization_struct() {
@@ -93,6 +102,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_serialization_set_without_hash() {
+        #[derive(Debug, Reflect, PartialEq, Eq, PartialOrd, Ord)]
+        struct Rank(u8);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<u8>();
+        registry.register::<Rank>();
+        registry.register::<BTreeSet<Rank>>();
+
+        let set = BTreeSet::from_iter([Rank(1), Rank(2)]);
+
+        let serializer = ReflectSerializer::new(&set, &registry);
+        let serialized =
+            ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default()).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(
+            Some(set),
+            <BTreeSet<Rank> as FromReflect>::from_reflect(&*value)
+        );
+    }
+
     #[test]
     #[should_panic(expected = "cannot get type info for bevy_reflect::struct_trait::DynamicStruct")]
     fn unproxied_dynamic_should_not_serialize() {
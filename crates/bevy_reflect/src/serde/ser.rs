@@ -1,6 +1,7 @@
+use crate::serde::ReflectSerializeWithRegistry;
 use crate::{
-    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
-    TypeInfo, TypeRegistry, VariantInfo, VariantType,
+    Array, Enum, EnumInfo, List, Map, Reflect, ReflectRef, ReflectSerialize, Set, Struct, Tuple,
+    TupleStruct, TypeInfo, TypeRegistry, VariantInfo, VariantType,
 };
 use serde::ser::{
     Error, SerializeStruct, SerializeStructVariant, SerializeTuple, SerializeTupleStruct,
@@ -11,7 +12,7 @@ use serde::{
     Serialize,
 };
 
-use super::SerializationData;
+use super::{SerializationData, VersionData, VERSION_FIELD};
 
 pub enum Serializable<'a> {
     Owned(Box<dyn erased_serde::Serialize + 'a>),
@@ -93,6 +94,14 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        // Handle types that need the registry in order to be serialized
+        if let Some(serialize_with_registry) = self
+            .registry
+            .get_type_data::<ReflectSerializeWithRegistry>(self.value.type_id())
+        {
+            return serialize_with_registry.serialize(self.value, serializer, self.registry);
+        }
+
         // Handle both Value case and types that have a custom `Serialize`
         let serializable = get_serializable::<S::Error>(self.value, self.registry);
         if let Ok(serializable) = serializable {
@@ -130,6 +139,11 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
                 registry: self.registry,
             }
             .serialize(serializer),
+            ReflectRef::Set(value) => SetSerializer {
+                set: value,
+                registry: self.registry,
+            }
+            .serialize(serializer),
             ReflectRef::Enum(value) => EnumSerializer {
                 enum_value: value,
                 registry: self.registry,
@@ -185,16 +199,20 @@ impl<'a> Serialize for StructSerializer<'a> {
             }
         };
 
-        let serialization_data = self
-            .registry
-            .get(type_info.type_id())
-            .and_then(|registration| registration.data::<SerializationData>());
+        let registration = self.registry.get(type_info.type_id());
+        let serialization_data =
+            registration.and_then(|registration| registration.data::<SerializationData>());
+        let version_data = registration.and_then(|registration| registration.data::<VersionData>());
         let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
         let mut state = serializer.serialize_struct(
             struct_info.name(),
-            self.struct_value.field_len() - ignored_len,
+            self.struct_value.field_len() - ignored_len + usize::from(version_data.is_some()),
         )?;
 
+        if let Some(version_data) = version_data {
+            state.serialize_field(VERSION_FIELD, &version_data.version())?;
+        }
+
         for (index, value) in self.struct_value.iter_fields().enumerate() {
             if serialization_data
                 .map(|data| data.is_ignored_field(index))
@@ -238,16 +256,20 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
             }
         };
 
-        let serialization_data = self
-            .registry
-            .get(type_info.type_id())
-            .and_then(|registration| registration.data::<SerializationData>());
+        let registration = self.registry.get(type_info.type_id());
+        let serialization_data =
+            registration.and_then(|registration| registration.data::<SerializationData>());
+        let version_data = registration.and_then(|registration| registration.data::<VersionData>());
         let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
         let mut state = serializer.serialize_tuple_struct(
             tuple_struct_info.name(),
-            self.tuple_struct.field_len() - ignored_len,
+            self.tuple_struct.field_len() - ignored_len + usize::from(version_data.is_some()),
         )?;
 
+        if let Some(version_data) = version_data {
+            state.serialize_field(&version_data.version())?;
+        }
+
         for (index, value) in self.tuple_struct.iter_fields().enumerate() {
             if serialization_data
                 .map(|data| data.is_ignored_field(index))
@@ -287,6 +309,46 @@ impl<'a> Serialize for EnumSerializer<'a> {
             }
         };
 
+        // Versioned enums are written as a tuple of their version and value
+        if let Some(version_data) = self
+            .registry
+            .get(type_info.type_id())
+            .and_then(|registration| registration.data::<VersionData>())
+        {
+            let mut state = serializer.serialize_tuple(2)?;
+            state.serialize_element(&version_data.version())?;
+            state.serialize_element(&VariantSerializer {
+                enum_serializer: self,
+                enum_info,
+            })?;
+            return state.end();
+        }
+
+        self.serialize_variant(serializer, enum_info)
+    }
+}
+
+/// Serializes the variant of an [`EnumSerializer`], without its version.
+struct VariantSerializer<'a> {
+    enum_serializer: &'a EnumSerializer<'a>,
+    enum_info: &'static EnumInfo,
+}
+
+impl<'a> Serialize for VariantSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.enum_serializer
+            .serialize_variant(serializer, self.enum_info)
+    }
+}
+
+impl<'a> EnumSerializer<'a> {
+    fn serialize_variant<S>(&self, serializer: S, enum_info: &EnumInfo) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
         let enum_name = enum_info.name();
         let variant_index = self.enum_value.variant_index() as u32;
         let variant_info = enum_info
@@ -413,6 +475,24 @@ impl<'a> Serialize for MapSerializer<'a> {
     }
 }
 
+pub struct SetSerializer<'a> {
+    pub set: &'a dyn Set,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for SetSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.set.len()))?;
+        for value in self.set.iter() {
+            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+        }
+        state.end()
+    }
+}
+
 pub struct ListSerializer<'a> {
     pub list: &'a dyn List,
     pub registry: &'a TypeRegistry,
@@ -755,6 +835,38 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_serialize_set() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct SetTest {
+            values: std::collections::BTreeSet<u32>,
+        }
+
+        let value = SetTest {
+            values: std::collections::BTreeSet::from([3, 1, 2]),
+        };
+
+        let registry = get_registry();
+        let serializer = ReflectSerializer::new(&value, &registry);
+
+        let config = PrettyConfig::default()
+            .new_line(String::from("\n"))
+            .indentor(String::from("    "));
+
+        let output = ron::ser::to_string_pretty(&serializer, config).unwrap();
+        let expected = r#"{
+    "bevy_reflect::serde::ser::tests::should_serialize_set::SetTest": (
+        values: [
+            1,
+            2,
+            3,
+        ],
+    ),
+}"#;
+
+        assert_eq!(expected, output);
+    }
+
     #[test]
     fn should_serialize_non_self_describing_binary() {
         let mut map = HashMap::new();
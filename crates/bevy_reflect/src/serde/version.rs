@@ -0,0 +1,615 @@
+use crate::{NamedField, Reflect, StructInfo};
+use bevy_utils::HashSet;
+use once_cell::sync::{Lazy, OnceCell};
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex, PoisonError};
+use thiserror::Error;
+
+/// The name of the entry holding the version of a versioned struct in serialized data.
+///
+/// Tuple structs and enums don't have named entries,
+/// so their version is written as the first element of their serialized data instead.
+pub const VERSION_FIELD: &str = "__version";
+
+/// An error returned while migrating serialized data to the current version of a type.
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    /// The data was serialized by a newer version of the type.
+    #[error("data has version {found}, which is newer than the current version {current}")]
+    UnknownVersion { found: u32, current: u32 },
+    /// A field expected by the migration was not present.
+    #[error("missing field `{0}`")]
+    MissingField(String),
+    /// A field did not have the type expected by the migration.
+    #[error("field `{field}` should be of type `{expected}`")]
+    MismatchedType {
+        field: String,
+        expected: &'static str,
+    },
+    /// The migration was written for a different kind of value than the one being deserialized.
+    #[error("expected the data to be a `{expected}`")]
+    MismatchedData { expected: &'static str },
+    /// A custom error returned by the migration.
+    #[error("{0}")]
+    Custom(String),
+}
+
+type MigrationFn = dyn Fn(&mut dyn Reflect) -> Result<(), MigrationError> + Send + Sync;
+
+/// A function that upgrades serialized data from one version of a type to the next.
+///
+/// Migrations operate on the dynamic value produced by the deserializer,
+/// before it is converted into the concrete type with [`FromReflect`]:
+/// a [`DynamicStruct`] for structs, a [`DynamicTupleStruct`] for tuple structs
+/// and a [`DynamicEnum`] for enums.
+/// Fields and variants that were renamed can be handled with `#[reflect(alias = "old_name")]` instead.
+///
+/// Fields which no longer exist on the type can't be deserialized by default,
+/// as their types are unknown.
+/// These can be declared with [`Migration::with_legacy_field`] so that they are available to the migration.
+/// Legacy fields are dropped once all migrations have run.
+///
+/// Formats without field names, such as [`bincode`], read the fields of older data
+/// in the order declared by [`Migration::with_fields`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{DynamicStruct, Reflect, Struct, TypeRegistry};
+/// # use bevy_reflect::serde::{Migration, MigrationError};
+/// #[derive(Reflect)]
+/// #[reflect(version = 2)]
+/// struct Player {
+///     // Version 1 stored health as a whole number called `hp`
+///     health: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// registry.register_migration::<Player>(
+///     Migration::new(1, |player: &mut DynamicStruct| {
+///         let hp = player
+///             .field("hp")
+///             .and_then(|hp| hp.downcast_ref::<u32>())
+///             .ok_or_else(|| MigrationError::MissingField(String::from("hp")))?;
+///         player.insert("health", *hp as f32);
+///         Ok(())
+///     })
+///     .with_legacy_field::<u32>("hp"),
+/// );
+/// ```
+///
+/// [`FromReflect`]: crate::FromReflect
+/// [`DynamicStruct`]: crate::DynamicStruct
+/// [`DynamicTupleStruct`]: crate::DynamicTupleStruct
+/// [`DynamicEnum`]: crate::DynamicEnum
+/// [`bincode`]: https://docs.rs/bincode
+#[derive(Clone)]
+pub struct Migration {
+    from_version: u32,
+    legacy_fields: Vec<NamedField>,
+    fields: Option<&'static [&'static str]>,
+    func: Arc<MigrationFn>,
+}
+
+impl Migration {
+    /// Creates a migration from the given version to the next.
+    ///
+    /// `T` is the dynamic type produced by the deserializer for the migrated type,
+    /// such as [`DynamicStruct`](crate::DynamicStruct).
+    pub fn new<T: Reflect>(
+        from_version: u32,
+        func: impl Fn(&mut T) -> Result<(), MigrationError> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            from_version,
+            legacy_fields: Vec::new(),
+            fields: None,
+            func: Arc::new(move |value: &mut dyn Reflect| {
+                let value = value
+                    .downcast_mut::<T>()
+                    .ok_or(MigrationError::MismatchedData {
+                        expected: std::any::type_name::<T>(),
+                    })?;
+                func(value)
+            }),
+        }
+    }
+
+    /// Declares a field of type `T` which was present in older data but has since been removed.
+    ///
+    /// The type `T` must be registered in order to deserialize the field.
+    pub fn with_legacy_field<T: Reflect>(mut self, name: &'static str) -> Self {
+        self.legacy_fields.push(NamedField::new::<T>(name));
+        self
+    }
+
+    /// Declares the fields of data at this migration's version, in the order they are serialized.
+    ///
+    /// This is only used by formats without field names, which would otherwise read older data
+    /// as if it had the fields of the next version.
+    /// Each name is either a current field, one of its aliases, or a legacy field.
+    /// The current fields of tuple structs are given by their index, such as `"0"`.
+    pub fn with_fields(mut self, fields: &'static [&'static str]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// The version this migration upgrades from.
+    pub fn from_version(&self) -> u32 {
+        self.from_version
+    }
+
+    /// The fields declared with [`Migration::with_legacy_field`].
+    pub fn legacy_fields(&self) -> &[NamedField] {
+        &self.legacy_fields
+    }
+
+    /// The fields declared with [`Migration::with_fields`], if any.
+    pub fn fields(&self) -> Option<&'static [&'static str]> {
+        self.fields
+    }
+
+    /// Runs the migration on the given value.
+    pub fn migrate(&self, value: &mut dyn Reflect) -> Result<(), MigrationError> {
+        (self.func)(value)
+    }
+}
+
+impl Debug for Migration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Migration")
+            .field("from_version", &self.from_version)
+            .field("legacy_fields", &self.legacy_fields)
+            .field("fields", &self.fields)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Contains the version of a type's serialized representation,
+/// along with the [migrations] used to upgrade data from older versions.
+///
+/// This is registered for types using `#[reflect(version = N)]`.
+/// The version is emitted alongside the fields of the type when serializing,
+/// and data with an older version is migrated when deserializing.
+///
+/// Struct data without a version is considered to be version 0.
+/// The version of tuple structs and enums is a required first element instead,
+/// so their data can only be migrated if it was written after they were given a version.
+///
+/// [migrations]: Migration
+#[derive(Debug, Clone)]
+pub struct VersionData {
+    version: u32,
+    migrations: BTreeMap<u32, Migration>,
+    field_names: OnceCell<&'static [&'static str]>,
+}
+
+impl VersionData {
+    /// Creates a new `VersionData` for the given version, with no migrations.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: BTreeMap::new(),
+            field_names: OnceCell::new(),
+        }
+    }
+
+    /// The current version of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Adds a migration, replacing any existing migration from the same version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the migration doesn't upgrade from a version older than the current one.
+    pub fn add_migration(&mut self, migration: Migration) {
+        assert!(
+            migration.from_version < self.version,
+            "cannot add a migration from version {}, as the current version is {}",
+            migration.from_version,
+            self.version
+        );
+        self.migrations.insert(migration.from_version, migration);
+        // Legacy fields may have been added
+        self.field_names = OnceCell::new();
+    }
+
+    /// Returns the migration from the given version, if any.
+    pub fn get_migration(&self, from_version: u32) -> Option<&Migration> {
+        self.migrations.get(&from_version)
+    }
+
+    /// Returns the legacy field with the given name from any of the migrations.
+    pub fn legacy_field(&self, name: &str) -> Option<&NamedField> {
+        self.migrations
+            .values()
+            .flat_map(Migration::legacy_fields)
+            .find(|field| field.name() == name)
+    }
+
+    /// Returns the fields of data at the given version in the order they are serialized,
+    /// or `None` if they are the same as the current fields.
+    ///
+    /// Data is assumed to have the same fields as the next version,
+    /// unless the migration from its version declares them with [`Migration::with_fields`].
+    pub fn fields_at(&self, version: u32) -> Option<&'static [&'static str]> {
+        self.migrations
+            .range(version..self.version)
+            .find_map(|(_, migration)| migration.fields)
+    }
+
+    /// Returns the greatest number of fields data may have at any version, excluding its version.
+    pub(crate) fn max_field_len(&self, field_len: usize) -> usize {
+        self.migrations
+            .values()
+            .filter_map(|migration| migration.fields.map(<[_]>::len))
+            .fold(field_len, usize::max)
+    }
+
+    /// Returns the names of the entries in serialized data, including [`VERSION_FIELD`]
+    /// and the legacy fields.
+    pub(crate) fn serialized_field_names(&self, info: &StructInfo) -> &'static [&'static str] {
+        self.field_names.get_or_init(|| {
+            let names = std::iter::once(VERSION_FIELD)
+                .chain(info.field_names().iter().copied())
+                .chain(
+                    self.migrations
+                        .values()
+                        .flat_map(|migration| migration.legacy_fields.iter().map(NamedField::name)),
+                )
+                .collect::<Vec<_>>();
+            intern_field_names(names)
+        })
+    }
+
+    /// Returns an error if data with the given version can't be migrated.
+    pub fn check_version(&self, data_version: u32) -> Result<(), MigrationError> {
+        if data_version > self.version {
+            return Err(MigrationError::UnknownVersion {
+                found: data_version,
+                current: self.version,
+            });
+        }
+        Ok(())
+    }
+
+    /// Runs every migration from `data_version` up to the current version, in order.
+    ///
+    /// Versions without a migration are skipped.
+    pub fn migrate(
+        &self,
+        data_version: u32,
+        value: &mut dyn Reflect,
+    ) -> Result<(), MigrationError> {
+        self.check_version(data_version)?;
+
+        for (_, migration) in self.migrations.range(data_version..self.version) {
+            migration.migrate(value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns a `'static` copy of the given field names, as required by serde.
+///
+/// Each distinct list of names is only leaked once,
+/// no matter how many registries contain the type they belong to.
+fn intern_field_names(names: Vec<&'static str>) -> &'static [&'static str] {
+    static FIELD_NAMES: Lazy<Mutex<HashSet<&'static [&'static str]>>> = Lazy::new(Default::default);
+
+    let mut interned = FIELD_NAMES.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(names) = interned.get(names.as_slice()) {
+        return names;
+    }
+    let names: &'static [&'static str] = Box::leak(names.into_boxed_slice());
+    interned.insert(names);
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer};
+    use crate::{
+        DynamicEnum, DynamicStruct, DynamicTupleStruct, Enum, FromReflect, ReflectRef, Struct,
+        TupleStruct, TypeInfo, TypeRegistry,
+    };
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(version = 2)]
+    struct Player {
+        #[reflect(alias = "name")]
+        display_name: String,
+        health: f32,
+        stance: Stance,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Stance {
+        #[reflect(alias = "Crouching")]
+        Crouched,
+        Standing,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(version = 1)]
+    struct Rgb(f32, f32, f32);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(version = 1)]
+    enum Weapon {
+        #[reflect(alias = "Blade")]
+        Sword {
+            damage: f32,
+        },
+        Bow,
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Stance>();
+        registry.register::<String>();
+        registry.register::<f32>();
+        registry.register::<u8>();
+        registry.register::<u32>();
+        registry.register::<Rgb>();
+        registry.register::<Weapon>();
+        registry.register_migration::<Player>(
+            Migration::new(0, |player: &mut DynamicStruct| {
+                let hp = player
+                    .field("hp")
+                    .and_then(|hp| hp.downcast_ref::<u32>())
+                    .ok_or_else(|| MigrationError::MissingField(String::from("hp")))?;
+                player.insert("health", *hp as f32);
+                Ok(())
+            })
+            .with_legacy_field::<u32>("hp")
+            .with_fields(&["name", "hp", "stance"]),
+        );
+        registry.register_migration::<Player>(Migration::new(1, |player: &mut DynamicStruct| {
+            let health = player
+                .field_mut("health")
+                .and_then(|health| health.downcast_mut::<f32>())
+                .ok_or_else(|| MigrationError::MismatchedType {
+                    field: String::from("health"),
+                    expected: "f32",
+                })?;
+            *health /= 100.0;
+            Ok(())
+        }));
+        // Version 0 stored the channels as bytes
+        registry.register_migration::<Rgb>(
+            Migration::new(0, |rgb: &mut DynamicTupleStruct| {
+                let mut output = DynamicTupleStruct::default();
+                for (index, channel) in rgb.iter_fields().enumerate() {
+                    let channel = channel.downcast_ref::<u8>().ok_or_else(|| {
+                        MigrationError::MismatchedType {
+                            field: index.to_string(),
+                            expected: "u8",
+                        }
+                    })?;
+                    output.insert(*channel as f32 / 255.0);
+                }
+                *rgb = output;
+                Ok(())
+            })
+            .with_legacy_field::<u8>("r")
+            .with_legacy_field::<u8>("g")
+            .with_legacy_field::<u8>("b")
+            .with_fields(&["r", "g", "b"]),
+        );
+        registry.register_migration::<Weapon>(Migration::new(0, |weapon: &mut DynamicEnum| {
+            if let Some(damage) = weapon
+                .field_mut("damage")
+                .and_then(|damage| damage.downcast_mut::<f32>())
+            {
+                *damage /= 100.0;
+            }
+            Ok(())
+        }));
+        registry
+    }
+
+    fn deserialize_dynamic(input: &str, registry: &TypeRegistry) -> Box<dyn Reflect> {
+        let registration = registry.get(std::any::TypeId::of::<Player>()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, registry);
+        reflect_deserializer.deserialize(&mut deserializer).unwrap()
+    }
+
+    fn deserialize<T: FromReflect>(input: &str, registry: &TypeRegistry) -> Result<T, ron::Error> {
+        let registration = registry.get(std::any::TypeId::of::<T>()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, registry);
+        let value = reflect_deserializer.deserialize(&mut deserializer)?;
+        Ok(T::from_reflect(value.as_ref()).unwrap())
+    }
+
+    fn deserialize_bincode<T: FromReflect>(input: &[u8], registry: &TypeRegistry) -> T {
+        let registration = registry.get(std::any::TypeId::of::<T>()).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, registry);
+        let value = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, input)
+            .unwrap();
+        T::from_reflect(value.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn should_serialize_version() {
+        let registry = get_registry();
+        let player = Player {
+            display_name: String::from("Alice"),
+            health: 0.5,
+            stance: Stance::Standing,
+        };
+
+        let serializer = ReflectSerializer::new(&player, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"{"bevy_reflect::serde::version::tests::Player":(__version:2,display_name:"Alice",health:0.5,stance:Standing)}"#;
+        assert_eq!(expected, output);
+
+        let serializer = TypedReflectSerializer::new(&player, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(player, deserialize::<Player>(&output, &registry).unwrap());
+    }
+
+    #[test]
+    fn should_migrate_from_older_versions() {
+        let registry = get_registry();
+        let expected = Player {
+            display_name: String::from("Alice"),
+            health: 0.5,
+            stance: Stance::Crouched,
+        };
+
+        // Data without a version is treated as version 0
+        let input = r#"(name: "Alice", hp: 50, stance: Crouching)"#;
+        assert_eq!(expected, deserialize::<Player>(input, &registry).unwrap());
+
+        let input = r#"(__version: 1, display_name: "Alice", health: 50.0, stance: Crouched)"#;
+        assert_eq!(expected, deserialize::<Player>(input, &registry).unwrap());
+
+        let input = r#"(__version: 2, display_name: "Alice", health: 0.5, stance: Crouched)"#;
+        assert_eq!(expected, deserialize::<Player>(input, &registry).unwrap());
+    }
+
+    #[test]
+    fn should_handle_aliases_and_legacy_fields_at_current_version() {
+        let registry = get_registry();
+        let input = r#"(__version: 2, name: "Alice", hp: 50, health: 0.5, stance: Crouching)"#;
+        let value = deserialize_dynamic(input, &registry);
+        let ReflectRef::Struct(value) = value.reflect_ref() else {
+            panic!("expected a struct");
+        };
+
+        assert_eq!(3, value.field_len());
+        assert!(value.field("hp").is_none());
+        assert_eq!(
+            Some(&String::from("Alice")),
+            value
+                .field("display_name")
+                .and_then(|name| name.downcast_ref::<String>())
+        );
+    }
+
+    #[test]
+    fn should_migrate_without_field_names() {
+        let registry = get_registry();
+        let expected = Player {
+            display_name: String::from("Alice"),
+            health: 0.5,
+            stance: Stance::Crouched,
+        };
+        let bincode = bincode::DefaultOptions::new().with_fixint_encoding();
+
+        // Version 0 has the fields declared by its migration
+        let input = bincode.serialize(&(0u32, "Alice", 50u32, 0u32)).unwrap();
+        assert_eq!(expected, deserialize_bincode::<Player>(&input, &registry));
+
+        // Version 1 has the same fields as the current version
+        let input = bincode.serialize(&(1u32, "Alice", 50f32, 0u32)).unwrap();
+        assert_eq!(expected, deserialize_bincode::<Player>(&input, &registry));
+
+        let serializer = TypedReflectSerializer::new(&expected, &registry);
+        let output = bincode.serialize(&serializer).unwrap();
+        assert_eq!(expected, deserialize_bincode::<Player>(&output, &registry));
+    }
+
+    #[test]
+    fn should_migrate_tuple_structs() {
+        let registry = get_registry();
+        let rgb = Rgb(0.0, 1.0, 0.0);
+
+        let output = ron::ser::to_string(&TypedReflectSerializer::new(&rgb, &registry)).unwrap();
+        assert_eq!("(1,0.0,1.0,0.0)", output);
+        assert_eq!(rgb, deserialize::<Rgb>(&output, &registry).unwrap());
+
+        assert_eq!(
+            rgb,
+            deserialize::<Rgb>("(0, 0, 255, 0)", &registry).unwrap()
+        );
+
+        let bincode = bincode::DefaultOptions::new().with_fixint_encoding();
+        let input = bincode.serialize(&(0u32, 0u8, 255u8, 0u8)).unwrap();
+        assert_eq!(rgb, deserialize_bincode::<Rgb>(&input, &registry));
+    }
+
+    #[test]
+    fn should_migrate_enums() {
+        let registry = get_registry();
+        let weapon = Weapon::Sword { damage: 0.5 };
+
+        let output = ron::ser::to_string(&TypedReflectSerializer::new(&weapon, &registry)).unwrap();
+        assert_eq!("(1,Sword(damage:0.5))", output);
+        assert_eq!(weapon, deserialize::<Weapon>(&output, &registry).unwrap());
+
+        let input = "(0, Blade(damage: 50.0))";
+        assert_eq!(weapon, deserialize::<Weapon>(input, &registry).unwrap());
+        assert_eq!(
+            Weapon::Bow,
+            deserialize::<Weapon>("(0, Bow)", &registry).unwrap()
+        );
+
+        let bincode = bincode::DefaultOptions::new().with_fixint_encoding();
+        let output = bincode
+            .serialize(&TypedReflectSerializer::new(&weapon, &registry))
+            .unwrap();
+        assert_eq!(weapon, deserialize_bincode::<Weapon>(&output, &registry));
+    }
+
+    #[test]
+    fn should_leak_field_names_once() {
+        let first = get_registry();
+        let second = get_registry();
+        let names = |registry: &TypeRegistry| {
+            let registration = registry.get(std::any::TypeId::of::<Player>()).unwrap();
+            let TypeInfo::Struct(info) = registration.type_info() else {
+                panic!("expected a struct");
+            };
+            registration
+                .data::<VersionData>()
+                .unwrap()
+                .serialized_field_names(info)
+        };
+
+        assert_eq!(
+            [VERSION_FIELD, "display_name", "health", "stance", "hp"],
+            names(&first)
+        );
+        assert!(std::ptr::eq(names(&first), names(&second)));
+    }
+
+    #[test]
+    fn should_error_on_newer_version() {
+        let registry = get_registry();
+        let input = r#"(__version: 3, display_name: "Alice", health: 0.5, stance: Crouched)"#;
+        let error = deserialize::<Player>(input, &registry).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("newer than the current version 2"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn should_error_on_failed_migration() {
+        let registry = get_registry();
+        let input = r#"(name: "Alice", stance: Crouched)"#;
+        let error = deserialize::<Player>(input, &registry).unwrap_err();
+        assert!(
+            error.to_string().contains("missing field `hp`"),
+            "unexpected error: {error}"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{FromType, Reflect, TypeRegistry};
+use serde::de::Error;
+use serde::{Deserializer, Serialize, Serializer};
+
+/// A serialization trait for types that require access to the [`TypeRegistry`].
+///
+/// This is useful for types that contain `dyn Reflect` values,
+/// which can only be serialized with the help of the registry
+/// (e.g. using a [`ReflectSerializer`]).
+///
+/// To be used by the reflection serializers, the type must register
+/// [`ReflectSerializeWithRegistry`] type data.
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+pub trait SerializeWithRegistry {
+    /// Serializes this value with the given serializer,
+    /// using the [`TypeRegistry`] to serialize any nested reflected values.
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+/// A deserialization trait for types that require access to the [`TypeRegistry`].
+///
+/// This is the deserialization counterpart to [`SerializeWithRegistry`].
+///
+/// To be used by the reflection deserializers, the type must register
+/// [`ReflectDeserializeWithRegistry`] type data.
+pub trait DeserializeWithRegistry: Sized {
+    /// Deserializes a value of this type from the given deserializer,
+    /// using the [`TypeRegistry`] to deserialize any nested reflected values.
+    fn deserialize<'de, D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Type data used to serialize types implementing [`SerializeWithRegistry`].
+///
+/// A `ReflectSerializeWithRegistry` for type `T` can be obtained via
+/// [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectSerializeWithRegistry {
+    get_serializable: for<'a> fn(
+        value: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+    ) -> Box<dyn erased_serde::Serialize + 'a>,
+}
+
+impl ReflectSerializeWithRegistry {
+    /// Serializes a reflected value using the given [`TypeRegistry`].
+    pub fn serialize<S>(
+        &self,
+        value: &dyn Reflect,
+        serializer: S,
+        registry: &TypeRegistry,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.get_serializable)(value, registry).serialize(serializer)
+    }
+}
+
+impl<T: Reflect + SerializeWithRegistry> FromType<T> for ReflectSerializeWithRegistry {
+    fn from_type() -> Self {
+        Self {
+            get_serializable: |value, registry| {
+                let value = value.downcast_ref::<T>().unwrap_or_else(|| {
+                    panic!("ReflectSerializeWithRegistry::serialize called with type `{}`, even though it was created for `{}`", value.type_name(), std::any::type_name::<T>())
+                });
+                Box::new(SerializableWithRegistry { value, registry })
+            },
+        }
+    }
+}
+
+/// Type data used to deserialize types implementing [`DeserializeWithRegistry`].
+///
+/// A `ReflectDeserializeWithRegistry` for type `T` can be obtained via
+/// [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectDeserializeWithRegistry {
+    func: fn(
+        deserializer: &mut dyn erased_serde::Deserializer,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, erased_serde::Error>,
+}
+
+impl ReflectDeserializeWithRegistry {
+    /// Deserializes a reflected value using the given [`TypeRegistry`].
+    pub fn deserialize<'de, D>(
+        &self,
+        deserializer: D,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
+        (self.func)(&mut erased, registry).map_err(D::Error::custom)
+    }
+}
+
+impl<T: Reflect + DeserializeWithRegistry> FromType<T> for ReflectDeserializeWithRegistry {
+    fn from_type() -> Self {
+        Self {
+            func: |deserializer, registry| Ok(Box::new(T::deserialize(deserializer, registry)?)),
+        }
+    }
+}
+
+/// Pairs a value with the registry so it can be serialized through [`Serialize`].
+struct SerializableWithRegistry<'a, T> {
+    value: &'a T,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, T: SerializeWithRegistry> Serialize for SerializableWithRegistry<'a, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer, self.registry)
+    }
+}
@@ -0,0 +1,561 @@
+use std::any::{Any, TypeId};
+use std::fmt::{Debug, Formatter};
+
+use bevy_reflect_derive::impl_type_path;
+use bevy_utils::{Entry, HashMap};
+
+use crate::{
+    self as bevy_reflect, Generics, Reflect, ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
+};
+
+/// A trait used to power [set-like] operations via [reflection].
+///
+/// Sets contain zero or more unique values,
+/// and correspond to types like [`HashSet`] and [`BTreeSet`].
+/// The order of these values is not guaranteed by this trait.
+///
+/// # Hashing
+///
+/// Values should return a valid hash value from [`Reflect::reflect_hash`].
+/// If using the [`#[derive(Reflect)]`](derive@crate::Reflect) macro, this can be done by adding `#[reflect(Hash)]`
+/// to the entire struct or enum.
+/// [`DynamicSet`] looks up values by their hash, and falls back to comparing them
+/// with [`Reflect::reflect_partial_eq`] one by one for values which cannot be hashed,
+/// such as the values of a [`BTreeSet`] which only implement [`Ord`].
+///
+/// # Example
+///
+/// ```
+/// use bevy_reflect::{Reflect, Set};
+/// use bevy_utils::HashSet;
+///
+///
+/// let foo: &mut dyn Set = &mut HashSet::<u32>::new();
+/// foo.insert_boxed(Box::new(123_u32));
+/// assert_eq!(foo.len(), 1);
+///
+/// let field: &dyn Reflect = foo.get(&123_u32).unwrap();
+/// assert_eq!(field.downcast_ref::<u32>(), Some(&123_u32));
+/// ```
+///
+/// [set-like]: https://doc.rust-lang.org/stable/std/collections/struct.HashSet.html
+/// [reflection]: crate
+/// [`HashSet`]: bevy_utils::HashSet
+/// [`BTreeSet`]: std::collections::BTreeSet
+pub trait Set: Reflect {
+    /// Returns a reference to the value equal to the given one.
+    ///
+    /// If no such value is contained in the set, returns `None`.
+    fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect>;
+
+    /// Returns the number of elements in the set.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the set contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the values of the set.
+    fn iter(&self) -> Box<dyn Iterator<Item = &dyn Reflect> + '_>;
+
+    /// Drain the values of this set to get a vector of owned values.
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>>;
+
+    /// Clones the set, producing a [`DynamicSet`].
+    fn clone_dynamic(&self) -> DynamicSet;
+
+    /// Inserts a value into the set.
+    ///
+    /// If the set did not have this value present, `true` is returned.
+    /// If the set did have this value present, `false` is returned.
+    fn insert_boxed(&mut self, value: Box<dyn Reflect>) -> bool;
+
+    /// Removes a value from the set.
+    ///
+    /// If the set did not have this value present, `false` is returned.
+    /// If the set did have this value present, `true` is returned.
+    fn remove(&mut self, value: &dyn Reflect) -> bool;
+
+    /// Checks if the given value is contained in the set.
+    fn contains(&self, value: &dyn Reflect) -> bool {
+        self.get(value).is_some()
+    }
+}
+
+/// A container for compile-time set info.
+#[derive(Clone, Debug)]
+pub struct SetInfo {
+    type_name: &'static str,
+    type_id: TypeId,
+    value_type_name: &'static str,
+    value_type_id: TypeId,
+    generics: Generics,
+    #[cfg(feature = "documentation")]
+    docs: Option<&'static str>,
+}
+
+impl SetInfo {
+    /// Create a new [`SetInfo`].
+    pub fn new<TSet: Set, TValue: Reflect>() -> Self {
+        Self {
+            type_name: std::any::type_name::<TSet>(),
+            type_id: TypeId::of::<TSet>(),
+            value_type_name: std::any::type_name::<TValue>(),
+            value_type_id: TypeId::of::<TValue>(),
+            generics: Generics::new(),
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
+
+    /// Sets the generic parameters for this set.
+    pub fn with_generics(self, generics: Generics) -> Self {
+        Self { generics, ..self }
+    }
+
+    /// Sets the docstring for this set.
+    #[cfg(feature = "documentation")]
+    pub fn with_docs(self, docs: Option<&'static str>) -> Self {
+        Self { docs, ..self }
+    }
+
+    /// The [type name] of the set.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the set.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the set type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+
+    /// The [type name] of the value.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn value_type_name(&self) -> &'static str {
+        self.value_type_name
+    }
+
+    /// The [`TypeId`] of the value.
+    pub fn value_type_id(&self) -> TypeId {
+        self.value_type_id
+    }
+
+    /// Check if the given type matches the value type.
+    pub fn value_is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.value_type_id
+    }
+
+    /// The generic parameters of this set.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
+    /// The docstring of this set, if any.
+    #[cfg(feature = "documentation")]
+    pub fn docs(&self) -> Option<&'static str> {
+        self.docs
+    }
+}
+
+/// An ordered set of reflected values.
+///
+/// Values are looked up by their [`Reflect::reflect_hash`], or in linear time with
+/// [`Reflect::reflect_partial_eq`] if they cannot be hashed.
+#[derive(Default)]
+pub struct DynamicSet {
+    represented_type: Option<&'static TypeInfo>,
+    values: Vec<Box<dyn Reflect>>,
+    /// The indices of the values which can be hashed.
+    indices: HashMap<u64, usize>,
+}
+
+impl DynamicSet {
+    /// Sets the [type] to be represented by this `DynamicSet`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given [type] is not a [`TypeInfo::Set`].
+    ///
+    /// [type]: TypeInfo
+    pub fn set_represented_type(&mut self, represented_type: Option<&'static TypeInfo>) {
+        if let Some(represented_type) = represented_type {
+            assert!(
+                matches!(represented_type, TypeInfo::Set(_)),
+                "expected TypeInfo::Set but received: {:?}",
+                represented_type
+            );
+        }
+
+        self.represented_type = represented_type;
+    }
+
+    /// Inserts a typed value into the set.
+    pub fn insert<V: Reflect>(&mut self, value: V) {
+        self.insert_boxed(Box::new(value));
+    }
+
+    /// Returns the index of the value equal to the given one.
+    fn index_of(&self, value: &dyn Reflect) -> Option<usize> {
+        match value.reflect_hash() {
+            Some(hash) => self.indices.get(&hash).copied(),
+            None => self
+                .values
+                .iter()
+                .position(|other| other.reflect_partial_eq(value) == Some(true)),
+        }
+    }
+}
+
+impl Set for DynamicSet {
+    fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
+        self.index_of(value).map(|index| &*self.values[index])
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = &dyn Reflect> + '_> {
+        Box::new(self.values.iter().map(|value| &**value))
+    }
+
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.values
+    }
+
+    fn clone_dynamic(&self) -> DynamicSet {
+        DynamicSet {
+            represented_type: self.represented_type,
+            values: self
+                .values
+                .iter()
+                .map(|value| value.clone_value())
+                .collect(),
+            indices: self.indices.clone(),
+        }
+    }
+
+    fn insert_boxed(&mut self, value: Box<dyn Reflect>) -> bool {
+        let Some(hash) = value.reflect_hash() else {
+            if self.index_of(&*value).is_some() {
+                return false;
+            }
+            self.values.push(value);
+            return true;
+        };
+        match self.indices.entry(hash) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(self.values.len());
+                self.values.push(value);
+                true
+            }
+        }
+    }
+
+    fn remove(&mut self, value: &dyn Reflect) -> bool {
+        let index = match value.reflect_hash() {
+            Some(hash) => self.indices.remove(&hash),
+            None => self.index_of(value),
+        };
+        let Some(index) = index else {
+            return false;
+        };
+        self.values.remove(index);
+        for other in self.indices.values_mut() {
+            if *other > index {
+                *other -= 1;
+            }
+        }
+        true
+    }
+}
+
+impl Reflect for DynamicSet {
+    fn type_name(&self) -> &str {
+        self.represented_type
+            .map(|info| info.type_name())
+            .unwrap_or_else(|| std::any::type_name::<Self>())
+    }
+
+    #[inline]
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        self.represented_type
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        set_apply(self, value);
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Set(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Set(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Set(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        set_partial_eq(self, value)
+    }
+
+    fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DynamicSet(")?;
+        set_debug(self, f)?;
+        write!(f, ")")
+    }
+
+    #[inline]
+    fn is_dynamic(&self) -> bool {
+        true
+    }
+}
+
+impl_type_path!((in bevy_reflect) DynamicSet);
+
+impl Debug for DynamicSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.debug(f)
+    }
+}
+
+impl IntoIterator for DynamicSet {
+    type Item = Box<dyn Reflect>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+/// Compares a [`Set`] with a [`Reflect`] value.
+///
+/// Returns true if and only if all of the following are true:
+/// - `b` is a set;
+/// - `b` is the same length as `a`;
+/// - For each value in `a`, `b` contains an equal value,
+///   and [`Reflect::reflect_partial_eq`] returns `Some(true)` for the two values.
+///
+/// Returns [`None`] if the comparison couldn't even be performed.
+#[inline]
+pub fn set_partial_eq<S: Set>(a: &S, b: &dyn Reflect) -> Option<bool> {
+    let ReflectRef::Set(set) = b.reflect_ref() else {
+        return Some(false);
+    };
+
+    if a.len() != set.len() {
+        return Some(false);
+    }
+
+    for value in a.iter() {
+        if let Some(set_value) = set.get(value) {
+            let eq_result = value.reflect_partial_eq(set_value);
+            if let failed @ (Some(false) | None) = eq_result {
+                return failed;
+            }
+        } else {
+            return Some(false);
+        }
+    }
+
+    Some(true)
+}
+
+/// The default debug formatter for [`Set`] types.
+///
+/// # Example
+/// ```
+/// # use bevy_utils::HashSet;
+/// use bevy_reflect::Reflect;
+///
+/// let mut my_set = HashSet::new();
+/// my_set.insert(String::from("Hello"));
+/// println!("{:#?}", &my_set as &dyn Reflect);
+///
+/// // Output:
+///
+/// // {
+/// //   "Hello",
+/// // }
+/// ```
+#[inline]
+pub fn set_debug(dyn_set: &dyn Set, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let mut debug = f.debug_set();
+    for value in dyn_set.iter() {
+        debug.entry(&value as &dyn Debug);
+    }
+    debug.finish()
+}
+
+/// Applies the values of reflected set `b` to set `a`.
+///
+/// Values from `b` which are not contained in `a` are cloned and inserted.
+/// Values of `a` which are not contained in `b` are removed.
+///
+/// # Panics
+///
+/// This function panics if `b` is not a reflected set.
+#[inline]
+pub fn set_apply<S: Set>(a: &mut S, b: &dyn Reflect) {
+    if let ReflectRef::Set(set_value) = b.reflect_ref() {
+        let removed = a
+            .iter()
+            .filter(|value| !set_value.contains(*value))
+            .map(|value| value.clone_value())
+            .collect::<Vec<_>>();
+        for value in removed {
+            a.remove(&*value);
+        }
+        for value in set_value.iter() {
+            if !a.contains(value) {
+                a.insert_boxed(value.clone_value());
+            }
+        }
+    } else {
+        panic!("Attempted to apply a non-set type to a set type.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynamicSet;
+    use super::Set;
+    use crate as bevy_reflect;
+    use crate::{FromReflect, Reflect};
+    use bevy_utils::HashSet;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_into_iter() {
+        let expected = vec!["foo", "bar", "baz"];
+
+        let mut set = DynamicSet::default();
+        for value in &expected {
+            set.insert(value.to_string());
+        }
+
+        for (index, item) in set.into_iter().enumerate() {
+            let value = item.take::<String>().expect("couldn't downcast to String");
+            assert_eq!(expected[index], value);
+        }
+    }
+
+    #[test]
+    fn test_set_insert_remove() {
+        let mut set = DynamicSet::default();
+        set.insert(0usize);
+        set.insert(1usize);
+        set.insert(2usize);
+        assert!(!set.insert_boxed(Box::new(1usize)));
+        assert_eq!(3, set.len());
+
+        assert!(set.remove(&1usize as &dyn Reflect));
+        assert!(!set.remove(&1usize as &dyn Reflect));
+        assert!(!set.contains(&1usize as &dyn Reflect));
+        assert!(set.contains(&2usize as &dyn Reflect));
+        assert_eq!(
+            Some(&2usize),
+            set.get(&2usize as &dyn Reflect)
+                .and_then(|value| value.downcast_ref::<usize>())
+        );
+    }
+
+    #[test]
+    fn test_set_apply() {
+        let mut set = HashSet::from_iter([1usize, 2, 3]);
+        let mut patch = DynamicSet::default();
+        patch.insert(2usize);
+        patch.insert(4usize);
+
+        set.apply(&patch);
+        assert_eq!(HashSet::from_iter([2usize, 4]), set);
+        assert_eq!(Some(true), set.reflect_partial_eq(&patch));
+    }
+
+    #[test]
+    fn test_btree_set_without_hash() {
+        #[derive(Reflect, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Rank(u8);
+
+        let mut set = BTreeSet::from_iter([Rank(1), Rank(2)]);
+        let patch = BTreeSet::from_iter([Rank(2), Rank(3)]);
+        assert!(Set::get(&set, &Rank(1)).is_some());
+
+        set.apply(&patch);
+        assert_eq!(BTreeSet::from_iter([Rank(2), Rank(3)]), set);
+
+        let mut dynamic = DynamicSet::default();
+        dynamic.insert(Rank(3));
+        dynamic.insert(Rank(4));
+        assert!(!dynamic.insert_boxed(Box::new(Rank(3))));
+        set.apply(&dynamic);
+        assert_eq!(BTreeSet::from_iter([Rank(3), Rank(4)]), set);
+
+        assert!(dynamic.remove(&Rank(3)));
+        assert!(!dynamic.contains(&Rank(3)));
+        assert_eq!(1, dynamic.len());
+    }
+
+    #[test]
+    fn test_btree_set_without_hash_clone_value() {
+        #[derive(Reflect, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Rank(u8);
+
+        let set = BTreeSet::from_iter([Rank(1), Rank(2)]);
+        let cloned = set.clone_value();
+        assert_eq!(Some(true), set.reflect_partial_eq(&*cloned));
+        assert_eq!(
+            Some(set),
+            <BTreeSet<Rank> as FromReflect>::from_reflect(&*cloned)
+        );
+    }
+}
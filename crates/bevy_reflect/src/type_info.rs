@@ -1,8 +1,11 @@
+use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
 use crate::{
-    ArrayInfo, EnumInfo, ListInfo, MapInfo, Reflect, StructInfo, TupleInfo, TupleStructInfo,
+    ArrayInfo, EnumInfo, Generics, ListInfo, MapInfo, Reflect, SetInfo, StructInfo, TupleInfo,
+    TupleStructInfo,
 };
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
+use std::sync::Arc;
 
 /// A static accessor to compile-time type information.
 ///
@@ -109,6 +112,7 @@ pub enum TypeInfo {
     List(ListInfo),
     Array(ArrayInfo),
     Map(MapInfo),
+    Set(SetInfo),
     Enum(EnumInfo),
     Value(ValueInfo),
 }
@@ -123,6 +127,7 @@ impl TypeInfo {
             Self::List(info) => info.type_id(),
             Self::Array(info) => info.type_id(),
             Self::Map(info) => info.type_id(),
+            Self::Set(info) => info.type_id(),
             Self::Enum(info) => info.type_id(),
             Self::Value(info) => info.type_id(),
         }
@@ -139,6 +144,7 @@ impl TypeInfo {
             Self::List(info) => info.type_name(),
             Self::Array(info) => info.type_name(),
             Self::Map(info) => info.type_name(),
+            Self::Set(info) => info.type_name(),
             Self::Enum(info) => info.type_name(),
             Self::Value(info) => info.type_name(),
         }
@@ -149,6 +155,21 @@ impl TypeInfo {
         TypeId::of::<T>() == self.type_id()
     }
 
+    /// The generic parameters of the underlying type.
+    pub fn generics(&self) -> &Generics {
+        match self {
+            Self::Struct(info) => info.generics(),
+            Self::TupleStruct(info) => info.generics(),
+            Self::Tuple(info) => info.generics(),
+            Self::List(info) => info.generics(),
+            Self::Array(info) => info.generics(),
+            Self::Map(info) => info.generics(),
+            Self::Set(info) => info.generics(),
+            Self::Enum(info) => info.generics(),
+            Self::Value(info) => info.generics(),
+        }
+    }
+
     /// The docstring of the underlying type, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -159,6 +180,7 @@ impl TypeInfo {
             Self::List(info) => info.docs(),
             Self::Array(info) => info.docs(),
             Self::Map(info) => info.docs(),
+            Self::Set(info) => info.docs(),
             Self::Enum(info) => info.docs(),
             Self::Value(info) => info.docs(),
         }
@@ -177,6 +199,8 @@ impl TypeInfo {
 pub struct ValueInfo {
     type_name: &'static str,
     type_id: TypeId,
+    custom_attributes: Arc<CustomAttributes>,
+    generics: Generics,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -186,11 +210,26 @@ impl ValueInfo {
         Self {
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            custom_attributes: Arc::new(CustomAttributes::default()),
+            generics: Generics::new(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the custom attributes for this value.
+    pub fn with_custom_attributes(self, custom_attributes: CustomAttributes) -> Self {
+        Self {
+            custom_attributes: Arc::new(custom_attributes),
+            ..self
+        }
+    }
+
+    /// Sets the generic parameters for this value.
+    pub fn with_generics(self, generics: Generics) -> Self {
+        Self { generics, ..self }
+    }
+
     /// Sets the docstring for this value.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, doc: Option<&'static str>) -> Self {
@@ -214,6 +253,13 @@ impl ValueInfo {
         TypeId::of::<T>() == self.type_id
     }
 
+    impl_custom_attribute_methods!(self.custom_attributes, "value");
+
+    /// The generic parameters of this value.
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
     /// The docstring of this dynamic value, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
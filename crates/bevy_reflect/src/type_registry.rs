@@ -1,10 +1,12 @@
-use crate::{serde::Serializable, Reflect, TypeInfo, Typed};
+use crate::func::{DynamicFunction, FunctionRegistrationError, IntoFunction};
+use crate::serde::{Migration, Serializable, VersionData};
+use crate::{Reflect, TypeInfo, Typed};
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use serde::Deserialize;
-use std::{any::TypeId, fmt::Debug, sync::Arc};
+use std::{any::TypeId, borrow::Cow, fmt::Debug, sync::Arc};
 
 /// A registry of [reflected] types.
 ///
@@ -23,6 +25,7 @@ pub struct TypeRegistry {
     short_name_to_id: HashMap<String, TypeId>,
     full_name_to_id: HashMap<String, TypeId>,
     ambiguous_names: HashSet<String>,
+    functions: HashMap<Cow<'static, str>, DynamicFunction>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -49,7 +52,29 @@ impl Debug for TypeRegistryArc {
 ///
 /// [crate-level documentation]: crate
 pub trait GetTypeRegistration {
+    /// Returns the default [`TypeRegistration`] for this type.
     fn get_type_registration() -> TypeRegistration;
+
+    /// Registers other types needed by this type, such as the types of its fields.
+    ///
+    /// This is called by [`TypeRegistry::register`] the first time this type is registered,
+    /// so that registering a type also registers everything reachable from it.
+    /// The `Reflect` derive registers the types of the reflected fields that implement
+    /// [`GetTypeRegistration`], except for types depending on the type's generic parameters.
+    #[allow(unused_variables)]
+    fn register_type_dependencies(registry: &mut TypeRegistry) {}
+}
+
+/// A trait which allows a type to register its reflected methods
+/// into the [`TypeRegistry`].
+///
+/// This trait is automatically implemented for impl blocks annotated with
+/// [`#[reflect_methods]`](macro@crate::reflect_methods).
+///
+/// See the [`func`](crate::func) module for more information on function reflection.
+pub trait RegisterMethods {
+    /// Registers the reflected methods of this type as functions in the given registry.
+    fn register_methods(registry: &mut TypeRegistry);
 }
 
 impl Default for TypeRegistry {
@@ -66,6 +91,7 @@ impl TypeRegistry {
             short_name_to_id: Default::default(),
             full_name_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            functions: Default::default(),
         }
     }
 
@@ -97,17 +123,54 @@ impl TypeRegistry {
     /// #[derive(Reflect)]
     /// #[reflect(Component, Serialize, Deserialize)] // will register ReflectComponent, ReflectSerialize, ReflectDeserialize
     /// ```
+    ///
+    /// If `T` was not already registered, its [type dependencies] are registered as well.
+    ///
+    /// [type dependencies]: GetTypeRegistration::register_type_dependencies
     pub fn register<T>(&mut self)
     where
         T: GetTypeRegistration,
     {
-        self.add_registration(T::get_type_registration());
+        if self.add_registration_internal(T::get_type_registration()) {
+            T::register_type_dependencies(self);
+        }
+    }
+
+    /// Registers every non-generic type deriving [`Reflect`] across all linked crates,
+    /// along with their [type dependencies].
+    ///
+    /// Types are collected at link time, so this includes types which are never named
+    /// by the application itself. Generic types can be included by listing their concrete
+    /// instantiations with the [`auto_register_types!`] macro.
+    ///
+    /// Returns `false` if no types were found, which happens on platforms
+    /// where link-time collection is unsupported.
+    ///
+    /// [type dependencies]: GetTypeRegistration::register_type_dependencies
+    /// [`auto_register_types!`]: crate::auto_register_types
+    #[cfg(feature = "auto_register")]
+    pub fn register_derived_types(&mut self) -> bool {
+        use crate::__macro_exports::auto_register::{inventory, AutomaticReflectRegistration};
+
+        let mut registered = false;
+        for registration in inventory::iter::<AutomaticReflectRegistration> {
+            (registration.0)(self);
+            registered = true;
+        }
+        registered
     }
 
     /// Registers the type described by `registration`.
+    ///
+    /// Unlike [`TypeRegistry::register`], this does not register any of the type's dependencies.
     pub fn add_registration(&mut self, registration: TypeRegistration) {
+        self.add_registration_internal(registration);
+    }
+
+    /// Registers the type described by `registration`, returning `false` if it was already registered.
+    fn add_registration_internal(&mut self, registration: TypeRegistration) -> bool {
         if self.registrations.contains_key(&registration.type_id()) {
-            return;
+            return false;
         }
 
         let short_name = registration.short_name.to_string();
@@ -125,6 +188,7 @@ impl TypeRegistry {
             .insert(registration.type_name().to_string(), registration.type_id());
         self.registrations
             .insert(registration.type_id(), registration);
+        true
     }
 
     /// Registers the type data `D` for type `T`.
@@ -154,6 +218,36 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Adds a [`Migration`] to the versioned type `T`.
+    ///
+    /// See [`VersionData`] for more information on versioning.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered, or was not given a version with `#[reflect(version = N)]`.
+    pub fn register_migration<T: Reflect + 'static>(&mut self, migration: Migration) {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_migration` for type `{T}` without registering `{T}` first",
+                T = std::any::type_name::<T>(),
+            )
+        });
+        let version_data = registration.data_mut::<VersionData>().unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_migration` for type `{T}`, which has no version",
+                T = std::any::type_name::<T>(),
+            )
+        });
+        version_data.add_migration(migration);
+    }
+
+    /// Returns `true` if the type with the given [`TypeId`] has been registered.
+    ///
+    /// [`TypeId`]: std::any::TypeId
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.registrations.contains_key(&type_id)
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given [`TypeId`].
     ///
@@ -262,6 +356,79 @@ impl TypeRegistry {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut TypeRegistration> {
         self.registrations.values_mut()
     }
+
+    /// Registers the given function under its own name.
+    ///
+    /// Closures have no name and must be registered using
+    /// [`TypeRegistry::register_function_with_name`] instead.
+    ///
+    /// Returns an error if the function has no name or if a function with the same name
+    /// has already been registered.
+    pub fn register_function<F, Marker>(
+        &mut self,
+        function: F,
+    ) -> Result<(), FunctionRegistrationError>
+    where
+        F: IntoFunction<Marker>,
+    {
+        let function = function.into_function();
+        let name = function
+            .name()
+            .ok_or(FunctionRegistrationError::MissingName)?
+            .to_string();
+        self.insert_function(Cow::Owned(name), function)
+    }
+
+    /// Registers the given function under the given name.
+    ///
+    /// Returns an error if a function with the same name has already been registered.
+    pub fn register_function_with_name<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        function: F,
+    ) -> Result<(), FunctionRegistrationError>
+    where
+        F: IntoFunction<Marker>,
+    {
+        let name = name.into();
+        let function = function.into_function().with_name(name.clone());
+        self.insert_function(name, function)
+    }
+
+    fn insert_function(
+        &mut self,
+        name: Cow<'static, str>,
+        function: DynamicFunction,
+    ) -> Result<(), FunctionRegistrationError> {
+        if self.functions.contains_key(&name) {
+            return Err(FunctionRegistrationError::DuplicateName(name));
+        }
+        self.functions.insert(name, function);
+        Ok(())
+    }
+
+    /// Registers the reflected methods of type `T`.
+    ///
+    /// Each method is registered under the [type path] of `T` followed by the method name,
+    /// e.g. `my_crate::Player::heal`.
+    /// Methods which have already been registered are skipped.
+    ///
+    /// [type path]: crate::TypePath
+    pub fn register_methods<T: RegisterMethods>(&mut self) {
+        T::register_methods(self);
+    }
+
+    /// Returns a reference to the function registered under the given name.
+    ///
+    /// If no function with the given name has been registered, returns `None`.
+    pub fn get_function(&self, name: &str) -> Option<&DynamicFunction> {
+        self.functions.get(name)
+    }
+
+    /// Returns an iterator over the registered functions.
+    pub fn functions(&self) -> impl Iterator<Item = &DynamicFunction> {
+        self.functions.values()
+    }
 }
 
 impl TypeRegistryArc {
@@ -575,9 +742,10 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
 
 #[cfg(test)]
 mod test {
-    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration};
+    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration, TypeRegistry};
     use bevy_ptr::{Ptr, PtrMut};
     use bevy_utils::HashMap;
+    use std::any::TypeId;
 
     use crate as bevy_reflect;
     use crate::Reflect;
@@ -655,4 +823,106 @@ mod test {
             "Option<HashMap<Option<String>, (String, Option<String>)>>"
         );
     }
+
+    #[test]
+    fn should_not_require_registrable_fields() {
+        // `DynamicStruct` implements `Reflect` but not `GetTypeRegistration`
+        #[derive(Reflect)]
+        #[reflect(from_reflect = false)]
+        struct Foo {
+            value: crate::DynamicStruct,
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Foo>();
+        assert!(registry.contains(TypeId::of::<Foo>()));
+        assert!(!registry.contains(TypeId::of::<crate::DynamicStruct>()));
+    }
+
+    #[test]
+    fn should_register_type_dependencies() {
+        #[derive(Reflect)]
+        struct Player {
+            name: String,
+            inventory: Vec<Item>,
+            equipped: Item,
+            stats: HashMap<String, Stat<f32>>,
+            strength: Stat<u16>,
+            #[reflect(ignore)]
+            _cache: Vec<u8>,
+        }
+
+        #[derive(Reflect)]
+        enum Item {
+            Weapon { damage: Option<u32> },
+            Potion((u8, i16)),
+        }
+
+        #[derive(Reflect)]
+        struct Stat<T> {
+            base: T,
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Player>();
+
+        assert!(registry.contains(TypeId::of::<String>()));
+        assert!(registry.contains(TypeId::of::<Vec<Item>>()));
+        assert!(registry.contains(TypeId::of::<Item>()));
+        assert!(registry.contains(TypeId::of::<Option<u32>>()));
+        assert!(registry.contains(TypeId::of::<u32>()));
+        assert!(registry.contains(TypeId::of::<(u8, i16)>()));
+        assert!(registry.contains(TypeId::of::<u8>()));
+        assert!(registry.contains(TypeId::of::<i16>()));
+        assert!(registry.contains(TypeId::of::<HashMap<String, Stat<f32>>>()));
+        assert!(registry.contains(TypeId::of::<Stat<f32>>()));
+        assert!(registry.contains(TypeId::of::<Stat<u16>>()));
+
+        // fields depending on a generic parameter are not registered
+        assert!(!registry.contains(TypeId::of::<u16>()));
+        assert!(!registry.contains(TypeId::of::<Vec<u8>>()));
+    }
+
+    #[test]
+    fn should_not_register_dependencies_of_added_registrations() {
+        #[derive(Reflect)]
+        struct Foo {
+            bar: Bar,
+        }
+
+        #[derive(Reflect)]
+        struct Bar;
+
+        let mut registry = TypeRegistry::empty();
+        registry.add_registration(Foo::get_type_registration());
+
+        assert!(registry.contains(TypeId::of::<Foo>()));
+        assert!(!registry.contains(TypeId::of::<Bar>()));
+    }
+
+    #[cfg(feature = "auto_register")]
+    #[test]
+    fn should_register_derived_types() {
+        #[derive(Reflect)]
+        struct Foo {
+            bar: Bar,
+        }
+
+        #[derive(Reflect)]
+        struct Bar;
+
+        #[derive(Reflect)]
+        struct Baz<T>(T);
+
+        crate::auto_register_types!(Baz<i8>);
+
+        let mut registry = TypeRegistry::empty();
+        assert!(registry.register_derived_types());
+
+        assert!(registry.contains(TypeId::of::<Foo>()));
+        assert!(registry.contains(TypeId::of::<Bar>()));
+        assert!(registry.contains(TypeId::of::<Baz<i8>>()));
+        assert!(registry.contains(TypeId::of::<i8>()));
+        assert!(!registry.contains(TypeId::of::<Baz<i16>>()));
+    }
 }
@@ -1,10 +1,10 @@
 use super::ShaderDefVal;
 use crate::define_atomic_id;
-use bevy_asset::{AssetLoader, AssetPath, Handle, LoadContext, LoadedAsset};
+use bevy_asset::{AssetLoader, AssetPath, AsyncReadExt, Handle, LoadContext, LoadedAsset, Reader};
 use bevy_reflect::{TypePath, TypeUuid};
 use bevy_utils::{tracing::error, BoxedFuture};
 
-use std::{borrow::Cow, marker::Copy};
+use std::{any::TypeId, borrow::Cow, marker::Copy};
 use thiserror::Error;
 
 define_atomic_id!(ShaderId);
@@ -236,32 +236,32 @@ pub struct ShaderLoader;
 impl AssetLoader for ShaderLoader {
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
         Box::pin(async move {
             let ext = load_context.path().extension().unwrap().to_str().unwrap();
 
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
             let shader = match ext {
-                "spv" => {
-                    Shader::from_spirv(Vec::from(bytes), load_context.path().to_string_lossy())
-                }
+                "spv" => Shader::from_spirv(bytes, load_context.path().to_string_lossy()),
                 "wgsl" => Shader::from_wgsl(
-                    String::from_utf8(Vec::from(bytes))?,
+                    String::from_utf8(bytes)?,
                     load_context.path().to_string_lossy(),
                 ),
                 "vert" => Shader::from_glsl(
-                    String::from_utf8(Vec::from(bytes))?,
+                    String::from_utf8(bytes)?,
                     naga::ShaderStage::Vertex,
                     load_context.path().to_string_lossy(),
                 ),
                 "frag" => Shader::from_glsl(
-                    String::from_utf8(Vec::from(bytes))?,
+                    String::from_utf8(bytes)?,
                     naga::ShaderStage::Fragment,
                     load_context.path().to_string_lossy(),
                 ),
                 "comp" => Shader::from_glsl(
-                    String::from_utf8(Vec::from(bytes))?,
+                    String::from_utf8(bytes)?,
                     naga::ShaderStage::Compute,
                     load_context.path().to_string_lossy(),
                 ),
@@ -294,6 +294,10 @@ impl AssetLoader for ShaderLoader {
     fn extensions(&self) -> &[&str] {
         &["spv", "wgsl", "vert", "frag", "comp"]
     }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<Shader>())
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
@@ -1,8 +1,9 @@
 use crate::texture::{Image, TextureFormatPixelInfo};
 use anyhow::Result;
-use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy_asset::{AssetLoader, AsyncReadExt, LoadContext, LoadedAsset, Reader};
 use bevy_utils::BoxedFuture;
 use image::ImageDecoder;
+use std::any::TypeId;
 use wgpu::{Extent3d, TextureDimension, TextureFormat};
 
 /// Loads EXR textures as Texture assets
@@ -12,7 +13,7 @@ pub struct ExrTextureLoader;
 impl AssetLoader for ExrTextureLoader {
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
@@ -23,6 +24,8 @@ impl AssetLoader for ExrTextureLoader {
                 "Format should have 32bit x 4 size"
             );
 
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
             let decoder = image::codecs::openexr::OpenExrDecoder::with_alpha_preference(
                 std::io::Cursor::new(bytes),
                 Some(true),
@@ -53,4 +56,8 @@ impl AssetLoader for ExrTextureLoader {
     fn extensions(&self) -> &[&str] {
         &["exr"]
     }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<Image>())
+    }
 }
@@ -1,7 +1,8 @@
 use crate::texture::{Image, TextureFormatPixelInfo};
 use anyhow::Result;
-use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy_asset::{AssetLoader, AsyncReadExt, LoadContext, LoadedAsset, Reader};
 use bevy_utils::BoxedFuture;
+use std::any::TypeId;
 use wgpu::{Extent3d, TextureDimension, TextureFormat};
 
 /// Loads HDR textures as Texture assets
@@ -11,7 +12,7 @@ pub struct HdrTextureLoader;
 impl AssetLoader for HdrTextureLoader {
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
@@ -22,7 +23,9 @@ impl AssetLoader for HdrTextureLoader {
                 "Format should have 32bit x 4 size"
             );
 
-            let decoder = image::codecs::hdr::HdrDecoder::new(bytes)?;
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let decoder = image::codecs::hdr::HdrDecoder::new(bytes.as_slice())?;
             let info = decoder.metadata();
             let rgb_data = decoder.read_image_hdr()?;
             let mut rgba_data = Vec::with_capacity(rgb_data.len() * format.pixel_size());
@@ -55,4 +58,8 @@ impl AssetLoader for HdrTextureLoader {
     fn extensions(&self) -> &[&str] {
         &["hdr"]
     }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<Image>())
+    }
 }
@@ -11,7 +11,7 @@ use crate::{
     renderer::{RenderDevice, RenderQueue},
     texture::BevyDefault,
 };
-use bevy_asset::HandleUntyped;
+use bevy_asset::{AssetMemorySize, HandleUntyped};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::system::{lifetimeless::SRes, Resource, SystemParamItem};
 use bevy_math::Vec2;
@@ -198,6 +198,12 @@ impl Default for Image {
     }
 }
 
+impl AssetMemorySize for Image {
+    fn memory_size(&self) -> usize {
+        self.data.len()
+    }
+}
+
 impl Image {
     /// Creates a new image from raw binary data and the corresponding metadata.
     ///
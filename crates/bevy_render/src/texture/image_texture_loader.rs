@@ -1,7 +1,8 @@
 use anyhow::Result;
-use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy_asset::{AssetLoader, AsyncReadExt, LoadContext, LoadedAsset, Reader};
 use bevy_ecs::prelude::{FromWorld, World};
 use bevy_utils::BoxedFuture;
+use std::any::TypeId;
 use thiserror::Error;
 
 use crate::{
@@ -49,15 +50,17 @@ pub(crate) const IMG_FILE_EXTENSIONS: &[&str] = &[
 impl AssetLoader for ImageTextureLoader {
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
             // use the file extension for the image type
             let ext = load_context.path().extension().unwrap().to_str().unwrap();
 
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
             let dyn_img = Image::from_buffer(
-                bytes,
+                &bytes,
                 ImageType::Extension(ext),
                 self.supported_compressed_formats,
                 true,
@@ -75,6 +78,10 @@ impl AssetLoader for ImageTextureLoader {
     fn extensions(&self) -> &[&str] {
         IMG_FILE_EXTENSIONS
     }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<Image>())
+    }
 }
 
 impl FromWorld for ImageTextureLoader {
@@ -2,16 +2,17 @@ use std::any::TypeId;
 
 use crate::{DynamicSceneBuilder, Scene, SceneSpawnError};
 use anyhow::Result;
+use bevy_asset::{AssetPath, Handle, HandleId};
 use bevy_ecs::{
     entity::{Entity, EntityMap},
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
     world::World,
 };
-use bevy_reflect::{Reflect, TypePath, TypeRegistryArc, TypeUuid};
+use bevy_reflect::{Reflect, TypePath, TypeRegistry, TypeRegistryArc, TypeUuid};
 use bevy_utils::HashMap;
 
 #[cfg(feature = "serialize")]
-use crate::serde::SceneSerializer;
+use crate::serde::{SceneSerializer, ShortSceneSerializer};
 use bevy_ecs::reflect::ReflectResource;
 #[cfg(feature = "serialize")]
 use serde::Serialize;
@@ -41,6 +42,51 @@ pub struct DynamicEntity {
     /// A vector of boxed components that belong to the given entity and
     /// implement the [`Reflect`] trait.
     pub components: Vec<Box<dyn Reflect>>,
+    /// Another scene to spawn as children of this entity, if any.
+    pub prefab: Option<ScenePrefab>,
+}
+
+/// A reference from a [`DynamicEntity`] to another [`DynamicScene`] asset,
+/// whose entities are spawned as children of the referencing entity.
+///
+/// Prefabs are resolved recursively by the [`SceneSpawner`](crate::SceneSpawner),
+/// which will wait for every referenced scene to be loaded before spawning anything.
+/// A scene which references itself, directly or through other prefabs, fails to spawn.
+///
+/// When a prefab's scene is modified, every instance that references it is updated,
+/// and its overrides are applied again.
+pub struct ScenePrefab {
+    /// The path of the [`DynamicScene`] asset.
+    pub path: AssetPath<'static>,
+    /// Components to apply to the entities of this instance of the prefab.
+    pub overrides: Vec<PrefabOverride>,
+}
+
+/// Components applied to an entity of a [`ScenePrefab`] after it has been spawned.
+///
+/// Components already present on the entity are patched using [`Reflect::apply`],
+/// so an override only needs to contain the fields it changes.
+/// Components that aren't present are inserted, and so must contain every field.
+pub struct PrefabOverride {
+    /// The identifier of the entity within the prefab's scene.
+    pub entity: Entity,
+    /// The components to apply to the entity.
+    pub components: Vec<Box<dyn Reflect>>,
+}
+
+impl ScenePrefab {
+    /// Creates a reference to the scene at the given path, without any overrides.
+    pub fn new(path: impl Into<AssetPath<'static>>) -> Self {
+        Self {
+            path: path.into(),
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Returns a weak handle to the referenced scene.
+    pub fn handle(&self) -> Handle<DynamicScene> {
+        Handle::weak(HandleId::from(&self.path))
+    }
 }
 
 impl DynamicScene {
@@ -64,6 +110,9 @@ impl DynamicScene {
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
     /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
+    ///
+    /// Any [prefabs](ScenePrefab) referenced by the entities are not spawned,
+    /// as they are resolved by the [`SceneSpawner`](crate::SceneSpawner).
     pub fn write_to_world_with(
         &self,
         world: &mut World,
@@ -72,79 +121,22 @@ impl DynamicScene {
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
 
-        for resource in &self.resources {
-            let registration = type_registry
-                .get_with_name(resource.type_name())
-                .ok_or_else(|| SceneSpawnError::UnregisteredType {
-                    type_name: resource.type_name().to_string(),
-                })?;
-            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
-                SceneSpawnError::UnregisteredResource {
-                    type_name: resource.type_name().to_string(),
-                }
-            })?;
-
-            // If the world already contains an instance of the given resource
-            // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, &**resource);
-        }
-
-        // For each component types that reference other entities, we keep track
-        // of which entities in the scene use that component.
-        // This is so we can update the scene-internal references to references
-        // of the actual entities in the world.
-        let mut scene_mappings: HashMap<TypeId, Vec<Entity>> = HashMap::default();
-
-        for scene_entity in &self.entities {
-            // Fetch the entity with the given entity id from the `entity_map`
-            // or spawn a new entity with a transiently unique id if there is
-            // no corresponding entry.
-            let entity = *entity_map
-                .entry(scene_entity.entity)
-                .or_insert_with(|| world.spawn_empty().id());
-            let entity_mut = &mut world.entity_mut(entity);
-
-            // Apply/ add each component to the given entity.
-            for component in &scene_entity.components {
-                let registration = type_registry
-                    .get_with_name(component.type_name())
-                    .ok_or_else(|| SceneSpawnError::UnregisteredType {
-                        type_name: component.type_name().to_string(),
-                    })?;
-                let reflect_component =
-                    registration.data::<ReflectComponent>().ok_or_else(|| {
-                        SceneSpawnError::UnregisteredComponent {
-                            type_name: component.type_name().to_string(),
-                        }
-                    })?;
-
-                // If this component references entities in the scene, track it
-                // so we can update it to the entity in the world.
-                if registration.data::<ReflectMapEntities>().is_some() {
-                    scene_mappings
-                        .entry(registration.type_id())
-                        .or_insert(Vec::new())
-                        .push(entity);
-                }
-
-                // If the entity already has the given component attached,
-                // just apply the (possibly) new value, otherwise add the
-                // component to the entity.
-                reflect_component.apply_or_insert(entity_mut, &**component);
-            }
-        }
-
-        // Updates references to entities in the scene to entities in the world
-        for (type_id, entities) in scene_mappings.into_iter() {
-            let registration = type_registry.get(type_id).expect(
-                "we should be getting TypeId from this TypeRegistration in the first place",
-            );
-            if let Some(map_entities_reflect) = registration.data::<ReflectMapEntities>() {
-                map_entities_reflect.map_entities(world, entity_map, &entities);
-            }
-        }
-
-        Ok(())
+        write_resources_to_world(
+            self.resources.iter().map(|resource| &**resource),
+            world,
+            &type_registry,
+        )?;
+        write_entities_to_world(
+            self.entities.iter().map(|entity| {
+                (
+                    entity.entity,
+                    entity.components.iter().map(|component| &**component),
+                )
+            }),
+            world,
+            entity_map,
+            &type_registry,
+        )
     }
 
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
@@ -161,12 +153,120 @@ impl DynamicScene {
         self.write_to_world_with(world, entity_map, &registry)
     }
 
+    /// Returns an iterator over the prefabs referenced by the entities of this scene.
+    pub fn prefabs(&self) -> impl Iterator<Item = &ScenePrefab> {
+        self.entities
+            .iter()
+            .filter_map(|entity| entity.prefab.as_ref())
+    }
+
     // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into rust object notation (ron).
     #[cfg(feature = "serialize")]
     pub fn serialize_ron(&self, registry: &TypeRegistryArc) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the [short scene format](crate::serde::ShortSceneSerializer),
+    /// which is meant to be edited by hand.
+    #[cfg(feature = "serialize")]
+    pub fn serialize_short_ron(&self, registry: &TypeRegistryArc) -> Result<String, ron::Error> {
+        serialize_ron(ShortSceneSerializer::new(self, registry))
+    }
+}
+
+/// Writes the given resources to the world.
+pub(crate) fn write_resources_to_world<'a>(
+    resources: impl Iterator<Item = &'a dyn Reflect>,
+    world: &mut World,
+    type_registry: &TypeRegistry,
+) -> Result<(), SceneSpawnError> {
+    for resource in resources {
+        let registration = type_registry
+            .get_with_name(resource.type_name())
+            .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                type_name: resource.type_name().to_string(),
+            })?;
+        let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+            SceneSpawnError::UnregisteredResource {
+                type_name: resource.type_name().to_string(),
+            }
+        })?;
+
+        // If the world already contains an instance of the given resource
+        // just apply the (possibly) new value, otherwise insert the resource
+        reflect_resource.apply_or_insert(world, resource);
+    }
+
+    Ok(())
+}
+
+/// Writes the given entities and their components to the world,
+/// spawning any entities which aren't in the `entity_map` yet.
+pub(crate) fn write_entities_to_world<'a, C>(
+    entities: impl Iterator<Item = (Entity, C)>,
+    world: &mut World,
+    entity_map: &mut EntityMap,
+    type_registry: &TypeRegistry,
+) -> Result<(), SceneSpawnError>
+where
+    C: IntoIterator<Item = &'a dyn Reflect>,
+{
+    // For each component types that reference other entities, we keep track
+    // of which entities in the scene use that component.
+    // This is so we can update the scene-internal references to references
+    // of the actual entities in the world.
+    let mut scene_mappings: HashMap<TypeId, Vec<Entity>> = HashMap::default();
+
+    for (scene_entity, components) in entities {
+        // Fetch the entity with the given entity id from the `entity_map`
+        // or spawn a new entity with a transiently unique id if there is
+        // no corresponding entry.
+        let entity = *entity_map
+            .entry(scene_entity)
+            .or_insert_with(|| world.spawn_empty().id());
+        let entity_mut = &mut world.entity_mut(entity);
+
+        // Apply/ add each component to the given entity.
+        for component in components {
+            let registration = type_registry
+                .get_with_name(component.type_name())
+                .ok_or_else(|| SceneSpawnError::UnregisteredType {
+                    type_name: component.type_name().to_string(),
+                })?;
+            let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredComponent {
+                    type_name: component.type_name().to_string(),
+                }
+            })?;
+
+            // If this component references entities in the scene, track it
+            // so we can update it to the entity in the world.
+            if registration.data::<ReflectMapEntities>().is_some() {
+                scene_mappings
+                    .entry(registration.type_id())
+                    .or_insert(Vec::new())
+                    .push(entity);
+            }
+
+            // If the entity already has the given component attached,
+            // just apply the (possibly) new value, otherwise add the
+            // component to the entity.
+            reflect_component.apply_or_insert(entity_mut, component);
+        }
+    }
+
+    // Updates references to entities in the scene to entities in the world
+    for (type_id, entities) in scene_mappings.into_iter() {
+        let registration = type_registry
+            .get(type_id)
+            .expect("we should be getting TypeId from this TypeRegistration in the first place");
+        if let Some(map_entities_reflect) = registration.data::<ReflectMapEntities>() {
+            map_entities_reflect.map_entities(world, entity_map, &entities);
+        }
+    }
+
+    Ok(())
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
@@ -242,6 +242,7 @@ impl<'w> DynamicSceneBuilder<'w> {
             let mut entry = DynamicEntity {
                 entity,
                 components: Vec::new(),
+                prefab: None,
             };
 
             let original_entity = self.original_world.entity(entity);
@@ -8,6 +8,11 @@ mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
 
+#[cfg(feature = "serialize")]
+mod save;
+#[cfg(feature = "serialize")]
+mod validation;
+
 #[cfg(feature = "serialize")]
 pub mod serde;
 
@@ -20,12 +25,21 @@ pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
 
+#[cfg(feature = "serialize")]
+pub use save::*;
+#[cfg(feature = "serialize")]
+pub use validation::*;
+
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         DynamicScene, DynamicSceneBuilder, DynamicSceneBundle, Scene, SceneBundle, SceneFilter,
         SceneSpawner,
     };
+
+    #[doc(hidden)]
+    #[cfg(feature = "serialize")]
+    pub use crate::{Persist, SaveCommandsExt, SavePlugin};
 }
 
 use bevy_app::{prelude::*, SpawnScene};
@@ -0,0 +1,614 @@
+use crate::{
+    serde::SceneDeserializer, DynamicScene, DynamicSceneBuilder, SceneFilter, SceneSpawnError,
+};
+use bevy_app::{App, Plugin, SpawnScene};
+use bevy_ecs::{
+    component::Component,
+    entity::{Entity, EntityMap},
+    event::Event,
+    prelude::{ReflectComponent, With},
+    reflect::AppTypeRegistry,
+    system::{Command, Commands, Resource},
+    world::World,
+};
+use bevy_reflect::{Reflect, TypeRegistryArc};
+use bevy_tasks::IoTaskPool;
+use bevy_utils::BoxedFuture;
+use crossbeam_channel::{Receiver, Sender};
+use serde::de::DeserializeSeed;
+use std::{
+    fmt::Debug,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// Adds support for saving and loading the [`Persist`] entities of the world to slots.
+///
+/// The slots are written and read through the [`SaveStorage`] of the [`SaveSettings`],
+/// which stores them as files in a `saves` directory of the working directory by default.
+///
+/// See [`SaveCommandsExt`] to save and load a game, and [`SaveSettings`] to configure what is saved.
+#[derive(Default)]
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Persist>()
+            .init_resource::<SaveSettings>()
+            .init_resource::<SaveTasks>()
+            .add_event::<GameSaved>()
+            .add_event::<GameLoaded>()
+            .add_event::<SaveFailed>()
+            .add_systems(SpawnScene, save_task_system);
+    }
+}
+
+/// Marks an entity to be saved by [`SaveCommandsExt::save_game`].
+///
+/// Entities loaded by [`SaveCommandsExt::load_game`] are given this component,
+/// so that they replace each other when a game is loaded again.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Persist;
+
+/// Reads and writes the files of the saved slots.
+///
+/// Implement this trait to store the saves somewhere other than the file system,
+/// such as the local storage of a browser, and set it as the [`SaveSettings::storage`].
+pub trait SaveStorage: Debug + Send + Sync + 'static {
+    /// Returns a future to load the full file data at the provided path.
+    fn read<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, io::Result<Vec<u8>>>;
+
+    /// Returns a future to write the full file data at the provided path,
+    /// creating the file and its parent directories if needed.
+    fn write<'a>(&'a self, path: &'a Path, bytes: Vec<u8>) -> BoxedFuture<'a, io::Result<()>>;
+}
+
+/// Stores the saved slots as files in the file system.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FileSaveStorage;
+
+impl SaveStorage for FileSaveStorage {
+    fn read<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, io::Result<Vec<u8>>> {
+        Box::pin(async move { fs::read(path) })
+    }
+
+    fn write<'a>(&'a self, path: &'a Path, bytes: Vec<u8>) -> BoxedFuture<'a, io::Result<()>> {
+        Box::pin(async move {
+            if let Some(directory) = path.parent() {
+                fs::create_dir_all(directory)?;
+            }
+            fs::write(path, bytes)
+        })
+    }
+}
+
+/// Settings used by the [`SavePlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct SaveSettings {
+    /// Reads and writes the slots, [`FileSaveStorage`] by default.
+    pub storage: Arc<dyn SaveStorage>,
+    /// The directory the slots are saved to, `saves` by default.
+    ///
+    /// Each slot is stored as a `<slot>.scn.ron` file within this directory.
+    /// With the [`FileSaveStorage`], a relative directory is relative to the working directory.
+    pub directory: PathBuf,
+    /// Filters the components saved on [`Persist`] entities.
+    pub component_filter: SceneFilter,
+    /// Filters the resources saved along with the entities.
+    ///
+    /// By default, no resources are saved.
+    pub resource_filter: SceneFilter,
+}
+
+impl Default for SaveSettings {
+    fn default() -> Self {
+        Self {
+            storage: Arc::new(FileSaveStorage),
+            directory: PathBuf::from("saves"),
+            component_filter: SceneFilter::allow_all(),
+            resource_filter: SceneFilter::deny_all(),
+        }
+    }
+}
+
+impl SaveSettings {
+    /// Returns the path of the file the given slot is saved to.
+    ///
+    /// Slots are always stored directly within the [directory](Self::directory), so slot names
+    /// which are empty or contain path separators or `..` are rejected.
+    pub fn slot_path(&self, slot: &str) -> Result<PathBuf, SaveError> {
+        if slot.is_empty() || slot.contains(['/', '\\']) || slot.contains("..") {
+            return Err(SaveError::InvalidSlot(slot.to_string()));
+        }
+        Ok(self.directory.join(format!("{slot}.scn.ron")))
+    }
+}
+
+/// Emitted when a [`SaveCommandsExt::save_game`] completes.
+#[derive(Event, Debug)]
+pub struct GameSaved {
+    pub slot: String,
+}
+
+/// Emitted when a [`SaveCommandsExt::load_game`] completes,
+/// once the loaded entities have replaced the previous [`Persist`] entities.
+#[derive(Event, Debug)]
+pub struct GameLoaded {
+    pub slot: String,
+}
+
+/// Emitted when saving or loading a slot fails.
+///
+/// When loading fails, the [`Persist`] entities of the world are left untouched.
+#[derive(Event, Debug)]
+pub struct SaveFailed {
+    pub slot: String,
+    pub error: SaveError,
+}
+
+#[derive(Error, Debug)]
+pub enum SaveError {
+    #[error("invalid slot name `{0}`")]
+    InvalidSlot(String),
+    #[error("could not access the save file: {0}")]
+    Io(#[from] io::Error),
+    #[error("could not serialize the save: {0}")]
+    Serialize(#[from] ron::Error),
+    #[error("could not parse the save: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("could not spawn the save: {0}")]
+    Spawn(#[from] SceneSpawnError),
+}
+
+/// The result of a save or load which ran on the [`IoTaskPool`].
+enum SaveResult {
+    Saved(String, Result<(), SaveError>),
+    Loaded(String, Result<DynamicScene, SaveError>),
+}
+
+/// Channel through which the saves and loads running on the [`IoTaskPool`] report their result.
+#[derive(Resource)]
+struct SaveTasks {
+    /// The number of saves and loads which didn't report their result yet.
+    pending: usize,
+    sender: Sender<SaveResult>,
+    receiver: Receiver<SaveResult>,
+}
+
+impl Default for SaveTasks {
+    fn default() -> Self {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        Self {
+            pending: 0,
+            sender,
+            receiver,
+        }
+    }
+}
+
+impl SaveTasks {
+    /// Runs the given save or load on the [`IoTaskPool`], sending its result once done.
+    fn spawn(
+        world: &mut World,
+        task: impl std::future::Future<Output = SaveResult> + Send + 'static,
+    ) {
+        let mut tasks = world.resource_mut::<SaveTasks>();
+        let sender = tasks.sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = sender.send(task.await);
+            })
+            .detach();
+        tasks.pending += 1;
+    }
+}
+
+/// Saving and loading games through [`Commands`].
+pub trait SaveCommandsExt {
+    /// Saves the [`Persist`] entities of the world to the given slot.
+    ///
+    /// The entities are extracted when the command is applied, and then serialized and written
+    /// on the [`IoTaskPool`]. A [`GameSaved`] or [`SaveFailed`] event is emitted once done.
+    fn save_game(&mut self, slot: impl Into<String>);
+
+    /// Loads the given slot, replacing the [`Persist`] entities of the world.
+    ///
+    /// The slot is read and deserialized on the [`IoTaskPool`]. Once done, the previous
+    /// [`Persist`] entities are despawned and the loaded ones spawned at once,
+    /// and a [`GameLoaded`] or [`SaveFailed`] event is emitted.
+    fn load_game(&mut self, slot: impl Into<String>);
+}
+
+impl<'w, 's> SaveCommandsExt for Commands<'w, 's> {
+    fn save_game(&mut self, slot: impl Into<String>) {
+        self.add(SaveGame { slot: slot.into() });
+    }
+
+    fn load_game(&mut self, slot: impl Into<String>) {
+        self.add(LoadGame { slot: slot.into() });
+    }
+}
+
+/// Command that saves the [`Persist`] entities of the world to a slot.
+///
+/// See [`SaveCommandsExt::save_game`].
+#[derive(Debug)]
+pub struct SaveGame {
+    pub slot: String,
+}
+
+impl Command for SaveGame {
+    fn apply(self, world: &mut World) {
+        let settings = world.resource::<SaveSettings>().clone();
+        let registry = world.resource::<AppTypeRegistry>().0.clone();
+
+        let entities = world
+            .query_filtered::<Entity, With<Persist>>()
+            .iter(world)
+            .collect::<Vec<_>>();
+        let mut builder = DynamicSceneBuilder::from_world(world);
+        builder
+            .with_filter(settings.component_filter.clone())
+            .with_resource_filter(settings.resource_filter.clone())
+            .extract_entities(entities.into_iter())
+            .extract_resources();
+        let scene = builder.build();
+
+        let path = match settings.slot_path(&self.slot) {
+            Ok(path) => path,
+            Err(error) => {
+                world.send_event(SaveFailed {
+                    slot: self.slot,
+                    error,
+                });
+                return;
+            }
+        };
+        SaveTasks::spawn(world, async move {
+            let result = save_scene(&scene, &registry, &*settings.storage, path).await;
+            SaveResult::Saved(self.slot, result)
+        });
+    }
+}
+
+/// Command that replaces the [`Persist`] entities of the world with those saved in a slot.
+///
+/// See [`SaveCommandsExt::load_game`].
+#[derive(Debug)]
+pub struct LoadGame {
+    pub slot: String,
+}
+
+impl Command for LoadGame {
+    fn apply(self, world: &mut World) {
+        let settings = world.resource::<SaveSettings>().clone();
+        let path = match settings.slot_path(&self.slot) {
+            Ok(path) => path,
+            Err(error) => {
+                world.send_event(SaveFailed {
+                    slot: self.slot,
+                    error,
+                });
+                return;
+            }
+        };
+        let registry = world.resource::<AppTypeRegistry>().0.clone();
+        SaveTasks::spawn(world, async move {
+            let result = load_scene(&registry, &*settings.storage, path).await;
+            SaveResult::Loaded(self.slot, result)
+        });
+    }
+}
+
+async fn save_scene(
+    scene: &DynamicScene,
+    registry: &TypeRegistryArc,
+    storage: &dyn SaveStorage,
+    path: PathBuf,
+) -> Result<(), SaveError> {
+    let serialized = scene.serialize_ron(registry)?;
+    storage.write(&path, serialized.into_bytes()).await?;
+    Ok(())
+}
+
+async fn load_scene(
+    registry: &TypeRegistryArc,
+    storage: &dyn SaveStorage,
+    path: PathBuf,
+) -> Result<DynamicScene, SaveError> {
+    let bytes = storage.read(&path).await?;
+    let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+    let scene_deserializer = SceneDeserializer {
+        type_registry: &registry.read(),
+    };
+    scene_deserializer
+        .deserialize(&mut deserializer)
+        .map_err(|error| deserializer.span_error(error).into())
+}
+
+/// Replaces the [`Persist`] entities of the world with the entities of the scene.
+///
+/// The previous entities are only despawned once the scene was written successfully.
+fn replace_persisted_entities(world: &mut World, scene: &DynamicScene) -> Result<(), SaveError> {
+    let previous_entities = world
+        .query_filtered::<Entity, With<Persist>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+
+    // References to other entities in the scene are updated through `MapEntities`
+    let mut entity_map = EntityMap::default();
+    if let Err(error) = scene.write_to_world(world, &mut entity_map) {
+        for entity in entity_map.values() {
+            let _ = world.despawn(entity);
+        }
+        return Err(error.into());
+    }
+
+    for entity in previous_entities {
+        let _ = world.despawn(entity);
+    }
+    for entity in entity_map.values() {
+        world.entity_mut(entity).insert(Persist);
+    }
+    Ok(())
+}
+
+/// Polls the running saves and loads, applying the loaded scenes and emitting their events.
+pub fn save_task_system(world: &mut World) {
+    let mut tasks = world.resource_mut::<SaveTasks>();
+    let results = tasks.receiver.try_iter().collect::<Vec<_>>();
+    tasks.pending -= results.len();
+
+    for result in results {
+        match result {
+            SaveResult::Saved(slot, Ok(())) => world.send_event(GameSaved { slot }),
+            SaveResult::Loaded(slot, Ok(scene)) => {
+                match replace_persisted_entities(world, &scene) {
+                    Ok(()) => world.send_event(GameLoaded { slot }),
+                    Err(error) => world.send_event(SaveFailed { slot, error }),
+                }
+            }
+            SaveResult::Saved(slot, Err(error)) | SaveResult::Loaded(slot, Err(error)) => {
+                world.send_event(SaveFailed { slot, error });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::{
+        entity::{Entity, EntityMapper, MapEntities},
+        event::Events,
+        prelude::{Component, ReflectComponent, With},
+        reflect::ReflectMapEntities,
+        system::Command,
+        world::{FromWorld, World},
+    };
+    use bevy_reflect::Reflect;
+    use bevy_utils::HashMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Stats {
+        health: u32,
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component, MapEntities)]
+    struct Target(Entity);
+
+    impl FromWorld for Target {
+        fn from_world(_world: &mut World) -> Self {
+            Self(Entity::PLACEHOLDER)
+        }
+    }
+
+    impl MapEntities for Target {
+        fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+            self.0 = entity_mapper.get_or_reserve(self.0);
+        }
+    }
+
+    /// Creates an app saving to a new temporary directory, which is removed when dropped.
+    fn setup() -> (App, TempDirectory) {
+        let directory =
+            TempDirectory(std::env::temp_dir().join(format!("bevy_scene_save_{}", Uuid::new_v4())));
+
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_core::TaskPoolPlugin::default(),
+            bevy_core::TypeRegistrationPlugin,
+            SavePlugin,
+        ))
+        .register_type::<Stats>()
+        .register_type::<Target>();
+        app.world.resource_mut::<SaveSettings>().directory = directory.0.clone();
+        (app, directory)
+    }
+
+    struct TempDirectory(PathBuf);
+
+    impl Drop for TempDirectory {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Updates the app until the running saves and loads have completed and their results are applied.
+    fn complete_tasks(app: &mut App) {
+        while app.world.resource::<SaveTasks>().pending > 0 {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn should_save_and_load_persisted_entities() {
+        let (mut app, _directory) = setup();
+
+        let stats = app.world.spawn((Persist, Stats { health: 10 })).id();
+        let target = app.world.spawn((Persist, Target(stats))).id();
+        let transient = app.world.spawn(Stats { health: 20 }).id();
+
+        SaveGame {
+            slot: "slot".to_string(),
+        }
+        .apply(&mut app.world);
+        complete_tasks(&mut app);
+        assert_eq!(1, app.world.resource::<Events<GameSaved>>().len());
+
+        app.world.get_mut::<Stats>(stats).unwrap().health = 0;
+        app.world.spawn(Persist);
+
+        LoadGame {
+            slot: "slot".to_string(),
+        }
+        .apply(&mut app.world);
+        complete_tasks(&mut app);
+        assert_eq!(1, app.world.resource::<Events<GameLoaded>>().len());
+
+        // The previously persisted entities were replaced
+        assert!(app.world.get_entity(stats).is_none());
+        assert!(app.world.get_entity(target).is_none());
+        assert_eq!(
+            2,
+            app.world
+                .query_filtered::<Entity, With<Persist>>()
+                .iter(&app.world)
+                .count()
+        );
+        assert_eq!(
+            &Stats { health: 20 },
+            app.world.get::<Stats>(transient).unwrap()
+        );
+
+        // References between the loaded entities were mapped
+        let loaded_target = app.world.query::<&Target>().single(&app.world).0;
+        assert_eq!(
+            &Stats { health: 10 },
+            app.world.get::<Stats>(loaded_target).unwrap()
+        );
+        assert!(app.world.get::<Persist>(loaded_target).is_some());
+    }
+
+    #[test]
+    fn should_keep_entities_when_load_fails() {
+        let (mut app, _directory) = setup();
+
+        let stats = app.world.spawn((Persist, Stats { health: 10 })).id();
+
+        LoadGame {
+            slot: "missing".to_string(),
+        }
+        .apply(&mut app.world);
+        complete_tasks(&mut app);
+
+        let failure = app
+            .world
+            .resource_mut::<Events<SaveFailed>>()
+            .drain()
+            .next()
+            .unwrap();
+        assert_eq!("missing", failure.slot);
+        assert!(
+            matches!(failure.error, SaveError::Io(ref error) if error.kind() == io::ErrorKind::NotFound)
+        );
+        assert_eq!(
+            &Stats { health: 10 },
+            app.world.get::<Stats>(stats).unwrap()
+        );
+    }
+
+    /// Keeps the saves in memory.
+    #[derive(Debug, Default)]
+    struct MemoryStorage(Mutex<HashMap<PathBuf, Vec<u8>>>);
+
+    impl SaveStorage for MemoryStorage {
+        fn read<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, io::Result<Vec<u8>>> {
+            Box::pin(async move {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .get(path)
+                    .cloned()
+                    .ok_or_else(|| io::ErrorKind::NotFound.into())
+            })
+        }
+
+        fn write<'a>(&'a self, path: &'a Path, bytes: Vec<u8>) -> BoxedFuture<'a, io::Result<()>> {
+            Box::pin(async move {
+                self.0.lock().unwrap().insert(path.to_owned(), bytes);
+                Ok(())
+            })
+        }
+    }
+
+    #[test]
+    fn should_save_to_configured_storage() {
+        let (mut app, directory) = setup();
+        let storage = Arc::new(MemoryStorage::default());
+        let mut settings = app.world.resource_mut::<SaveSettings>();
+        settings.storage = storage.clone();
+        settings.directory = PathBuf::from("memory");
+
+        app.world.spawn((Persist, Stats { health: 10 }));
+        SaveGame {
+            slot: "slot".to_string(),
+        }
+        .apply(&mut app.world);
+        complete_tasks(&mut app);
+
+        assert!(storage
+            .0
+            .lock()
+            .unwrap()
+            .contains_key(Path::new("memory/slot.scn.ron")));
+        assert!(!directory.0.exists());
+
+        LoadGame {
+            slot: "slot".to_string(),
+        }
+        .apply(&mut app.world);
+        complete_tasks(&mut app);
+        assert_eq!(1, app.world.resource::<Events<GameLoaded>>().len());
+        assert_eq!(
+            &Stats { health: 10 },
+            app.world.query::<&Stats>().single(&app.world)
+        );
+    }
+
+    #[test]
+    fn should_reject_slots_outside_directory() {
+        let settings = SaveSettings::default();
+        assert!(settings.slot_path("slot").is_ok());
+        for slot in ["", "../slot", "..", "nested/slot", "nested\\slot"] {
+            assert!(matches!(
+                settings.slot_path(slot),
+                Err(SaveError::InvalidSlot(_))
+            ));
+        }
+
+        let (mut app, _directory) = setup();
+        LoadGame {
+            slot: "../slot".to_string(),
+        }
+        .apply(&mut app.world);
+        complete_tasks(&mut app);
+
+        let failure = app
+            .world
+            .resource_mut::<Events<SaveFailed>>()
+            .drain()
+            .next()
+            .unwrap();
+        assert!(matches!(failure.error, SaveError::InvalidSlot(_)));
+    }
+}
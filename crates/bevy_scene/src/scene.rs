@@ -5,7 +5,7 @@ use bevy_ecs::{
 };
 use bevy_reflect::{TypePath, TypeUuid};
 
-use crate::{DynamicScene, InstanceInfo, SceneSpawnError};
+use crate::{DynamicScene, InstanceInfo, SceneFilter, SceneSpawnError};
 
 /// To spawn a scene, you can use either:
 /// * [`SceneSpawner::spawn`](crate::SceneSpawner::spawn)
@@ -55,9 +55,17 @@ impl Scene {
         world: &mut World,
         type_registry: &AppTypeRegistry,
     ) -> Result<InstanceInfo, SceneSpawnError> {
-        let mut instance_info = InstanceInfo {
-            entity_map: EntityMap::default(),
-        };
+        self.write_to_world_filtered(world, type_registry, &SceneFilter::Unset)
+    }
+
+    /// Write the entities and the components allowed by the filter to the given world.
+    pub(crate) fn write_to_world_filtered(
+        &self,
+        world: &mut World,
+        type_registry: &AppTypeRegistry,
+        filter: &SceneFilter,
+    ) -> Result<InstanceInfo, SceneSpawnError> {
+        let mut instance_info = InstanceInfo::default();
 
         let type_registry = type_registry.read();
 
@@ -99,6 +107,9 @@ impl Scene {
                         .components()
                         .get_info(component_id)
                         .expect("component_ids in archetypes should have ComponentInfo");
+                    if !filter.is_allowed_by_id(component_info.type_id().unwrap()) {
+                        continue;
+                    }
 
                     let reflect_component = type_registry
                         .get(component_info.type_id().unwrap())
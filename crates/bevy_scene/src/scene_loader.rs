@@ -1,18 +1,39 @@
 #[cfg(feature = "serialize")]
-use crate::serde::SceneDeserializer;
+use crate::serde::{LenientSceneDeserializer, SceneDeserializer, ShortSceneDeserializer};
+use crate::DynamicScene;
+#[cfg(feature = "serialize")]
+use crate::SceneReport;
 use anyhow::{anyhow, Result};
-use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy_asset::{AssetLoader, AsyncReadExt, LoadContext, LoadedAsset, Reader};
 use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_ecs::system::Resource;
 use bevy_ecs::world::{FromWorld, World};
 use bevy_reflect::TypeRegistryArc;
+#[cfg(feature = "serialize")]
+use bevy_utils::tracing::warn;
 use bevy_utils::BoxedFuture;
+use std::any::TypeId;
 
 #[cfg(feature = "serialize")]
 use serde::de::DeserializeSeed;
 
+/// Settings of the [`SceneLoader`].
+///
+/// This resource must be inserted before the [`ScenePlugin`](crate::ScenePlugin) is added.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SceneLoaderSettings {
+    /// Skip unregistered types and unknown fields instead of failing to load the scene.
+    ///
+    /// Each skipped value is logged as a warning.
+    /// Scenes in the [short format](crate::serde::ShortSceneDeserializer) are never loaded leniently,
+    /// and a warning is logged when one is loaded with this setting.
+    pub lenient: bool,
+}
+
 #[derive(Debug)]
 pub struct SceneLoader {
     type_registry: TypeRegistryArc,
+    settings: SceneLoaderSettings,
 }
 
 impl FromWorld for SceneLoader {
@@ -20,6 +41,10 @@ impl FromWorld for SceneLoader {
         let type_registry = world.resource::<AppTypeRegistry>();
         SceneLoader {
             type_registry: type_registry.0.clone(),
+            settings: world
+                .get_resource::<SceneLoaderSettings>()
+                .cloned()
+                .unwrap_or_default(),
         }
     }
 }
@@ -28,31 +53,70 @@ impl FromWorld for SceneLoader {
 impl AssetLoader for SceneLoader {
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            let mut deserializer = ron::de::Deserializer::from_bytes(bytes)?;
-            let scene_deserializer = SceneDeserializer {
-                type_registry: &self.type_registry.read(),
-            };
-            let scene = scene_deserializer
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+            let type_registry = self.type_registry.read();
+            let mut report = SceneReport::default();
+            let is_short = load_context
+                .path()
+                .to_string_lossy()
+                .to_lowercase()
+                .ends_with(".short.scn.ron");
+            if is_short && self.settings.lenient {
+                warn!(
+                    "{} is in the short scene format, which can't be loaded leniently",
+                    load_context.path().to_string_lossy()
+                );
+            }
+            let scene = if is_short {
+                ShortSceneDeserializer {
+                    type_registry: &type_registry,
+                }
                 .deserialize(&mut deserializer)
-                .map_err(|e| {
-                    let span_error = deserializer.span_error(e);
-                    anyhow!(
-                        "{} at {}:{}",
-                        span_error.code,
-                        load_context.path().to_string_lossy(),
-                        span_error.position,
-                    )
-                })?;
-            load_context.set_default_asset(LoadedAsset::new(scene));
+            } else if self.settings.lenient {
+                LenientSceneDeserializer {
+                    type_registry: &type_registry,
+                    report: &mut report,
+                }
+                .deserialize(&mut deserializer)
+            } else {
+                SceneDeserializer {
+                    type_registry: &type_registry,
+                }
+                .deserialize(&mut deserializer)
+            };
+            let scene = scene.map_err(|e| {
+                let span_error = deserializer.span_error(e);
+                anyhow!(
+                    "{} at {}:{}",
+                    span_error.code,
+                    load_context.path().to_string_lossy(),
+                    span_error.position,
+                )
+            })?;
+            for issue in &report.issues {
+                warn!(
+                    "skipped a value of {}: {issue}",
+                    load_context.path().to_string_lossy()
+                );
+            }
+            // Make sure the scenes used as prefabs are loaded along with this one
+            let prefab_paths = scene.prefabs().map(|prefab| prefab.path.clone()).collect();
+            load_context.set_default_asset(LoadedAsset::new(scene).with_dependencies(prefab_paths));
             Ok(())
         })
     }
 
     fn extensions(&self) -> &[&str] {
-        &["scn", "scn.ron"]
+        &["scn", "scn.ron", "short.scn.ron"]
+    }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<DynamicScene>())
     }
 }
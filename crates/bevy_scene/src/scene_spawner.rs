@@ -1,14 +1,20 @@
-use crate::{DynamicScene, Scene};
-use bevy_asset::{AssetEvent, Assets, Handle};
+use crate::{
+    dynamic_scene::{write_entities_to_world, write_resources_to_world},
+    DynamicScene, PrefabOverride, Scene, SceneFilter,
+};
+use bevy_asset::{AssetEvent, AssetPath, Assets, Handle, HandleId};
 use bevy_ecs::{
     entity::{Entity, EntityMap},
     event::{Event, Events, ManualEventReader},
-    reflect::AppTypeRegistry,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
     system::{Command, Resource},
     world::{Mut, World},
 };
 use bevy_hierarchy::{AddChild, Parent};
+use bevy_reflect::{Reflect, TypeInfo, TypeRegistry};
+use bevy_transform::{components::Transform, TransformBundle};
 use bevy_utils::{tracing::error, HashMap, HashSet};
+use std::sync::{Arc, Weak};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -22,10 +28,236 @@ pub struct SceneInstanceReady {
 }
 
 /// Information about a scene instance.
-#[derive(Debug)]
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::{entity::{Entity, EntityMap}, world::World};
+/// # use bevy_scene::InstanceInfo;
+/// let mut world = World::new();
+/// let mut entity_map = EntityMap::default();
+/// entity_map.insert(Entity::from_raw(0), world.spawn_empty().id());
+///
+/// let instance = InstanceInfo {
+///     entity_map,
+///     ..Default::default()
+/// };
+/// assert_eq!(1, instance.root_entities(&world).count());
+/// ```
+#[derive(Debug, Default)]
 pub struct InstanceInfo {
     /// Mapping of entities from the scene world to the instance world.
     pub entity_map: EntityMap,
+    /// Instances of the [prefabs](crate::ScenePrefab) referenced by the scene,
+    /// keyed by the referencing entity in the scene world.
+    pub prefab_instances: HashMap<Entity, InstanceInfo>,
+    /// The components which are written to the world by the instance.
+    pub filter: SceneFilter,
+    /// The content of the scene as it was last written to the world.
+    pub snapshot: InstanceSnapshot,
+}
+
+/// The content of a scene, shared by all the instances which were written
+/// to the world from the same version of the scene.
+#[derive(Debug, Default)]
+struct SceneSnapshot {
+    resources: Vec<Box<dyn Reflect>>,
+    entities: Vec<(Entity, Vec<Box<dyn Reflect>>)>,
+}
+
+impl SceneSnapshot {
+    fn new(scene: &DynamicScene) -> Self {
+        Self {
+            resources: scene
+                .resources
+                .iter()
+                .map(|resource| resource.clone_value())
+                .collect(),
+            entities: scene
+                .entities
+                .iter()
+                .map(|entity| {
+                    (
+                        entity.entity,
+                        entity
+                            .components
+                            .iter()
+                            .map(|component| component.clone_value())
+                            .collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The resources and components written to the world by a scene instance.
+///
+/// When the scene is modified, the new content is compared against this
+/// so that only the changes are written to the world.
+#[derive(Debug, Default)]
+pub struct InstanceSnapshot {
+    scene: Option<HandleId>,
+    content: Arc<SceneSnapshot>,
+    /// The components modified or added by the overrides of the prefab, if the instance is one.
+    overridden: Vec<(Entity, Box<dyn Reflect>)>,
+}
+
+impl InstanceSnapshot {
+    /// Resolves the given prefab overrides on top of the content of the scene.
+    fn new(
+        scene_handle: HandleId,
+        content: Arc<SceneSnapshot>,
+        overrides: &[PrefabOverride],
+    ) -> Self {
+        let mut overridden: Vec<(Entity, Box<dyn Reflect>)> = Vec::new();
+        for entity_override in overrides {
+            for component_override in &entity_override.components {
+                if let Some((_, component)) = overridden.iter_mut().find(|(entity, component)| {
+                    *entity == entity_override.entity
+                        && component.type_name() == component_override.type_name()
+                }) {
+                    component.apply(&**component_override);
+                    continue;
+                }
+
+                let component = content
+                    .entities
+                    .iter()
+                    .find(|(entity, _)| *entity == entity_override.entity)
+                    .and_then(|(_, components)| {
+                        components.iter().find(|component| {
+                            component.type_name() == component_override.type_name()
+                        })
+                    });
+                let component = match component {
+                    Some(component) => {
+                        let mut component = component.clone_value();
+                        component.apply(&**component_override);
+                        component
+                    }
+                    None => component_override.clone_value(),
+                };
+                overridden.push((entity_override.entity, component));
+            }
+        }
+
+        Self {
+            scene: Some(scene_handle),
+            content,
+            overridden,
+        }
+    }
+
+    /// Returns the components of each entity, with the prefab overrides applied
+    /// and leaving out the components denied by the filter.
+    fn entities(&self, filter: &SceneFilter) -> Vec<(Entity, Vec<&dyn Reflect>)> {
+        let overridden = |entity: Entity, type_name: &str| {
+            self.overridden
+                .iter()
+                .find(|(overridden, component)| {
+                    *overridden == entity && component.type_name() == type_name
+                })
+                .map(|(_, component)| &**component)
+        };
+        let mut entities: Vec<(Entity, Vec<&dyn Reflect>)> = self
+            .content
+            .entities
+            .iter()
+            .map(|(entity, components)| {
+                let components = components
+                    .iter()
+                    .map(|component| {
+                        overridden(*entity, component.type_name()).unwrap_or(&**component)
+                    })
+                    .collect();
+                (*entity, components)
+            })
+            .collect();
+
+        // Overrides can add components, and entities, which aren't part of the scene
+        for (entity, component) in &self.overridden {
+            let components = match entities
+                .iter_mut()
+                .find(|(scene_entity, _)| scene_entity == entity)
+            {
+                Some((_, components)) => components,
+                None => {
+                    entities.push((*entity, Vec::new()));
+                    &mut entities.last_mut().unwrap().1
+                }
+            };
+            if !components
+                .iter()
+                .any(|current| current.type_name() == component.type_name())
+            {
+                components.push(&**component);
+            }
+        }
+
+        for (_, components) in &mut entities {
+            components.retain(|component| {
+                let type_id = component
+                    .get_represented_type_info()
+                    .map_or_else(|| component.as_any().type_id(), TypeInfo::type_id);
+                filter.is_allowed_by_id(type_id)
+            });
+        }
+        entities
+    }
+}
+
+/// Returns true if `values` contains a value equal to `value`.
+fn contains_equal(values: &[&dyn Reflect], value: &dyn Reflect) -> bool {
+    values.iter().any(|other| {
+        other.type_name() == value.type_name() && other.reflect_partial_eq(value) == Some(true)
+    })
+}
+
+impl InstanceInfo {
+    /// Returns an iterator over the entities of this instance, including those of its prefab instances.
+    pub fn entities(&self) -> Box<dyn Iterator<Item = Entity> + '_> {
+        Box::new(
+            self.entity_map.values().chain(
+                self.prefab_instances
+                    .values()
+                    .flat_map(InstanceInfo::entities),
+            ),
+        )
+    }
+
+    /// Returns an iterator over the root entities of this instance, which are the entities
+    /// without a parent, or whose parent isn't part of the instance.
+    pub fn root_entities<'a>(&'a self, world: &'a World) -> impl Iterator<Item = Entity> + 'a {
+        let entities = self.entities().collect::<HashSet<_>>();
+        self.entities().filter(move |entity| {
+            world.get_entity(*entity).is_some_and(|entity| {
+                !entity
+                    .get::<Parent>()
+                    .is_some_and(|parent| entities.contains(&parent.get()))
+            })
+        })
+    }
+}
+
+/// Options to customize the spawning of a scene instance, see [`SceneSpawner::spawn_with`].
+///
+/// The options are applied as soon as the instance is written to the world,
+/// by the same run of [`scene_spawner_system`]. When a dynamic scene is modified, they are
+/// applied again to the root entities of its instances which were spawned, or whose
+/// [`Transform`] was written, by the update.
+#[derive(Default)]
+pub struct SpawnOptions {
+    /// Entity to which the root entities of the instance are added as children.
+    pub parent: Option<Entity>,
+    /// Transform applied on top of the [`Transform`] of the root entities of the instance,
+    /// which are given a [`TransformBundle`] if they don't have one.
+    pub transform: Option<Transform>,
+    /// Called once the instance is spawned, with the mapping from the entities of the scene
+    /// to the entities of the instance.
+    pub on_spawned: Option<Box<dyn FnOnce(&mut World, &EntityMap) + Send + Sync>>,
+    /// The components spawned from the scene. Components denied by the filter are left out.
+    pub filter: SceneFilter,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -44,10 +276,13 @@ pub struct SceneSpawner {
     spawned_instances: HashMap<InstanceId, InstanceInfo>,
     scene_asset_event_reader: ManualEventReader<AssetEvent<DynamicScene>>,
     dynamic_scenes_to_spawn: Vec<(Handle<DynamicScene>, InstanceId)>,
+    dynamic_scenes_to_update: Vec<Handle<DynamicScene>>,
     scenes_to_spawn: Vec<(Handle<Scene>, InstanceId)>,
     scenes_to_despawn: Vec<Handle<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    spawn_options: HashMap<InstanceId, SpawnOptions>,
+    scene_snapshots: HashMap<HandleId, Weak<SceneSnapshot>>,
 }
 
 #[derive(Error, Debug)]
@@ -62,6 +297,8 @@ pub enum SceneSpawnError {
     NonExistentScene { handle: Handle<DynamicScene> },
     #[error("scene does not exist")]
     NonExistentRealScene { handle: Handle<Scene> },
+    #[error("scene contains itself through the prefab `{path:?}`")]
+    RecursivePrefab { path: AssetPath<'static> },
 }
 
 impl SceneSpawner {
@@ -97,6 +334,30 @@ impl SceneSpawner {
         instance_id
     }
 
+    /// Spawns the scene, customized with the given options.
+    ///
+    /// Unlike [`Self::spawn_as_child`], the options are applied synchronously once the scene
+    /// is spawned, so that there is no need to wait for the [`SceneInstanceReady`] event.
+    pub fn spawn_with(&mut self, scene_handle: Handle<Scene>, options: SpawnOptions) -> InstanceId {
+        let instance_id = self.spawn(scene_handle);
+        self.spawn_options.insert(instance_id, options);
+        instance_id
+    }
+
+    /// Spawns the dynamic scene, customized with the given options.
+    ///
+    /// The options are also used when the instance is updated after the scene is modified,
+    /// except for [`SpawnOptions::on_spawned`] which is only called once.
+    pub fn spawn_dynamic_with(
+        &mut self,
+        scene_handle: Handle<DynamicScene>,
+        options: SpawnOptions,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic(scene_handle);
+        self.spawn_options.insert(instance_id, options);
+        instance_id
+    }
+
     pub fn despawn(&mut self, scene_handle: Handle<DynamicScene>) {
         self.scenes_to_despawn.push(scene_handle);
     }
@@ -119,8 +380,9 @@ impl SceneSpawner {
     }
 
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
+        self.spawn_options.remove(instance_id);
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
-            for entity in instance.entity_map.values() {
+            for entity in instance.entities() {
                 let _ = world.despawn(entity);
             }
         }
@@ -131,11 +393,12 @@ impl SceneSpawner {
         world: &mut World,
         scene_handle: &Handle<DynamicScene>,
     ) -> Result<(), SceneSpawnError> {
-        let mut entity_map = EntityMap::default();
-        Self::spawn_dynamic_internal(world, scene_handle, &mut entity_map)?;
+        let mut instance_info = InstanceInfo::default();
+        let mut snapshots = self.cached_snapshots();
+        Self::spawn_dynamic_internal(world, scene_handle, &mut instance_info, &mut snapshots)?;
+        self.cache_snapshots(snapshots);
         let instance_id = InstanceId::new();
-        self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+        self.spawned_instances.insert(instance_id, instance_info);
         let spawned = self
             .spawned_dynamic_scenes
             .entry(scene_handle.clone())
@@ -147,16 +410,329 @@ impl SceneSpawner {
     fn spawn_dynamic_internal(
         world: &mut World,
         scene_handle: &Handle<DynamicScene>,
-        entity_map: &mut EntityMap,
+        instance_info: &mut InstanceInfo,
+        snapshots: &mut HashMap<HandleId, Arc<SceneSnapshot>>,
     ) -> Result<(), SceneSpawnError> {
         world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
-            let scene =
-                scenes
-                    .get(scene_handle)
-                    .ok_or_else(|| SceneSpawnError::NonExistentScene {
-                        handle: scene_handle.clone_weak(),
-                    })?;
-            scene.write_to_world(world, entity_map)
+            // Make sure every prefab is available before writing anything,
+            // so that the spawn can be retried once they are loaded
+            Self::validate_prefabs(&scenes, scene_handle, &mut Vec::new())?;
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = type_registry.read();
+            let filter = instance_info.filter.clone();
+            Self::write_dynamic_scene(
+                world,
+                &scenes,
+                scene_handle,
+                &[],
+                &filter,
+                instance_info,
+                snapshots,
+                &type_registry,
+            )
+        })
+    }
+
+    /// Returns the snapshots of the scenes which are still used by an instance.
+    fn cached_snapshots(&self) -> HashMap<HandleId, Arc<SceneSnapshot>> {
+        self.scene_snapshots
+            .iter()
+            .filter_map(|(scene, snapshot)| Some((*scene, snapshot.upgrade()?)))
+            .collect()
+    }
+
+    /// Keeps the given snapshots so that the next instances of the same scenes can share them.
+    fn cache_snapshots(&mut self, snapshots: HashMap<HandleId, Arc<SceneSnapshot>>) {
+        self.scene_snapshots
+            .retain(|_, snapshot| snapshot.strong_count() > 0);
+        self.scene_snapshots.extend(
+            snapshots
+                .iter()
+                .map(|(scene, snapshot)| (*scene, Arc::downgrade(snapshot))),
+        );
+    }
+
+    fn validate_prefabs(
+        scenes: &Assets<DynamicScene>,
+        scene_handle: &Handle<DynamicScene>,
+        stack: &mut Vec<HandleId>,
+    ) -> Result<(), SceneSpawnError> {
+        let scene = scenes
+            .get(scene_handle)
+            .ok_or_else(|| SceneSpawnError::NonExistentScene {
+                handle: scene_handle.clone_weak(),
+            })?;
+
+        stack.push(scene_handle.id());
+        for prefab in scene.prefabs() {
+            let prefab_handle = prefab.handle();
+            if stack.contains(&prefab_handle.id()) {
+                return Err(SceneSpawnError::RecursivePrefab {
+                    path: prefab.path.clone(),
+                });
+            }
+            Self::validate_prefabs(scenes, &prefab_handle, stack)?;
+        }
+        stack.pop();
+
+        Ok(())
+    }
+
+    /// Writes the scene to the world, updating the entities of the instance if it was already written.
+    ///
+    /// Only the differences with the content previously written by the instance are applied,
+    /// so that components added at runtime and the identity of the entities are preserved.
+    #[allow(clippy::too_many_arguments)]
+    fn write_dynamic_scene(
+        world: &mut World,
+        scenes: &Assets<DynamicScene>,
+        scene_handle: &Handle<DynamicScene>,
+        overrides: &[PrefabOverride],
+        filter: &SceneFilter,
+        instance_info: &mut InstanceInfo,
+        snapshots: &mut HashMap<HandleId, Arc<SceneSnapshot>>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        let scene = scenes
+            .get(scene_handle)
+            .ok_or_else(|| SceneSpawnError::NonExistentScene {
+                handle: scene_handle.clone_weak(),
+            })?;
+        let content = snapshots
+            .entry(scene_handle.id())
+            .or_insert_with(|| Arc::new(SceneSnapshot::new(scene)))
+            .clone();
+        let snapshot = InstanceSnapshot::new(scene_handle.id(), content, overrides);
+        let previous = std::mem::take(&mut instance_info.snapshot);
+
+        // Remove the resources which were removed from the scene
+        for resource in &previous.content.resources {
+            if snapshot
+                .content
+                .resources
+                .iter()
+                .any(|current| current.type_name() == resource.type_name())
+            {
+                continue;
+            }
+            if let Some(reflect_resource) = type_registry
+                .get_with_name(resource.type_name())
+                .and_then(|registration| registration.data::<ReflectResource>())
+            {
+                reflect_resource.remove(world);
+            }
+        }
+
+        let previous_resources = previous
+            .content
+            .resources
+            .iter()
+            .map(|resource| &**resource)
+            .collect::<Vec<_>>();
+        write_resources_to_world(
+            snapshot
+                .content
+                .resources
+                .iter()
+                .map(|resource| &**resource)
+                .filter(|resource| !contains_equal(&previous_resources, *resource)),
+            world,
+            type_registry,
+        )?;
+
+        let previous_entities: HashMap<Entity, Vec<&dyn Reflect>> =
+            previous.entities(filter).into_iter().collect();
+        let current_entities = snapshot.entities(filter);
+
+        // Despawn the entities which were removed from the scene
+        for scene_entity in previous_entities.keys() {
+            if current_entities
+                .iter()
+                .any(|(current, _)| current == scene_entity)
+            {
+                continue;
+            }
+            if let Some(entity) = instance_info.entity_map.remove(*scene_entity) {
+                let _ = world.despawn(entity);
+            }
+            if let Some(prefab_info) = instance_info.prefab_instances.remove(scene_entity) {
+                for entity in prefab_info.entities() {
+                    let _ = world.despawn(entity);
+                }
+            }
+        }
+
+        // Remove the components which were removed from the scene
+        for (scene_entity, components) in &current_entities {
+            let Some(previous_components) = previous_entities.get(scene_entity) else {
+                continue;
+            };
+            let Some(mut entity_mut) = instance_info
+                .entity_map
+                .get(*scene_entity)
+                .and_then(|entity| world.get_entity_mut(entity))
+            else {
+                continue;
+            };
+            for component in previous_components {
+                if components
+                    .iter()
+                    .any(|current| current.type_name() == component.type_name())
+                {
+                    continue;
+                }
+                if let Some(reflect_component) = type_registry
+                    .get_with_name(component.type_name())
+                    .and_then(|registration| registration.data::<ReflectComponent>())
+                {
+                    reflect_component.remove(&mut entity_mut);
+                }
+            }
+        }
+
+        // Unchanged components referencing other entities would keep pointing to the previous
+        // entities, so they are written again when entities of the scene are (re)spawned
+        let spawns_entities = current_entities
+            .iter()
+            .any(|(scene_entity, _)| instance_info.entity_map.get(*scene_entity).is_none());
+        let maps_entities = |component: &dyn Reflect| {
+            type_registry
+                .get_with_name(component.type_name())
+                .is_some_and(|registration| registration.data::<ReflectMapEntities>().is_some())
+        };
+
+        // Write the components which were added or changed, spawning the entities which were
+        // added to the scene. Entities which were despawned by something else are left alone.
+        let changed_entities = current_entities
+            .iter()
+            .filter(
+                |(scene_entity, _)| match instance_info.entity_map.get(*scene_entity) {
+                    Some(entity) => world.get_entity(entity).is_some(),
+                    None => true,
+                },
+            )
+            .map(|(scene_entity, components)| {
+                let previous_components = previous_entities
+                    .get(scene_entity)
+                    .map_or(&[][..], Vec::as_slice);
+                let changed_components = components
+                    .iter()
+                    .copied()
+                    .filter(|component| {
+                        !contains_equal(previous_components, *component)
+                            || (spawns_entities && maps_entities(*component))
+                    })
+                    .collect::<Vec<_>>();
+                (*scene_entity, changed_components)
+            })
+            .collect::<Vec<_>>();
+        write_entities_to_world(
+            changed_entities.into_iter(),
+            world,
+            &mut instance_info.entity_map,
+            type_registry,
+        )?;
+
+        // Mapping references to entities which aren't part of the scene reserves dead entities
+        // for them, which would prevent spawning them if they're added to the scene later
+        let reserved = instance_info
+            .entity_map
+            .keys()
+            .filter(|scene_entity| {
+                !current_entities
+                    .iter()
+                    .any(|(current, _)| current == scene_entity)
+            })
+            .collect::<Vec<_>>();
+        for scene_entity in reserved {
+            instance_info.entity_map.remove(scene_entity);
+        }
+
+        instance_info.snapshot = snapshot;
+
+        // Despawn the prefab instances of entities which now reference another scene, or none
+        let prefabs: HashMap<Entity, HandleId> = scene
+            .entities
+            .iter()
+            .filter_map(|entity| {
+                let prefab = entity.prefab.as_ref()?;
+                Some((entity.entity, prefab.handle().id()))
+            })
+            .collect();
+        instance_info
+            .prefab_instances
+            .retain(|scene_entity, prefab_info| {
+                let retain = prefab_info.snapshot.scene.as_ref() == prefabs.get(scene_entity);
+                if !retain {
+                    for entity in prefab_info.entities() {
+                        let _ = world.despawn(entity);
+                    }
+                }
+                retain
+            });
+
+        for scene_entity in &scene.entities {
+            let Some(prefab) = &scene_entity.prefab else {
+                continue;
+            };
+            let Some(parent) = instance_info.entity_map.get(scene_entity.entity) else {
+                continue;
+            };
+            if world.get_entity(parent).is_none() {
+                continue;
+            }
+
+            let prefab_info = instance_info
+                .prefab_instances
+                .entry(scene_entity.entity)
+                .or_default();
+            Self::write_dynamic_scene(
+                world,
+                scenes,
+                &prefab.handle(),
+                &prefab.overrides,
+                filter,
+                prefab_info,
+                snapshots,
+                type_registry,
+            )?;
+
+            // Parent the roots of the prefab to the entity referencing it
+            for entity in prefab_info.entity_map.values() {
+                if world
+                    .get_entity(entity)
+                    .is_some_and(|entity| !entity.contains::<Parent>())
+                {
+                    AddChild {
+                        parent,
+                        child: entity,
+                    }
+                    .apply(world);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the scene references the target scene through its prefabs, recursively.
+    fn references_prefab(
+        scenes: &Assets<DynamicScene>,
+        scene_handle: &Handle<DynamicScene>,
+        target: HandleId,
+        visited: &mut HashSet<HandleId>,
+    ) -> bool {
+        if !visited.insert(scene_handle.id()) {
+            return false;
+        }
+
+        let Some(scene) = scenes.get(scene_handle) else {
+            return false;
+        };
+        scene.prefabs().any(|prefab| {
+            let prefab_handle = prefab.handle();
+            prefab_handle.id() == target
+                || Self::references_prefab(scenes, &prefab_handle, target, visited)
         })
     }
 
@@ -165,7 +741,7 @@ impl SceneSpawner {
         world: &mut World,
         scene_handle: Handle<Scene>,
     ) -> Result<InstanceId, SceneSpawnError> {
-        self.spawn_sync_internal(world, scene_handle, InstanceId::new())
+        self.spawn_sync_internal(world, scene_handle, InstanceId::new(), SceneFilter::Unset)
     }
 
     fn spawn_sync_internal(
@@ -173,6 +749,7 @@ impl SceneSpawner {
         world: &mut World,
         scene_handle: Handle<Scene>,
         instance_id: InstanceId,
+        filter: SceneFilter,
     ) -> Result<InstanceId, SceneSpawnError> {
         world.resource_scope(|world, scenes: Mut<Assets<Scene>>| {
             let scene =
@@ -182,8 +759,12 @@ impl SceneSpawner {
                         handle: scene_handle.clone(),
                     })?;
 
-            let instance_info =
-                scene.write_to_world_with(world, &world.resource::<AppTypeRegistry>().clone())?;
+            let mut instance_info = scene.write_to_world_filtered(
+                world,
+                &world.resource::<AppTypeRegistry>().clone(),
+                &filter,
+            )?;
+            instance_info.filter = filter;
 
             self.spawned_instances.insert(instance_id, instance_info);
             let spawned = self
@@ -195,24 +776,61 @@ impl SceneSpawner {
         })
     }
 
+    /// Updates the instances of the given scenes after they, or the prefabs they use, were modified.
+    ///
+    /// If a prefab of a scene isn't loaded, its instances are left as they are and the update is
+    /// retried by the [`scene_spawner_system`]. If a scene now contains itself through its
+    /// prefabs, the error is logged and its instances are left as they are.
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_handles: &[Handle<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
+        // The scenes may have been modified, so their snapshots are taken again
+        let mut snapshots = HashMap::default();
         for scene_handle in scene_handles {
-            if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(scene_handle) {
-                for instance_id in spawned_instances {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(
-                            world,
-                            scene_handle,
-                            &mut instance_info.entity_map,
-                        )?;
+            let Some(spawned_instances) = self.spawned_dynamic_scenes.get(scene_handle) else {
+                continue;
+            };
+            for instance_id in spawned_instances.clone() {
+                let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) else {
+                    continue;
+                };
+                let previous_transforms =
+                    self.spawn_options.contains_key(&instance_id).then(|| {
+                        instance_info
+                            .entities()
+                            .map(|entity| (entity, world.get::<Transform>(entity).copied()))
+                            .collect::<HashMap<_, _>>()
+                    });
+                // Prefabs are validated before anything is written, so a failed update leaves
+                // the instance untouched
+                match Self::spawn_dynamic_internal(
+                    world,
+                    scene_handle,
+                    instance_info,
+                    &mut snapshots,
+                ) {
+                    Ok(_) => {}
+                    Err(SceneSpawnError::NonExistentScene { handle })
+                        if handle.id() != scene_handle.id() =>
+                    {
+                        self.dynamic_scenes_to_update
+                            .push(scene_handle.clone_weak());
+                        break;
                     }
+                    Err(err @ SceneSpawnError::RecursivePrefab { .. }) => {
+                        error!("{err}, its instances are not updated");
+                        break;
+                    }
+                    Err(err) => return Err(err),
+                }
+                if let Some(previous_transforms) = previous_transforms {
+                    self.apply_spawn_options(world, instance_id, Some(&previous_transforms));
                 }
             }
         }
+        self.cache_snapshots(snapshots);
         Ok(())
     }
 
@@ -236,32 +854,49 @@ impl SceneSpawner {
     pub fn spawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
         let scenes_to_spawn = std::mem::take(&mut self.dynamic_scenes_to_spawn);
 
+        let mut snapshots = self.cached_snapshots();
         for (scene_handle, instance_id) in scenes_to_spawn {
-            let mut entity_map = EntityMap::default();
+            let mut instance_info = InstanceInfo {
+                filter: self.spawn_filter(instance_id),
+                ..Default::default()
+            };
 
-            match Self::spawn_dynamic_internal(world, &scene_handle, &mut entity_map) {
+            match Self::spawn_dynamic_internal(
+                world,
+                &scene_handle,
+                &mut instance_info,
+                &mut snapshots,
+            ) {
                 Ok(_) => {
-                    self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                    self.spawned_instances.insert(instance_id, instance_info);
                     let spawned = self
                         .spawned_dynamic_scenes
                         .entry(scene_handle.clone())
                         .or_insert_with(Vec::new);
                     spawned.push(instance_id);
+                    self.apply_spawn_options(world, instance_id, None);
                 }
                 Err(SceneSpawnError::NonExistentScene { .. }) => {
                     self.dynamic_scenes_to_spawn
                         .push((scene_handle, instance_id));
                 }
+                Err(err @ SceneSpawnError::RecursivePrefab { .. }) => {
+                    error!("{err}, the instance is not spawned");
+                    self.spawn_options.remove(&instance_id);
+                    self.scenes_with_parent
+                        .retain(|(instance, _)| *instance != instance_id);
+                }
                 Err(err) => return Err(err),
             }
         }
+        self.cache_snapshots(snapshots);
 
         let scenes_to_spawn = std::mem::take(&mut self.scenes_to_spawn);
 
         for (scene_handle, instance_id) in scenes_to_spawn {
-            match self.spawn_sync_internal(world, scene_handle, instance_id) {
-                Ok(_) => {}
+            let filter = self.spawn_filter(instance_id);
+            match self.spawn_sync_internal(world, scene_handle, instance_id, filter) {
+                Ok(_) => self.apply_spawn_options(world, instance_id, None),
                 Err(SceneSpawnError::NonExistentRealScene { handle }) => {
                     self.scenes_to_spawn.push((handle, instance_id));
                 }
@@ -272,43 +907,122 @@ impl SceneSpawner {
         Ok(())
     }
 
-    pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
-        let scenes_with_parent = std::mem::take(&mut self.scenes_with_parent);
+    /// Returns the filter of the components to spawn for the instance.
+    fn spawn_filter(&self, instance_id: InstanceId) -> SceneFilter {
+        self.spawn_options
+            .get(&instance_id)
+            .map(|options| options.filter.clone())
+            .unwrap_or_default()
+    }
 
-        for (instance_id, parent) in scenes_with_parent {
-            if let Some(instance) = self.spawned_instances.get(&instance_id) {
-                for entity in instance.entity_map.values() {
-                    // Add the `Parent` component to the scene root, and update the `Children` component of
-                    // the scene parent
-                    if !world
-                        .get_entity(entity)
-                        // This will filter only the scene root entity, as all other from the
-                        // scene have a parent
-                        .map(|entity| entity.contains::<Parent>())
-                        // Default is true so that it won't run on an entity that wouldn't exist anymore
-                        // this case shouldn't happen anyway
-                        .unwrap_or(true)
-                    {
+    /// Applies the [`SpawnOptions`] of an instance, if it has any.
+    ///
+    /// When the instance was just updated after its scene was modified, `previous_transforms`
+    /// holds the transforms of its entities before the update. The options are then only applied
+    /// to the root entities which were spawned, or whose transform was written, by the update.
+    fn apply_spawn_options(
+        &mut self,
+        world: &mut World,
+        instance_id: InstanceId,
+        previous_transforms: Option<&HashMap<Entity, Option<Transform>>>,
+    ) {
+        let Some(options) = self.spawn_options.get_mut(&instance_id) else {
+            return;
+        };
+        let Some(instance) = self.spawned_instances.get(&instance_id) else {
+            return;
+        };
+
+        let roots = instance
+            .root_entities(world)
+            .filter(|root| {
+                let Some(previous) = previous_transforms.and_then(|previous| previous.get(root))
+                else {
+                    return true;
+                };
+                world.get::<Transform>(*root) != previous.as_ref()
+            })
+            .collect::<Vec<_>>();
+        if let Some(transform) = options.transform {
+            for root in &roots {
+                let mut root = world.entity_mut(*root);
+                match root.get_mut::<Transform>() {
+                    Some(mut root_transform) => *root_transform = transform * *root_transform,
+                    None => {
+                        root.insert(TransformBundle::from_transform(transform));
+                    }
+                }
+            }
+        }
+        if let Some(parent) = options.parent {
+            if previous_transforms.is_some() {
+                for root in roots {
+                    if !world.entity(root).contains::<Parent>() {
                         AddChild {
                             parent,
-                            child: entity,
+                            child: root,
                         }
                         .apply(world);
-
-                        world.send_event(SceneInstanceReady { parent });
                     }
                 }
+            } else {
+                Self::set_instance_parent(world, instance, parent);
+            }
+        }
+        if let Some(on_spawned) = options.on_spawned.take() {
+            on_spawned(world, &instance.entity_map);
+        }
+    }
+
+    pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
+        let scenes_with_parent = std::mem::take(&mut self.scenes_with_parent);
+
+        for (instance_id, parent) in scenes_with_parent {
+            if let Some(instance) = self.spawned_instances.get(&instance_id) {
+                Self::set_instance_parent(world, instance, parent);
             } else {
                 self.scenes_with_parent.push((instance_id, parent));
             }
         }
     }
 
+    fn set_instance_parent(world: &mut World, instance: &InstanceInfo, parent: Entity) {
+        for entity in instance.entities() {
+            // Add the `Parent` component to the scene root, and update the `Children` component of
+            // the scene parent
+            if !world
+                .get_entity(entity)
+                // This will filter only the scene root entity, as all other from the
+                // scene have a parent
+                .map(|entity| entity.contains::<Parent>())
+                // Default is true so that it won't run on an entity that wouldn't exist anymore
+                // this case shouldn't happen anyway
+                .unwrap_or(true)
+            {
+                AddChild {
+                    parent,
+                    child: entity,
+                }
+                .apply(world);
+
+                world.send_event(SceneInstanceReady { parent });
+            }
+        }
+    }
+
     /// Check that an scene instance spawned previously is ready to use
     pub fn instance_is_ready(&self, instance_id: InstanceId) -> bool {
         self.spawned_instances.contains_key(&instance_id)
     }
 
+    /// Get the information about an instance, once it's spawned.
+    ///
+    /// It can be used to find the root entities of the instance with [`InstanceInfo::root_entities`],
+    /// or the entities spawned for the entities of the scene with [`InstanceInfo::entity_map`].
+    pub fn instance_info(&self, instance_id: InstanceId) -> Option<&InstanceInfo> {
+        self.spawned_instances.get(&instance_id)
+    }
+
     /// Get an iterator over the entities in an instance, once it's spawned.
     ///
     /// Before the scene is spawned, the iterator will be empty. Use [`Self::instance_is_ready`]
@@ -319,7 +1033,7 @@ impl SceneSpawner {
     ) -> impl Iterator<Item = Entity> + '_ {
         self.spawned_instances
             .get(&instance_id)
-            .map(|instance| instance.entity_map.values())
+            .map(|instance| instance.entities())
             .into_iter()
             .flatten()
     }
@@ -340,6 +1054,18 @@ pub fn scene_spawner_system(world: &mut World) {
 
                 retain
             });
+        scene_spawner.spawn_options.retain(|instance, options| {
+            let retain = match options.parent {
+                Some(parent) => world.get_entity(parent).is_some(),
+                None => true,
+            };
+
+            if !retain {
+                dead_instances.insert(*instance);
+            }
+
+            retain
+        });
         scene_spawner
             .dynamic_scenes_to_spawn
             .retain(|(_, instance)| !dead_instances.contains(instance));
@@ -349,15 +1075,37 @@ pub fn scene_spawner_system(world: &mut World) {
 
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
-        let mut updated_spawned_scenes = Vec::new();
         let scene_spawner = &mut *scene_spawner;
-        for event in scene_spawner
+        let modified_scenes = scene_spawner
             .scene_asset_event_reader
             .iter(scene_asset_events)
-        {
-            if let AssetEvent::Modified { handle } = event {
-                if scene_spawner.spawned_dynamic_scenes.contains_key(handle) {
-                    updated_spawned_scenes.push(handle.clone_weak());
+            .filter_map(|event| match event {
+                AssetEvent::Modified { handle } => Some(handle.id()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Update the instances of modified scenes, along with those using them as prefabs,
+        // and retry the updates which were waiting for a prefab to be loaded
+        let mut updated_spawned_scenes =
+            std::mem::take(&mut scene_spawner.dynamic_scenes_to_update);
+        if !modified_scenes.is_empty() {
+            scene_spawner
+                .scene_snapshots
+                .retain(|scene, _| !modified_scenes.contains(scene));
+            let scenes = world.resource::<Assets<DynamicScene>>();
+            for scene_handle in scene_spawner.spawned_dynamic_scenes.keys() {
+                if modified_scenes.iter().any(|&modified| {
+                    scene_handle.id() == modified
+                        || SceneSpawner::references_prefab(
+                            scenes,
+                            scene_handle,
+                            modified,
+                            &mut HashSet::default(),
+                        )
+                }) && !updated_spawned_scenes.contains(scene_handle)
+                {
+                    updated_spawned_scenes.push(scene_handle.clone_weak());
                 }
             }
         }
@@ -367,9 +1115,667 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .spawn_queued_scenes(world)
             .unwrap_or_else(|err| panic!("{}", err));
-        scene_spawner
-            .update_spawned_scenes(world, &updated_spawned_scenes)
-            .unwrap();
+        if let Err(err) = scene_spawner.update_spawned_scenes(world, &updated_spawned_scenes) {
+            error!("failed to update scene instances: {err}");
+        }
         scene_spawner.set_scene_instance_parent_sync(world);
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{AssetPlugin, Assets, HandleId};
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, EntityMapper, MapEntities},
+        prelude::{ReflectComponent, ReflectResource},
+        query::{With, Without},
+        reflect::ReflectMapEntities,
+        world::{FromWorld, World},
+    };
+    use bevy_hierarchy::Parent;
+    use bevy_reflect::{DynamicStruct, Reflect, Typed};
+
+    use super::*;
+    use crate::{DynamicEntity, PrefabOverride, ScenePlugin, ScenePrefab};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Stats {
+        health: u32,
+        speed: u32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Root;
+
+    /// A component added at runtime, which isn't part of any scene.
+    #[derive(Component)]
+    struct Marker;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component, MapEntities)]
+    struct Target(Entity);
+
+    impl FromWorld for Target {
+        fn from_world(_world: &mut World) -> Self {
+            Self(Entity::PLACEHOLDER)
+        }
+    }
+
+    impl MapEntities for Target {
+        fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+            self.0 = entity_mapper.get_or_reserve(self.0);
+        }
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Settings;
+
+    const PREFAB_PATH: &str = "prefab.scn.ron";
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy_core::TaskPoolPlugin::default(),
+            bevy_core::TypeRegistrationPlugin,
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Stats>()
+        .register_type::<Root>()
+        .register_type::<Target>()
+        .register_type::<Settings>();
+        app
+    }
+
+    fn prefab_scene(speed: u32) -> DynamicScene {
+        DynamicScene {
+            resources: Vec::new(),
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(Stats { health: 100, speed })],
+                prefab: None,
+            }],
+        }
+    }
+
+    fn instancing_scene(prefab: ScenePrefab) -> DynamicScene {
+        DynamicScene {
+            resources: Vec::new(),
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(Root)],
+                prefab: Some(prefab),
+            }],
+        }
+    }
+
+    fn health_override(health: u32) -> PrefabOverride {
+        let mut stats = DynamicStruct::default();
+        stats.set_represented_type(Some(Stats::type_info()));
+        stats.insert("health", health);
+        PrefabOverride {
+            entity: Entity::from_raw(0),
+            components: vec![Box::new(stats)],
+        }
+    }
+
+    /// Returns the stats of the prefab entity, checking that it is a child of the root.
+    fn get_stats(world: &mut World) -> &Stats {
+        let root = world.query_filtered::<Entity, With<Root>>().single(world);
+        let (parent, stats) = world
+            .query_filtered::<(&Parent, &Stats), Without<Root>>()
+            .single(world);
+        assert_eq!(root, parent.get());
+        stats
+    }
+
+    #[test]
+    fn should_spawn_prefab_with_overrides() {
+        let mut app = setup();
+
+        let mut scenes = app.world.resource_mut::<Assets<DynamicScene>>();
+        scenes.set_untracked(HandleId::from(PREFAB_PATH), prefab_scene(1));
+        let mut prefab = ScenePrefab::new(PREFAB_PATH);
+        prefab.overrides.push(health_override(5));
+        let handle = scenes.add(instancing_scene(prefab));
+
+        app.world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle);
+        app.update();
+
+        let stats = get_stats(&mut app.world);
+        assert_eq!(
+            &Stats {
+                health: 5,
+                speed: 1
+            },
+            stats
+        );
+    }
+
+    #[test]
+    fn should_reapply_overrides_when_prefab_changes() {
+        let mut app = setup();
+
+        let mut scenes = app.world.resource_mut::<Assets<DynamicScene>>();
+        scenes.set_untracked(HandleId::from(PREFAB_PATH), prefab_scene(1));
+        let mut prefab = ScenePrefab::new(PREFAB_PATH);
+        prefab.overrides.push(health_override(5));
+        let handle = scenes.add(instancing_scene(prefab));
+
+        app.world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle);
+        app.update();
+
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(HandleId::from(PREFAB_PATH), prefab_scene(2));
+        app.update();
+        app.update();
+
+        let stats = get_stats(&mut app.world);
+        assert_eq!(
+            &Stats {
+                health: 5,
+                speed: 2
+            },
+            stats
+        );
+    }
+
+    fn scene(entities: Vec<(u32, Vec<Box<dyn Reflect>>)>) -> DynamicScene {
+        DynamicScene {
+            resources: Vec::new(),
+            entities: entities
+                .into_iter()
+                .map(|(index, components)| DynamicEntity {
+                    entity: Entity::from_raw(index),
+                    components,
+                    prefab: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn should_update_modified_scene_in_place() {
+        let mut app = setup();
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene(vec![
+                (
+                    0,
+                    vec![
+                        Box::new(Root),
+                        Box::new(Stats {
+                            health: 100,
+                            speed: 1,
+                        }),
+                    ],
+                ),
+                (1, vec![Box::new(Stats::default())]),
+            ]));
+        let instance_id = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+        app.update();
+
+        let entities = app
+            .world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance_id)
+            .collect::<Vec<_>>();
+        let root = app
+            .world
+            .query_filtered::<Entity, With<Root>>()
+            .single(&app.world);
+        let removed = *entities.iter().find(|entity| **entity != root).unwrap();
+        app.world.entity_mut(root).insert(Marker);
+
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(
+                &handle,
+                scene(vec![
+                    (
+                        0,
+                        vec![Box::new(Stats {
+                            health: 100,
+                            speed: 2,
+                        })],
+                    ),
+                    (2, vec![Box::new(Root)]),
+                ]),
+            );
+        app.update();
+        app.update();
+
+        // The entity kept its identity and runtime components, and only the scene changes were applied
+        let entity = app.world.entity(root);
+        assert!(entity.contains::<Marker>());
+        assert!(!entity.contains::<Root>());
+        assert_eq!(
+            &Stats {
+                health: 100,
+                speed: 2
+            },
+            entity.get::<Stats>().unwrap()
+        );
+
+        // The removed entity was despawned, and the added one spawned
+        assert!(app.world.get_entity(removed).is_none());
+        let added = app
+            .world
+            .query_filtered::<Entity, With<Root>>()
+            .single(&app.world);
+        let entities = app
+            .world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance_id)
+            .collect::<Vec<_>>();
+        assert_eq!(2, entities.len());
+        assert!(entities.contains(&root));
+        assert!(entities.contains(&added));
+    }
+
+    #[test]
+    fn should_not_reapply_unchanged_components() {
+        let mut app = setup();
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene(vec![(
+                0,
+                vec![Box::new(Stats {
+                    health: 100,
+                    speed: 1,
+                })],
+            )]));
+        app.world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+        app.update();
+
+        let entity = app
+            .world
+            .query_filtered::<Entity, With<Stats>>()
+            .single(&app.world);
+        app.world.get_mut::<Stats>(entity).unwrap().health = 50;
+
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(
+                &handle,
+                scene(vec![(
+                    0,
+                    vec![
+                        Box::new(Stats {
+                            health: 100,
+                            speed: 1,
+                        }),
+                        Box::new(Root),
+                    ],
+                )]),
+            );
+        app.update();
+        app.update();
+
+        let entity = app.world.entity(entity);
+        assert!(entity.contains::<Root>());
+        assert_eq!(50, entity.get::<Stats>().unwrap().health);
+    }
+
+    #[test]
+    fn should_share_snapshots_between_instances() {
+        let mut app = setup();
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene(vec![(0, vec![Box::new(Stats::default())])]));
+        let mut spawner = app.world.resource_mut::<SceneSpawner>();
+        let first = spawner.spawn_dynamic(handle.clone());
+        app.update();
+        let second = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+        app.update();
+
+        let shares_snapshot = |app: &App| {
+            let spawner = app.world.resource::<SceneSpawner>();
+            Arc::ptr_eq(
+                &spawner.instance_info(first).unwrap().snapshot.content,
+                &spawner.instance_info(second).unwrap().snapshot.content,
+            )
+        };
+        assert!(shares_snapshot(&app));
+
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(&handle, scene(vec![(0, vec![Box::new(Root)])]));
+        app.update();
+        app.update();
+
+        assert!(shares_snapshot(&app));
+        assert_eq!(
+            2,
+            app.world
+                .query_filtered::<(), (With<Root>, Without<Stats>)>()
+                .iter(&app.world)
+                .count()
+        );
+    }
+
+    #[test]
+    fn should_remap_unchanged_components_to_spawned_entities() {
+        let mut app = setup();
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene(vec![(
+                0,
+                vec![Box::new(Target(Entity::from_raw(1)))],
+            )]));
+        let instance_id = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+        app.update();
+
+        // The referenced entity is added to the scene, while the referencing one is unchanged
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(
+                &handle,
+                scene(vec![
+                    (0, vec![Box::new(Target(Entity::from_raw(1)))]),
+                    (1, vec![Box::new(Root)]),
+                ]),
+            );
+        app.update();
+        app.update();
+
+        let instance_info = app
+            .world
+            .resource::<SceneSpawner>()
+            .instance_info(instance_id)
+            .unwrap();
+        let entity = instance_info.entity_map.get(Entity::from_raw(0)).unwrap();
+        let target = instance_info.entity_map.get(Entity::from_raw(1)).unwrap();
+        assert!(app.world.entity(target).contains::<Root>());
+        assert_eq!(target, app.world.get::<Target>(entity).unwrap().0);
+    }
+
+    #[test]
+    fn should_remove_resources_removed_from_scene() {
+        let mut app = setup();
+
+        let mut with_resource = scene(vec![(0, vec![Box::new(Root)])]);
+        with_resource.resources.push(Box::new(Settings));
+        let handle = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(with_resource);
+        app.world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+        app.update();
+        assert!(app.world.contains_resource::<Settings>());
+
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(&handle, scene(vec![(0, vec![Box::new(Root)])]));
+        app.update();
+        app.update();
+        assert!(!app.world.contains_resource::<Settings>());
+    }
+
+    #[test]
+    fn should_apply_spawn_options() {
+        let mut app = setup();
+        app.register_type::<Transform>();
+
+        let stats = || {
+            Box::new(Stats {
+                health: 100,
+                speed: 1,
+            })
+        };
+        let handle = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene(vec![
+                (
+                    0,
+                    vec![
+                        Box::new(Root),
+                        Box::new(Transform::from_xyz(1.0, 0.0, 0.0)),
+                        stats(),
+                    ],
+                ),
+                (1, vec![stats()]),
+            ]));
+        let parent = app.world.spawn_empty().id();
+        let mut filter = SceneFilter::allow_all();
+        filter.deny::<Stats>();
+        let instance_id = app.world.resource_mut::<SceneSpawner>().spawn_dynamic_with(
+            handle.clone(),
+            SpawnOptions {
+                parent: Some(parent),
+                transform: Some(Transform::from_xyz(10.0, 0.0, 0.0)),
+                on_spawned: Some(Box::new(|world, entity_map| {
+                    let entity = entity_map.get(Entity::from_raw(0)).unwrap();
+                    world.entity_mut(entity).insert(Marker);
+                })),
+                filter,
+            },
+        );
+        app.update();
+
+        let spawner = app.world.resource::<SceneSpawner>();
+        let instance_info = spawner.instance_info(instance_id).unwrap();
+        let root = instance_info.entity_map.get(Entity::from_raw(0)).unwrap();
+        let other = instance_info.entity_map.get(Entity::from_raw(1)).unwrap();
+        let mut roots = instance_info.root_entities(&app.world).collect::<Vec<_>>();
+        roots.sort();
+        let mut expected = vec![root, other];
+        expected.sort();
+        assert_eq!(expected, roots);
+
+        for (entity, x) in [(root, 11.0), (other, 10.0)] {
+            let entity = app.world.entity(entity);
+            assert_eq!(parent, entity.get::<Parent>().unwrap().get());
+            assert_eq!(x, entity.get::<Transform>().unwrap().translation.x);
+            assert!(!entity.contains::<Stats>());
+        }
+        assert!(app.world.entity(root).contains::<Marker>());
+
+        // The filter is still applied once the scene is modified
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(&handle, scene(vec![(2, vec![stats()])]));
+        app.update();
+        app.update();
+        assert_eq!(
+            0,
+            app.world
+                .query_filtered::<(), With<Stats>>()
+                .iter(&app.world)
+                .count()
+        );
+    }
+
+    #[test]
+    fn should_reapply_spawn_options_on_reload() {
+        let mut app = setup();
+        app.register_type::<Transform>();
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene(vec![
+                (0, vec![Box::new(Transform::from_xyz(1.0, 0.0, 0.0))]),
+                (1, vec![Box::new(Transform::from_xyz(2.0, 0.0, 0.0))]),
+            ]));
+        let parent = app.world.spawn_empty().id();
+        let instance_id = app.world.resource_mut::<SceneSpawner>().spawn_dynamic_with(
+            handle.clone(),
+            SpawnOptions {
+                parent: Some(parent),
+                transform: Some(Transform::from_xyz(10.0, 0.0, 0.0)),
+                ..Default::default()
+            },
+        );
+        app.update();
+
+        // One entity is moved by the scene, one is moved at runtime, and one is added
+        let entity = |app: &App, index| {
+            app.world
+                .resource::<SceneSpawner>()
+                .instance_info(instance_id)
+                .unwrap()
+                .entity_map
+                .get(Entity::from_raw(index))
+                .unwrap()
+        };
+        let moved_at_runtime = entity(&app, 1);
+        app.world
+            .get_mut::<Transform>(moved_at_runtime)
+            .unwrap()
+            .translation
+            .y = 5.0;
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(
+                &handle,
+                scene(vec![
+                    (0, vec![Box::new(Transform::from_xyz(3.0, 0.0, 0.0))]),
+                    (1, vec![Box::new(Transform::from_xyz(2.0, 0.0, 0.0))]),
+                    (2, vec![Box::new(Transform::from_xyz(4.0, 0.0, 0.0))]),
+                ]),
+            );
+        app.update();
+        app.update();
+
+        for (index, translation) in [(0, [13.0, 0.0]), (1, [12.0, 5.0]), (2, [14.0, 0.0])] {
+            let entity = app.world.entity(entity(&app, index));
+            assert_eq!(parent, entity.get::<Parent>().unwrap().get());
+            let transform = entity.get::<Transform>().unwrap();
+            assert_eq!(
+                translation,
+                [transform.translation.x, transform.translation.y]
+            );
+        }
+    }
+
+    #[test]
+    fn should_detect_recursive_prefabs() {
+        let mut app = setup();
+
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(
+                HandleId::from(PREFAB_PATH),
+                instancing_scene(ScenePrefab::new(PREFAB_PATH)),
+            );
+
+        let result = app
+            .world
+            .resource_scope(|world, mut spawner: Mut<SceneSpawner>| {
+                spawner.spawn_dynamic_sync(world, &Handle::weak(HandleId::from(PREFAB_PATH)))
+            });
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::RecursivePrefab { .. })
+        ));
+        assert_eq!(0, app.world.entities().len());
+
+        // the instance is dropped instead of spawned
+        let instance_id = app
+            .world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(Handle::weak(HandleId::from(PREFAB_PATH)));
+        app.update();
+        assert!(!app
+            .world
+            .resource::<SceneSpawner>()
+            .instance_is_ready(instance_id));
+        assert_eq!(0, app.world.entities().len());
+    }
+
+    #[test]
+    fn should_keep_instances_when_reloaded_into_recursive_prefabs() {
+        let mut app = setup();
+
+        let mut scenes = app.world.resource_mut::<Assets<DynamicScene>>();
+        scenes.set_untracked(HandleId::from(PREFAB_PATH), prefab_scene(1));
+        let handle = scenes.add(instancing_scene(ScenePrefab::new(PREFAB_PATH)));
+        app.world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle);
+        app.update();
+
+        // the prefab now instances itself
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(
+                HandleId::from(PREFAB_PATH),
+                instancing_scene(ScenePrefab::new(PREFAB_PATH)),
+            );
+        app.update();
+        app.update();
+        assert_eq!(1, get_stats(&mut app.world).speed);
+
+        // fixing the prefab updates the instance again
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(HandleId::from(PREFAB_PATH), prefab_scene(2));
+        app.update();
+        app.update();
+        assert_eq!(2, get_stats(&mut app.world).speed);
+    }
+
+    #[test]
+    fn should_update_instances_once_prefab_is_loaded() {
+        const OTHER_PREFAB_PATH: &str = "other_prefab.scn.ron";
+        let mut app = setup();
+
+        let mut scenes = app.world.resource_mut::<Assets<DynamicScene>>();
+        scenes.set_untracked(HandleId::from(PREFAB_PATH), prefab_scene(1));
+        let handle = scenes.add(instancing_scene(ScenePrefab::new(PREFAB_PATH)));
+        app.world
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+        app.update();
+
+        // the scene now uses a prefab which isn't loaded yet
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(
+                &handle,
+                instancing_scene(ScenePrefab::new(OTHER_PREFAB_PATH)),
+            );
+        app.update();
+        app.update();
+        assert_eq!(1, get_stats(&mut app.world).speed);
+
+        app.world
+            .resource_mut::<Assets<DynamicScene>>()
+            .set_untracked(HandleId::from(OTHER_PREFAB_PATH), prefab_scene(2));
+        app.update();
+        assert_eq!(2, get_stats(&mut app.world).speed);
+    }
+}
@@ -1,26 +1,42 @@
-use crate::{DynamicEntity, DynamicScene};
+use crate::{
+    DynamicEntity, DynamicScene, PrefabOverride, SceneIssue, SceneIssueReason, ScenePrefab,
+    SceneReport,
+};
 use anyhow::Result;
+use bevy_asset::AssetPath;
 use bevy_ecs::entity::Entity;
-use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy_reflect::serde::{SkippedFields, TypedReflectDeserializer, TypedReflectSerializer};
 use bevy_reflect::{
     serde::{TypeRegistrationDeserializer, UntypedReflectDeserializer},
-    Reflect, TypeRegistry, TypeRegistryArc,
+    Reflect, TypeRegistration, TypeRegistry, TypeRegistryArc,
 };
 use bevy_utils::HashSet;
 use serde::ser::SerializeMap;
 use serde::{
-    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
     ser::SerializeStruct,
     Deserialize, Deserializer, Serialize, Serializer,
 };
+use std::cell::{Cell, RefCell};
 use std::fmt::Formatter;
 
+mod short;
+
+pub use short::{ShortSceneDeserializer, ShortSceneSerializer};
+
 pub const SCENE_STRUCT: &str = "Scene";
 pub const SCENE_RESOURCES: &str = "resources";
 pub const SCENE_ENTITIES: &str = "entities";
 
 pub const ENTITY_STRUCT: &str = "Entity";
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
+pub const ENTITY_FIELD_PREFAB: &str = "prefab";
+pub const ENTITY_FIELD_CHILDREN: &str = "children";
+
+pub const PREFAB_STRUCT: &str = "Prefab";
+pub const PREFAB_FIELD_PATH: &str = "path";
+pub const PREFAB_FIELD_OVERRIDES: &str = "overrides";
 
 pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
@@ -91,7 +107,22 @@ impl<'a> Serialize for EntitySerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
+        // Formats which aren't human-readable may rely on every field being present, so they
+        // keep the single field entities always had and store the prefab among the components
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
+            state.serialize_field(
+                ENTITY_FIELD_COMPONENTS,
+                &EntityComponentsSerializer {
+                    entity: self.entity,
+                    registry: self.registry,
+                },
+            )?;
+            return state.end();
+        }
+
+        let len = 1 + usize::from(self.entity.prefab.is_some());
+        let mut state = serializer.serialize_struct(ENTITY_STRUCT, len)?;
         state.serialize_field(
             ENTITY_FIELD_COMPONENTS,
             &SceneMapSerializer {
@@ -99,6 +130,101 @@ impl<'a> Serialize for EntitySerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if let Some(prefab) = &self.entity.prefab {
+            state.serialize_field(
+                ENTITY_FIELD_PREFAB,
+                &ScenePrefabSerializer {
+                    prefab,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes the components of an entity, followed by its prefab under the [`PREFAB_STRUCT`] key.
+struct EntityComponentsSerializer<'a> {
+    entity: &'a DynamicEntity,
+    registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for EntityComponentsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let components = &self.entity.components;
+        let len = components.len() + usize::from(self.entity.prefab.is_some());
+        let mut state = serializer.serialize_map(Some(len))?;
+        for reflect in components {
+            state.serialize_entry(
+                reflect.type_name(),
+                &TypedReflectSerializer::new(&**reflect, &self.registry.read()),
+            )?;
+        }
+        if let Some(prefab) = &self.entity.prefab {
+            state.serialize_entry(
+                PREFAB_STRUCT,
+                &ScenePrefabSerializer {
+                    prefab,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+pub struct ScenePrefabSerializer<'a> {
+    pub prefab: &'a ScenePrefab,
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for ScenePrefabSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let path = self.prefab.path.path().to_string_lossy();
+        let path = match self.prefab.path.label() {
+            Some(label) => format!("{path}#{label}"),
+            None => path.into_owned(),
+        };
+
+        let mut state = serializer.serialize_struct(PREFAB_STRUCT, 2)?;
+        state.serialize_field(PREFAB_FIELD_PATH, &path)?;
+        state.serialize_field(
+            PREFAB_FIELD_OVERRIDES,
+            &PrefabOverridesSerializer {
+                overrides: &self.prefab.overrides,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+pub struct PrefabOverridesSerializer<'a> {
+    pub overrides: &'a [PrefabOverride],
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for PrefabOverridesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.overrides.len()))?;
+        for entity in self.overrides {
+            state.serialize_entry(
+                &entity.entity,
+                &SceneMapSerializer {
+                    entries: &entity.components,
+                    registry: self.registry,
+                },
+            )?;
+        }
         state.end()
     }
 }
@@ -135,6 +261,14 @@ enum SceneField {
 #[serde(field_identifier, rename_all = "lowercase")]
 enum EntityField {
     Components,
+    Prefab,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum PrefabField {
+    Path,
+    Overrides,
 }
 
 pub struct SceneDeserializer<'a> {
@@ -148,18 +282,97 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_struct(
-            SCENE_STRUCT,
-            &[SCENE_RESOURCES, SCENE_ENTITIES],
-            SceneVisitor {
-                type_registry: self.type_registry,
-            },
-        )
+        SceneVisitor {
+            type_registry: self.type_registry,
+            lenient: None,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+/// A [`SceneDeserializer`] which skips unregistered types and unknown fields instead of failing.
+///
+/// Every skipped value is recorded in the `report`.
+/// Skipping values requires a self-describing format, such as RON.
+pub struct LenientSceneDeserializer<'a> {
+    pub type_registry: &'a TypeRegistry,
+    pub report: &'a mut SceneReport,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for LenientSceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let lenient = Lenient::default();
+        let scene = SceneVisitor {
+            type_registry: self.type_registry,
+            lenient: Some(&lenient),
+        }
+        .deserialize(deserializer);
+        self.report
+            .issues
+            .append(&mut lenient.report.into_inner().issues);
+        scene
+    }
+}
+
+/// State shared by the visitors of a [`LenientSceneDeserializer`].
+#[derive(Default)]
+struct Lenient {
+    /// The entity being deserialized, or `None` while deserializing resources.
+    entity: Cell<Option<Entity>>,
+    skipped: SkippedFields,
+    report: RefCell<SceneReport>,
+}
+
+impl Lenient {
+    fn report(&self, type_path: &str, field_path: Option<String>, reason: SceneIssueReason) {
+        self.report.borrow_mut().issues.push(SceneIssue {
+            entity: self.entity.get(),
+            type_path: type_path.to_string(),
+            field_path,
+            reason,
+        });
     }
 }
 
 struct SceneVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    lenient: Option<&'a Lenient>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneVisitor<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(SCENE_STRUCT, &[SCENE_RESOURCES, SCENE_ENTITIES], self)
+    }
+}
+
+impl<'a> SceneVisitor<'a> {
+    fn resources(&self) -> SceneMapVisitor<'a> {
+        if let Some(lenient) = self.lenient {
+            lenient.entity.set(None);
+        }
+        SceneMapVisitor {
+            registry: self.type_registry,
+            lenient: self.lenient,
+            prefab: None,
+        }
+    }
+
+    fn entities(&self) -> SceneEntitiesVisitor<'a> {
+        SceneEntitiesVisitor {
+            type_registry: self.type_registry,
+            lenient: self.lenient,
+        }
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
@@ -181,17 +394,13 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                     if resources.is_some() {
                         return Err(Error::duplicate_field(SCENE_RESOURCES));
                     }
-                    resources = Some(map.next_value_seed(SceneMapDeserializer {
-                        registry: self.type_registry,
-                    })?);
+                    resources = Some(map.next_value_seed(self.resources())?);
                 }
                 SceneField::Entities => {
                     if entities.is_some() {
                         return Err(Error::duplicate_field(SCENE_ENTITIES));
                     }
-                    entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
-                        type_registry: self.type_registry,
-                    })?);
+                    entities = Some(map.next_value_seed(self.entities())?);
                 }
             }
         }
@@ -210,15 +419,11 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let resources = seq
-            .next_element_seed(SceneMapDeserializer {
-                registry: self.type_registry,
-            })?
+            .next_element_seed(self.resources())?
             .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
 
         let entities = seq
-            .next_element_seed(SceneEntitiesDeserializer {
-                type_registry: self.type_registry,
-            })?
+            .next_element_seed(self.entities())?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
         Ok(DynamicScene {
@@ -239,14 +444,28 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneEntitiesVisitor {
+        SceneEntitiesVisitor {
             type_registry: self.type_registry,
-        })
+            lenient: None,
+        }
+        .deserialize(deserializer)
     }
 }
 
 struct SceneEntitiesVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    lenient: Option<&'a Lenient>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesVisitor<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
@@ -262,9 +481,10 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
     {
         let mut entities = Vec::new();
         while let Some(entity) = map.next_key::<Entity>()? {
-            let entity = map.next_value_seed(SceneEntityDeserializer {
+            let entity = map.next_value_seed(SceneEntityVisitor {
                 entity,
-                type_registry: self.type_registry,
+                registry: self.type_registry,
+                lenient: self.lenient,
             })?;
             entities.push(entity);
         }
@@ -285,20 +505,59 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_struct(
-            ENTITY_STRUCT,
-            &[ENTITY_FIELD_COMPONENTS],
-            SceneEntityVisitor {
-                entity: self.entity,
-                registry: self.type_registry,
-            },
-        )
+        SceneEntityVisitor {
+            entity: self.entity,
+            registry: self.type_registry,
+            lenient: None,
+        }
+        .deserialize(deserializer)
     }
 }
 
 struct SceneEntityVisitor<'a> {
     pub entity: Entity,
     pub registry: &'a TypeRegistry,
+    lenient: Option<&'a Lenient>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntityVisitor<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if let Some(lenient) = self.lenient {
+            lenient.entity.set(Some(self.entity));
+        }
+        // Only human-readable formats write the prefab as a separate field
+        let fields: &[&str] = if deserializer.is_human_readable() {
+            &[ENTITY_FIELD_COMPONENTS, ENTITY_FIELD_PREFAB]
+        } else {
+            &[ENTITY_FIELD_COMPONENTS]
+        };
+        deserializer.deserialize_struct(ENTITY_STRUCT, fields, self)
+    }
+}
+
+impl<'a> SceneEntityVisitor<'a> {
+    fn components<'p>(&self, prefab: &'p Cell<Option<ScenePrefab>>) -> SceneMapVisitor<'p>
+    where
+        'a: 'p,
+    {
+        SceneMapVisitor {
+            registry: self.registry,
+            lenient: self.lenient,
+            prefab: Some(prefab),
+        }
+    }
+
+    fn prefab(&self) -> ScenePrefabVisitor<'a> {
+        ScenePrefabVisitor {
+            registry: self.registry,
+            lenient: self.lenient,
+        }
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
@@ -312,15 +571,20 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
+        let prefab = Cell::new(None);
         let components = seq
-            .next_element_seed(SceneMapDeserializer {
-                registry: self.registry,
-            })?
+            .next_element_seed(self.components(&prefab))?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
+        let prefab = match prefab.into_inner() {
+            Some(prefab) => Some(prefab),
+            None => seq.next_element_seed(self.prefab())?,
+        };
+
         Ok(DynamicEntity {
             entity: self.entity,
             components,
+            prefab,
         })
     }
 
@@ -329,6 +593,8 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut components = None;
+        let mut prefab = None;
+        let stored_prefab = Cell::new(None);
         while let Some(key) = map.next_key()? {
             match key {
                 EntityField::Components => {
@@ -336,9 +602,14 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
                         return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
                     }
 
-                    components = Some(map.next_value_seed(SceneMapDeserializer {
-                        registry: self.registry,
-                    })?);
+                    components = Some(map.next_value_seed(self.components(&stored_prefab))?);
+                }
+                EntityField::Prefab => {
+                    if prefab.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_PREFAB));
+                    }
+
+                    prefab = Some(map.next_value_seed(self.prefab())?);
                 }
             }
         }
@@ -346,13 +617,182 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         let components = components
             .take()
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        let prefab = match (prefab, stored_prefab.into_inner()) {
+            (Some(_), Some(_)) => return Err(Error::duplicate_field(ENTITY_FIELD_PREFAB)),
+            (prefab, stored_prefab) => prefab.or(stored_prefab),
+        };
         Ok(DynamicEntity {
             entity: self.entity,
             components,
+            prefab,
+        })
+    }
+}
+
+pub struct ScenePrefabDeserializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePrefabDeserializer<'a> {
+    type Value = ScenePrefab;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ScenePrefabVisitor {
+            registry: self.registry,
+            lenient: None,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+struct ScenePrefabVisitor<'a> {
+    registry: &'a TypeRegistry,
+    lenient: Option<&'a Lenient>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePrefabVisitor<'a> {
+    type Value = ScenePrefab;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            PREFAB_STRUCT,
+            &[PREFAB_FIELD_PATH, PREFAB_FIELD_OVERRIDES],
+            self,
+        )
+    }
+}
+
+impl<'a> ScenePrefabVisitor<'a> {
+    fn overrides(&self) -> PrefabOverridesVisitor<'a> {
+        PrefabOverridesVisitor {
+            registry: self.registry,
+            lenient: self.lenient,
+        }
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ScenePrefabVisitor<'a> {
+    type Value = ScenePrefab;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("prefab struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let path = seq
+            .next_element::<String>()?
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_PATH))?;
+        let overrides = seq
+            .next_element_seed(self.overrides())?
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_OVERRIDES))?;
+
+        Ok(ScenePrefab {
+            path: AssetPath::from(path),
+            overrides,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut path = None;
+        let mut overrides = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                PrefabField::Path => {
+                    if path.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_PATH));
+                    }
+                    path = Some(map.next_value::<String>()?);
+                }
+                PrefabField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(self.overrides())?);
+                }
+            }
+        }
+
+        let path = path.ok_or_else(|| Error::missing_field(PREFAB_FIELD_PATH))?;
+        Ok(ScenePrefab {
+            path: AssetPath::from(path),
+            // Overrides may be left out when there are none
+            overrides: overrides.unwrap_or_default(),
         })
     }
 }
 
+pub struct PrefabOverridesDeserializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for PrefabOverridesDeserializer<'a> {
+    type Value = Vec<PrefabOverride>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        PrefabOverridesVisitor {
+            registry: self.registry,
+            lenient: None,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+struct PrefabOverridesVisitor<'a> {
+    registry: &'a TypeRegistry,
+    lenient: Option<&'a Lenient>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for PrefabOverridesVisitor<'a> {
+    type Value = Vec<PrefabOverride>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for PrefabOverridesVisitor<'a> {
+    type Value = Vec<PrefabOverride>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of prefab overrides")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut overrides = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let components = map.next_value_seed(SceneMapVisitor {
+                registry: self.registry,
+                lenient: self.lenient,
+                prefab: None,
+            })?;
+            overrides.push(PrefabOverride { entity, components });
+        }
+
+        Ok(overrides)
+    }
+}
+
 pub struct SceneMapDeserializer<'a> {
     pub registry: &'a TypeRegistry,
 }
@@ -364,14 +804,113 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneMapVisitor {
+        SceneMapVisitor {
             registry: self.registry,
-        })
+            lenient: None,
+            prefab: None,
+        }
+        .deserialize(deserializer)
     }
 }
 
 struct SceneMapVisitor<'a> {
     pub registry: &'a TypeRegistry,
+    lenient: Option<&'a Lenient>,
+    /// Receives the prefab stored under the [`PREFAB_STRUCT`] key by [`EntityComponentsSerializer`].
+    prefab: Option<&'a Cell<Option<ScenePrefab>>>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapVisitor<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a> SceneMapVisitor<'a> {
+    /// Returns the registration of the type, or reports why it should be skipped.
+    fn lenient_registration(
+        &self,
+        lenient: &Lenient,
+        type_path: &str,
+    ) -> Option<&'a TypeRegistration> {
+        let Some(registration) = self.registry.get_with_name(type_path) else {
+            lenient.report(type_path, None, SceneIssueReason::UnregisteredType);
+            return None;
+        };
+
+        match lenient.entity.get() {
+            Some(_) if registration.data::<ReflectComponent>().is_none() => {
+                lenient.report(type_path, None, SceneIssueReason::NotAComponent);
+                None
+            }
+            None if registration.data::<ReflectResource>().is_none() => {
+                lenient.report(type_path, None, SceneIssueReason::NotAResource);
+                None
+            }
+            _ => Some(registration),
+        }
+    }
+
+    /// Reads the prefab stored among the components of an entity.
+    fn visit_prefab<'de, A>(&self, map: &mut A) -> Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let prefab = map.next_value_seed(ScenePrefabVisitor {
+            registry: self.registry,
+            lenient: self.lenient,
+        })?;
+        match self.prefab {
+            Some(slot) if slot.replace(Some(prefab)).is_some() => {
+                Err(Error::duplicate_field(ENTITY_FIELD_PREFAB))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn lenient_visit_map<'de, A>(
+        self,
+        mut map: A,
+        lenient: &Lenient,
+    ) -> Result<Vec<Box<dyn Reflect>>, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = HashSet::new();
+        let mut entries = Vec::new();
+        while let Some(type_path) = map.next_key::<String>()? {
+            if self.prefab.is_some() && type_path == PREFAB_STRUCT {
+                self.visit_prefab(&mut map)?;
+                continue;
+            }
+            let Some(registration) = self.lenient_registration(lenient, &type_path) else {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+            if !added.insert(registration.type_id()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate reflect type: `{}`",
+                    registration.type_name()
+                )));
+            }
+
+            entries.push(map.next_value_seed(TypedReflectDeserializer::lenient(
+                registration,
+                self.registry,
+                &lenient.skipped,
+            ))?);
+            for field_path in lenient.skipped.take() {
+                lenient.report(&type_path, Some(field_path), SceneIssueReason::UnknownField);
+            }
+        }
+
+        Ok(entries)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
@@ -385,11 +924,23 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
+        if let Some(lenient) = self.lenient {
+            return self.lenient_visit_map(map, lenient);
+        }
+
         let mut added = HashSet::new();
         let mut entries = Vec::new();
-        while let Some(registration) =
-            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-        {
+        while let Some(key) = map.next_key_seed(SceneMapKeyDeserializer {
+            registry: self.registry,
+            accepts_prefab: self.prefab.is_some(),
+        })? {
+            let registration = match key {
+                SceneMapKey::Prefab => {
+                    self.visit_prefab(&mut map)?;
+                    continue;
+                }
+                SceneMapKey::Type(registration) => registration,
+            };
             if !added.insert(registration.type_id()) {
                 return Err(Error::custom(format_args!(
                     "duplicate reflect type: `{}`",
@@ -420,10 +971,62 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     }
 }
 
+enum SceneMapKey<'a> {
+    Prefab,
+    Type(&'a TypeRegistration),
+}
+
+/// Deserializes the type path keys of a [`SceneMapVisitor`], as well as the prefab key if it
+/// accepts one.
+struct SceneMapKeyDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    accepts_prefab: bool,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapKeyDeserializer<'a> {
+    type Value = SceneMapKey<'a>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !self.accepts_prefab {
+            return TypeRegistrationDeserializer::new(self.registry)
+                .deserialize(deserializer)
+                .map(SceneMapKey::Type);
+        }
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SceneMapKeyDeserializer<'a> {
+    type Value = SceneMapKey<'a>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("type path or prefab key")
+    }
+
+    fn visit_str<E>(self, key: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if key == PREFAB_STRUCT {
+            return Ok(SceneMapKey::Prefab);
+        }
+        self.registry
+            .get_with_name(key)
+            .map(SceneMapKey::Type)
+            .ok_or_else(|| Error::custom(format_args!("No registration found for `{key}`")))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::serde::{SceneDeserializer, SceneSerializer};
-    use crate::{DynamicScene, DynamicSceneBuilder};
+    use crate::serde::{LenientSceneDeserializer, SceneDeserializer, SceneSerializer};
+    use crate::{
+        DynamicEntity, DynamicScene, DynamicSceneBuilder, PrefabOverride, SceneIssue,
+        SceneIssueReason, ScenePrefab, SceneReport,
+    };
     use bevy_ecs::entity::{Entity, EntityMap, EntityMapper, MapEntities};
     use bevy_ecs::prelude::{Component, ReflectComponent, ReflectResource, Resource, World};
     use bevy_ecs::query::{With, Without};
@@ -617,6 +1220,194 @@ mod tests {
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_deserialize_leniently() {
+        let world = create_world();
+
+        let input = r#"(
+  resources: {
+    "bevy_scene::serde::tests::MyResource": (
+      foo: 123,
+      bar: 456,
+    ),
+    "bevy_scene::serde::tests::Foo": (123),
+  },
+  entities: {
+    0: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (123),
+        "game::Removed": (value: 1),
+        "bevy_scene::serde::tests::MyComponent": (
+          foo: (1, 2, 3),
+          bar: (1.0, 2.0),
+          baz: Struct(value: 1, extra: [4]),
+          old: 5,
+        ),
+      },
+    ),
+  },
+)"#;
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry,
+        };
+        assert!(scene_deserializer.deserialize(&mut deserializer).is_err());
+
+        let mut report = SceneReport::default();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = LenientSceneDeserializer {
+            type_registry: &registry,
+            report: &mut report,
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(1, scene.resources.len());
+        assert_eq!(1, scene.entities.len());
+        assert_eq!(2, scene.entities[0].components.len());
+
+        let issue =
+            |entity: Option<u32>, type_path: &str, field_path: Option<&str>, reason| SceneIssue {
+                entity: entity.map(Entity::from_raw),
+                type_path: type_path.to_string(),
+                field_path: field_path.map(str::to_string),
+                reason,
+            };
+        assert_eq!(
+            vec![
+                issue(
+                    None,
+                    "bevy_scene::serde::tests::MyResource",
+                    Some("bar"),
+                    SceneIssueReason::UnknownField
+                ),
+                issue(
+                    None,
+                    "bevy_scene::serde::tests::Foo",
+                    None,
+                    SceneIssueReason::NotAResource
+                ),
+                issue(
+                    Some(0),
+                    "game::Removed",
+                    None,
+                    SceneIssueReason::UnregisteredType
+                ),
+                issue(
+                    Some(0),
+                    "bevy_scene::serde::tests::MyComponent",
+                    Some("baz.extra"),
+                    SceneIssueReason::UnknownField
+                ),
+                issue(
+                    Some(0),
+                    "bevy_scene::serde::tests::MyComponent",
+                    Some("old"),
+                    SceneIssueReason::UnknownField
+                ),
+            ],
+            report.issues
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_prefab() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>();
+
+        let scene = DynamicScene {
+            resources: Vec::new(),
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(Foo(123))],
+                prefab: Some(ScenePrefab {
+                    path: "prefabs/tree.scn.ron".into(),
+                    overrides: vec![PrefabOverride {
+                        entity: Entity::from_raw(1),
+                        components: vec![Box::new(Bar(345))],
+                    }],
+                }),
+            }],
+        };
+
+        let expected = r#"(
+  resources: {},
+  entities: {
+    0: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (123),
+      },
+      prefab: (
+        path: "prefabs/tree.scn.ron",
+        overrides: {
+          1: {
+            "bevy_scene::serde::tests::Bar": (345),
+          },
+        },
+      ),
+    ),
+  },
+)"#;
+        let output = scene.serialize_ron(&registry.0).unwrap();
+        assert_eq!(expected, output);
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry.read(),
+        };
+        let deserialized_scene = scene_deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(&output).unwrap())
+            .unwrap();
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        let prefab = deserialized_scene.entities[0].prefab.as_ref().unwrap();
+        assert_eq!(scene.entities[0].prefab.as_ref().unwrap().path, prefab.path);
+        assert_eq!(Entity::from_raw(1), prefab.overrides[0].entity);
+        assert!(prefab.overrides[0].components[0]
+            .reflect_partial_eq(&Bar(345))
+            .unwrap());
+
+        let serialized_scene =
+            bincode::serialize(&SceneSerializer::new(&scene, &registry.0)).unwrap();
+        let deserialized_scene = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                SceneDeserializer {
+                    type_registry: &registry.read(),
+                },
+                &serialized_scene,
+            )
+            .unwrap();
+        assert_eq!(
+            scene.entities[0].prefab.as_ref().unwrap().path,
+            deserialized_scene.entities[0].prefab.as_ref().unwrap().path
+        );
+
+        let serialized_scene =
+            postcard::to_allocvec(&SceneSerializer::new(&scene, &registry.0)).unwrap();
+        let deserialized_scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
+        .unwrap();
+        assert_scene_eq(&scene, &deserialized_scene);
+        let prefab = deserialized_scene.entities[0].prefab.as_ref().unwrap();
+        assert_eq!(Entity::from_raw(1), prefab.overrides[0].entity);
+
+        let mut buf = Vec::new();
+        SceneSerializer::new(&scene, &registry.0)
+            .serialize(&mut rmp_serde::Serializer::new(&mut buf))
+            .unwrap();
+        let deserialized_scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut rmp_serde::Deserializer::new(buf.as_slice()))
+        .unwrap();
+        assert_scene_eq(&scene, &deserialized_scene);
+        let prefab = deserialized_scene.entities[0].prefab.as_ref().unwrap();
+        assert_eq!(Entity::from_raw(1), prefab.overrides[0].entity);
+    }
+
     #[test]
     fn should_roundtrip_with_later_generations_and_obsolete_references() {
         let mut world = create_world();
@@ -0,0 +1,859 @@
+//! A human-friendly variant of the scene format, meant to be written by hand.
+//!
+//! Compared to the format of [`SceneSerializer`](super::SceneSerializer):
+//! - components and resources are keyed by their short type name when it is unambiguous,
+//!   for example `"Transform"` instead of `"bevy_transform::components::transform::Transform"`
+//! - the fields of structs can be omitted, in which case they take the value they have in the
+//!   [`ReflectDefault`] of the component or resource
+//! - the children of an entity are nested under its `children` field, instead of being
+//!   expressed with [`Parent`] and [`Children`] components
+//!
+//! ```ron
+//! (
+//!   entities: {
+//!     0: (
+//!       components: {
+//!         "Transform": (translation: (0.0, 1.0, 0.0)),
+//!       },
+//!       children: {
+//!         1: (
+//!           components: {
+//!             "Visibility": Hidden,
+//!           },
+//!         ),
+//!       },
+//!     ),
+//!   },
+//! )
+//! ```
+
+use super::{
+    SceneField, ScenePrefabSerializer, ScenePrefabVisitor, ENTITY_FIELD_CHILDREN,
+    ENTITY_FIELD_COMPONENTS, ENTITY_FIELD_PREFAB, ENTITY_STRUCT, SCENE_ENTITIES, SCENE_RESOURCES,
+    SCENE_STRUCT,
+};
+use crate::{DynamicEntity, DynamicScene};
+use bevy_ecs::entity::Entity;
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::serde::{
+    ReflectSerializeWithRegistry, SerializationData, TypedReflectDeserializer,
+    TypedReflectSerializer, VersionData, VERSION_FIELD,
+};
+use bevy_reflect::{
+    std_traits::ReflectDefault, DynamicList, DynamicStruct, DynamicTupleStruct, Reflect,
+    ReflectRef, ReflectSerialize, TypeInfo, TypeRegistry, TypeRegistryArc, Typed,
+};
+use bevy_utils::{HashMap, HashSet};
+use serde::{
+    de::{DeserializeSeed, Error, MapAccess, Visitor},
+    ser::{SerializeMap, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt::Formatter;
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum ShortEntityField {
+    Components,
+    Prefab,
+    Children,
+}
+
+/// Serializes a [`DynamicScene`] to the [short scene format](self).
+///
+/// Fields equal to those of the [`ReflectDefault`] of their component or resource are left out,
+/// and entities whose [`Parent`] is part of the scene are nested under it.
+pub struct ShortSceneSerializer<'a> {
+    pub scene: &'a DynamicScene,
+    pub registry: &'a TypeRegistryArc,
+}
+
+impl<'a> ShortSceneSerializer<'a> {
+    pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistryArc) -> Self {
+        ShortSceneSerializer { scene, registry }
+    }
+}
+
+impl<'a> Serialize for ShortSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        state.serialize_field(
+            SCENE_RESOURCES,
+            &ShortMapSerializer {
+                entries: self.scene.resources.iter().map(|entry| &**entry).collect(),
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            SCENE_ENTITIES,
+            &ShortEntitiesSerializer {
+                entities: &EntityTree::build(&self.scene.entities),
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+/// An entity of the scene along with the entities nested under it.
+struct EntityTree<'a> {
+    entity: &'a DynamicEntity,
+    /// Whether the entity is nested under its parent, which makes its [`Parent`] implicit.
+    nested: bool,
+    /// Whether the [`Children`] of the entity are exactly the nested entities, which makes it implicit.
+    implicit_children: bool,
+    children: Vec<EntityTree<'a>>,
+}
+
+impl<'a> EntityTree<'a> {
+    /// Nests the entities under their parent, when it is part of the scene.
+    fn build(entities: &'a [DynamicEntity]) -> Vec<EntityTree<'a>> {
+        let scene_entities: HashSet<Entity> = entities.iter().map(|entity| entity.entity).collect();
+        let mut children: HashMap<Entity, Vec<&DynamicEntity>> = HashMap::default();
+        let mut roots = Vec::new();
+        for entity in entities {
+            match parent_of(entity).filter(|parent| scene_entities.contains(parent)) {
+                Some(parent) => children.entry(parent).or_default().push(entity),
+                None => roots.push(entity),
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut trees: Vec<_> = roots
+            .into_iter()
+            .map(|entity| Self::new(entity, false, &children, &mut visited))
+            .collect();
+        // Entities whose parents form a cycle are not reachable from the roots,
+        // so they keep their `Parent` component
+        for entity in entities {
+            if !visited.contains(&entity.entity) {
+                trees.push(Self::new(entity, false, &children, &mut visited));
+            }
+        }
+        trees
+    }
+
+    fn new(
+        entity: &'a DynamicEntity,
+        nested: bool,
+        children: &HashMap<Entity, Vec<&'a DynamicEntity>>,
+        visited: &mut HashSet<Entity>,
+    ) -> Self {
+        visited.insert(entity.entity);
+
+        let listed_children = children_of(entity);
+        let mut nested_children = children
+            .get(&entity.entity)
+            .into_iter()
+            .flatten()
+            .filter(|child| !visited.contains(&child.entity))
+            .copied()
+            .collect::<Vec<_>>();
+        // Keep the order of the `Children` component
+        if let Some(listed_children) = &listed_children {
+            nested_children.sort_by_key(|child| {
+                listed_children
+                    .iter()
+                    .position(|listed| *listed == child.entity)
+                    .unwrap_or(usize::MAX)
+            });
+        }
+
+        let children = nested_children
+            .into_iter()
+            .map(|child| Self::new(child, true, children, visited))
+            .collect::<Vec<_>>();
+        let implicit_children = listed_children.is_some_and(|listed_children| {
+            listed_children
+                .iter()
+                .eq(children.iter().map(|child| &child.entity.entity))
+        });
+
+        EntityTree {
+            entity,
+            nested,
+            implicit_children,
+            children,
+        }
+    }
+}
+
+struct ShortEntitiesSerializer<'a> {
+    entities: &'a [EntityTree<'a>],
+    registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for ShortEntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entities.len()))?;
+        for tree in self.entities {
+            state.serialize_entry(
+                &tree.entity.entity,
+                &ShortEntitySerializer {
+                    tree,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct ShortEntitySerializer<'a> {
+    tree: &'a EntityTree<'a>,
+    registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for ShortEntitySerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let tree = self.tree;
+        let components = tree
+            .entity
+            .components
+            .iter()
+            .map(|component| &**component)
+            .filter(|component| {
+                !(tree.nested && component.represents::<Parent>()
+                    || tree.implicit_children && component.represents::<Children>())
+            })
+            .collect::<Vec<_>>();
+        let len = usize::from(!components.is_empty())
+            + usize::from(tree.entity.prefab.is_some())
+            + usize::from(!tree.children.is_empty());
+
+        let mut state = serializer.serialize_struct(ENTITY_STRUCT, len)?;
+        if !components.is_empty() {
+            state.serialize_field(
+                ENTITY_FIELD_COMPONENTS,
+                &ShortMapSerializer {
+                    entries: components,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        if let Some(prefab) = &tree.entity.prefab {
+            state.serialize_field(
+                ENTITY_FIELD_PREFAB,
+                &ScenePrefabSerializer {
+                    prefab,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        if !tree.children.is_empty() {
+            state.serialize_field(
+                ENTITY_FIELD_CHILDREN,
+                &ShortEntitiesSerializer {
+                    entities: &tree.children,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct ShortMapSerializer<'a> {
+    entries: Vec<&'a dyn Reflect>,
+    registry: &'a TypeRegistryArc,
+}
+
+impl<'a> Serialize for ShortMapSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry.read();
+        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
+        for value in &self.entries {
+            let registration = registry.get_with_name(value.type_name());
+            // Short names are only used when they resolve back to the same type
+            let key = match registration {
+                Some(registration)
+                    if registry
+                        .get_with_short_name(registration.short_name())
+                        .is_some_and(|short| short.type_id() == registration.type_id()) =>
+                {
+                    registration.short_name()
+                }
+                _ => value.type_name(),
+            };
+            let default = registration
+                .and_then(|registration| registration.data::<ReflectDefault>())
+                .map(ReflectDefault::default);
+            state.serialize_entry(
+                key,
+                &NonDefaultFieldsSerializer {
+                    value: *value,
+                    default: default.as_deref(),
+                    registry: &registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes a value, leaving out the fields of structs which are equal to those of `default`.
+struct NonDefaultFieldsSerializer<'a> {
+    value: &'a dyn Reflect,
+    default: Option<&'a dyn Reflect>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for NonDefaultFieldsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serialize_all = || TypedReflectSerializer::new(self.value, self.registry);
+        // Types with their own serialization are written as a whole
+        let type_id = self.value.type_id();
+        if self
+            .registry
+            .get_type_data::<ReflectSerializeWithRegistry>(type_id)
+            .is_some()
+            || self
+                .registry
+                .get_type_data::<ReflectSerialize>(type_id)
+                .is_some()
+        {
+            return serialize_all().serialize(serializer);
+        }
+        let (ReflectRef::Struct(value), Some(ReflectRef::Struct(default))) = (
+            self.value.reflect_ref(),
+            self.default.map(Reflect::reflect_ref),
+        ) else {
+            return serialize_all().serialize(serializer);
+        };
+        let Some(TypeInfo::Struct(struct_info)) = value.get_represented_type_info() else {
+            return serialize_all().serialize(serializer);
+        };
+
+        let registration = self.registry.get(struct_info.type_id());
+        let serialization_data =
+            registration.and_then(|registration| registration.data::<SerializationData>());
+        let version_data = registration.and_then(|registration| registration.data::<VersionData>());
+        let fields = value
+            .iter_fields()
+            .enumerate()
+            .filter(|(index, _)| {
+                !serialization_data.is_some_and(|data| data.is_ignored_field(*index))
+            })
+            .filter_map(|(index, field)| {
+                let name = struct_info.field(value.name_at(index)?)?.name();
+                let default = default.field(name);
+                if default.is_some_and(|default| field.reflect_partial_eq(default) == Some(true)) {
+                    return None;
+                }
+                Some((name, field, default))
+            })
+            .collect::<Vec<_>>();
+
+        let mut state = serializer.serialize_struct(
+            struct_info.name(),
+            fields.len() + usize::from(version_data.is_some()),
+        )?;
+        if let Some(version_data) = version_data {
+            state.serialize_field(VERSION_FIELD, &version_data.version())?;
+        }
+        for (name, field, default) in fields {
+            state.serialize_field(
+                name,
+                &NonDefaultFieldsSerializer {
+                    value: field,
+                    default,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Deserializes a [`DynamicScene`] from the [short scene format](self).
+///
+/// The serialization of [`SceneSerializer`](super::SceneSerializer) is also accepted,
+/// as long as it doesn't contain the same type twice under different names.
+pub struct ShortSceneDeserializer<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ShortSceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(SCENE_STRUCT, &[SCENE_RESOURCES, SCENE_ENTITIES], self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ShortSceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("scene struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut resources = None;
+        let mut entities = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SceneField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(ShortMapDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+                SceneField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_ENTITIES));
+                    }
+                    let (_, scene_entities) = map.next_value_seed(ShortEntitiesDeserializer {
+                        parent: None,
+                        registry: self.type_registry,
+                    })?;
+                    entities = Some(scene_entities);
+                }
+            }
+        }
+
+        Ok(DynamicScene {
+            resources: resources.unwrap_or_default(),
+            entities: entities.unwrap_or_default(),
+        })
+    }
+}
+
+/// Deserializes a map of entities, along with the entities nested under them.
+///
+/// Returns the entities of the map, followed by all the entities.
+struct ShortEntitiesDeserializer<'a> {
+    parent: Option<Entity>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ShortEntitiesDeserializer<'a> {
+    type Value = (Vec<Entity>, Vec<DynamicEntity>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ShortEntitiesDeserializer<'a> {
+    type Value = (Vec<Entity>, Vec<DynamicEntity>);
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of entities")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut map_entities = Vec::new();
+        let mut entities = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            map_entities.push(entity);
+            entities.extend(map.next_value_seed(ShortEntityDeserializer {
+                entity,
+                parent: self.parent,
+                registry: self.registry,
+            })?);
+        }
+
+        Ok((map_entities, entities))
+    }
+}
+
+/// Deserializes an entity, followed by the entities nested under it.
+struct ShortEntityDeserializer<'a> {
+    entity: Entity,
+    parent: Option<Entity>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ShortEntityDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            ENTITY_STRUCT,
+            &[
+                ENTITY_FIELD_COMPONENTS,
+                ENTITY_FIELD_PREFAB,
+                ENTITY_FIELD_CHILDREN,
+            ],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ShortEntityDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("entity struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut components = None;
+        let mut prefab = None;
+        let mut children = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ShortEntityField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+                    components = Some(map.next_value_seed(ShortMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+                ShortEntityField::Prefab => {
+                    if prefab.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_PREFAB));
+                    }
+                    prefab = Some(map.next_value_seed(ScenePrefabVisitor {
+                        registry: self.registry,
+                        lenient: None,
+                    })?);
+                }
+                ShortEntityField::Children => {
+                    if children.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_CHILDREN));
+                    }
+                    children = Some(map.next_value_seed(ShortEntitiesDeserializer {
+                        parent: Some(self.entity),
+                        registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        // The hierarchy is implicit, unless the components are given explicitly
+        let mut components: Vec<Box<dyn Reflect>> = components.unwrap_or_default();
+        if let Some(parent) = self.parent {
+            if !components
+                .iter()
+                .any(|component| component.represents::<Parent>())
+            {
+                let mut component = DynamicTupleStruct::default();
+                component.set_represented_type(Some(Parent::type_info()));
+                component.insert(parent);
+                components.push(Box::new(component));
+            }
+        }
+        let (children, descendants) = children.unwrap_or_default();
+        if !children.is_empty()
+            && !components
+                .iter()
+                .any(|component| component.represents::<Children>())
+        {
+            let mut list = DynamicList::default();
+            for child in children {
+                list.push(child);
+            }
+            let mut component = DynamicTupleStruct::default();
+            component.set_represented_type(Some(Children::type_info()));
+            component.insert(list);
+            components.push(Box::new(component));
+        }
+
+        let mut entities = vec![DynamicEntity {
+            entity: self.entity,
+            components,
+            prefab,
+        }];
+        entities.extend(descendants);
+        Ok(entities)
+    }
+}
+
+/// Deserializes a map of components or resources keyed by their full or short type name.
+struct ShortMapDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ShortMapDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ShortMapDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of reflect types")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = HashSet::new();
+        let mut entries = Vec::new();
+        while let Some(type_name) = map.next_key::<String>()? {
+            let registration = self
+                .registry
+                .get_with_name(&type_name)
+                .or_else(|| self.registry.get_with_short_name(&type_name))
+                .ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "no registered type named `{type_name}`, or its short name is ambiguous"
+                    ))
+                })?;
+            if !added.insert(registration.type_id()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate reflect type: `{}`",
+                    registration.type_name()
+                )));
+            }
+
+            let value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            let value = match registration.data::<ReflectDefault>() {
+                Some(default) => with_default_fields(value, &*default.default()),
+                None => value,
+            };
+            entries.push(value);
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Fills the fields missing from the structs of `value` with the fields of `default`.
+fn with_default_fields(value: Box<dyn Reflect>, default: &dyn Reflect) -> Box<dyn Reflect> {
+    let filled = match (value.reflect_ref(), default.reflect_ref()) {
+        (ReflectRef::Struct(value), ReflectRef::Struct(default)) => {
+            let mut filled = DynamicStruct::default();
+            filled.set_represented_type(default.get_represented_type_info());
+            for (index, default_field) in default.iter_fields().enumerate() {
+                let name = default.name_at(index).unwrap();
+                let field = match value.field(name) {
+                    Some(field) => with_default_fields(field.clone_value(), default_field),
+                    None => default_field.clone_value(),
+                };
+                filled.insert_boxed(name, field);
+            }
+            filled
+        }
+        _ => return value,
+    };
+    Box::new(filled)
+}
+
+/// Returns the parent of the entity, from its [`Parent`] component.
+fn parent_of(entity: &DynamicEntity) -> Option<Entity> {
+    let component = entity
+        .components
+        .iter()
+        .find(|component| component.represents::<Parent>())?;
+    let ReflectRef::TupleStruct(parent) = component.reflect_ref() else {
+        return None;
+    };
+    parent.field(0)?.downcast_ref::<Entity>().copied()
+}
+
+/// Returns the children of the entity, from its [`Children`] component.
+fn children_of(entity: &DynamicEntity) -> Option<Vec<Entity>> {
+    let component = entity
+        .components
+        .iter()
+        .find(|component| component.represents::<Children>())?;
+    let ReflectRef::TupleStruct(children) = component.reflect_ref() else {
+        return None;
+    };
+    let ReflectRef::List(children) = children.field(0)?.reflect_ref() else {
+        return None;
+    };
+    children
+        .iter()
+        .map(|child| child.downcast_ref::<Entity>().copied())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::ShortSceneDeserializer;
+    use crate::{DynamicEntity, DynamicScene};
+    use bevy_ecs::entity::{Entity, EntityMap};
+    use bevy_ecs::prelude::{Component, ReflectComponent};
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_ecs::world::World;
+    use bevy_hierarchy::{Children, Parent};
+    use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Stats {
+        health: u32,
+        speed: u32,
+        position: Position,
+    }
+
+    impl Default for Stats {
+        fn default() -> Self {
+            Self {
+                health: 100,
+                speed: 1,
+                position: Position { x: 1.0, y: 2.0 },
+            }
+        }
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Player;
+
+    const INPUT: &str = r#"(
+  resources: {},
+  entities: {
+    0: (
+      components: {
+        "Player": (),
+        "Stats": (
+          speed: 2,
+          position: (
+            y: 3.0,
+          ),
+        ),
+      },
+      children: {
+        1: (
+          components: {
+            "Stats": (),
+          },
+        ),
+        2: (),
+      },
+    ),
+  },
+)"#;
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Stats>();
+            registry.register::<Position>();
+            registry.register::<Player>();
+            registry.register::<Parent>();
+            registry.register::<Children>();
+            registry.register::<Entity>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    fn deserialize(world: &World, input: &str) -> DynamicScene {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        ShortSceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap()
+    }
+
+    fn stats(entity: &DynamicEntity) -> Stats {
+        let stats = entity
+            .components
+            .iter()
+            .find(|component| component.represents::<Stats>())
+            .unwrap();
+        Stats::from_reflect(&**stats).unwrap()
+    }
+
+    #[test]
+    fn should_deserialize_short_scene() {
+        let mut world = create_world();
+        let scene = deserialize(&world, INPUT);
+
+        assert_eq!(3, scene.entities.len());
+        assert_eq!(
+            Stats {
+                speed: 2,
+                position: Position { x: 1.0, y: 3.0 },
+                ..Default::default()
+            },
+            stats(&scene.entities[0])
+        );
+        assert_eq!(Stats::default(), stats(&scene.entities[1]));
+
+        let mut entity_map = EntityMap::default();
+        scene.write_to_world(&mut world, &mut entity_map).unwrap();
+        let root = entity_map.get(Entity::from_raw(0)).unwrap();
+        let children = [1, 2].map(|index| entity_map.get(Entity::from_raw(index)).unwrap());
+        assert_eq!(&children, &**world.get::<Children>(root).unwrap());
+        for child in children {
+            assert_eq!(root, world.get::<Parent>(child).unwrap().get());
+        }
+        assert!(world.get::<Player>(root).is_some());
+    }
+
+    #[test]
+    fn should_roundtrip_short_scene() {
+        let world = create_world();
+        let scene = deserialize(&world, INPUT);
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let output = scene.serialize_short_ron(&registry.0).unwrap();
+        assert_eq!(INPUT, output);
+    }
+
+    #[test]
+    fn should_keep_parent_outside_of_scene() {
+        let world = create_world();
+        let scene = deserialize(
+            &world,
+            r#"(entities: {0: (components: {"bevy_hierarchy::components::parent::Parent": (5)})})"#,
+        );
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let output = scene.serialize_short_ron(&registry.0).unwrap();
+        assert!(output.contains(r#""Parent": (5)"#), "{output}");
+    }
+}
@@ -0,0 +1,267 @@
+use crate::serde::{LenientSceneDeserializer, ShortSceneDeserializer};
+use bevy_ecs::entity::Entity;
+use bevy_reflect::TypeRegistry;
+use serde::de::DeserializeSeed;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The problems found while deserializing a scene with a [`LenientSceneDeserializer`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SceneReport {
+    pub issues: Vec<SceneIssue>,
+}
+
+impl SceneReport {
+    /// Returns true if no problems were found.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for SceneReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A value skipped while deserializing a scene.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneIssue {
+    /// The scene entity the value belongs to, or `None` for resources.
+    pub entity: Option<Entity>,
+    /// The type path of the component or resource.
+    pub type_path: String,
+    /// The path of the skipped field within the component or resource,
+    /// or `None` if the whole value was skipped.
+    pub field_path: Option<String>,
+    pub reason: SceneIssueReason,
+}
+
+impl Display for SceneIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.entity {
+            Some(entity) => write!(f, "entity {}: ", entity.index())?,
+            None => write!(f, "resources: ")?,
+        }
+        write!(f, "`{}`", self.type_path)?;
+        if let Some(field_path) = &self.field_path {
+            write!(f, " field `{field_path}`")?;
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SceneIssueReason {
+    #[error("the type is not registered")]
+    UnregisteredType,
+    #[error("the type does not reflect `Component`")]
+    NotAComponent,
+    #[error("the type does not reflect `Resource`")]
+    NotAResource,
+    #[error("the field does not exist")]
+    UnknownField,
+}
+
+/// The scene files checked by [`validate_scene`].
+#[derive(Debug, Default)]
+pub struct SceneValidation {
+    /// The report of each scene file which could be parsed.
+    ///
+    /// Scenes in the [short format](ShortSceneDeserializer) are checked strictly, so their
+    /// report is always empty and any problem is found in [`errors`](Self::errors) instead.
+    pub reports: Vec<(PathBuf, SceneReport)>,
+    /// The scene files which could not be parsed at all.
+    pub errors: Vec<(PathBuf, ron::error::SpannedError)>,
+}
+
+impl SceneValidation {
+    /// Returns true if every scene file was parsed without any problem.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.reports.iter().all(|(_, report)| report.is_empty())
+    }
+}
+
+impl Display for SceneValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (path, error) in &self.errors {
+            writeln!(f, "{}: {error}", path.display())?;
+        }
+        for (path, report) in &self.reports {
+            for issue in &report.issues {
+                writeln!(f, "{}: {issue}", path.display())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks the scene files at the given path against the type registry.
+///
+/// The path can either be a single file, or a folder whose `.scn` and `.scn.ron` files
+/// are checked recursively. Files ending with `.short.scn.ron` are read in the
+/// [short format](ShortSceneDeserializer), like [`SceneLoader`](crate::SceneLoader) does.
+/// This is meant to catch scenes broken by renamed or removed types
+/// from tests and CI:
+///
+/// ```no_run
+/// # use bevy_reflect::TypeRegistry;
+/// # use bevy_scene::validate_scene;
+/// let registry = TypeRegistry::default();
+/// let validation = validate_scene("assets/scenes", &registry).unwrap();
+/// assert!(validation.is_valid(), "{validation}");
+/// ```
+pub fn validate_scene(
+    path: impl AsRef<Path>,
+    type_registry: &TypeRegistry,
+) -> std::io::Result<SceneValidation> {
+    let mut validation = SceneValidation::default();
+    let path = path.as_ref();
+    if path.is_dir() {
+        validate_folder(path, type_registry, &mut validation)?;
+    } else {
+        validate_file(path, type_registry, &mut validation)?;
+    }
+    Ok(validation)
+}
+
+fn validate_folder(
+    path: &Path,
+    type_registry: &TypeRegistry,
+    validation: &mut SceneValidation,
+) -> std::io::Result<()> {
+    let mut entries = std::fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+
+    for entry in entries {
+        if entry.is_dir() {
+            validate_folder(&entry, type_registry, validation)?;
+        } else if entry
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(".scn") || name.ends_with(".scn.ron"))
+        {
+            validate_file(&entry, type_registry, validation)?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_file(
+    path: &Path,
+    type_registry: &TypeRegistry,
+    validation: &mut SceneValidation,
+) -> std::io::Result<()> {
+    let bytes = std::fs::read(path)?;
+    let is_short = path
+        .to_string_lossy()
+        .to_lowercase()
+        .ends_with(".short.scn.ron");
+    let mut report = SceneReport::default();
+    let result = ron::de::Deserializer::from_bytes(&bytes).and_then(|mut deserializer| {
+        if is_short {
+            ShortSceneDeserializer { type_registry }.deserialize(&mut deserializer)
+        } else {
+            LenientSceneDeserializer {
+                type_registry,
+                report: &mut report,
+            }
+            .deserialize(&mut deserializer)
+        }
+        .map_err(|error| deserializer.span_error(error))
+    });
+
+    match result {
+        Ok(_) => validation.reports.push((path.to_path_buf(), report)),
+        Err(error) => validation.errors.push((path.to_path_buf(), error)),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::{Component, ReflectComponent};
+    use bevy_reflect::{Reflect, TypeRegistry};
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    /// A temporary directory, which is removed when dropped.
+    struct TempDirectory(PathBuf);
+
+    impl Drop for TempDirectory {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn should_validate_scene_folder() {
+        let directory = TempDirectory(
+            std::env::temp_dir().join(format!("bevy_scene_validate_{}", Uuid::new_v4())),
+        );
+        let folder = &directory.0;
+        std::fs::create_dir_all(folder.join("nested")).unwrap();
+        let write =
+            |name: &str, contents: &str| std::fs::write(folder.join(name), contents).unwrap();
+        write(
+            "valid.scn.ron",
+            r#"(resources: {}, entities: {0: (components: {"bevy_scene::validation::tests::Health": (10)})})"#,
+        );
+        write(
+            "nested/outdated.scn.ron",
+            r#"(resources: {}, entities: {3: (components: {"game::Mana": (10)})})"#,
+        );
+        write(
+            "valid.short.scn.ron",
+            r#"(entities: {0: (components: {"Health": (10)})})"#,
+        );
+        write(
+            "outdated.short.scn.ron",
+            r#"(entities: {1: (components: {"Mana": (10)})})"#,
+        );
+        write("malformed.scn", "(resources: {}, entities:");
+        write("notes.txt", "not a scene");
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+        let validation = validate_scene(folder, &registry).unwrap();
+
+        assert!(!validation.is_valid());
+        assert_eq!(2, validation.errors.len());
+        assert_eq!(folder.join("malformed.scn"), validation.errors[0].0);
+        assert_eq!(
+            folder.join("outdated.short.scn.ron"),
+            validation.errors[1].0
+        );
+
+        assert_eq!(3, validation.reports.len());
+        let (path, report) = &validation.reports[0];
+        assert_eq!(&folder.join("nested/outdated.scn.ron"), path);
+        assert_eq!(
+            vec![SceneIssue {
+                entity: Some(Entity::from_raw(3)),
+                type_path: "game::Mana".to_string(),
+                field_path: None,
+                reason: SceneIssueReason::UnregisteredType,
+            }],
+            report.issues
+        );
+        let (path, report) = &validation.reports[1];
+        assert_eq!(&folder.join("valid.scn.ron"), path);
+        assert!(report.is_empty());
+        let (path, report) = &validation.reports[2];
+        assert_eq!(&folder.join("valid.short.scn.ron"), path);
+        assert!(report.is_empty());
+    }
+}
@@ -1,7 +1,8 @@
 use crate::Font;
 use anyhow::Result;
-use bevy_asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy_asset::{AssetLoader, AsyncReadExt, LoadContext, LoadedAsset, Reader};
 use bevy_utils::BoxedFuture;
+use std::any::TypeId;
 
 #[derive(Default)]
 pub struct FontLoader;
@@ -9,11 +10,13 @@ pub struct FontLoader;
 impl AssetLoader for FontLoader {
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
         Box::pin(async move {
-            let font = Font::try_from_bytes(bytes.into())?;
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let font = Font::try_from_bytes(bytes)?;
             load_context.set_default_asset(LoadedAsset::new(font));
             Ok(())
         })
@@ -22,4 +25,8 @@ impl AssetLoader for FontLoader {
     fn extensions(&self) -> &[&str] {
         &["ttf", "otf"]
     }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<Font>())
+    }
 }
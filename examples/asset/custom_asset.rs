@@ -1,12 +1,13 @@
 //! Implements loader for a custom asset type.
 
 use bevy::{
-    asset::{AssetLoader, LoadContext, LoadedAsset},
+    asset::{AssetLoader, AsyncReadExt, LoadContext, LoadedAsset, Reader},
     prelude::*,
     reflect::{TypePath, TypeUuid},
     utils::BoxedFuture,
 };
 use serde::Deserialize;
+use std::any::TypeId;
 
 #[derive(Debug, Deserialize, TypeUuid, TypePath)]
 #[uuid = "39cadc56-aa9c-4543-8640-a018b74b5052"]
@@ -20,11 +21,13 @@ pub struct CustomAssetLoader;
 impl AssetLoader for CustomAssetLoader {
     fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
+        reader: &'a mut Reader,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
         Box::pin(async move {
-            let custom_asset = ron::de::from_bytes::<CustomAsset>(bytes)?;
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let custom_asset = ron::de::from_bytes::<CustomAsset>(&bytes)?;
             load_context.set_default_asset(LoadedAsset::new(custom_asset));
             Ok(())
         })
@@ -33,6 +36,10 @@ impl AssetLoader for CustomAssetLoader {
     fn extensions(&self) -> &[&str] {
         &["custom"]
     }
+
+    fn asset_type_id(&self) -> Option<TypeId> {
+        Some(TypeId::of::<CustomAsset>())
+    }
 }
 
 fn main() {
@@ -3,7 +3,7 @@
 //! It does not know anything about the asset formats, only how to talk to the underlying storage.
 
 use bevy::{
-    asset::{AssetIo, AssetIoError, ChangeWatcher, Metadata},
+    asset::{AssetIo, AssetIoError, ChangeWatcher, Metadata, Reader},
     prelude::*,
     utils::BoxedFuture,
 };
@@ -22,6 +22,14 @@ impl AssetIo for CustomAssetIo {
         self.0.load_path(path)
     }
 
+    fn read_path<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetIoError>> {
+        info!("read_path({path:?})");
+        self.0.read_path(path)
+    }
+
     fn read_directory(
         &self,
         path: &Path,